@@ -21,6 +21,9 @@
 //!
 //! [yew-and-bulma]: https://crates.io/crates/yew-and-bulma
 //! [proc-macro]: https://doc.rust-lang.org/reference/procedural-macros.html
+// `nightly_error_messages` is only set when building on nightly, and the
+// `unexpected_cfgs` lint is unknown to toolchains as old as the MSRV.
+#![allow(unknown_lints, unexpected_cfgs)]
 #![cfg_attr(nightly_error_messages, feature(rustc_attrs))]
 #![forbid(unsafe_code)]
 
@@ -107,10 +107,12 @@ pub struct ColumnsProperties {
     /// [bd]: https://bulma.io/documentation/columns/gap/#variable-gap
     #[prop_or_default]
     pub gap_size: Option<GapSize>,
-    /// Sets the size of the [Bulma column element][bd] for a viewport.
+    /// Sets the gap size between the columns for a viewport.
     ///
-    /// Sets the size for a viewport of the [Bulma column element][bd] which
-    /// will receive these properties.
+    /// Sets the gap size between the columns inside the
+    /// [Bulma columns element][bd], which will receive these properties, for
+    /// a viewport (ie `is-variable is-3-mobile is-0-desktop`). Can be
+    /// combined with `gap_size`, which then applies to the other viewports.
     ///
     /// # Examples
     ///
@@ -126,10 +128,11 @@ pub struct ColumnsProperties {
     /// #[function_component(App)]
     /// fn app() -> Html {
     ///     let mut viewport_gap_sizes = HashMap::new();
-    ///     viewport_gap_sizes.insert(Viewport::Mobile, GapSize::Five);
+    ///     viewport_gap_sizes.insert(Viewport::Mobile, GapSize::Three);
+    ///     viewport_gap_sizes.insert(Viewport::Desktop, GapSize::Zero);
     ///
     ///     html! {
-    ///         <Columns {viewport_gap_sizes}>
+    ///         <Columns gap_size={GapSize::Two} {viewport_gap_sizes}>
     ///             <Column>
     ///                 {"First column"}
     ///             </Column>
@@ -265,12 +268,75 @@ pub struct ColumnsProperties {
     /// [bd]: https://bulma.io/documentation/columns/gap/#gapless
     #[prop_or_default]
     pub multiline: bool,
+    /// The list of items to be wrapped in [column elements][bd].
+    ///
+    /// Defines a list of items, each of which will be wrapped in a
+    /// [`crate::columns::Column`] and rendered after the children of the
+    /// [Bulma columns element][bd] which will receive these properties. Useful
+    /// when rendering dynamic data, avoiding the need to wrap each item by
+    /// hand.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::columns::Columns;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let items = ["First column", "Second column"]
+    ///         .into_iter()
+    ///         .map(|text| html! { {text} })
+    ///         .collect::<Vec<_>>();
+    ///
+    ///     html! {
+    ///         <Columns {items} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/columns/basics
+    #[prop_or_default]
+    pub items: Vec<Html>,
+    /// Sets the number of [column elements][bd] generated from `items` found
+    /// on each row.
+    ///
+    /// Sets the number of columns generated from the `items` property which
+    /// should be found on each row of the [Bulma columns element][bd] which
+    /// will receive these properties. Setting it makes the columns multiline
+    /// and sizes each generated column accordingly, if there is a
+    /// [Bulma column size][sizes] matching the number of items per row (ie
+    /// 1, 2, 3, 4, 5, 6 or 12).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::columns::Columns;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let items = (1..=6)
+    ///         .map(|i| html! { {format!("Column {i}")} })
+    ///         .collect::<Vec<_>>();
+    ///
+    ///     html! {
+    ///         <Columns {items} per_row={3} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/columns/basics
+    /// [sizes]: https://bulma.io/documentation/columns/sizes/
+    #[prop_or_default]
+    pub per_row: Option<usize>,
     /// The list of elements found inside the [columns element][bd].
     ///
     /// Defines the elements that will be found inside the
     /// [Bulma columns element][bd] which will receive these properties.
     ///
     /// [bd]: https://bulma.io/documentation/columns/basics
+    #[prop_or_default]
     pub children: ChildrenRenderer<ColumnsItem>,
 }
 
@@ -417,20 +483,23 @@ pub fn columns(props: &ColumnsProperties) -> Html {
         .as_ref()
         .map(|viewport| format!("{IS_PREFIX}-{viewport}"))
         .unwrap_or("".to_owned());
-    let multiline = if props.multiline { "is-multiline" } else { "" };
+    let multiline = if props.multiline || props.per_row.is_some() {
+        "is-multiline"
+    } else {
+        ""
+    };
     let gapless = if props.gapless { "is-gapless" } else { "" };
     let gap_size = props
         .gap_size
         .as_ref()
         .map(|gap_size| format!("{IS_PREFIX}-{gap_size}"))
         .unwrap_or("".to_owned());
-    let mut viewport_gap_sizes = String::new();
-    props
+    let viewport_gap_sizes = props
         .viewport_gap_sizes
         .iter()
-        .for_each(|(viewport, gap_size)| {
-            viewport_gap_sizes.push_str(&format!("{IS_PREFIX}-{gap_size}-{viewport}"))
-        });
+        .map(|(viewport, gap_size)| viewport_gap_class(gap_size, viewport))
+        .collect::<Vec<_>>()
+        .join(" ");
     let is_variable = if gap_size.is_empty() && viewport_gap_sizes.is_empty() {
         ""
     } else {
@@ -476,6 +545,9 @@ pub fn columns(props: &ColumnsProperties) -> Html {
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
             ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
             { for props.children.iter() }
+            { for props.items.iter().map(|item| html! {
+                <Column size={props.per_row.and_then(Size::per_row)}>{item.clone()}</Column>
+            }) }
         </div>
     }
 }
@@ -535,6 +607,21 @@ pub enum Size {
     Twelve,
 }
 
+impl Size {
+    fn per_row(per_row: usize) -> Option<Self> {
+        match per_row {
+            1 => Some(Size::Full),
+            2 => Some(Size::Half),
+            3 => Some(Size::OneThird),
+            4 => Some(Size::OneQuarter),
+            5 => Some(Size::OneFifth),
+            6 => Some(Size::Two),
+            12 => Some(Size::One),
+            _ => None,
+        }
+    }
+}
+
 impl Display for Size {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let size = match self {
@@ -661,6 +748,161 @@ pub struct ColumnProperties {
     /// [bd]: https://bulma.io/documentation/columns/responsiveness/#different-column-sizes-per-breakpoint
     #[prop_or_default]
     pub viewport_sizes: HashMap<Viewport, Size>,
+    /// Sets the size of the [Bulma column element][bd] on mobile.
+    ///
+    /// Sets the size of the [Bulma column element][bd], which will receive
+    /// these properties, for the `mobile` viewport. Takes precedence over the
+    /// size set for the same viewport through `viewport_sizes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::columns::{Column, Columns, Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Columns>
+    ///             <Column size_mobile={Size::Half}>
+    ///                 {"First column"}
+    ///             </Column>
+    ///
+    ///             <Column>
+    ///                 {"Second column"}
+    ///             </Column>
+    ///         </Columns>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/columns/responsiveness/#different-column-sizes-per-breakpoint
+    #[prop_or_default]
+    pub size_mobile: Option<Size>,
+    /// Sets the size of the [Bulma column element][bd] on tablet and up.
+    ///
+    /// Sets the size of the [Bulma column element][bd], which will receive
+    /// these properties, for the `tablet` viewport. Takes precedence over the
+    /// size set for the same viewport through `viewport_sizes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::columns::{Column, Columns, Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Columns>
+    ///             <Column size_tablet={Size::Half}>
+    ///                 {"First column"}
+    ///             </Column>
+    ///
+    ///             <Column>
+    ///                 {"Second column"}
+    ///             </Column>
+    ///         </Columns>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/columns/responsiveness/#different-column-sizes-per-breakpoint
+    #[prop_or_default]
+    pub size_tablet: Option<Size>,
+    /// Sets the size of the [Bulma column element][bd] on desktop and up.
+    ///
+    /// Sets the size of the [Bulma column element][bd], which will receive
+    /// these properties, for the `desktop` viewport. Takes precedence over the
+    /// size set for the same viewport through `viewport_sizes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::columns::{Column, Columns, Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Columns>
+    ///             <Column size_desktop={Size::Half}>
+    ///                 {"First column"}
+    ///             </Column>
+    ///
+    ///             <Column>
+    ///                 {"Second column"}
+    ///             </Column>
+    ///         </Columns>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/columns/responsiveness/#different-column-sizes-per-breakpoint
+    #[prop_or_default]
+    pub size_desktop: Option<Size>,
+    /// Sets the size of the [Bulma column element][bd] on widescreen and up.
+    ///
+    /// Sets the size of the [Bulma column element][bd], which will receive
+    /// these properties, for the `widescreen` viewport. Takes precedence over the
+    /// size set for the same viewport through `viewport_sizes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::columns::{Column, Columns, Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Columns>
+    ///             <Column size_widescreen={Size::Half}>
+    ///                 {"First column"}
+    ///             </Column>
+    ///
+    ///             <Column>
+    ///                 {"Second column"}
+    ///             </Column>
+    ///         </Columns>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/columns/responsiveness/#different-column-sizes-per-breakpoint
+    #[prop_or_default]
+    pub size_widescreen: Option<Size>,
+    /// Sets the size of the [Bulma column element][bd] on FullHD.
+    ///
+    /// Sets the size of the [Bulma column element][bd], which will receive
+    /// these properties, for the `fullhd` viewport. Takes precedence over the
+    /// size set for the same viewport through `viewport_sizes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::columns::{Column, Columns, Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Columns>
+    ///             <Column size_fullhd={Size::Half}>
+    ///                 {"First column"}
+    ///             </Column>
+    ///
+    ///             <Column>
+    ///                 {"Second column"}
+    ///             </Column>
+    ///         </Columns>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/columns/responsiveness/#different-column-sizes-per-breakpoint
+    #[prop_or_default]
+    pub size_fullhd: Option<Size>,
     /// Sets the offset of the [Bulma column element][bd].
     ///
     /// Sets the offset of the [Bulma column element][bd] which will receive
@@ -795,21 +1037,37 @@ pub fn column(props: &ColumnProperties) -> Html {
         .as_ref()
         .map(|size| format!("{IS_PREFIX}-{size}"))
         .unwrap_or("".to_owned());
-    let mut viewport_sizes = String::new();
-    props.viewport_sizes.iter().for_each(|(size, viewport)| {
-        viewport_sizes.push_str(&format!("{IS_PREFIX}-{size}-{viewport}"))
+    let mut sizes: HashMap<&Viewport, &Size> = props.viewport_sizes.iter().collect();
+    [
+        (&Viewport::Mobile, &props.size_mobile),
+        (&Viewport::Tablet, &props.size_tablet),
+        (&Viewport::Desktop, &props.size_desktop),
+        (&Viewport::Widescreen, &props.size_widescreen),
+        (&Viewport::FullHD, &props.size_fullhd),
+    ]
+    .into_iter()
+    .for_each(|(viewport, size)| {
+        if let Some(size) = size {
+            sizes.insert(viewport, size);
+        }
     });
+    let viewport_sizes = sizes
+        .into_iter()
+        .map(|(viewport, size)| viewport_size_class(size, viewport))
+        .collect::<Vec<_>>()
+        .join(" ");
     let offset = props
         .offset
         .as_ref()
         .map(|offset| format!("{IS_OFFSET_PREFIX}-{offset}"))
         .unwrap_or("".to_owned());
     let narrow = if props.narrow { IS_NARROW } else { "" };
-    let mut narrow_viewports = String::new();
-    props
+    let narrow_viewports = props
         .narrow_viewports
         .iter()
-        .for_each(|viewport| narrow_viewports.push_str(&format!("{IS_NARROW}-{viewport}")));
+        .map(|viewport| format!("{IS_NARROW}-{viewport}"))
+        .collect::<Vec<_>>()
+        .join(" ");
     let class = ClassBuilder::default()
         .with_custom_class("column")
         .with_custom_class(
@@ -844,3 +1102,34 @@ pub fn column(props: &ColumnProperties) -> Html {
         </div>
     }
 }
+
+fn viewport_gap_class(gap_size: &GapSize, viewport: &Viewport) -> String {
+    format!("{IS_PREFIX}-{gap_size}-{viewport}")
+}
+
+fn viewport_size_class(size: &Size, viewport: &Viewport) -> String {
+    format!("{IS_PREFIX}-{size}-{viewport}")
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(GapSize::Three, Viewport::Mobile => "is-3-mobile" ; "three on mobile")]
+    #[test_case(GapSize::Zero, Viewport::Desktop => "is-0-desktop" ; "zero on desktop")]
+    fn viewport_gap_class_should_put_the_viewport_last(
+        gap_size: GapSize,
+        viewport: Viewport,
+    ) -> String {
+        viewport_gap_class(&gap_size, &viewport)
+    }
+
+    #[test_case(Size::Half, Viewport::Mobile => "is-half-mobile" ; "half on mobile")]
+    #[test_case(Size::Three, Viewport::Desktop => "is-3-desktop" ; "three on desktop")]
+    #[test_case(Size::OneQuarter, Viewport::FullHD => "is-one-quarter-fullhd" ; "one quarter on fullhd")]
+    fn viewport_size_class_should_put_the_viewport_last(size: Size, viewport: Viewport) -> String {
+        viewport_size_class(&size, &viewport)
+    }
+}
@@ -0,0 +1,279 @@
+use web_sys::{Element, HtmlInputElement, HtmlMediaElement};
+use yew::{
+    classes, function_component, html, use_node_ref, use_state, AttrValue, Callback, Event, Html,
+    InputEvent, MouseEvent, Properties, TargetCast,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::{
+        button::{Button, Style},
+        progress::ProgressBar,
+    },
+    helpers::color::Color,
+    utils::size::Size,
+};
+
+/// Defines the properties of the audio player.
+///
+/// Defines the properties of the audio player, wrapping a native `audio`
+/// element with Bulma styled controls: a play/pause [button][button], a
+/// [progress bar][progress] used to seek and a volume slider.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::audio::AudioPlayer;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <AudioPlayer src="media/audio/episode-1.mp3" />
+///     }
+/// }
+/// ```
+///
+/// [button]: https://bulma.io/documentation/elements/button/
+/// [progress]: https://bulma.io/documentation/elements/progress/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct AudioPlayerProperties {
+    /// Sets the source of the audio player.
+    pub src: AttrValue,
+    /// Sets the color of the controls of the audio player.
+    ///
+    /// Sets the color of the play/pause button and of the progress bar of the
+    /// audio player which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::audio::AudioPlayer, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <AudioPlayer src="media/audio/episode-1.mp3" color={Color::Primary} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the text of the play button. Defaults to `▶`.
+    #[prop_or(AttrValue::from("▶"))]
+    pub play_label: AttrValue,
+    /// Sets the text of the pause button. Defaults to `❚❚`.
+    #[prop_or(AttrValue::from("❚❚"))]
+    pub pause_label: AttrValue,
+    /// Sets the initial volume of the audio player, between `0.0` and `1.0`.
+    /// Defaults to `1.0`.
+    #[prop_or(1.0)]
+    pub volume: f64,
+}
+
+/// Yew implementation of the audio player.
+///
+/// Renders a hidden native `audio` element, driven by its media events
+/// (which are still forwarded to the callbacks set on the player), next to
+/// a play/pause [Bulma button][button], the elapsed and total time, a
+/// [Bulma progress bar][progress] which seeks when clicked and a volume
+/// slider.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::audio::AudioPlayer;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let onended = Callback::from(|_| ());
+///
+///     html! {
+///         <AudioPlayer src="media/audio/episode-1.mp3" {onended} />
+///     }
+/// }
+/// ```
+///
+/// [button]: https://bulma.io/documentation/elements/button/
+/// [progress]: https://bulma.io/documentation/elements/progress/
+#[function_component(AudioPlayer)]
+pub fn audio_player(props: &AudioPlayerProperties) -> Html {
+    let node = use_node_ref();
+    let playing = use_state(|| false);
+    let time = use_state(|| 0.0);
+    let duration = use_state(|| 0.0);
+    let volume = use_state(|| props.volume.clamp(0.0, 1.0));
+    let media = {
+        let node = node.clone();
+        move || node.cast::<HtmlMediaElement>()
+    };
+    let forward = |callback: &Option<Callback<Event>>, update: Callback<HtmlMediaElement>| {
+        let callback = callback.clone();
+        let media = media.clone();
+        Callback::from(move |event: Event| {
+            if let Some(media) = media() {
+                update.emit(media);
+            }
+            if let Some(callback) = &callback {
+                callback.emit(event);
+            }
+        })
+    };
+
+    let onplay = {
+        let playing = playing.clone();
+        forward(&props.onplay, Callback::from(move |_| playing.set(true)))
+    };
+    let onpause = {
+        let playing = playing.clone();
+        forward(&props.onpause, Callback::from(move |_| playing.set(false)))
+    };
+    let onended = {
+        let playing = playing.clone();
+        forward(&props.onended, Callback::from(move |_| playing.set(false)))
+    };
+    let ontimeupdate = {
+        let time = time.clone();
+        forward(
+            &props.ontimeupdate,
+            Callback::from(move |media: HtmlMediaElement| time.set(media.current_time())),
+        )
+    };
+    let onloadedmetadata = {
+        let duration = duration.clone();
+        let volume = *volume;
+        forward(
+            &props.onloadedmetadata,
+            Callback::from(move |media: HtmlMediaElement| {
+                media.set_volume(volume);
+                duration.set(finite(media.duration()));
+            }),
+        )
+    };
+    let ondurationchange = {
+        let duration = duration.clone();
+        forward(
+            &props.ondurationchange,
+            Callback::from(move |media: HtmlMediaElement| duration.set(finite(media.duration()))),
+        )
+    };
+    let ontoggle = {
+        let media = media.clone();
+        let playing = *playing;
+        Callback::from(move |_: MouseEvent| {
+            if let Some(media) = media() {
+                if playing {
+                    let _ = media.pause();
+                } else {
+                    let _ = media.play();
+                }
+            }
+        })
+    };
+    let onseek = {
+        let media = media.clone();
+        let duration = *duration;
+        Callback::from(move |event: MouseEvent| {
+            let width = event
+                .target_dyn_into::<Element>()
+                .map(|element| element.get_bounding_client_rect().width())
+                .unwrap_or_default();
+            if let (Some(media), Some(time)) = (
+                media(),
+                seek_time(f64::from(event.offset_x()), width, duration),
+            ) {
+                media.set_current_time(time);
+            }
+        })
+    };
+    let onvolume = {
+        let volume = volume.clone();
+        Callback::from(move |event: InputEvent| {
+            let input: HtmlInputElement = event.target_unchecked_into();
+            let value = input.value().parse::<f64>().unwrap_or(1.0).clamp(0.0, 1.0);
+            if let Some(media) = media() {
+                media.set_volume(value);
+            }
+            volume.set(value);
+        })
+    };
+
+    html! {
+        <div id={props.id.clone()} class={props.class.clone()}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}>
+            <audio ref={node} src={props.src.clone()} preload="metadata"
+                {onplay} {onpause} {onended} {ontimeupdate} {onloadedmetadata} {ondurationchange}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                onemptied={props.onemptied.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadstart={props.onloadstart.clone()}
+                onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}></audio>
+            <div class="is-flex is-align-items-center">
+                <Button color={props.color} style={Style::Rounded} onclick={ontoggle}>
+                    { if *playing { props.pause_label.clone() } else { props.play_label.clone() } }
+                </Button>
+                <span class="mx-3 is-family-monospace">
+                    {format!("{} / {}", format_time(*time), format_time(*duration))}
+                </span>
+                <ProgressBar class={classes!("mb-0", "is-clickable")} color={props.color} size={Size::Small}
+                    value={*time} max={if *duration > 0.0 { *duration } else { 100.0 }} onclick={onseek} />
+                <input class="ml-3" type="range" min="0" max="1" step="0.05" aria-label="Volume"
+                    value={volume.to_string()} oninput={onvolume} style="width: 6rem;" />
+            </div>
+        </div>
+    }
+}
+
+fn finite(value: f64) -> f64 {
+    if value.is_finite() {
+        value
+    } else {
+        0.0
+    }
+}
+
+fn seek_time(offset: f64, width: f64, duration: f64) -> Option<f64> {
+    (width > 0.0 && duration > 0.0).then(|| (offset / width).clamp(0.0, 1.0) * duration)
+}
+
+fn format_time(seconds: f64) -> String {
+    let seconds = seconds.max(0.0).floor() as u64;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(50.0, 200.0, 60.0, Some(15.0) ; "offsets are relative to the width")]
+    #[test_case(250.0, 200.0, 60.0, Some(60.0) ; "offsets after the end are clamped")]
+    #[test_case(50.0, 0.0, 60.0, None ; "empty widths do not seek")]
+    #[test_case(50.0, 200.0, 0.0, None ; "unknown durations do not seek")]
+    fn seek_time_values(offset: f64, width: f64, duration: f64, expected: Option<f64>) {
+        assert_eq!(seek_time(offset, width, duration), expected);
+    }
+
+    #[test_case(0.0, "0:00" ; "zero")]
+    #[test_case(65.7, "1:05" ; "partial seconds are truncated")]
+    #[test_case(3_725.0, "1:02:05" ; "hours are shown when needed")]
+    #[test_case(-3.0, "0:00" ; "negative times are zero")]
+    fn format_time_values(seconds: f64, expected: &str) {
+        assert_eq!(format_time(seconds), expected);
+    }
+}
@@ -0,0 +1,514 @@
+use yew::{
+    function_component, html, use_context, use_state, AttrValue, Callback, Children,
+    ChildrenWithProps, ContextProvider, DragEvent, Html, Properties, UseStateHandle,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::{
+        r#box::Box,
+        title::{Size, Title},
+    },
+    utils::class::ClassBuilder,
+};
+
+/// Describes the movement of a card between the columns of a board.
+///
+/// Holds the name of the [`crate::components::board::BoardCard`] which was
+/// moved, along with the names of the [`crate::components::board::BoardColumn`]
+/// it was moved from and to.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::board::{Board, CardMove};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let oncardmove = Callback::from(|card_move: CardMove| {
+///         let CardMove { card, from, to } = card_move;
+///     });
+///
+///     html! {
+///         <Board {oncardmove} />
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardMove {
+    /// The name of the card that was moved.
+    pub card: AttrValue,
+    /// The name of the column the card was moved from.
+    pub from: AttrValue,
+    /// The name of the column the card was moved to.
+    pub to: AttrValue,
+}
+
+#[derive(Clone, PartialEq)]
+struct BoardContext {
+    dragged: UseStateHandle<Option<(AttrValue, AttrValue)>>,
+    oncardmove: Option<Callback<CardMove>>,
+}
+
+#[derive(Clone, PartialEq)]
+struct BoardColumnContext {
+    name: AttrValue,
+}
+
+/// Defines the properties of the board.
+///
+/// Defines the properties of the board, a set of
+/// [Bulma columns][bd] holding cards which can be dragged between them.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::board::{Board, BoardCard, BoardColumn};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Board>
+///             <BoardColumn name="todo" title="To do">
+///                 <BoardCard name="1">{"Write docs"}</BoardCard>
+///             </BoardColumn>
+///             <BoardColumn name="done" title="Done" />
+///         </Board>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/columns/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct BoardProperties {
+    /// The callback to be used when a card is moved.
+    ///
+    /// Defines the callback called when a card of the board, which will
+    /// receive these properties, is dropped on a different column. The board
+    /// does not move the card by itself, the data it is rendered from should
+    /// be updated instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::board::{Board, BoardCard, BoardColumn, CardMove};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let done = use_state(|| false);
+    ///     let oncardmove = {
+    ///         let done = done.clone();
+    ///         Callback::from(move |card_move: CardMove| done.set(card_move.to == "done"))
+    ///     };
+    ///     let card = html_nested! { <BoardCard name="1">{"Write docs"}</BoardCard> };
+    ///     let (todo, finished) = if *done {
+    ///         (vec![], vec![card])
+    ///     } else {
+    ///         (vec![card], vec![])
+    ///     };
+    ///
+    ///     html! {
+    ///         <Board {oncardmove}>
+    ///             <BoardColumn name="todo" title="To do">{ for todo }</BoardColumn>
+    ///             <BoardColumn name="done" title="Done">{ for finished }</BoardColumn>
+    ///         </Board>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub oncardmove: Option<Callback<CardMove>>,
+    /// The list of columns found inside the board.
+    ///
+    /// Defines the columns that will be found inside the board which will
+    /// receive these properties.
+    #[prop_or_default]
+    pub children: ChildrenWithProps<BoardColumn>,
+}
+
+/// Yew implementation of a board.
+///
+/// Renders a Kanban style board, made up of [Bulma columns][columns], each
+/// holding cards rendered as [Bulma box elements][box] which can be dragged
+/// and dropped between the columns.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::board::{Board, BoardCard, BoardColumn};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Board>
+///             <BoardColumn name="todo" title="To do">
+///                 <BoardCard name="1">{"Write docs"}</BoardCard>
+///                 <BoardCard name="2">{"Write tests"}</BoardCard>
+///             </BoardColumn>
+///             <BoardColumn name="doing" title="Doing" />
+///             <BoardColumn name="done" title="Done" />
+///         </Board>
+///     }
+/// }
+/// ```
+///
+/// [box]: https://bulma.io/documentation/elements/box/
+/// [columns]: https://bulma.io/documentation/columns/
+#[function_component(Board)]
+pub fn board(props: &BoardProperties) -> Html {
+    let dragged = use_state(|| None);
+    let context = BoardContext {
+        dragged,
+        oncardmove: props.oncardmove.clone(),
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("columns")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<BoardContext> {context}>
+            <div id={props.id.clone()} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+            </div>
+        </ContextProvider<BoardContext>>
+    }
+}
+
+/// Defines the properties of a board column.
+///
+/// Defines the properties of a board column, a [Bulma column][bd] onto which
+/// the cards of a [`crate::components::board::Board`] can be dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::board::{Board, BoardCard, BoardColumn};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Board>
+///             <BoardColumn name="todo" title="To do">
+///                 <BoardCard name="1">{"Write docs"}</BoardCard>
+///             </BoardColumn>
+///         </Board>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/columns/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct BoardColumnProperties {
+    /// Sets the name of the board column.
+    ///
+    /// Sets the name identifying the board column which will receive these
+    /// properties, passed to the `oncardmove` callback of the
+    /// [`crate::components::board::Board`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::board::{Board, BoardColumn};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Board>
+    ///             <BoardColumn name="todo" />
+    ///         </Board>
+    ///     }
+    /// }
+    /// ```
+    pub name: AttrValue,
+    /// Sets the title of the board column.
+    ///
+    /// Sets the title displayed, as a [Bulma title][bd], above the cards of the
+    /// board column which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::board::{Board, BoardColumn};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Board>
+    ///             <BoardColumn name="todo" title="To do" />
+    ///         </Board>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/title/
+    #[prop_or_default]
+    pub title: Option<AttrValue>,
+    /// The list of cards found inside the board column.
+    ///
+    /// Defines the cards that will be found inside the board column which
+    /// will receive these properties.
+    #[prop_or_default]
+    pub children: ChildrenWithProps<BoardCard>,
+}
+
+/// Yew implementation of a board column.
+///
+/// Renders a [Bulma column][bd] holding an optional title and the cards of
+/// the column, accepting cards dropped from the other columns of the same
+/// [`crate::components::board::Board`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::board::{Board, BoardCard, BoardColumn};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Board>
+///             <BoardColumn name="todo" title="To do">
+///                 <BoardCard name="1">{"Write docs"}</BoardCard>
+///             </BoardColumn>
+///         </Board>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/columns/
+#[function_component(BoardColumn)]
+pub fn board_column(props: &BoardColumnProperties) -> Html {
+    let board = use_context::<BoardContext>();
+    let context = BoardColumnContext {
+        name: props.name.clone(),
+    };
+    let ondragover = {
+        let ondragover = props.ondragover.clone();
+        Callback::from(move |event: DragEvent| {
+            event.prevent_default();
+            if let Some(ondragover) = &ondragover {
+                ondragover.emit(event);
+            }
+        })
+    };
+    let ondrop = {
+        let ondrop = props.ondrop.clone();
+        let name = props.name.clone();
+        Callback::from(move |event: DragEvent| {
+            event.prevent_default();
+            if let Some(board) = &board {
+                if let Some((card, from)) = (*board.dragged).clone() {
+                    board.dragged.set(None);
+                    if let Some(oncardmove) = board.oncardmove.as_ref().filter(|_| from != name) {
+                        oncardmove.emit(CardMove {
+                            card,
+                            from,
+                            to: name.clone(),
+                        });
+                    }
+                }
+            }
+            if let Some(ondrop) = &ondrop {
+                ondrop.emit(event);
+            }
+        })
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("column")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<BoardColumnContext> {context}>
+            <div id={props.id.clone()} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} {ondragover} ondragstart={props.ondragstart.clone()} {ondrop}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                if let Some(title) = &props.title {
+                    <Title size={Size::Five}>{title}</Title>
+                }
+                { for props.children.iter() }
+            </div>
+        </ContextProvider<BoardColumnContext>>
+    }
+}
+
+/// Defines the properties of a board card.
+///
+/// Defines the properties of a board card, a draggable
+/// [Bulma box element][bd] found inside a
+/// [`crate::components::board::BoardColumn`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::board::{Board, BoardCard, BoardColumn};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Board>
+///             <BoardColumn name="todo">
+///                 <BoardCard name="1">{"Write docs"}</BoardCard>
+///             </BoardColumn>
+///         </Board>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/box/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct BoardCardProperties {
+    /// Sets the name of the board card.
+    ///
+    /// Sets the name identifying the board card which will receive these
+    /// properties, passed to the `oncardmove` callback of the
+    /// [`crate::components::board::Board`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::board::{Board, BoardCard, BoardColumn};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Board>
+    ///             <BoardColumn name="todo">
+    ///                 <BoardCard name="write-docs">{"Write docs"}</BoardCard>
+    ///             </BoardColumn>
+    ///         </Board>
+    ///     }
+    /// }
+    /// ```
+    pub name: AttrValue,
+    /// The list of elements found inside the board card.
+    ///
+    /// Defines the elements that will be found inside the board card which
+    /// will receive these properties.
+    pub children: Children,
+}
+
+/// Yew implementation of a board card.
+///
+/// Renders a draggable [Bulma box element][bd] which can be dropped on the
+/// other columns of the same [`crate::components::board::Board`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::board::{Board, BoardCard, BoardColumn};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Board>
+///             <BoardColumn name="todo">
+///                 <BoardCard name="1">{"Write docs"}</BoardCard>
+///             </BoardColumn>
+///         </Board>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/box/
+#[function_component(BoardCard)]
+pub fn board_card(props: &BoardCardProperties) -> Html {
+    let board = use_context::<BoardContext>();
+    let column = use_context::<BoardColumnContext>();
+    let ondragstart = {
+        let board = board.clone();
+        let ondragstart = props.ondragstart.clone();
+        let name = props.name.clone();
+        Callback::from(move |event: DragEvent| {
+            if let Some(data_transfer) = event.data_transfer() {
+                let _ = data_transfer.set_data("text/plain", &name);
+            }
+            if let (Some(board), Some(column)) = (&board, &column) {
+                board.dragged.set(Some((name.clone(), column.name.clone())));
+            }
+            if let Some(ondragstart) = &ondragstart {
+                ondragstart.emit(event);
+            }
+        })
+    };
+    // Dropping the card outside of a column, or cancelling the drag, does
+    // not fire a drop event, so the dragged card is forgotten here instead.
+    let ondragend = {
+        let ondragend = props.ondragend.clone();
+        Callback::from(move |event: DragEvent| {
+            if let Some(board) = &board {
+                board.dragged.set(None);
+            }
+            if let Some(ondragend) = &ondragend {
+                ondragend.emit(event);
+            }
+        })
+    };
+
+    html! {
+        <div draggable="true" {ondragstart} {ondragend}>
+            <Box id={props.id.clone()} class={props.class.clone()}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+            </Box>
+        </div>
+    }
+}
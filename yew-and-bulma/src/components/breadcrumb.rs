@@ -0,0 +1,457 @@
+use yew::{function_component, html, AttrValue, Callback, Html, MouseEvent, Properties};
+use yew_and_bulma_macros::base_component_properties;
+#[cfg(feature = "router")]
+use yew_router::{hooks::use_navigator, Routable};
+
+#[cfg(feature = "router")]
+use crate::utils::router::RouteTarget;
+use crate::{
+    elements::{button::Align, icon::Icon},
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the possible separators of the [Bulma breadcrumb component][bd].
+///
+/// Defines the separators which can be shown between the crumbs of the
+/// [Bulma breadcrumb component][bd], based on its documentation.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::breadcrumb::{Breadcrumb, Crumb, Separator};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let crumbs = vec![Crumb::new("/", "Home"), Crumb::new("/docs", "Docs").active()];
+///
+///     html! {
+///         <Breadcrumb {crumbs} separator={Separator::Arrow} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/breadcrumb/#alternative-separators
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Separator {
+    Arrow,
+    Bullet,
+    Dot,
+    Succeeds,
+}
+
+impl From<&Separator> for String {
+    fn from(value: &Separator) -> Self {
+        let separator = match value {
+            Separator::Arrow => "arrow",
+            Separator::Bullet => "bullet",
+            Separator::Dot => "dot",
+            Separator::Succeeds => "succeeds",
+        };
+
+        format!("has-{separator}-separator")
+    }
+}
+
+/// A link shown by a [`Breadcrumb`].
+///
+/// Defines a single link of the [Bulma breadcrumb component][bd], with its
+/// URL, label, optional icon and whether it is the current page.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::breadcrumb::{Breadcrumb, Crumb};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let crumbs = vec![
+///         Crumb::new("/", "Home").with_icon(html! { <i class="fas fa-home"></i> }),
+///         Crumb::new("/docs", "Docs").active(),
+///     ];
+///
+///     html! {
+///         <Breadcrumb {crumbs} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/breadcrumb/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Crumb {
+    pub href: AttrValue,
+    pub label: AttrValue,
+    pub active: bool,
+    pub icon: Option<Html>,
+    #[cfg(feature = "router")]
+    pub to: Option<RouteTarget>,
+}
+
+impl Crumb {
+    /// Creates an inactive crumb linking to the given URL.
+    pub fn new(href: impl Into<AttrValue>, label: impl Into<AttrValue>) -> Self {
+        Self {
+            href: href.into(),
+            label: label.into(),
+            active: false,
+            icon: None,
+            #[cfg(feature = "router")]
+            to: None,
+        }
+    }
+
+    /// Creates an inactive crumb navigating to the given route on the client
+    /// side. Only available with the `router` feature.
+    #[cfg(feature = "router")]
+    pub fn from_route<R: Routable + 'static>(route: R, label: impl Into<AttrValue>) -> Self {
+        let to = RouteTarget::new(route);
+        Self {
+            href: AttrValue::from(to.path().to_owned()),
+            to: Some(to),
+            ..Self::new("", label)
+        }
+    }
+
+    /// Marks the crumb as the current page.
+    pub fn active(mut self) -> Self {
+        self.active = true;
+        self
+    }
+
+    /// Shows the given icon before the label of the crumb.
+    pub fn with_icon(mut self, icon: Html) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+}
+
+/// Defines the properties of the [Bulma breadcrumb component][bd].
+///
+/// Defines the properties of the breadcrumb component, based on the
+/// specification found in the [Bulma breadcrumb component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::breadcrumb::{Breadcrumb, Crumb};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let crumbs = vec![Crumb::new("/", "Home"), Crumb::new("/docs", "Docs").active()];
+///
+///     html! {
+///         <Breadcrumb {crumbs} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/breadcrumb/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct BreadcrumbProperties {
+    /// The crumbs of the [Bulma breadcrumb component][bd], in order.
+    ///
+    /// Defines the links shown by the [Bulma breadcrumb component][bd]
+    /// which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/
+    #[prop_or_default]
+    pub crumbs: Vec<Crumb>,
+    /// Sets the alignment of the [Bulma breadcrumb component][bd].
+    ///
+    /// Sets the alignment of the crumbs of the [Bulma breadcrumb component][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::breadcrumb::{Breadcrumb, Crumb},
+    ///     elements::button::Align,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let crumbs = vec![Crumb::new("/", "Home")];
+    ///
+    ///     html! {
+    ///         <Breadcrumb {crumbs} align={Align::Center} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/#alignment
+    #[prop_or(Align::Left)]
+    pub align: Align,
+    /// Sets the separator of the [Bulma breadcrumb component][bd].
+    ///
+    /// Sets the separator shown between the crumbs of the
+    /// [Bulma breadcrumb component][bd] which will receive these properties.
+    /// Defaults to a slash.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/#alternative-separators
+    #[prop_or_default]
+    pub separator: Option<Separator>,
+    /// Sets the size of the [Bulma breadcrumb component][bd].
+    ///
+    /// Sets the size of the [Bulma breadcrumb component][bd] which will
+    /// receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Sets the callback used when a crumb is clicked.
+    ///
+    /// Sets the callback called with the index of the crumb clicked inside
+    /// the [Bulma breadcrumb component][bd] which will receive these
+    /// properties. When set, the browser does not follow the link, letting
+    /// a router navigate instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::breadcrumb::{Breadcrumb, Crumb};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let page = use_state(|| 1);
+    ///     let oncrumbclick = {
+    ///         let page = page.clone();
+    ///         Callback::from(move |index| page.set(index))
+    ///     };
+    ///     let crumbs = vec![Crumb::new("/", "Home"), Crumb::new("/docs", "Docs").active()];
+    ///
+    ///     html! {
+    ///         <Breadcrumb {crumbs} {oncrumbclick} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/
+    #[prop_or_default]
+    pub oncrumbclick: Option<Callback<usize>>,
+    /// Renders the crumbs as structured data as well.
+    ///
+    /// Renders a `<script type="application/ld+json">` containing a
+    /// [schema.org `BreadcrumbList`][sd] built from the crumbs, next to the
+    /// [Bulma breadcrumb component][bd] which will receive these properties.
+    /// Useful for server side rendered apps which want search engines to pick
+    /// up the trail.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::breadcrumb::{Breadcrumb, Crumb};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let crumbs = vec![Crumb::new("/", "Home"), Crumb::new("/docs", "Docs").active()];
+    ///
+    ///     html! {
+    ///         <Breadcrumb {crumbs} structured_data=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [sd]: https://schema.org/BreadcrumbList
+    /// [bd]: https://bulma.io/documentation/components/breadcrumb/
+    #[prop_or_default]
+    pub structured_data: bool,
+}
+
+/// Yew implementation of the [Bulma breadcrumb component][bd].
+///
+/// Yew implementation of the breadcrumb component, based on the
+/// specification found in the [Bulma breadcrumb component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::breadcrumb::{Breadcrumb, Crumb, Separator};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let crumbs = vec![
+///         Crumb::new("/", "Home"),
+///         Crumb::new("/docs", "Docs"),
+///         Crumb::new("/docs/breadcrumb", "Breadcrumb").active(),
+///     ];
+///
+///     html! {
+///         <Breadcrumb {crumbs} separator={Separator::Succeeds} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/breadcrumb/
+#[function_component(Breadcrumb)]
+pub fn breadcrumb(props: &BreadcrumbProperties) -> Html {
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| {
+            if Size::Normal == *size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let separator = props
+        .separator
+        .as_ref()
+        .map(String::from)
+        .unwrap_or_default();
+    let class = ClassBuilder::default()
+        .with_custom_class("breadcrumb")
+        .with_custom_class(&String::from(&props.align))
+        .with_custom_class(&separator)
+        .with_custom_class(&size)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    #[cfg(feature = "router")]
+    let navigator = use_navigator();
+    let crumbs = props.crumbs.iter().enumerate().map(|(index, crumb)| {
+        let onclick = props.oncrumbclick.clone().map(|oncrumbclick| {
+            Callback::from(move |event: MouseEvent| {
+                event.prevent_default();
+                oncrumbclick.emit(index);
+            })
+        });
+        #[cfg(feature = "router")]
+        let (href, onclick) = match (&crumb.to, &navigator) {
+            (Some(to), Some(navigator)) => (
+                to.href(navigator),
+                Some(to.onclick(navigator.clone(), onclick)),
+            ),
+            _ => (crumb.href.clone(), onclick),
+        };
+        #[cfg(not(feature = "router"))]
+        let href = crumb.href.clone();
+        let active = crumb.active.then(|| format!("{IS_PREFIX}-active"));
+        let current = crumb.active.then(|| AttrValue::from("page"));
+
+        html! {
+            <li class={active}>
+                <a {href} aria-current={current} {onclick}>
+                    if let Some(icon) = &crumb.icon {
+                        <Icon size={Size::Small} icon={icon.clone()} />
+                    }
+                    <span>{crumb.label.clone()}</span>
+                </a>
+            </li>
+        }
+    });
+
+    html! {
+        <nav id={props.id.clone()} {class} aria-label="breadcrumbs"
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <ul>
+                { for crumbs }
+            </ul>
+            if props.structured_data {
+                <script type="application/ld+json">{structured_data(&props.crumbs)}</script>
+            }
+        </nav>
+    }
+}
+
+/// Builds the JSON-LD `BreadcrumbList` describing the given crumbs.
+fn structured_data(crumbs: &[Crumb]) -> String {
+    let items = crumbs
+        .iter()
+        .enumerate()
+        .map(|(index, crumb)| {
+            format!(
+                r#"{{"@type":"ListItem","position":{},"name":{},"item":{}}}"#,
+                index + 1,
+                json_string(&crumb.label),
+                json_string(&crumb.href),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(
+        r#"{{"@context":"https://schema.org","@type":"BreadcrumbList","itemListElement":[{items}]}}"#
+    )
+}
+
+/// Quotes the value as a JSON string which is safe to embed in a `<script>`.
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            '<' => json.push_str("\\u003c"),
+            '>' => json.push_str("\\u003e"),
+            '&' => json.push_str("\\u0026"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("Home", r#""Home""# ; "plain")]
+    #[test_case(r#"Say "hi""#, r#""Say \"hi\"""# ; "quotes")]
+    #[test_case(r"C:\docs", r#""C:\\docs""# ; "backslash")]
+    #[test_case("</script>", r#""\u003c/script\u003e""# ; "script end tag")]
+    #[test_case("a\u{1}b", r#""a\u0001b""# ; "control character")]
+    fn json_string_should_escape(value: &str, expected: &str) {
+        assert_eq!(expected, json_string(value));
+    }
+
+    #[test]
+    fn structured_data_should_list_crumbs_in_order() {
+        let crumbs = vec![
+            Crumb::new("/", "Home"),
+            Crumb::new("/docs", "Docs").active(),
+        ];
+
+        assert_eq!(
+            concat!(
+                r#"{"@context":"https://schema.org","@type":"BreadcrumbList","itemListElement":["#,
+                r#"{"@type":"ListItem","position":1,"name":"Home","item":"/"},"#,
+                r#"{"@type":"ListItem","position":2,"name":"Docs","item":"/docs"}]}"#,
+            ),
+            structured_data(&crumbs)
+        );
+    }
+}
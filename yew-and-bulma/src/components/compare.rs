@@ -0,0 +1,321 @@
+use gloo::events::EventListener;
+use js_sys::wasm_bindgen::JsCast;
+use web_sys::Element;
+use yew::{
+    function_component, html, use_effect_with_deps, use_node_ref, use_state, AttrValue, Callback,
+    Html, KeyboardEvent, PointerEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::elements::image::{Figure, Size};
+
+/// Defines the properties of the image comparison slider.
+///
+/// Defines the properties of the image comparison slider, showing two images
+/// on top of each other inside a [Bulma figure element][bd], split by a
+/// divider which can be dragged to reveal more of either image (ie to compare
+/// a photo before and after being edited).
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::compare::ImageCompare;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ImageCompare before="media/images/before.png" after="media/images/after.png" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/image/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ImageCompareProperties {
+    /// Sets the source of the first image.
+    ///
+    /// Sets the `src` of the image shown on the left side of the divider of
+    /// the image comparison slider which will receive these properties.
+    pub before: AttrValue,
+    /// Sets the source of the second image.
+    ///
+    /// Sets the `src` of the image shown on the right side of the divider of
+    /// the image comparison slider which will receive these properties. It
+    /// also sets the dimensions of the slider, unless `size` is set.
+    pub after: AttrValue,
+    /// Sets the alternative text of the first image.
+    ///
+    /// Sets the `alt` of the image shown on the left side of the divider of
+    /// the image comparison slider which will receive these properties.
+    #[prop_or_default]
+    pub before_alt: Option<AttrValue>,
+    /// Sets the alternative text of the second image.
+    ///
+    /// Sets the `alt` of the image shown on the right side of the divider of
+    /// the image comparison slider which will receive these properties.
+    #[prop_or_default]
+    pub after_alt: Option<AttrValue>,
+    /// Sets the initial position of the divider.
+    ///
+    /// Sets the position of the divider of the image comparison slider, which
+    /// will receive these properties, when it is first rendered, as a
+    /// percentage of its width. Defaults to `50.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::compare::ImageCompare;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ImageCompare before="media/images/before.png" after="media/images/after.png"
+    ///             position={25.0} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(50.0)]
+    pub position: f64,
+    /// Sets the size of the image comparison slider.
+    ///
+    /// Sets the size of the [Bulma figure element][bd] holding the images of
+    /// the image comparison slider which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::compare::ImageCompare, elements::image::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ImageCompare before="media/images/before.png" after="media/images/after.png"
+    ///             size={Size::Ratio16x9} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/image/#fixed-square-images
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Sets the callback used when the divider is moved.
+    ///
+    /// Sets the callback called with the new position of the divider, as a
+    /// percentage of the width of the image comparison slider which will
+    /// receive these properties, whenever it is dragged or moved using the
+    /// keyboard.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::compare::ImageCompare;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let position = use_state(|| 50.0);
+    ///     let onpositionchange = {
+    ///         let position = position.clone();
+    ///         Callback::from(move |value| position.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <ImageCompare before="media/images/before.png" after="media/images/after.png"
+    ///             {onpositionchange} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onpositionchange: Option<Callback<f64>>,
+}
+
+/// Yew implementation of the image comparison slider.
+///
+/// Renders two images on top of each other inside a
+/// [Bulma figure element][bd], clipping the first one at the position of a
+/// divider. The divider follows the pointer while it is pressed anywhere on
+/// the images, and it can be focused and moved using the arrow keys (holding
+/// `Shift` for larger steps), `Home` and `End`.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::compare::ImageCompare;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ImageCompare before="media/images/before.png" after="media/images/after.png"
+///             before_alt="Original photo" after_alt="Edited photo" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/image/
+#[function_component(ImageCompare)]
+pub fn image_compare(props: &ImageCompareProperties) -> Html {
+    let node = use_node_ref();
+    let position = use_state(|| props.position.clamp(0.0, 100.0));
+    let dragging = use_state(|| false);
+    let set_position = {
+        let position = position.clone();
+        let onpositionchange = props.onpositionchange.clone();
+        Callback::from(move |value: f64| {
+            position.set(value);
+            if let Some(onpositionchange) = &onpositionchange {
+                onpositionchange.emit(value);
+            }
+        })
+    };
+    let position_at = {
+        let node = node.clone();
+        move |client_x: i32| {
+            node.cast::<Element>().map(|element| {
+                let rect = element.get_bounding_client_rect();
+                position_at(f64::from(client_x), rect.left(), rect.width())
+            })
+        }
+    };
+
+    {
+        let active = *dragging;
+        let dragging = dragging.clone();
+        let set_position = set_position.clone();
+        let position_at = position_at.clone();
+        use_effect_with_deps(
+            move |active| {
+                let listeners = active
+                    .then(web_sys::window)
+                    .flatten()
+                    .and_then(|window| window.document())
+                    .map(|document| {
+                        let onpointermove =
+                            EventListener::new(&document, "pointermove", move |event| {
+                                let position = event
+                                    .dyn_ref::<PointerEvent>()
+                                    .and_then(|event| position_at(event.client_x()));
+                                if let Some(position) = position {
+                                    set_position.emit(position);
+                                }
+                            });
+                        let onpointerup = EventListener::new(&document, "pointerup", move |_| {
+                            dragging.set(false)
+                        });
+
+                        (onpointermove, onpointerup)
+                    });
+
+                move || drop(listeners)
+            },
+            active,
+        );
+    }
+
+    let onpointerdown = {
+        let dragging = dragging.clone();
+        let set_position = set_position.clone();
+        Callback::from(move |event: PointerEvent| {
+            event.prevent_default();
+            if let Some(position) = position_at(event.client_x()) {
+                set_position.emit(position);
+            }
+            dragging.set(true);
+        })
+    };
+    let onkeydown = {
+        let current = *position;
+        Callback::from(move |event: KeyboardEvent| {
+            if let Some(position) = stepped(current, &event.key(), event.shift_key()) {
+                event.prevent_default();
+                set_position.emit(position);
+            }
+        })
+    };
+    let before_style = format!(
+        "position: absolute; top: 0; left: 0; width: 100%; height: 100%; object-fit: cover; \
+        clip-path: inset(0 {}% 0 0);",
+        100.0 - *position
+    );
+    let divider_style = format!(
+        "position: absolute; top: 0; bottom: 0; left: {}%; width: 4px; margin-left: -2px; \
+        background: white; cursor: ew-resize;",
+        *position
+    );
+
+    html! {
+        <Figure id={props.id.clone()} class={props.class.clone()} size={props.size}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <div ref={node} style="position: relative; height: 100%; overflow: hidden; touch-action: none; user-select: none;"
+                {onpointerdown}>
+                <img src={props.after.clone()} alt={props.after_alt.clone()} draggable="false" />
+                <img src={props.before.clone()} alt={props.before_alt.clone()} draggable="false" style={before_style} />
+                <div role="slider" tabindex="0" aria-label="Divider" aria-valuemin="0" aria-valuemax="100"
+                    aria-valuenow={format!("{:.0}", *position)} style={divider_style} {onkeydown}></div>
+            </div>
+        </Figure>
+    }
+}
+
+const STEP: f64 = 1.0;
+const LARGE_STEP: f64 = 10.0;
+
+fn position_at(client_x: f64, left: f64, width: f64) -> f64 {
+    if width > 0.0 {
+        ((client_x - left) / width * 100.0).clamp(0.0, 100.0)
+    } else {
+        0.0
+    }
+}
+
+fn stepped(position: f64, key: &str, large: bool) -> Option<f64> {
+    let step = if large { LARGE_STEP } else { STEP };
+    let position = match key {
+        "ArrowLeft" | "ArrowDown" => position - step,
+        "ArrowRight" | "ArrowUp" => position + step,
+        "Home" => 0.0,
+        "End" => 100.0,
+        _ => return None,
+    };
+
+    Some(position.clamp(0.0, 100.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(150.0, 100.0, 200.0, 25.0 ; "positions are relative to the left edge")]
+    #[test_case(50.0, 100.0, 200.0, 0.0 ; "positions before the left edge are clamped")]
+    #[test_case(400.0, 100.0, 200.0, 100.0 ; "positions after the right edge are clamped")]
+    #[test_case(150.0, 100.0, 0.0, 0.0 ; "empty widths are at the start")]
+    fn position_at_values(client_x: f64, left: f64, width: f64, expected: f64) {
+        assert_eq!(position_at(client_x, left, width), expected);
+    }
+
+    #[test_case(50.0, "ArrowLeft", false, Some(49.0) ; "left arrow moves left")]
+    #[test_case(50.0, "ArrowRight", true, Some(60.0) ; "shift moves further")]
+    #[test_case(95.0, "ArrowUp", true, Some(100.0) ; "steps are clamped")]
+    #[test_case(50.0, "Home", false, Some(0.0) ; "home moves to the start")]
+    #[test_case(50.0, "End", false, Some(100.0) ; "end moves to the end")]
+    #[test_case(50.0, "Enter", false, None ; "other keys are ignored")]
+    fn stepped_values(position: f64, key: &str, large: bool, expected: Option<f64>) {
+        assert_eq!(stepped(position, key, large), expected);
+    }
+}
@@ -0,0 +1,325 @@
+use yew::{
+    classes, function_component, html, use_state, AttrValue, Callback, Children, Html, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::{
+        button::{Button, Buttons},
+        notification::Notification,
+    },
+    helpers::color::Color,
+    utils::class::ClassBuilder,
+};
+
+/// Defines the properties of the consent banner.
+///
+/// Defines the properties of the consent banner, a
+/// [Bulma notification element][bd] fixed to the bottom of the page, holding
+/// the accept and decline buttons.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::consent::ConsentBanner;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ConsentBanner>{"This website uses cookies."}</ConsentBanner>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/notification/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ConsentBannerProperties {
+    /// Sets the color of the consent banner.
+    ///
+    /// Sets the color of the [Bulma notification element][bd] used by the
+    /// consent banner which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::consent::ConsentBanner, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ConsentBanner color={Color::Dark}>{"This website uses cookies."}</ConsentBanner>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/notification/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the text of the accept button.
+    ///
+    /// Sets the text of the button used to accept, found inside the consent
+    /// banner which will receive these properties. Defaults to `Accept`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::consent::ConsentBanner;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ConsentBanner accept_label="Sure">{"This website uses cookies."}</ConsentBanner>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("Accept"))]
+    pub accept_label: AttrValue,
+    /// Sets the text of the decline button.
+    ///
+    /// Sets the text of the button used to decline, found inside the consent
+    /// banner which will receive these properties. Defaults to `Decline`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::consent::ConsentBanner;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ConsentBanner decline_label="No, thanks">{"This website uses cookies."}</ConsentBanner>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("Decline"))]
+    pub decline_label: AttrValue,
+    /// The callback to be used when accepting.
+    ///
+    /// Defines the callback called when the accept button of the consent
+    /// banner, which will receive these properties, is clicked. The banner is
+    /// hidden afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::consent::ConsentBanner;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onaccept = Callback::from(|_| ());
+    ///
+    ///     html! {
+    ///         <ConsentBanner {onaccept}>{"This website uses cookies."}</ConsentBanner>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onaccept: Option<Callback<()>>,
+    /// The callback to be used when declining.
+    ///
+    /// Defines the callback called when the decline button of the consent
+    /// banner, which will receive these properties, is clicked. The banner is
+    /// hidden afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::consent::ConsentBanner;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let ondecline = Callback::from(|_| ());
+    ///
+    ///     html! {
+    ///         <ConsentBanner {ondecline}>{"This website uses cookies."}</ConsentBanner>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub ondecline: Option<Callback<()>>,
+    /// Sets the key used to persist the decision.
+    ///
+    /// Sets the [local storage][ls] key under which the decision taken using
+    /// the consent banner, which will receive these properties, is saved (
+    /// `true` if accepted, `false` otherwise). When a decision is already
+    /// saved under the key, the banner is not shown. Read it back using
+    /// [`stored_consent`].
+    ///
+    /// > _Only available with the `storage` feature enabled._
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::consent::ConsentBanner;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ConsentBanner storage_key="cookie-consent">{"This website uses cookies."}</ConsentBanner>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [ls]: https://developer.mozilla.org/en-US/docs/Web/API/Window/localStorage
+    #[cfg(feature = "storage")]
+    #[prop_or_default]
+    pub storage_key: Option<AttrValue>,
+    /// The content of the consent banner.
+    ///
+    /// Defines the elements that will be found inside the consent banner
+    /// which will receive these properties, before the buttons.
+    pub children: Children,
+}
+
+/// Yew implementation of a consent banner.
+///
+/// Renders a [Bulma notification element][bd], fixed to the bottom of the
+/// page, holding the given content along with accept and decline buttons.
+/// The banner hides itself once either button is clicked.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{components::consent::ConsentBanner, helpers::color::Color};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ConsentBanner color={Color::Dark}>
+///             {"This website uses cookies to improve your experience."}
+///         </ConsentBanner>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/notification/
+#[function_component(ConsentBanner)]
+pub fn consent_banner(props: &ConsentBannerProperties) -> Html {
+    #[cfg(feature = "storage")]
+    let saved_decision = props
+        .storage_key
+        .as_ref()
+        .and_then(|key| stored_consent(key));
+    #[cfg(not(feature = "storage"))]
+    let saved_decision: Option<bool> = None;
+    let decided = use_state(|| saved_decision.is_some());
+
+    if *decided {
+        return html! {};
+    }
+
+    let decide = {
+        let decided = decided.clone();
+        #[cfg(feature = "storage")]
+        let storage_key = props.storage_key.clone();
+        Callback::from(move |accepted: bool| {
+            #[cfg(feature = "storage")]
+            if let Some(key) = &storage_key {
+                use gloo::storage::{LocalStorage, Storage};
+
+                let _ = LocalStorage::set(key.as_str(), accepted);
+            }
+            #[cfg(not(feature = "storage"))]
+            let _ = accepted;
+            decided.set(true);
+        })
+    };
+    let onaccept = {
+        let decide = decide.clone();
+        let onaccept = props.onaccept.clone();
+        Callback::from(move |_| {
+            decide.emit(true);
+            if let Some(onaccept) = &onaccept {
+                onaccept.emit(());
+            }
+        })
+    };
+    let ondecline = {
+        let ondecline = props.ondecline.clone();
+        Callback::from(move |_| {
+            decide.emit(false);
+            if let Some(ondecline) = &ondecline {
+                ondecline.emit(());
+            }
+        })
+    };
+    let actions = html! {
+        <Buttons>
+            <Button color={Color::Primary} onclick={onaccept}>{&props.accept_label}</Button>
+            <Button onclick={ondecline}>{&props.decline_label}</Button>
+        </Buttons>
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class} style="position: fixed; right: 0; bottom: 0; left: 0; z-index: 30;"
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <Notification color={props.color} {actions} delete_button=false class={classes!("mb-0")}>
+                { for props.children.iter() }
+            </Notification>
+        </div>
+    }
+}
+
+/// Returns the decision saved by a [`ConsentBanner`] under the given key.
+///
+/// Reads the decision saved in [local storage][ls] by a [`ConsentBanner`]
+/// using the given `storage_key`, returning `true` if it was accepted,
+/// `false` if it was declined and `None` if it was not taken yet (ie to
+/// decide whether to load analytics on later visits).
+///
+/// > _Only available with the `storage` feature enabled._
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::consent::stored_consent;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let accepted = stored_consent("cookie-consent").unwrap_or(false);
+///
+///     html! {
+///         if accepted {
+///             <p>{"Thank you for accepting cookies."}</p>
+///         }
+///     }
+/// }
+/// ```
+///
+/// [ls]: https://developer.mozilla.org/en-US/docs/Web/API/Window/localStorage
+#[cfg(feature = "storage")]
+pub fn stored_consent(storage_key: &str) -> Option<bool> {
+    use gloo::storage::{LocalStorage, Storage};
+
+    LocalStorage::get::<bool>(storage_key).ok()
+}
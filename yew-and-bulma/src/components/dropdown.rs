@@ -0,0 +1,975 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use gloo::events::EventListener;
+use js_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, Node, ScrollIntoViewOptions, ScrollLogicalPosition};
+use yew::{
+    function_component, html, use_context, use_effect_with_deps, use_node_ref, use_state,
+    AttrValue, Callback, Children, ContextProvider, Html, KeyboardEvent, Properties, TargetCast,
+};
+use yew_and_bulma_macros::base_component_properties;
+#[cfg(feature = "router")]
+use yew_router::hooks::use_navigator;
+
+#[cfg(feature = "router")]
+use crate::utils::router::RouteTarget;
+use crate::utils::{class::ClassBuilder, constants::IS_PREFIX};
+
+static NEXT_ITEM_ID: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Clone, PartialEq)]
+struct DropdownContext {
+    highlighted: Option<AttrValue>,
+}
+
+/// Defines the properties of the [Bulma dropdown component][bd].
+///
+/// Defines the properties of the dropdown component, based on the
+/// specification found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown active=true>
+///             <DropdownTrigger>
+///                 <button class="button">{"Options"}</button>
+///             </DropdownTrigger>
+///             <DropdownMenu>
+///                 <DropdownItem href="#">{"Edit"}</DropdownItem>
+///             </DropdownMenu>
+///         </Dropdown>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DropdownProperties {
+    /// Whether or not the dropdown is open.
+    ///
+    /// Whether or not the menu of the [Bulma dropdown component][bd], which
+    /// will receive these properties, is shown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let active = use_state(|| false);
+    ///     let onclick = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |_| active.set(!*active))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Dropdown active={*active}>
+    ///             <DropdownTrigger>
+    ///                 <button class="button" {onclick}>{"Options"}</button>
+    ///             </DropdownTrigger>
+    ///             <DropdownMenu>
+    ///                 <DropdownItem href="#">{"Edit"}</DropdownItem>
+    ///             </DropdownMenu>
+    ///         </Dropdown>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    #[prop_or_default]
+    pub active: bool,
+    /// Whether or not the dropdown opens on hover.
+    ///
+    /// Whether or not the menu of the [Bulma dropdown component][bd], which
+    /// will receive these properties, is shown while hovering over it.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/#hoverable-or-toggable
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Whether or not the dropdown menu is aligned to the right.
+    ///
+    /// Whether or not the menu of the [Bulma dropdown component][bd], which
+    /// will receive these properties, is aligned to the right of its trigger.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/#alignment
+    #[prop_or_default]
+    pub right: bool,
+    /// Whether or not the dropdown menu opens upwards.
+    ///
+    /// Whether or not the menu of the [Bulma dropdown component][bd], which
+    /// will receive these properties, is shown above its trigger.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/#dropup
+    #[prop_or_default]
+    pub up: bool,
+    /// Whether or not the dropdown menu is positioned automatically.
+    ///
+    /// Whether or not the menu of the [Bulma dropdown component][bd], which
+    /// will receive these properties, is measured each time it is activated,
+    /// opening upwards or aligning to the right of its trigger when its
+    /// default placement would overflow the viewport.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Dropdown active=true smart_position=true>
+    ///             <DropdownTrigger>
+    ///                 <button class="button">{"Options"}</button>
+    ///             </DropdownTrigger>
+    ///             <DropdownMenu>
+    ///                 <DropdownItem href="#">{"Edit"}</DropdownItem>
+    ///             </DropdownMenu>
+    ///         </Dropdown>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/#alignment
+    #[prop_or_default]
+    pub smart_position: bool,
+    /// Whether or not clicking outside of the dropdown closes it.
+    ///
+    /// Whether or not clicking anywhere outside of the open
+    /// [Bulma dropdown component][bd], which will receive these properties,
+    /// emits the [`DropdownProperties::onclose`] callback.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let active = use_state(|| false);
+    ///     let onclick = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |_| active.set(!*active))
+    ///     };
+    ///     let onclose = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |_| active.set(false))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Dropdown active={*active} close_on_click_outside=true {onclose}>
+    ///             <DropdownTrigger>
+    ///                 <button class="button" {onclick}>{"Options"}</button>
+    ///             </DropdownTrigger>
+    ///             <DropdownMenu>
+    ///                 <DropdownItem href="#">{"Edit"}</DropdownItem>
+    ///             </DropdownMenu>
+    ///         </Dropdown>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    #[prop_or_default]
+    pub close_on_click_outside: bool,
+    /// Sets the callback used when the dropdown should close.
+    ///
+    /// Sets the callback called when the open [Bulma dropdown component][bd],
+    /// which will receive these properties, should be closed, such as when
+    /// clicking outside of it.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    #[prop_or_default]
+    pub onclose: Option<Callback<()>>,
+    /// The list of elements found inside the dropdown.
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma dropdown component][bd] which will receive these properties,
+    /// usually a [`DropdownTrigger`] and a [`DropdownMenu`].
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma dropdown component][bd].
+///
+/// Yew implementation of the dropdown component, based on the specification
+/// found in the [Bulma dropdown component documentation][bd]. Its menu is
+/// shown while it is active, which is controlled by its parent.
+///
+/// While it is active, the dropdown can be used with the keyboard: the arrow
+/// keys, `Home` and `End` highlight its menu items, `Enter` clicks the
+/// highlighted one and `Escape` emits the [`DropdownProperties::onclose`]
+/// callback.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{
+///     Dropdown, DropdownDivider, DropdownItem, DropdownMenu, DropdownTrigger,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown hoverable=true>
+///             <DropdownTrigger>
+///                 <button class="button">{"Options"}</button>
+///             </DropdownTrigger>
+///             <DropdownMenu>
+///                 <DropdownItem href="#">{"Edit"}</DropdownItem>
+///                 <DropdownDivider />
+///                 <DropdownItem href="#">{"Delete"}</DropdownItem>
+///             </DropdownMenu>
+///         </Dropdown>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[function_component(Dropdown)]
+pub fn dropdown(props: &DropdownProperties) -> Html {
+    let node = use_node_ref();
+    let highlighted = use_state(|| None::<AttrValue>);
+    let flipped = use_state(|| (false, false));
+
+    {
+        let highlighted = highlighted.clone();
+        use_effect_with_deps(
+            move |active| {
+                if !*active {
+                    highlighted.set(None);
+                }
+            },
+            props.active,
+        );
+    }
+
+    {
+        let node = node.clone();
+        let flipped = flipped.clone();
+        use_effect_with_deps(
+            move |(active, smart_position)| {
+                if *active && *smart_position {
+                    if let Some(placement) = node
+                        .cast::<Element>()
+                        .and_then(|dropdown| smart_placement(&dropdown))
+                    {
+                        flipped.set(placement);
+                    }
+                }
+            },
+            (props.active, props.smart_position),
+        );
+    }
+
+    {
+        let node = node.clone();
+        let onclose = props.onclose.clone();
+        use_effect_with_deps(
+            move |listening| {
+                let listener = listening
+                    .then(web_sys::window)
+                    .flatten()
+                    .and_then(|window| window.document())
+                    .map(|document| {
+                        EventListener::new(&document, "click", move |event| {
+                            let outside = match (node.get(), event.target_dyn_into::<Node>()) {
+                                (Some(dropdown), Some(target)) => !dropdown.contains(Some(&target)),
+                                _ => false,
+                            };
+                            if let Some(onclose) = onclose.as_ref().filter(|_| outside) {
+                                onclose.emit(());
+                            }
+                        })
+                    });
+
+                move || drop(listener)
+            },
+            props.active && props.close_on_click_outside,
+        );
+    }
+
+    let onkeydown = {
+        let onkeydown = props.onkeydown.clone();
+        let onclose = props.onclose.clone();
+        let node = node.clone();
+        let highlighted = highlighted.clone();
+        let active = props.active;
+        Callback::from(move |event: KeyboardEvent| {
+            if active {
+                let items = node
+                    .cast::<Element>()
+                    .and_then(|dropdown| {
+                        dropdown
+                            .query_selector_all(r#".dropdown-item[role="menuitem"]"#)
+                            .ok()
+                    })
+                    .map(|list| {
+                        (0..list.length())
+                            .filter_map(|index| list.item(index))
+                            .filter_map(|item| item.dyn_into::<HtmlElement>().ok())
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+                let current = items
+                    .iter()
+                    .position(|item| Some(item.id().as_str()) == highlighted.as_deref());
+                let last = items.len().saturating_sub(1);
+                let next = match event.key().as_str() {
+                    "ArrowDown" => Some(current.map(|index| (index + 1).min(last)).unwrap_or(0)),
+                    "ArrowUp" => Some(current.map(|index| index.saturating_sub(1)).unwrap_or(last)),
+                    "Home" => Some(0),
+                    "End" => Some(last),
+                    "Enter" => {
+                        if let Some(item) = current.and_then(|index| items.get(index)) {
+                            event.prevent_default();
+                            item.click();
+                        }
+                        None
+                    }
+                    "Escape" => {
+                        if let Some(onclose) = &onclose {
+                            onclose.emit(());
+                        }
+                        None
+                    }
+                    _ => None,
+                };
+                if let Some(item) = next.and_then(|index| items.get(index)) {
+                    event.prevent_default();
+                    let options = ScrollIntoViewOptions::new();
+                    options.set_block(ScrollLogicalPosition::Nearest);
+                    item.scroll_into_view_with_scroll_into_view_options(&options);
+                    highlighted.set(Some(AttrValue::from(item.id())));
+                }
+            }
+            if let Some(onkeydown) = &onkeydown {
+                onkeydown.emit(event);
+            }
+        })
+    };
+    let context = DropdownContext {
+        highlighted: (*highlighted).clone(),
+    };
+    let (up, right) = if props.smart_position {
+        *flipped
+    } else {
+        (false, false)
+    };
+    let modifiers = [
+        (props.active, "active"),
+        (props.hoverable, "hoverable"),
+        (props.right || right, "right"),
+        (props.up || up, "up"),
+    ]
+    .iter()
+    .filter(|(enabled, _)| *enabled)
+    .map(|(_, modifier)| format!("{IS_PREFIX}-{modifier}"))
+    .collect::<Vec<_>>()
+    .join(" ");
+    let class = ClassBuilder::default()
+        .with_custom_class("dropdown")
+        .with_custom_class(&modifiers)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<DropdownContext> {context}>
+            <div ref={node} id={props.id.clone()} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                {onkeydown} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+            </div>
+        </ContextProvider<DropdownContext>>
+    }
+}
+
+/// Returns whether the menu of the given dropdown should open upwards and
+/// align to the right, so that it fits inside the viewport.
+fn smart_placement(dropdown: &Element) -> Option<(bool, bool)> {
+    let window = web_sys::window()?;
+    let viewport_width = window.inner_width().ok()?.as_f64()?;
+    let viewport_height = window.inner_height().ok()?.as_f64()?;
+    let menu = dropdown
+        .query_selector(".dropdown-menu")
+        .ok()??
+        .get_bounding_client_rect();
+    let trigger = dropdown.get_bounding_client_rect();
+
+    Some(flipped_placement(
+        (trigger.left(), trigger.top(), trigger.bottom()),
+        (menu.width(), menu.height()),
+        (viewport_width, viewport_height),
+    ))
+}
+
+/// Returns whether a menu of the given size, placed under the left edge of a
+/// trigger, should be flipped upwards and to the right to fit the viewport.
+fn flipped_placement(
+    (left, top, bottom): (f64, f64, f64),
+    (width, height): (f64, f64),
+    (viewport_width, viewport_height): (f64, f64),
+) -> (bool, bool) {
+    let up = bottom + height > viewport_height && top - height >= 0.0;
+    let right = left + width > viewport_width;
+
+    (up, right)
+}
+
+/// Defines the properties of the [Bulma dropdown trigger][bd].
+///
+/// Defines the properties of the dropdown trigger, the always visible part
+/// of the dropdown, based on the specification found in the
+/// [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{Dropdown, DropdownTrigger};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown>
+///             <DropdownTrigger>
+///                 <button class="button">{"Options"}</button>
+///             </DropdownTrigger>
+///         </Dropdown>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DropdownTriggerProperties {
+    /// The list of elements found inside the dropdown trigger.
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma dropdown trigger][bd] which will receive these properties,
+    /// usually a button.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma dropdown trigger][bd].
+///
+/// Yew implementation of the dropdown trigger, based on the specification
+/// found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{Dropdown, DropdownTrigger};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown>
+///             <DropdownTrigger>
+///                 <button class="button">{"Options"}</button>
+///             </DropdownTrigger>
+///         </Dropdown>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[function_component(DropdownTrigger)]
+pub fn dropdown_trigger(props: &DropdownTriggerProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("dropdown-trigger")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma dropdown menu][bd].
+///
+/// Defines the properties of the dropdown menu, the part of the dropdown
+/// shown while it is active, based on the specification found in the
+/// [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{DropdownItem, DropdownMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DropdownMenu>
+///             <DropdownItem href="#">{"Edit"}</DropdownItem>
+///         </DropdownMenu>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DropdownMenuProperties {
+    /// Sets the maximum height of the [Bulma dropdown menu][bd].
+    ///
+    /// Sets the maximum height, as a CSS length (ie `20rem` or `50vh`), of
+    /// the content of the [Bulma dropdown menu][bd] which will receive these
+    /// properties. Longer lists of items scroll inside of it, and the item
+    /// highlighted using the keyboard is scrolled into view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::dropdown::{DropdownItem, DropdownMenu};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <DropdownMenu max_height="12rem">
+    ///             { for (1..=50).map(|page| html! {
+    ///                 <DropdownItem href={format!("#page-{page}")}>{format!("Page {page}")}</DropdownItem>
+    ///             }) }
+    ///         </DropdownMenu>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    #[prop_or_default]
+    pub max_height: Option<AttrValue>,
+    /// The list of elements found inside the dropdown menu.
+    ///
+    /// Defines the elements that will be found inside the content of the
+    /// [Bulma dropdown menu][bd] which will receive these properties, usually
+    /// [`DropdownItem`]s and [`DropdownDivider`]s.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma dropdown menu][bd].
+///
+/// Yew implementation of the dropdown menu, based on the specification found
+/// in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{DropdownDivider, DropdownItem, DropdownMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DropdownMenu>
+///             <DropdownItem href="#">{"Edit"}</DropdownItem>
+///             <DropdownDivider />
+///             <DropdownItem href="#">{"Delete"}</DropdownItem>
+///         </DropdownMenu>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[function_component(DropdownMenu)]
+pub fn dropdown_menu(props: &DropdownMenuProperties) -> Html {
+    let highlighted = use_context::<DropdownContext>().and_then(|dropdown| dropdown.highlighted);
+    let class = ClassBuilder::default()
+        .with_custom_class("dropdown-menu")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let style = props
+        .max_height
+        .as_ref()
+        .map(|max_height| format!("max-height: {max_height}; overflow-y: auto;"));
+
+    html! {
+        <div id={props.id.clone()} {class} role="menu" tabindex="-1" aria-activedescendant={highlighted}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <div class="dropdown-content" {style}>
+                { for props.children.iter() }
+            </div>
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma dropdown item][bd].
+///
+/// Defines the properties of the dropdown item, based on the specification
+/// found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::DropdownItem;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DropdownItem href="#">{"Edit"}</DropdownItem>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DropdownItemProperties {
+    /// Sets the link of the dropdown item.
+    ///
+    /// Sets the `href` of the [Bulma dropdown item][bd] which will receive
+    /// these properties, rendering it as an `a` tag. If it is not set, the
+    /// item is rendered as a `div` tag instead, useful for holding other
+    /// content.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/#dropdown-content
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Sets the route of the dropdown item.
+    ///
+    /// Sets the [`RouteTarget`] of the [Bulma dropdown item][bd] which will receive
+    /// these properties, rendering it as an `a` tag which navigates to the
+    /// route on the client side, without a full page load. Takes precedence
+    /// over [`DropdownItemProperties::href`] when used inside a router. Only
+    /// available with the `router` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::dropdown::DropdownItem, utils::router::RouteTarget};
+    /// use yew_router::prelude::*;
+    ///
+    /// #[derive(Clone, PartialEq, Routable)]
+    /// enum Route {
+    ///     #[at("/settings")]
+    ///     Settings,
+    /// }
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <BrowserRouter>
+    ///             <DropdownItem to={RouteTarget::new(Route::Settings)}>{"Settings"}</DropdownItem>
+    ///         </BrowserRouter>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/#dropdown-content
+    #[cfg(feature = "router")]
+    #[prop_or_default]
+    pub to: Option<RouteTarget>,
+    /// Marks the dropdown item as active.
+    ///
+    /// Highlights the [Bulma dropdown item][bd] which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::dropdown::DropdownItem;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <DropdownItem href="#" active=true>{"Edit"}</DropdownItem>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    #[prop_or_default]
+    pub active: bool,
+    /// Marks the dropdown item as content instead of a menu item.
+    ///
+    /// Renders the [Bulma dropdown item][bd] which will receive these
+    /// properties as plain content, which is skipped by the keyboard
+    /// navigation of its dropdown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::dropdown::DropdownItem;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <DropdownItem content=true>
+    ///             <p>{"You can insert any type of content within the dropdown menu."}</p>
+    ///         </DropdownItem>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/#dropdown-content
+    #[prop_or_default]
+    pub content: bool,
+    /// The list of elements found inside the dropdown item.
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma dropdown item][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/dropdown/
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma dropdown item][bd].
+///
+/// Yew implementation of the dropdown item, based on the specification found
+/// in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::DropdownItem;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DropdownItem>{"Some text."}</DropdownItem>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[function_component(DropdownItem)]
+pub fn dropdown_item(props: &DropdownItemProperties) -> Html {
+    let generated_id = use_state(|| {
+        let id = NEXT_ITEM_ID.fetch_add(1, Ordering::Relaxed);
+        AttrValue::from(format!("dropdown-item-{id}"))
+    });
+    let id = props.id.clone().unwrap_or_else(|| (*generated_id).clone());
+    let highlighted = use_context::<DropdownContext>()
+        .and_then(|dropdown| dropdown.highlighted)
+        .map(|highlighted| highlighted == id)
+        .unwrap_or_default();
+    let active = if props.active || highlighted {
+        format!("{IS_PREFIX}-active")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("dropdown-item")
+        .with_custom_class(&active)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    #[cfg(feature = "router")]
+    let navigator = use_navigator();
+    #[cfg(feature = "router")]
+    let (href, onclick) = match (&props.to, navigator) {
+        (Some(to), Some(navigator)) => (
+            Some(to.href(&navigator)),
+            Some(to.onclick(navigator, props.onclick.clone())),
+        ),
+        _ => (props.href.clone(), props.onclick.clone()),
+    };
+    #[cfg(not(feature = "router"))]
+    let (href, onclick) = (props.href.clone(), props.onclick.clone());
+    let tag = if href.is_some() { "a" } else { "div" };
+    let role = (!props.content).then(|| AttrValue::from("menuitem"));
+
+    html! {
+        <@{tag} {id} {class} {href} {role}
+            {onclick} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </@>
+    }
+}
+
+/// Defines the properties of the [Bulma dropdown divider][bd].
+///
+/// Defines the properties of the dropdown divider, a horizontal line used to
+/// separate dropdown items, based on the specification found in the
+/// [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{DropdownDivider, DropdownItem, DropdownMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DropdownMenu>
+///             <DropdownItem href="#">{"Edit"}</DropdownItem>
+///             <DropdownDivider />
+///             <DropdownItem href="#">{"Delete"}</DropdownItem>
+///         </DropdownMenu>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DropdownDividerProperties {}
+
+/// Yew implementation of the [Bulma dropdown divider][bd].
+///
+/// Yew implementation of the dropdown divider, based on the specification
+/// found in the [Bulma dropdown component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{DropdownDivider, DropdownItem, DropdownMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <DropdownMenu>
+///             <DropdownItem href="#">{"Edit"}</DropdownItem>
+///             <DropdownDivider />
+///             <DropdownItem href="#">{"Delete"}</DropdownItem>
+///         </DropdownMenu>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+#[function_component(DropdownDivider)]
+pub fn dropdown_divider(props: &DropdownDividerProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("dropdown-divider")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <hr id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()} />
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case((10.0, 10.0, 40.0), (200.0, 300.0), (false, false) ; "menu fits under the trigger")]
+    #[test_case((10.0, 600.0, 640.0), (200.0, 300.0), (true, false) ; "menu overflows the bottom")]
+    #[test_case((10.0, 100.0, 140.0), (200.0, 800.0), (false, false) ; "menu fits neither above nor under")]
+    #[test_case((900.0, 10.0, 40.0), (200.0, 300.0), (false, true) ; "menu overflows the right")]
+    #[test_case((900.0, 600.0, 640.0), (200.0, 300.0), (true, true) ; "menu overflows the bottom right corner")]
+    fn flipped_placement_fits_the_viewport(
+        trigger: (f64, f64, f64),
+        menu: (f64, f64),
+        expected: (bool, bool),
+    ) {
+        assert_eq!(flipped_placement(trigger, menu, (1024.0, 768.0)), expected);
+    }
+}
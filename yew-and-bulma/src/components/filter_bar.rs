@@ -0,0 +1,261 @@
+use yew::{function_component, html, AttrValue, Callback, Html, MouseEvent, Properties};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::{
+        button::Button,
+        tag::{Tag, Tags},
+    },
+    form::general::{Control, Field},
+    helpers::color::Color,
+    utils::size::Size,
+};
+
+/// A filter shown by a [`FilterBar`].
+///
+/// Defines an active filter, made up of the name of what is filtered (ie a
+/// column of a [`crate::elements::table::DataTable`]) and the value it is
+/// filtered by.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::filter_bar::{Filter, FilterBar};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let filters = vec![Filter::new("Status", "Active")];
+///
+///     html! {
+///         <FilterBar {filters} />
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Filter {
+    pub name: AttrValue,
+    pub value: AttrValue,
+}
+
+impl Filter {
+    /// Creates a filter of the given name, filtering by the given value.
+    pub fn new(name: impl Into<AttrValue>, value: impl Into<AttrValue>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// Defines the properties of the filter bar.
+///
+/// Defines the properties of the filter bar, a list of active filters shown
+/// as deletable [Bulma tags][bd], usually placed above a table.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::filter_bar::{Filter, FilterBar};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let filters = vec![Filter::new("Status", "Active"), Filter::new("Role", "Admin")];
+///
+///     html! {
+///         <FilterBar {filters} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/tag/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct FilterBarProperties {
+    /// The active filters, in order.
+    ///
+    /// Defines the filters shown by the filter bar which will receive these
+    /// properties. The filters are controlled by its parent, through the
+    /// [`FilterBarProperties::onfilterchange`] callback.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::filter_bar::{Filter, FilterBar};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let filters = vec![Filter::new("Status", "Active")];
+    ///
+    ///     html! {
+    ///         <FilterBar {filters} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub filters: Vec<Filter>,
+    /// Sets the color of the filters.
+    ///
+    /// Sets the color of the [Bulma tags][bd] showing the filters of the
+    /// filter bar which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::filter_bar::{Filter, FilterBar},
+    ///     helpers::color::Color,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let filters = vec![Filter::new("Status", "Active")];
+    ///
+    ///     html! {
+    ///         <FilterBar {filters} color={Color::Info} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the text of the clear all button.
+    ///
+    /// Sets the text of the button removing every filter of the filter bar
+    /// which will receive these properties. Defaults to `Clear all`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::filter_bar::{Filter, FilterBar};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let filters = vec![Filter::new("Status", "Active")];
+    ///
+    ///     html! {
+    ///         <FilterBar {filters} clear_label="Reset" />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("Clear all"))]
+    pub clear_label: AttrValue,
+    /// Sets the callback used when the filters change.
+    ///
+    /// Sets the callback called with the remaining filters when one of the
+    /// filters of the filter bar, which will receive these properties, is
+    /// deleted or when all of them are cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::filter_bar::{Filter, FilterBar};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let filters = use_state(|| vec![Filter::new("Status", "Active")]);
+    ///     let onfilterchange = {
+    ///         let filters = filters.clone();
+    ///         Callback::from(move |value| filters.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <FilterBar filters={(*filters).clone()} {onfilterchange} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onfilterchange: Option<Callback<Vec<Filter>>>,
+}
+
+/// Yew implementation of the filter bar.
+///
+/// Yew implementation of the filter bar, rendering each active filter as a
+/// pair of attached [Bulma tags][bd], the second one deleting it, followed
+/// by a button clearing all of them. Nothing is rendered inside it while
+/// there are no filters.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::filter_bar::{Filter, FilterBar};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let filters = use_state(|| vec![Filter::new("Status", "Active"), Filter::new("Role", "Admin")]);
+///     let onfilterchange = {
+///         let filters = filters.clone();
+///         Callback::from(move |value| filters.set(value))
+///     };
+///
+///     html! {
+///         <FilterBar filters={(*filters).clone()} {onfilterchange} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/tag/
+#[function_component(FilterBar)]
+pub fn filter_bar(props: &FilterBarProperties) -> Html {
+    let filters = props.filters.iter().enumerate().map(|(index, filter)| {
+        let onclick = {
+            let filters = props.filters.clone();
+            let onfilterchange = props.onfilterchange.clone();
+            Callback::from(move |event: MouseEvent| {
+                event.prevent_default();
+                let mut filters = filters.clone();
+                filters.remove(index);
+                if let Some(onfilterchange) = &onfilterchange {
+                    onfilterchange.emit(filters);
+                }
+            })
+        };
+
+        html! {
+            <Control>
+                <Tags addons=true>
+                    <Tag color={props.color}>{format!("{}: {}", filter.name, filter.value)}</Tag>
+                    <Tag delete=true {onclick} />
+                </Tags>
+            </Control>
+        }
+    });
+    let onclear = {
+        let onfilterchange = props.onfilterchange.clone();
+        Callback::from(move |_| {
+            if let Some(onfilterchange) = &onfilterchange {
+                onfilterchange.emit(Vec::new());
+            }
+        })
+    };
+
+    html! {
+        <Field id={props.id.clone()} class={props.class.clone()} grouped=true multiline=true
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for filters }
+            if !props.filters.is_empty() {
+                <Control>
+                    <Button size={Size::Small} onclick={onclear}>{props.clear_label.clone()}</Button>
+                </Control>
+            }
+        </Field>
+    }
+}
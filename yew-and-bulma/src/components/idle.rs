@@ -0,0 +1,344 @@
+use gloo::{events::EventListener, timers::callback::Interval};
+use js_sys::Date;
+use yew::{
+    function_component, html, use_effect_with_deps, use_mut_ref, use_state_eq, AttrValue, Callback,
+    Children, Html, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    components::modal::{Modal, ModalCard},
+    elements::{button::Button, time::Countdown},
+    helpers::{color::Color, typography::TextWeight},
+};
+
+const ACTIVITY_EVENTS: [&str; 5] = ["mousemove", "mousedown", "keydown", "scroll", "touchstart"];
+
+/// Defines the properties of the idle timeout dialog.
+///
+/// Defines the properties of the idle timeout dialog, a
+/// [Bulma modal card][bd] warning the user, using a countdown, that they will
+/// be considered idle (ie signed out) unless they continue their session.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::idle::IdleTimeout;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let ontimeout = Callback::from(|_| ());
+///
+///     html! {
+///         <IdleTimeout {ontimeout} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct IdleTimeoutProperties {
+    /// Sets the time without activity after which the user is idle.
+    ///
+    /// Sets the number of milliseconds without any mouse, keyboard, scroll or
+    /// touch activity after which the idle timeout dialog, which will receive
+    /// these properties, emits its `ontimeout` callback. Defaults to 15
+    /// minutes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::idle::IdleTimeout;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <IdleTimeout timeout={30 * 60 * 1_000} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(15 * 60 * 1_000)]
+    pub timeout: u32,
+    /// Sets how long before the timeout the dialog is shown.
+    ///
+    /// Sets the number of milliseconds before the timeout at which the idle
+    /// timeout dialog, which will receive these properties, is shown, along
+    /// with the countdown. Activity stops being tracked while it is shown.
+    /// Defaults to 1 minute.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::idle::IdleTimeout;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <IdleTimeout warning={2 * 60 * 1_000} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(60 * 1_000)]
+    pub warning: u32,
+    /// Sets the title of the idle timeout dialog.
+    ///
+    /// Sets the title of the [Bulma modal card][bd] which will receive these
+    /// properties. Defaults to `Are you still there?`.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+    #[prop_or(AttrValue::from("Are you still there?"))]
+    pub title: AttrValue,
+    /// Sets the text of the continue button.
+    ///
+    /// Sets the text of the button used to continue the session, found inside
+    /// the idle timeout dialog which will receive these properties. Defaults
+    /// to `Continue`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::idle::IdleTimeout;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <IdleTimeout continue_label="Stay signed in" />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("Continue"))]
+    pub continue_label: AttrValue,
+    /// Sets the callback used when the user is idle.
+    ///
+    /// Sets the callback called once the timeout of the idle timeout dialog,
+    /// which will receive these properties, ends without the user continuing
+    /// their session, usually used to sign them out. Tracking starts over
+    /// afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::idle::IdleTimeout;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let signed_in = use_state(|| true);
+    ///     let ontimeout = {
+    ///         let signed_in = signed_in.clone();
+    ///         Callback::from(move |_| signed_in.set(false))
+    ///     };
+    ///
+    ///     html! {
+    ///         if *signed_in {
+    ///             <IdleTimeout {ontimeout} />
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub ontimeout: Option<Callback<()>>,
+    /// Sets the callback used when the user continues their session.
+    ///
+    /// Sets the callback called when the continue button, or the delete
+    /// button, of the idle timeout dialog, which will receive these
+    /// properties, is clicked, usually used to refresh the session.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::idle::IdleTimeout;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let refreshes = use_state(|| 0);
+    ///     let oncontinue = {
+    ///         let refreshes = refreshes.clone();
+    ///         Callback::from(move |_| refreshes.set(*refreshes + 1))
+    ///     };
+    ///
+    ///     html! {
+    ///         <IdleTimeout {oncontinue} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub oncontinue: Option<Callback<()>>,
+    /// The content found inside the idle timeout dialog, above the countdown.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the idle timeout dialog.
+///
+/// Tracks the activity of the user on the page and, once they have been
+/// inactive for `timeout - warning` milliseconds, opens a
+/// [Bulma modal card][bd] counting down to the timeout, which emits the
+/// `ontimeout` callback unless the user continues their session.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::idle::IdleTimeout;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let ontimeout = Callback::from(|_| ());
+///
+///     html! {
+///         <IdleTimeout timeout={10 * 60 * 1_000} {ontimeout}>
+///             <p>{"You will be signed out due to inactivity."}</p>
+///         </IdleTimeout>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[function_component(IdleTimeout)]
+pub fn idle_timeout(props: &IdleTimeoutProperties) -> Html {
+    let last_activity = use_mut_ref(Date::now);
+    let deadline = use_state_eq(|| None::<f64>);
+
+    {
+        let last_activity = last_activity.clone();
+        let timeout = f64::from(props.timeout);
+        let warning = f64::from(props.warning);
+        use_effect_with_deps(
+            move |_| {
+                let listeners =
+                    web_sys::window()
+                        .and_then(|window| window.document())
+                        .map(|document| {
+                            ACTIVITY_EVENTS
+                                .iter()
+                                .map(|event| {
+                                    let last_activity = last_activity.clone();
+                                    EventListener::new(&document, *event, move |_| {
+                                        let now = Date::now();
+                                        if phase(now - *last_activity.borrow(), timeout, warning)
+                                            == Phase::Active
+                                        {
+                                            *last_activity.borrow_mut() = now;
+                                        }
+                                    })
+                                })
+                                .collect::<Vec<_>>()
+                        });
+
+                move || drop(listeners)
+            },
+            (props.timeout, props.warning),
+        );
+    }
+    {
+        let last_activity = last_activity.clone();
+        let deadline = deadline.clone();
+        let timeout = f64::from(props.timeout);
+        let warning = f64::from(props.warning);
+        use_effect_with_deps(
+            move |(_, _, ontimeout)| {
+                let ontimeout = ontimeout.clone();
+                let interval = Interval::new(1_000, move || {
+                    let now = Date::now();
+                    match phase(now - *last_activity.borrow(), timeout, warning) {
+                        Phase::Active => deadline.set(None),
+                        Phase::Warning => deadline.set(Some(*last_activity.borrow() + timeout)),
+                        Phase::TimedOut => {
+                            *last_activity.borrow_mut() = now;
+                            deadline.set(None);
+                            if let Some(ontimeout) = &ontimeout {
+                                ontimeout.emit(());
+                            }
+                        }
+                    }
+                });
+
+                move || drop(interval)
+            },
+            (props.timeout, props.warning, props.ontimeout.clone()),
+        );
+    }
+
+    let oncontinue = {
+        let deadline = deadline.clone();
+        let oncontinue = props.oncontinue.clone();
+        Callback::from(move |_: ()| {
+            *last_activity.borrow_mut() = Date::now();
+            deadline.set(None);
+            if let Some(oncontinue) = &oncontinue {
+                oncontinue.emit(());
+            }
+        })
+    };
+    let onclick = {
+        let oncontinue = oncontinue.clone();
+        Callback::from(move |_| oncontinue.emit(()))
+    };
+    let foot = html! {
+        <Button color={Color::Primary} {onclick}>{&props.continue_label}</Button>
+    };
+
+    html! {
+        <Modal id={props.id.clone()} class={props.class.clone()} active={deadline.is_some()} trap_focus=true onclose={oncontinue}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <ModalCard title={props.title.clone()} {foot}>
+                { for props.children.iter() }
+                if let Some(until) = *deadline {
+                    <p><Countdown {until} weight={TextWeight::Bold} /></p>
+                }
+            </ModalCard>
+        </Modal>
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Phase {
+    Active,
+    Warning,
+    TimedOut,
+}
+
+fn phase(idle_for: f64, timeout: f64, warning: f64) -> Phase {
+    if idle_for >= timeout {
+        Phase::TimedOut
+    } else if idle_for >= timeout - warning {
+        Phase::Warning
+    } else {
+        Phase::Active
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(0.0, 900.0, 60.0 => Phase::Active ; "just active")]
+    #[test_case(839.0, 900.0, 60.0 => Phase::Active ; "right before the warning")]
+    #[test_case(840.0, 900.0, 60.0 => Phase::Warning ; "warning starts")]
+    #[test_case(899.0, 900.0, 60.0 => Phase::Warning ; "right before the timeout")]
+    #[test_case(900.0, 900.0, 60.0 => Phase::TimedOut ; "timed out")]
+    #[test_case(10.0, 900.0, 1_000.0 => Phase::Warning ; "warning longer than the timeout")]
+    fn phase_should_follow_the_idle_time(idle_for: f64, timeout: f64, warning: f64) -> Phase {
+        phase(idle_for, timeout, warning)
+    }
+}
@@ -0,0 +1,443 @@
+use std::{collections::HashMap, rc::Rc};
+
+use yew::{
+    function_component, hook, html, use_context, use_effect_with_deps, use_mut_ref, use_reducer,
+    AttrValue, Children, ContextProvider, Html, Properties, Reducible, UseReducerHandle,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    components::topbar_progress::use_top_progress,
+    elements::button::{Button, ButtonType, State, Style},
+    helpers::color::Color,
+    utils::size::Size,
+};
+
+#[derive(Default, PartialEq)]
+struct LoadingScopes {
+    active: HashMap<AttrValue, usize>,
+}
+
+impl LoadingScopes {
+    fn is_loading(&self, scope: &str) -> bool {
+        self.active.contains_key(scope)
+    }
+
+    fn any(&self) -> bool {
+        !self.active.is_empty()
+    }
+}
+
+enum LoadingAction {
+    Start(AttrValue),
+    Finish(AttrValue),
+}
+
+impl Reducible for LoadingScopes {
+    type Action = LoadingAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut active = self.active.clone();
+        match action {
+            LoadingAction::Start(scope) => *active.entry(scope).or_default() += 1,
+            LoadingAction::Finish(scope) => {
+                if let Some(count) = active.get_mut(&scope) {
+                    *count -= 1;
+                    if *count == 0 {
+                        active.remove(&scope);
+                    }
+                }
+            }
+        }
+
+        Rc::new(Self { active })
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct LoadingContext {
+    scopes: UseReducerHandle<LoadingScopes>,
+}
+
+/// Tracks the operations of a loading scope.
+///
+/// Handle returned by [`use_loading`], used to mark the start and end of an
+/// asynchronous operation belonging to a scope (ie `"save"`), and to tell
+/// whether any operation of that scope is in progress. Every component using
+/// the same scope inside a [`LoadingProvider`] sees the same state. Outside
+/// of one, the scope is never loading and starting it does nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::loading::use_loading,
+///     elements::button::{Button, State},
+/// };
+///
+/// #[function_component(Save)]
+/// fn save() -> Html {
+///     let loading = use_loading("save");
+///     let onclick = {
+///         let loading = loading.clone();
+///         Callback::from(move |_| loading.start())
+///     };
+///
+///     html! {
+///         <Button state={loading.loading().then(|| State::Loading)} {onclick}>{"Save"}</Button>
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct LoadingHandle {
+    scope: AttrValue,
+    context: Option<LoadingContext>,
+}
+
+impl LoadingHandle {
+    /// Returns the scope of the handle.
+    pub fn scope(&self) -> &AttrValue {
+        &self.scope
+    }
+
+    /// Returns whether or not any operation of the scope is in progress.
+    pub fn loading(&self) -> bool {
+        self.context
+            .as_ref()
+            .map(|context| context.scopes.is_loading(&self.scope))
+            .unwrap_or_default()
+    }
+
+    /// Marks the start of an operation of the scope.
+    pub fn start(&self) {
+        if let Some(context) = &self.context {
+            context
+                .scopes
+                .dispatch(LoadingAction::Start(self.scope.clone()));
+        }
+    }
+
+    /// Marks the end of an operation of the scope, previously started.
+    pub fn finish(&self) {
+        if let Some(context) = &self.context {
+            context
+                .scopes
+                .dispatch(LoadingAction::Finish(self.scope.clone()));
+        }
+    }
+}
+
+/// Defines the properties of the loading provider.
+///
+/// Defines the properties of the loading provider, which shares the state of
+/// the asynchronous operations started by its children, grouped by scope.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::loading::LoadingProvider;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <LoadingProvider>{"The rest of the application."}</LoadingProvider>
+///     }
+/// }
+/// ```
+#[derive(Properties, PartialEq)]
+pub struct LoadingProviderProperties {
+    /// Whether or not to show the top progress bar while loading.
+    ///
+    /// Whether or not the loading provider, which will receive these
+    /// properties, starts the closest [`TopProgress`] bar while any scope is
+    /// loading, finishing it once they all end. Defaults to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::{loading::LoadingProvider, topbar_progress::TopProgress};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TopProgress>
+    ///             <LoadingProvider show_progress=false>
+    ///                 {"The rest of the application."}
+    ///             </LoadingProvider>
+    ///         </TopProgress>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`TopProgress`]: crate::components::topbar_progress::TopProgress
+    #[prop_or(true)]
+    pub show_progress: bool,
+    /// The content found inside the loading provider.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the loading provider.
+///
+/// Provides the state of the loading scopes to its children, which read and
+/// update it using [`use_loading`], or reflect it using a [`LoadingButton`].
+/// When placed inside a [`TopProgress`], the progress bar is shown while any
+/// scope is loading.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::{
+///     loading::{LoadingButton, LoadingProvider},
+///     topbar_progress::TopProgress,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <TopProgress>
+///             <LoadingProvider>
+///                 <LoadingButton scope="save">{"Save"}</LoadingButton>
+///             </LoadingProvider>
+///         </TopProgress>
+///     }
+/// }
+/// ```
+///
+/// [`TopProgress`]: crate::components::topbar_progress::TopProgress
+#[function_component(LoadingProvider)]
+pub fn loading_provider(props: &LoadingProviderProperties) -> Html {
+    let scopes = use_reducer(LoadingScopes::default);
+    let top_progress = use_top_progress();
+    let was_loading = use_mut_ref(|| false);
+
+    {
+        let show_progress = props.show_progress;
+        use_effect_with_deps(
+            move |loading| {
+                let mut was_loading = was_loading.borrow_mut();
+                if let (true, Some(top_progress)) = (show_progress, top_progress) {
+                    if *loading && !*was_loading {
+                        top_progress.start();
+                    } else if !*loading && *was_loading {
+                        top_progress.finish();
+                    }
+                }
+                *was_loading = *loading;
+
+                || ()
+            },
+            scopes.any(),
+        );
+    }
+
+    let context = LoadingContext { scopes };
+
+    html! {
+        <ContextProvider<LoadingContext> {context}>
+            { for props.children.iter() }
+        </ContextProvider<LoadingContext>>
+    }
+}
+
+/// Returns the handle of a loading scope.
+///
+/// Returns the [`LoadingHandle`] of the given scope, shared with every other
+/// component using the same scope inside the closest [`LoadingProvider`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{components::loading::use_loading, form::general::Control};
+///
+/// #[function_component(Search)]
+/// fn search() -> Html {
+///     let loading = use_loading("search");
+///
+///     html! {
+///         <Control loading={loading.loading()}>
+///             <input class="input" type="search" />
+///         </Control>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_loading(scope: impl Into<AttrValue>) -> LoadingHandle {
+    LoadingHandle {
+        scope: scope.into(),
+        context: use_context::<LoadingContext>(),
+    }
+}
+
+/// Defines the properties of the loading button.
+///
+/// Defines the properties of the loading button, a [`Button`] displaying a
+/// loading indicator while any operation of its scope is in progress.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::loading::{LoadingButton, LoadingProvider};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <LoadingProvider>
+///             <LoadingButton scope="save">{"Save"}</LoadingButton>
+///         </LoadingProvider>
+///     }
+/// }
+/// ```
+///
+/// [`Button`]: crate::elements::button::Button
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct LoadingButtonProperties {
+    /// Sets the loading scope the loading button reflects.
+    ///
+    /// Sets the scope, shared through the closest [`LoadingProvider`], whose
+    /// operations make the loading button, which will receive these
+    /// properties, display a loading indicator while any of them is in
+    /// progress.
+    pub scope: AttrValue,
+    /// Sets the color of the loading button.
+    ///
+    /// Sets the color of the [`Button`] rendered by the loading button which
+    /// will receive these properties.
+    ///
+    /// [`Button`]: crate::elements::button::Button
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the loading button.
+    ///
+    /// Sets the size of the [`Button`] rendered by the loading button which
+    /// will receive these properties.
+    ///
+    /// [`Button`]: crate::elements::button::Button
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Sets the style of the loading button.
+    ///
+    /// Sets the style of the [`Button`] rendered by the loading button which
+    /// will receive these properties.
+    ///
+    /// [`Button`]: crate::elements::button::Button
+    #[prop_or_default]
+    pub style: Option<Style>,
+    /// Whether or not the loading button should be disabled.
+    ///
+    /// Whether or not the [`Button`] rendered by the loading button, which
+    /// will receive these properties, will be disabled.
+    ///
+    /// [`Button`]: crate::elements::button::Button
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Sets the HTML type of the loading button.
+    ///
+    /// Sets the `type` attribute of the [`Button`] rendered by the loading
+    /// button which will receive these properties.
+    ///
+    /// [`Button`]: crate::elements::button::Button
+    #[prop_or_default]
+    pub button_type: Option<ButtonType>,
+    /// The list of elements found inside the loading button.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the loading button.
+///
+/// Renders a [`Button`] in the [`State::Loading`] state while any operation
+/// of its scope, shared through the closest [`LoadingProvider`], is in
+/// progress.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::loading::{use_loading, LoadingButton, LoadingProvider},
+///     helpers::color::Color,
+/// };
+///
+/// #[function_component(Save)]
+/// fn save() -> Html {
+///     let loading = use_loading("save");
+///     let onclick = Callback::from(move |_| loading.start());
+///
+///     html! {
+///         <LoadingButton scope="save" color={Color::Primary} {onclick}>{"Save"}</LoadingButton>
+///     }
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <LoadingProvider>
+///             <Save />
+///         </LoadingProvider>
+///     }
+/// }
+/// ```
+///
+/// [`Button`]: crate::elements::button::Button
+/// [`State::Loading`]: crate::elements::button::State::Loading
+#[function_component(LoadingButton)]
+pub fn loading_button(props: &LoadingButtonProperties) -> Html {
+    let loading = use_loading(props.scope.clone());
+    let state = loading.loading().then(|| State::Loading);
+
+    html! {
+        <Button id={props.id.clone()} class={props.class.clone()} color={props.color} size={props.size}
+            style={props.style} {state} disabled={props.disabled} button_type={props.button_type}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </Button>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scopes_stay_loading_until_every_operation_finishes() {
+        let scopes = Rc::new(LoadingScopes::default())
+            .reduce(LoadingAction::Start("save".into()))
+            .reduce(LoadingAction::Start("save".into()))
+            .reduce(LoadingAction::Finish("save".into()));
+
+        assert!(scopes.is_loading("save"));
+        assert!(!scopes.is_loading("search"));
+        assert!(scopes.any());
+
+        let scopes = scopes.reduce(LoadingAction::Finish("save".into()));
+
+        assert!(!scopes.is_loading("save"));
+        assert!(!scopes.any());
+    }
+
+    #[test]
+    fn finishing_an_idle_scope_does_nothing() {
+        let scopes = Rc::new(LoadingScopes::default()).reduce(LoadingAction::Finish("save".into()));
+
+        assert!(!scopes.any());
+    }
+}
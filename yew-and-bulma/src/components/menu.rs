@@ -0,0 +1,481 @@
+use yew::{function_component, html, AttrValue, Callback, Children, Html, MouseEvent, Properties};
+use yew_and_bulma_macros::base_component_properties;
+#[cfg(feature = "router")]
+use yew_router::{hooks::use_navigator, navigator::Navigator, Routable};
+
+#[cfg(feature = "router")]
+use crate::utils::router::RouteTarget;
+use crate::utils::{class::ClassBuilder, constants::IS_PREFIX};
+
+/// Defines an entry of the [Bulma menu list][bd].
+///
+/// Defines a link shown inside a [`MenuList`], made up of its URL, label,
+/// whether it is the current page and the entries nested under it, which are
+/// rendered as a sub-list.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{MenuItem, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let items = vec![
+///         MenuItem::new("/team", "Team").with_children(vec![
+///             MenuItem::new("/team/members", "Members").active(),
+///             MenuItem::new("/team/plugins", "Plugins"),
+///         ]),
+///     ];
+///
+///     html! {
+///         <MenuList {items} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[derive(Clone, Debug, PartialEq)]
+pub struct MenuItem {
+    pub href: AttrValue,
+    pub label: AttrValue,
+    pub active: bool,
+    pub children: Vec<MenuItem>,
+    #[cfg(feature = "router")]
+    pub to: Option<RouteTarget>,
+}
+
+impl MenuItem {
+    /// Creates an inactive entry linking to the given URL.
+    pub fn new(href: impl Into<AttrValue>, label: impl Into<AttrValue>) -> Self {
+        Self {
+            href: href.into(),
+            label: label.into(),
+            active: false,
+            children: Vec::new(),
+            #[cfg(feature = "router")]
+            to: None,
+        }
+    }
+
+    /// Creates an inactive entry navigating to the given route on the client
+    /// side. Only available with the `router` feature.
+    #[cfg(feature = "router")]
+    pub fn from_route<R: Routable + 'static>(route: R, label: impl Into<AttrValue>) -> Self {
+        let to = RouteTarget::new(route);
+        Self {
+            href: AttrValue::from(to.path().to_owned()),
+            to: Some(to),
+            ..Self::new("", label)
+        }
+    }
+
+    /// Marks the entry as the current page.
+    pub fn active(mut self) -> Self {
+        self.active = true;
+        self
+    }
+
+    /// Nests the given entries under this one.
+    pub fn with_children(mut self, children: Vec<MenuItem>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// Defines the properties of the [Bulma menu component][bd].
+///
+/// Defines the properties of the menu component, based on the specification
+/// found in the [Bulma menu component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuLabel, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let items = vec![MenuItem::new("/", "Dashboard")];
+///
+///     html! {
+///         <Menu>
+///             <MenuLabel>{"General"}</MenuLabel>
+///             <MenuList {items} />
+///         </Menu>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MenuProperties {
+    /// The list of elements found inside the [Bulma menu component][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma menu component][bd] which will receive these properties,
+    /// usually [`MenuLabel`]s and [`MenuList`]s.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma menu component][bd].
+///
+/// Yew implementation of the menu component, based on the specification found
+/// in the [Bulma menu component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuLabel, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let items = vec![
+///         MenuItem::new("/", "Dashboard").active(),
+///         MenuItem::new("/customers", "Customers"),
+///     ];
+///
+///     html! {
+///         <Menu>
+///             <MenuLabel>{"General"}</MenuLabel>
+///             <MenuList {items} />
+///         </Menu>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[function_component(Menu)]
+pub fn menu(props: &MenuProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("menu")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <aside id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </aside>
+    }
+}
+
+/// Defines the properties of the [Bulma menu label][bd].
+///
+/// Defines the properties of the menu label, the heading shown above a
+/// [`MenuList`], based on the specification found in the
+/// [Bulma menu component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::MenuLabel;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <MenuLabel>{"Administration"}</MenuLabel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MenuLabelProperties {
+    /// The list of elements found inside the [Bulma menu label][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma menu label][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma menu label][bd].
+///
+/// Yew implementation of the menu label, based on the specification found in
+/// the [Bulma menu component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::MenuLabel;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <MenuLabel>{"Transactions"}</MenuLabel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[function_component(MenuLabel)]
+pub fn menu_label(props: &MenuLabelProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("menu-label")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <p id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </p>
+    }
+}
+
+/// Defines the properties of the [Bulma menu list][bd].
+///
+/// Defines the properties of the menu list, based on the specification found
+/// in the [Bulma menu component documentation][bd]. Its entries can either be
+/// built from data, through [`MenuListProperties::items`], or written by hand
+/// as its children.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::MenuList;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <MenuList>
+///             <li><a href="/">{"Dashboard"}</a></li>
+///         </MenuList>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MenuListProperties {
+    /// The entries of the [Bulma menu list][bd], in order.
+    ///
+    /// Defines the links shown by the [Bulma menu list][bd] which will receive
+    /// these properties. The children of each entry are rendered as a nested
+    /// list under it. They are rendered before the children of the menu list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::menu::{MenuItem, MenuList};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let items = vec![
+    ///         MenuItem::new("/settings", "Settings").with_children(vec![
+    ///             MenuItem::new("/settings/profile", "Profile"),
+    ///             MenuItem::new("/settings/billing", "Billing"),
+    ///         ]),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <MenuList {items} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub items: Vec<MenuItem>,
+    /// Sets the callback used when an entry is clicked.
+    ///
+    /// Sets the callback called with the [`MenuItem`] clicked inside the
+    /// [Bulma menu list][bd] which will receive these properties, at any depth.
+    /// When set, the browser does not follow the link, letting a router
+    /// navigate instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::menu::{MenuItem, MenuList};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let current = use_state(|| AttrValue::from("/"));
+    ///     let items = vec![MenuItem::new("/", "Dashboard"), MenuItem::new("/customers", "Customers")]
+    ///         .into_iter()
+    ///         .map(|item| if item.href == *current { item.active() } else { item })
+    ///         .collect::<Vec<_>>();
+    ///     let onitemselect = Callback::from(move |item: MenuItem| current.set(item.href));
+    ///
+    ///     html! {
+    ///         <MenuList {items} {onitemselect} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub onitemselect: Option<Callback<MenuItem>>,
+    /// The list of elements found inside the [Bulma menu list][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma menu list][bd] which will receive these properties, usually
+    /// `li` tags holding links.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/menu/
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma menu list][bd].
+///
+/// Yew implementation of the menu list, based on the specification found in
+/// the [Bulma menu component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{MenuItem, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let items = vec![
+///         MenuItem::new("/payments", "Payments").active(),
+///         MenuItem::new("/transfers", "Transfers"),
+///     ];
+///
+///     html! {
+///         <MenuList {items} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+#[function_component(MenuList)]
+pub fn menu_list(props: &MenuListProperties) -> Html {
+    #[cfg(feature = "router")]
+    let navigator = use_navigator();
+    let class = ClassBuilder::default()
+        .with_custom_class("menu-list")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let items = props.items.iter().map(|item| {
+        menu_item(
+            item,
+            &props.onitemselect,
+            #[cfg(feature = "router")]
+            &navigator,
+        )
+    });
+
+    html! {
+        <ul id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for items }
+            { for props.children.iter() }
+        </ul>
+    }
+}
+
+fn menu_item(
+    item: &MenuItem,
+    onitemselect: &Option<Callback<MenuItem>>,
+    #[cfg(feature = "router")] navigator: &Option<Navigator>,
+) -> Html {
+    let onclick = onitemselect.clone().map(|onitemselect| {
+        let item = item.clone();
+        Callback::from(move |event: MouseEvent| {
+            event.prevent_default();
+            onitemselect.emit(item.clone());
+        })
+    });
+    #[cfg(feature = "router")]
+    let (href, onclick) = match (&item.to, navigator) {
+        (Some(to), Some(navigator)) => (
+            to.href(navigator),
+            Some(to.onclick(navigator.clone(), onclick)),
+        ),
+        _ => (item.href.clone(), onclick),
+    };
+    #[cfg(not(feature = "router"))]
+    let href = item.href.clone();
+    let active = item.active.then(|| format!("{IS_PREFIX}-active"));
+    let current = item.active.then(|| AttrValue::from("page"));
+    let children = item.children.iter().map(|child| {
+        menu_item(
+            child,
+            onitemselect,
+            #[cfg(feature = "router")]
+            navigator,
+        )
+    });
+
+    html! {
+        <li>
+            <a {href} class={active} aria-current={current} {onclick}>{item.label.clone()}</a>
+            if !item.children.is_empty() {
+                <ul>
+                    { for children }
+                </ul>
+            }
+        </li>
+    }
+}
@@ -0,0 +1,511 @@
+use yew::{
+    function_component, html, use_context, use_state, Callback, Children, ContextProvider, Html,
+    MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::delete::Delete,
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+#[derive(Clone, PartialEq)]
+struct MessageContext {
+    onclose: Callback<MouseEvent>,
+}
+
+/// Defines the properties of the [Bulma message component][bd].
+///
+/// Defines the properties of the message component, based on the
+/// specification found in the [Bulma message component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageBody, MessageHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageHeader delete=true>{"Hello, world!"}</MessageHeader>
+///             <MessageBody>{"Some message content."}</MessageBody>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MessageProperties {
+    /// Sets the color of the [Bulma message component][bd].
+    ///
+    /// Sets the color of the [Bulma message component][bd] which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::message::{Message, MessageBody},
+    ///     helpers::color::Color,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Message color={Color::Info}>
+    ///             <MessageBody>{"Some message content."}</MessageBody>
+    ///         </Message>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma message component][bd].
+    ///
+    /// Sets the size of the [Bulma message component][bd] which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::message::{Message, MessageBody},
+    ///     utils::size::Size,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Message size={Size::Small}>
+    ///             <MessageBody>{"Some message content."}</MessageBody>
+    ///         </Message>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the message hides itself when closed.
+    ///
+    /// Whether or not the [Bulma message component][bd], which will receive
+    /// these properties, stops being rendered once the delete button of its
+    /// [`MessageHeader`] is clicked. Defaults to `true`; set it to `false` to
+    /// control whether the message is shown from its parent instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::message::{Message, MessageBody, MessageHeader};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let shown = use_state(|| true);
+    ///     let onclose = {
+    ///         let shown = shown.clone();
+    ///         Callback::from(move |_| shown.set(false))
+    ///     };
+    ///
+    ///     html! {
+    ///         if *shown {
+    ///             <Message hide_on_close=false {onclose}>
+    ///                 <MessageHeader delete=true>{"Hello, world!"}</MessageHeader>
+    ///                 <MessageBody>{"Some message content."}</MessageBody>
+    ///             </Message>
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    #[prop_or(true)]
+    pub hide_on_close: bool,
+    /// Sets the callback used when the message is closed.
+    ///
+    /// Sets the callback called when the delete button found inside the
+    /// [`MessageHeader`] of the [Bulma message component][bd], which will
+    /// receive these properties, is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::message::{Message, MessageBody, MessageHeader};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let closed = use_state(|| false);
+    ///     let onclose = {
+    ///         let closed = closed.clone();
+    ///         Callback::from(move |_| closed.set(true))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Message {onclose}>
+    ///             <MessageHeader delete=true>{"Hello, world!"}</MessageHeader>
+    ///             <MessageBody>{"Some message content."}</MessageBody>
+    ///         </Message>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    #[prop_or_default]
+    pub onclose: Option<Callback<MouseEvent>>,
+    /// The list of elements found inside the [Bulma message component][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma message component][bd] which will receive these properties,
+    /// usually a [`MessageHeader`] and a [`MessageBody`].
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma message component][bd].
+///
+/// Yew implementation of the message component, based on the specification
+/// found in the [Bulma message component documentation][bd]. Clicking the
+/// delete button of its [`MessageHeader`] emits its
+/// [`MessageProperties::onclose`] callback and, unless
+/// [`MessageProperties::hide_on_close`] is unset, hides the message.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     components::message::{Message, MessageBody, MessageHeader},
+///     helpers::color::Color,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message color={Color::Warning}>
+///             <MessageHeader delete=true>{"Warning"}</MessageHeader>
+///             <MessageBody>{"Some message content."}</MessageBody>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[function_component(Message)]
+pub fn message(props: &MessageProperties) -> Html {
+    let hidden = use_state(|| false);
+    if *hidden {
+        return html! {};
+    }
+
+    let context = MessageContext {
+        onclose: {
+            let onclose = props.onclose.clone();
+            let hide_on_close = props.hide_on_close;
+            Callback::from(move |event: MouseEvent| {
+                if hide_on_close {
+                    hidden.set(true);
+                }
+                if let Some(onclose) = &onclose {
+                    onclose.emit(event);
+                }
+            })
+        },
+    };
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| {
+            if Size::Normal == *size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("message")
+        .with_color(props.color)
+        .with_custom_class(&size)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<MessageContext> {context}>
+            <article id={props.id.clone()} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+            </article>
+        </ContextProvider<MessageContext>>
+    }
+}
+
+/// Defines the properties of the [Bulma message header][bd].
+///
+/// Defines the properties of the message header, based on the specification
+/// found in the [Bulma message component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageHeader>{"Hello, world!"}</MessageHeader>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MessageHeaderProperties {
+    /// Whether or not the message header has a delete button.
+    ///
+    /// Whether or not a delete button is shown inside the
+    /// [Bulma message header][bd] which will receive these properties.
+    /// Clicking it closes the [`Message`] it is found in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::message::{Message, MessageHeader};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Message>
+    ///             <MessageHeader delete=true>{"Hello, world!"}</MessageHeader>
+    ///         </Message>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    #[prop_or_default]
+    pub delete: bool,
+    /// Sets the callback used when the delete button is clicked.
+    ///
+    /// Sets the callback called when the delete button of the
+    /// [Bulma message header][bd], which will receive these properties, is
+    /// clicked, before closing the [`Message`] it is found in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::message::{Message, MessageHeader};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onclose = Callback::from(|event: MouseEvent| event.stop_propagation());
+    ///
+    ///     html! {
+    ///         <Message>
+    ///             <MessageHeader delete=true {onclose}>{"Hello, world!"}</MessageHeader>
+    ///         </Message>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    #[prop_or_default]
+    pub onclose: Option<Callback<MouseEvent>>,
+    /// The list of elements found inside the [Bulma message header][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma message header][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma message header][bd].
+///
+/// Yew implementation of the message header, based on the specification
+/// found in the [Bulma message component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageHeader delete=true>{"Hello, world!"}</MessageHeader>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[function_component(MessageHeader)]
+pub fn message_header(props: &MessageHeaderProperties) -> Html {
+    let message = use_context::<MessageContext>();
+    let onclick = {
+        let onclose = props.onclose.clone();
+        Callback::from(move |event: MouseEvent| {
+            if let Some(onclose) = &onclose {
+                onclose.emit(event.clone());
+            }
+            if let Some(message) = &message {
+                message.onclose.emit(event);
+            }
+        })
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("message-header")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+            if props.delete {
+                <Delete {onclick} />
+            }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma message body][bd].
+///
+/// Defines the properties of the message body, based on the specification
+/// found in the [Bulma message component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageBody};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageBody>{"Some message content."}</MessageBody>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MessageBodyProperties {
+    /// The list of elements found inside the [Bulma message body][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma message body][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma message body][bd].
+///
+/// Yew implementation of the message body, based on the specification
+/// found in the [Bulma message component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageBody};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageBody>{"Some message content."}</MessageBody>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[function_component(MessageBody)]
+pub fn message_body(props: &MessageBodyProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("message-body")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
@@ -0,0 +1,463 @@
+/// Provides utilities for creating audio players in Yew.
+///
+/// Defines the necessary components to play audio files using Bulma styled
+/// controls, such as [Bulma buttons][bd] and progress bars.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::audio::AudioPlayer;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <AudioPlayer src="media/audio/episode-1.mp3" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/button/
+pub mod audio;
+/// Provides utilities for creating Kanban style boards in Yew.
+///
+/// Defines the necessary components to build a board made up of
+/// [Bulma columns][bd], holding cards which can be dragged between them.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::board::{Board, BoardCard, BoardColumn};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Board>
+///             <BoardColumn name="todo" title="To do">
+///                 <BoardCard name="1">{"Write docs"}</BoardCard>
+///             </BoardColumn>
+///             <BoardColumn name="done" title="Done" />
+///         </Board>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/columns/
+pub mod board;
+/// Provides utilities for creating [breadcrumb components][bd] in Yew.
+///
+/// Defines the necessary components to build a [Bulma breadcrumb][bd],
+/// whose links can be intercepted to navigate without a full page load.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::breadcrumb::{Breadcrumb, Crumb};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let crumbs = vec![Crumb::new("/", "Home"), Crumb::new("/docs", "Docs").active()];
+///
+///     html! {
+///         <Breadcrumb {crumbs} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/breadcrumb/
+pub mod breadcrumb;
+/// Provides utilities for creating image comparison sliders in Yew.
+///
+/// Defines the necessary components to build a before/after slider, showing
+/// two images inside a [Bulma figure element][bd] split by a draggable
+/// divider.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::compare::ImageCompare;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ImageCompare before="media/images/before.png" after="media/images/after.png" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/image/
+pub mod compare;
+/// Provides utilities for creating consent banners in Yew.
+///
+/// Defines the necessary components to build a cookie/GDPR consent banner,
+/// styled as a [Bulma notification element][bd] fixed to the bottom of the
+/// page.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::consent::ConsentBanner;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ConsentBanner>{"This website uses cookies."}</ConsentBanner>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/notification/
+pub mod consent;
+/// Provides utilities for creating [dropdown components][bd] in Yew.
+///
+/// Defines the necessary components to build a [Bulma dropdown][bd], which
+/// can optionally be closed by clicking outside of it.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::dropdown::{Dropdown, DropdownItem, DropdownMenu, DropdownTrigger};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dropdown hoverable=true>
+///             <DropdownTrigger>
+///                 <button class="button">{"Options"}</button>
+///             </DropdownTrigger>
+///             <DropdownMenu>
+///                 <DropdownItem href="#">{"Edit"}</DropdownItem>
+///             </DropdownMenu>
+///         </Dropdown>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/dropdown/
+pub mod dropdown;
+/// Provides utilities for creating filter bars in Yew.
+///
+/// Defines the necessary components to show the active filters of a list or
+/// table as deletable [Bulma tags][bd], along with a button clearing them.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::filter_bar::{Filter, FilterBar};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let filters = vec![Filter::new("Status", "Active")];
+///
+///     html! {
+///         <FilterBar {filters} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/tag/
+pub mod filter_bar;
+/// Provides utilities for creating idle timeout dialogs in Yew.
+///
+/// Defines a [Bulma modal card][bd] which tracks the activity of the user
+/// and warns them, using a countdown, before they are considered idle (ie
+/// signed out of an authenticated dashboard).
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::idle::IdleTimeout;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let ontimeout = Callback::from(|_| ());
+///
+///     html! {
+///         <IdleTimeout {ontimeout} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+pub mod idle;
+/// Provides utilities for sharing loading states in Yew.
+///
+/// Defines a provider and a hook used to share the state of asynchronous
+/// operations, grouped by scope, between components (ie a [`Button`] and the
+/// [`TopProgress`] bar), without passing it down through properties.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::loading::{LoadingButton, LoadingProvider};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <LoadingProvider>
+///             <LoadingButton scope="save">{"Save"}</LoadingButton>
+///         </LoadingProvider>
+///     }
+/// }
+/// ```
+///
+/// [`Button`]: crate::elements::button::Button
+/// [`TopProgress`]: crate::components::topbar_progress::TopProgress
+pub mod loading;
+/// Provides utilities for creating [menu components][bd] in Yew.
+///
+/// Defines the necessary components to build a [Bulma menu][bd], whose lists
+/// can be built from a tree of [`crate::components::menu::MenuItem`]s.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::menu::{Menu, MenuItem, MenuLabel, MenuList};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let items = vec![MenuItem::new("/team", "Team")
+///         .with_children(vec![MenuItem::new("/team/members", "Members").active()])];
+///
+///     html! {
+///         <Menu>
+///             <MenuLabel>{"Administration"}</MenuLabel>
+///             <MenuList {items} />
+///         </Menu>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/menu/
+pub mod menu;
+/// Provides utilities for creating [message components][bd] in Yew.
+///
+/// Defines the necessary components to build a [Bulma message][bd], which
+/// can optionally be dismissed using the delete button of its header.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::message::{Message, MessageBody, MessageHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Message>
+///             <MessageHeader delete=true>{"Hello, world!"}</MessageHeader>
+///             <MessageBody>{"Some message content."}</MessageBody>
+///         </Message>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+pub mod message;
+/// Provides utilities for creating [modal components][bd] in Yew.
+///
+/// Defines the necessary components to build a [Bulma modal][bd], which can
+/// optionally be closed by pressing `Escape` or clicking its background.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalContent};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal active=true closable=true>
+///             <ModalContent>{"Some modal content."}</ModalContent>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+pub mod modal;
+/// Provides utilities for creating [navbar components][bd] in Yew.
+///
+/// Defines the necessary components to build a [Bulma navbar][bd], including
+/// the burger toggle used to open the navbar menu on mobile devices.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{
+///     Navbar, NavbarBrand, NavbarBurger, NavbarItem, NavbarMenu, NavbarStart,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem href="/">{"Home"}</NavbarItem>
+///                 <NavbarBurger />
+///             </NavbarBrand>
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarItem href="/docs">{"Documentation"}</NavbarItem>
+///                 </NavbarStart>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/
+pub mod navbar;
+/// Provides utilities for creating [pagination components][bd] in Yew.
+///
+/// Defines the necessary components to build a [Bulma pagination][bd],
+/// generating the page links, ellipses and previous/next buttons from the
+/// number of pages and the current page.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::pagination::Pagination;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Pagination total_pages={20} current_page={5} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/pagination/
+pub mod pagination;
+/// Provides utilities for creating [panel components][bd] in Yew.
+///
+/// Defines the necessary components to build a [Bulma panel][bd], with
+/// selectable tabs and a search input filtering its blocks.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelHeading, PanelSearch};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelHeading>{"Repositories"}</PanelHeading>
+///             <PanelSearch />
+///             <PanelBlock href="#" label="bulma">{"bulma"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+pub mod panel;
+/// Provides utilities for creating notification permission prompts in Yew.
+///
+/// Defines a dismissible [Bulma message component][bd] asking the user to
+/// allow the page to show notifications. Only available with the `web-apis`
+/// feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::push_prompt::PushPrompt;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <PushPrompt>{"Get notified when your order ships."}</PushPrompt>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[cfg(feature = "web-apis")]
+pub mod push_prompt;
+/// Provides utilities for creating [tabs components][bd] in Yew.
+///
+/// Defines the necessary components to build [Bulma tabs][bd], whose active
+/// tab is controlled by their parent, optionally along with the panel
+/// matching the active tab.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{TabPanel, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let tabs = vec![html! { "Pictures" }, html! { "Music" }];
+///
+///     html! {
+///         <Tabs {tabs}>
+///             <TabPanel>{"Some pictures."}</TabPanel>
+///             <TabPanel>{"Some music."}</TabPanel>
+///         </Tabs>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+pub mod tabs;
+/// Provides utilities for showing toasts in Yew.
+///
+/// Defines the necessary components and hooks to show short-lived messages,
+/// rendered as [Bulma notification elements][bd] stacked over the page, from
+/// anywhere in the application.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::toast::ToastProvider;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ToastProvider>{"The rest of the application."}</ToastProvider>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/notification/
+pub mod toast;
+/// Provides utilities for creating a global busy indicator in Yew.
+///
+/// Defines the necessary components to show a thin
+/// [Bulma progress bar][bd] fixed to the top of the viewport, controlled
+/// through a context handle, for route transitions and fetch activity. With
+/// the `router` feature, `RouterProgress` drives it on route changes.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::topbar_progress::TopProgress;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <TopProgress>{"The rest of the application."}</TopProgress>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/progress/
+pub mod topbar_progress;
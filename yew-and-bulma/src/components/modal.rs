@@ -0,0 +1,1212 @@
+use gloo::events::EventListener;
+use js_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, Node};
+use yew::{
+    function_component, hook, html, use_context, use_effect_with_deps, use_node_ref, use_state,
+    AttrValue, Callback, Children, ContextProvider, Html, KeyboardEvent, MouseEvent, NodeRef,
+    Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::delete::Delete,
+    utils::{class::ClassBuilder, constants::IS_PREFIX},
+};
+
+const FOCUSABLE: &str = "a[href], area[href], button:not([disabled]), input:not([disabled]), \
+    select:not([disabled]), textarea:not([disabled]), iframe, [contenteditable], \
+    [tabindex]:not([tabindex='-1'])";
+
+#[derive(Clone, PartialEq)]
+struct ModalContext {
+    onclose: Option<Callback<()>>,
+}
+
+/// Defines the properties of the [Bulma modal component][bd].
+///
+/// Defines the properties of the modal component, based on the
+/// specification found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalContent};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal active=true>
+///             <ModalContent>{"Some modal content."}</ModalContent>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ModalProperties {
+    /// Whether or not the modal is open.
+    ///
+    /// Whether or not the [Bulma modal component][bd], which will receive
+    /// these properties, is shown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalContent};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let active = use_state(|| false);
+    ///     let onclick = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |_| active.set(true))
+    ///     };
+    ///
+    ///     html! {
+    ///         <>
+    ///             <button class="button" {onclick}>{"Open"}</button>
+    ///             <Modal active={*active}>
+    ///                 <ModalContent>{"Some modal content."}</ModalContent>
+    ///             </Modal>
+    ///         </>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub active: bool,
+    /// Whether or not pressing `Escape` closes the modal.
+    ///
+    /// Whether or not pressing the `Escape` key while the
+    /// [Bulma modal component][bd], which will receive these properties, is
+    /// open emits the [`ModalProperties::onclose`] callback.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub close_on_escape: bool,
+    /// Whether or not clicking the background closes the modal.
+    ///
+    /// Whether or not clicking the background of the
+    /// [Bulma modal component][bd], which will receive these properties,
+    /// emits the [`ModalProperties::onclose`] callback.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub close_on_background: bool,
+    /// Whether or not to show the close button of the modal.
+    ///
+    /// Whether or not to show the close button found in the top right corner
+    /// of the [Bulma modal component][bd], which will receive these
+    /// properties. Clicking it emits the [`ModalProperties::onclose`]
+    /// callback.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub closable: bool,
+    /// Whether or not to keep the focus inside the modal.
+    ///
+    /// Whether or not the focus stays inside the open
+    /// [Bulma modal component][bd], which will receive these properties.
+    /// When set, its first focusable element is focused when it opens and
+    /// `Tab`/`Shift+Tab` cycle through its focusable elements instead of
+    /// reaching the page behind it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalCard};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Modal active=true trap_focus=true>
+    ///             <ModalCard title="Sign in">
+    ///                 <input class="input" type="email" />
+    ///                 <input class="input" type="password" />
+    ///             </ModalCard>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub trap_focus: bool,
+    /// Whether or not to stop the page from scrolling behind the modal.
+    ///
+    /// Whether or not the page behind the open [Bulma modal component][bd],
+    /// which will receive these properties, can be scrolled. When set, the
+    /// `is-clipped` class is added to the `<html>` element while the modal
+    /// is open.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalContent};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Modal active=true lock_scroll=true>
+    ///             <ModalContent>{"Some modal content."}</ModalContent>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub lock_scroll: bool,
+    /// Sets the callback used when the modal should close.
+    ///
+    /// Sets the callback called when the [Bulma modal component][bd], which
+    /// will receive these properties, should be closed: when `Escape` is
+    /// pressed, its background is clicked or one of its close buttons is
+    /// clicked, as enabled by its other properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalContent};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let active = use_state(|| true);
+    ///     let onclose = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |_| active.set(false))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Modal active={*active} close_on_escape=true close_on_background=true {onclose}>
+    ///             <ModalContent>{"Some modal content."}</ModalContent>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub onclose: Option<Callback<()>>,
+    /// Sets the blur of the modal background.
+    ///
+    /// Sets the blur radius, as a CSS length, applied to the page seen
+    /// through the [`ModalBackground`] of the [Bulma modal component][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalContent};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Modal active=true background_blur="4px" background_opacity={0.5}>
+    ///             <ModalContent>{"Some modal content."}</ModalContent>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub background_blur: Option<AttrValue>,
+    /// Sets the opacity of the modal background.
+    ///
+    /// Sets the opacity, between `0.0` and `1.0`, of the [`ModalBackground`]
+    /// of the [Bulma modal component][bd] which will receive these
+    /// properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub background_opacity: Option<f64>,
+    /// The list of elements found inside the modal.
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma modal component][bd] which will receive these properties,
+    /// usually a [`ModalContent`] or a [`ModalCard`].
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma modal component][bd].
+///
+/// Yew implementation of the modal component, based on the specification
+/// found in the [Bulma modal component documentation][bd]. It renders its
+/// own background, which can close it when clicked.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalContent};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal active=true closable=true>
+///             <ModalContent>{"Some modal content."}</ModalContent>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[function_component(Modal)]
+pub fn modal(props: &ModalProperties) -> Html {
+    let node = use_node_ref();
+
+    let onkeydown = use_focus_trap(
+        node.clone(),
+        props.active && props.trap_focus,
+        props.onkeydown.clone(),
+    );
+    use_close_on_escape(props.onclose.clone(), props.active && props.close_on_escape);
+    use_effect_with_deps(
+        move |locking| {
+            let html = locking
+                .then(web_sys::window)
+                .flatten()
+                .and_then(|window| window.document())
+                .and_then(|document| document.document_element());
+            if let Some(html) = &html {
+                let _ = html.class_list().add_1(&format!("{IS_PREFIX}-clipped"));
+            }
+
+            move || {
+                if let Some(html) = html {
+                    let _ = html.class_list().remove_1(&format!("{IS_PREFIX}-clipped"));
+                }
+            }
+        },
+        props.active && props.lock_scroll,
+    );
+
+    let close = {
+        let onclose = props.onclose.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(onclose) = &onclose {
+                onclose.emit(());
+            }
+        })
+    };
+    let onbackgroundclick = props.close_on_background.then(|| close.clone());
+    let context = ModalContext {
+        onclose: props.onclose.clone(),
+    };
+    let active = if props.active {
+        format!("{IS_PREFIX}-active")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("modal")
+        .with_custom_class(&active)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<ModalContext> {context}>
+            <div ref={node} id={props.id.clone()} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                {onkeydown} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                <ModalBackground blur={props.background_blur.clone()} opacity={props.background_opacity} onclick={onbackgroundclick} />
+                { for props.children.iter() }
+                if props.closable {
+                    <button class={format!("modal-close {IS_PREFIX}-large")} aria-label="close" onclick={close}></button>
+                }
+            </div>
+        </ContextProvider<ModalContext>>
+    }
+}
+
+/// Defines the properties of the [Bulma modal background][bd].
+///
+/// Defines the properties of the modal background, the overlay shown behind
+/// the content of the modal, based on the specification found in the
+/// [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::ModalBackground;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <div class="modal is-active">
+///             <ModalBackground blur="4px" opacity={0.5} />
+///         </div>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ModalBackgroundProperties {
+    /// Sets the blur of the modal background.
+    ///
+    /// Sets the blur radius, as a CSS length, applied through a
+    /// `backdrop-filter` to the page seen through the
+    /// [Bulma modal background][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::ModalBackground;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ModalBackground blur="0.5rem" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub blur: Option<AttrValue>,
+    /// Sets the opacity of the modal background.
+    ///
+    /// Sets the opacity, between `0.0` and `1.0`, of the
+    /// [Bulma modal background][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::ModalBackground;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ModalBackground opacity={0.3} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    #[prop_or_default]
+    pub opacity: Option<f64>,
+}
+
+/// Yew implementation of the [Bulma modal background][bd].
+///
+/// Yew implementation of the modal background, based on the specification
+/// found in the [Bulma modal component documentation][bd]. The [`Modal`]
+/// renders one itself, so this is only needed when building a modal by hand.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::ModalBackground;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <div class="modal is-active">
+///             <ModalBackground blur="4px" />
+///         </div>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[function_component(ModalBackground)]
+pub fn modal_background(props: &ModalBackgroundProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("modal-background")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let style = background_style(props.blur.as_deref(), props.opacity);
+
+    html! {
+        <div id={props.id.clone()} {class} {style}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+        </div>
+    }
+}
+
+/// Builds the inline style of a modal background from its blur and opacity.
+fn background_style(blur: Option<&str>, opacity: Option<f64>) -> Option<String> {
+    let mut style = String::new();
+    if let Some(blur) = blur {
+        style.push_str(&format!(
+            "backdrop-filter: blur({blur}); -webkit-backdrop-filter: blur({blur});"
+        ));
+    }
+    if let Some(opacity) = opacity {
+        style.push_str(&format!("opacity: {};", opacity.clamp(0.0, 1.0)));
+    }
+
+    (!style.is_empty()).then(|| style)
+}
+
+/// Defines the properties of the popout.
+///
+/// Defines the properties of the popout, a non-modal variant of the
+/// [Bulma modal component][bd] which shows its content, usually a
+/// [`ModalCard`], anchored under a trigger instead of over a full-screen
+/// background, leaving the rest of the page usable.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{ModalCard, Popout};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let trigger = html! { <button class="button">{"Share"}</button> };
+///
+///     html! {
+///         <Popout active=true {trigger}>
+///             <ModalCard title="Share">{"Some popout content."}</ModalCard>
+///         </Popout>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PopoutProperties {
+    /// Whether or not the popout is open.
+    ///
+    /// Whether or not the content of the popout, which will receive these
+    /// properties, is shown under its trigger.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{ModalCard, Popout};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let active = use_state(|| false);
+    ///     let onclick = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |_| active.set(!*active))
+    ///     };
+    ///     let trigger = html! { <button class="button" {onclick}>{"Share"}</button> };
+    ///
+    ///     html! {
+    ///         <Popout active={*active} {trigger}>
+    ///             <ModalCard title="Share">{"Some popout content."}</ModalCard>
+    ///         </Popout>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub active: bool,
+    /// The element the popout is anchored to.
+    ///
+    /// Defines the element, usually a button, under which the content of the
+    /// popout, which will receive these properties, is shown. It is always
+    /// rendered, whether or not the popout is open.
+    #[prop_or_default]
+    pub trigger: Html,
+    /// Whether or not pressing `Escape` closes the popout.
+    ///
+    /// Whether or not pressing the `Escape` key while the popout, which will
+    /// receive these properties, is open emits the
+    /// [`PopoutProperties::onclose`] callback.
+    #[prop_or_default]
+    pub close_on_escape: bool,
+    /// Whether or not clicking outside of the popout closes it.
+    ///
+    /// Whether or not pressing the mouse outside of the trigger and content of
+    /// the popout, which will receive these properties, while it is open
+    /// emits the [`PopoutProperties::onclose`] callback.
+    #[prop_or_default]
+    pub close_on_outside_click: bool,
+    /// Whether or not to keep the focus inside the popout.
+    ///
+    /// Whether or not the focus stays inside the content of the open popout,
+    /// which will receive these properties, the same way it does for
+    /// [`ModalProperties::trap_focus`].
+    #[prop_or_default]
+    pub trap_focus: bool,
+    /// Sets the callback used when the popout should close.
+    ///
+    /// Sets the callback called when the popout, which will receive these
+    /// properties, should be closed: when `Escape` is pressed, the page is
+    /// clicked outside of it or the close button of its [`ModalCard`] is
+    /// clicked, as enabled by its other properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{ModalCard, Popout};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let active = use_state(|| true);
+    ///     let onclose = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |_| active.set(false))
+    ///     };
+    ///     let trigger = html! { <button class="button">{"Share"}</button> };
+    ///
+    ///     html! {
+    ///         <Popout active={*active} {trigger} close_on_escape=true close_on_outside_click=true {onclose}>
+    ///             <ModalCard title="Share">{"Some popout content."}</ModalCard>
+    ///         </Popout>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onclose: Option<Callback<()>>,
+    /// The list of elements found inside the popout.
+    ///
+    /// Defines the elements shown under the trigger of the open popout which
+    /// will receive these properties, usually a [`ModalCard`].
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the popout.
+///
+/// Yew implementation of the popout, a non-modal variant of the
+/// [Bulma modal component][bd] which shares its focus and `Escape` handling,
+/// but renders its content next to its trigger, without a background or
+/// locking the scroll of the page.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{ModalCard, Popout};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let trigger = html! { <button class="button">{"Filters"}</button> };
+///
+///     html! {
+///         <Popout active=true {trigger} trap_focus=true>
+///             <ModalCard title="Filters">
+///                 <input class="input" type="search" />
+///             </ModalCard>
+///         </Popout>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[function_component(Popout)]
+pub fn popout(props: &PopoutProperties) -> Html {
+    let node = use_node_ref();
+    let content = use_node_ref();
+    let onkeydown = use_focus_trap(
+        content.clone(),
+        props.active && props.trap_focus,
+        props.onkeydown.clone(),
+    );
+    use_close_on_escape(props.onclose.clone(), props.active && props.close_on_escape);
+
+    {
+        let node = node.clone();
+        let onclose = props.onclose.clone();
+        use_effect_with_deps(
+            move |listening| {
+                let listener = listening
+                    .then(web_sys::window)
+                    .flatten()
+                    .and_then(|window| window.document())
+                    .map(|document| {
+                        EventListener::new(&document, "mousedown", move |event| {
+                            let target = event.target().and_then(|target| target.dyn_into().ok());
+                            let inside = node
+                                .cast::<Node>()
+                                .map(|popout| popout.contains(target.as_ref()))
+                                .unwrap_or_default();
+                            if let Some(onclose) = onclose.as_ref().filter(|_| !inside) {
+                                onclose.emit(());
+                            }
+                        })
+                    });
+
+                move || drop(listener)
+            },
+            props.active && props.close_on_outside_click,
+        );
+    }
+
+    let context = ModalContext {
+        onclose: props.onclose.clone(),
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<ModalContext> {context}>
+            <div ref={node} id={props.id.clone()} {class} style="position: relative; display: inline-block;"
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                {onkeydown} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { props.trigger.clone() }
+                if props.active {
+                    <div ref={content} style="position: absolute; top: 100%; left: 0; z-index: 40;">
+                        { for props.children.iter() }
+                    </div>
+                }
+            </div>
+        </ContextProvider<ModalContext>>
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Drag {
+    pointer: (i32, i32),
+    offset: (f64, f64),
+    min: (f64, f64),
+    max: (f64, f64),
+}
+
+impl Drag {
+    fn offset_at(&self, x: i32, y: i32) -> (f64, f64) {
+        let x = self.offset.0 + f64::from(x - self.pointer.0);
+        let y = self.offset.1 + f64::from(y - self.pointer.1);
+
+        (
+            x.max(self.min.0).min(self.max.0),
+            y.max(self.min.1).min(self.max.1),
+        )
+    }
+}
+
+#[hook]
+fn use_focus_trap(
+    node: NodeRef,
+    trapping: bool,
+    onkeydown: Option<Callback<KeyboardEvent>>,
+) -> Callback<KeyboardEvent> {
+    {
+        let node = node.clone();
+        use_effect_with_deps(
+            move |trapping| {
+                if *trapping {
+                    if let Some(first) = focusable_elements(&node).first() {
+                        let _ = first.focus();
+                    }
+                }
+            },
+            trapping,
+        );
+    }
+
+    Callback::from(move |event: KeyboardEvent| {
+        if trapping && event.key() == "Tab" {
+            let elements = focusable_elements(&node);
+            let focused = web_sys::window()
+                .and_then(|window| window.document())
+                .and_then(|document| document.active_element());
+            let is_focused = |element: Option<&HtmlElement>| {
+                element.map(|element| element.unchecked_ref::<Element>()) == focused.as_ref()
+            };
+            let wrap_to = if event.shift_key() && is_focused(elements.first()) {
+                elements.last()
+            } else if !event.shift_key() && is_focused(elements.last()) {
+                elements.first()
+            } else {
+                None
+            };
+            if let Some(element) = wrap_to {
+                event.prevent_default();
+                let _ = element.focus();
+            }
+        }
+        if let Some(onkeydown) = &onkeydown {
+            onkeydown.emit(event);
+        }
+    })
+}
+
+#[hook]
+fn use_close_on_escape(onclose: Option<Callback<()>>, listening: bool) {
+    use_effect_with_deps(
+        move |listening| {
+            let listener = listening
+                .then(web_sys::window)
+                .flatten()
+                .and_then(|window| window.document())
+                .map(|document| {
+                    EventListener::new(&document, "keydown", move |event| {
+                        let escape = event
+                            .dyn_ref::<KeyboardEvent>()
+                            .map(|event| event.key() == "Escape")
+                            .unwrap_or_default();
+                        if let Some(onclose) = onclose.as_ref().filter(|_| escape) {
+                            onclose.emit(());
+                        }
+                    })
+                });
+
+            move || drop(listener)
+        },
+        listening,
+    );
+}
+
+fn focusable_elements(node: &NodeRef) -> Vec<HtmlElement> {
+    node.cast::<Element>()
+        .and_then(|modal| modal.query_selector_all(FOCUSABLE).ok())
+        .map(|list| {
+            (0..list.length())
+                .filter_map(|index| list.item(index))
+                .filter_map(|item| item.dyn_into::<HtmlElement>().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Defines the properties of the [Bulma modal content][bd].
+///
+/// Defines the properties of the modal content, a container for any content
+/// shown inside the modal, based on the specification found in the
+/// [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalContent};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal active=true>
+///             <ModalContent>{"Some modal content."}</ModalContent>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ModalContentProperties {
+    /// The list of elements found inside the modal content.
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma modal content][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma modal content][bd].
+///
+/// Yew implementation of the modal content, based on the specification
+/// found in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalContent};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal active=true>
+///             <ModalContent>{"Some modal content."}</ModalContent>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/
+#[function_component(ModalContent)]
+pub fn modal_content(props: &ModalContentProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("modal-content")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma modal card][bd].
+///
+/// Defines the properties of the modal card, a modal content made up of a
+/// head, a body and a foot, based on the specification found in the
+/// [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalCard};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal active=true>
+///             <ModalCard title="Modal title">{"Some modal content."}</ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ModalCardProperties {
+    /// Sets the title of the modal card.
+    ///
+    /// Sets the title shown in the head of the [Bulma modal card][bd] which
+    /// will receive these properties, next to a button which emits the
+    /// [`ModalProperties::onclose`] callback of its modal.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+    pub title: AttrValue,
+    /// Sets the foot of the modal card.
+    ///
+    /// Sets the elements found inside the foot of the [Bulma modal card][bd]
+    /// which will receive these properties, usually buttons. The foot is
+    /// only rendered when set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalCard};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let foot = html! { <button class="button is-success">{"Save"}</button> };
+    ///
+    ///     html! {
+    ///         <Modal active=true>
+    ///             <ModalCard title="Modal title" {foot}>{"Some modal content."}</ModalCard>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+    #[prop_or_default]
+    pub foot: Option<Html>,
+    /// Whether or not the modal card can be moved.
+    ///
+    /// Whether or not the [Bulma modal card][bd], which will receive these
+    /// properties, can be moved around by dragging its head. It cannot be
+    /// dragged outside of the viewport.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalCard};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Modal active=true>
+    ///             <ModalCard title="Tools" draggable=true>{"Some tools."}</ModalCard>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+    #[prop_or_default]
+    pub draggable: bool,
+    /// Whether or not the modal card can be resized.
+    ///
+    /// Whether or not the [Bulma modal card][bd], which will receive these
+    /// properties, can be resized using the handle found in its bottom right
+    /// corner. It cannot grow larger than the viewport.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::modal::{Modal, ModalCard};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Modal active=true>
+    ///             <ModalCard title="Tools" draggable=true resizable=true>{"Some tools."}</ModalCard>
+    ///         </Modal>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+    #[prop_or_default]
+    pub resizable: bool,
+    /// The list of elements found inside the body of the modal card.
+    ///
+    /// Defines the elements that will be found inside the body of the
+    /// [Bulma modal card][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma modal card][bd].
+///
+/// Yew implementation of the modal card, based on the specification found
+/// in the [Bulma modal component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::modal::{Modal, ModalCard};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Modal active=true>
+///             <ModalCard title="Modal title">{"Some modal content."}</ModalCard>
+///         </Modal>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/modal/#modal-card
+#[function_component(ModalCard)]
+pub fn modal_card(props: &ModalCardProperties) -> Html {
+    let node = use_node_ref();
+    let offset = use_state(|| (0.0, 0.0));
+    let drag = use_state(|| None::<Drag>);
+
+    {
+        let offset = offset.clone();
+        let drag = drag.clone();
+        let current = *drag;
+        use_effect_with_deps(
+            move |current| {
+                let listeners = current
+                    .zip(web_sys::window())
+                    .and_then(|(start, window)| window.document().map(|document| (start, document)))
+                    .map(|(start, document)| {
+                        let onmousemove =
+                            EventListener::new(&document, "mousemove", move |event| {
+                                if let Some(event) = event.dyn_ref::<MouseEvent>() {
+                                    offset.set(start.offset_at(event.client_x(), event.client_y()));
+                                }
+                            });
+                        let onmouseup =
+                            EventListener::new(&document, "mouseup", move |_| drag.set(None));
+
+                        (onmousemove, onmouseup)
+                    });
+
+                move || drop(listeners)
+            },
+            current,
+        );
+    }
+
+    let onmousedown = {
+        let node = node.clone();
+        let offset = *offset;
+        let drag = drag.clone();
+        let draggable = props.draggable;
+        Callback::from(move |event: MouseEvent| {
+            let rect = node
+                .cast::<Element>()
+                .map(|card| card.get_bounding_client_rect());
+            let viewport = web_sys::window().and_then(|window| {
+                let width = window.inner_width().ok()?.as_f64()?;
+                let height = window.inner_height().ok()?.as_f64()?;
+                Some((width, height))
+            });
+            if let (true, Some(rect), Some((width, height))) = (draggable, rect, viewport) {
+                event.prevent_default();
+                drag.set(Some(Drag {
+                    pointer: (event.client_x(), event.client_y()),
+                    offset,
+                    min: (offset.0 - rect.left(), offset.1 - rect.top()),
+                    max: (
+                        offset.0 + width - rect.right(),
+                        offset.1 + height - rect.bottom(),
+                    ),
+                }));
+            }
+        })
+    };
+    let onclose = use_context::<ModalContext>().and_then(|modal| modal.onclose);
+    let onclick = Callback::from(move |_: MouseEvent| {
+        if let Some(onclose) = &onclose {
+            onclose.emit(());
+        }
+    });
+    let class = ClassBuilder::default()
+        .with_custom_class("modal-card")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let mut style = String::new();
+    if props.draggable {
+        style.push_str(&format!(
+            "transform: translate({}px, {}px);",
+            offset.0, offset.1
+        ));
+    }
+    if props.resizable {
+        style.push_str("resize: both; overflow: auto; max-width: 100vw; max-height: 100vh;");
+    }
+    let style = (!style.is_empty()).then(|| style);
+    let head_style = props.draggable.then(|| "cursor: move;");
+
+    html! {
+        <div ref={node} id={props.id.clone()} {class} {style}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <header class="modal-card-head" style={head_style} {onmousedown}>
+                <p class="modal-card-title">{props.title.clone()}</p>
+                <Delete {onclick} />
+            </header>
+            <section class="modal-card-body">
+                { for props.children.iter() }
+            </section>
+            if let Some(foot) = &props.foot {
+                <footer class="modal-card-foot">{foot.clone()}</footer>
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const DRAG: Drag = Drag {
+        pointer: (100, 100),
+        offset: (10.0, 20.0),
+        min: (-50.0, -40.0),
+        max: (60.0, 80.0),
+    };
+
+    #[test_case(100, 100, (10.0, 20.0) ; "pointer did not move")]
+    #[test_case(120, 90, (30.0, 10.0) ; "moves with the pointer")]
+    #[test_case(0, 100, (-50.0, 20.0) ; "stops at the left of the viewport")]
+    #[test_case(100, 0, (10.0, -40.0) ; "stops at the top of the viewport")]
+    #[test_case(500, 500, (60.0, 80.0) ; "stops at the bottom right of the viewport")]
+    fn drag_offset_at(x: i32, y: i32, expected: (f64, f64)) {
+        assert_eq!(DRAG.offset_at(x, y), expected);
+    }
+
+    #[test_case(None, None, None ; "no style")]
+    #[test_case(Some("4px"), None, Some("backdrop-filter: blur(4px); -webkit-backdrop-filter: blur(4px);") ; "blur")]
+    #[test_case(None, Some(0.5), Some("opacity: 0.5;") ; "opacity")]
+    #[test_case(None, Some(2.0), Some("opacity: 1;") ; "opacity is clamped")]
+    #[test_case(Some("1rem"), Some(0.0), Some("backdrop-filter: blur(1rem); -webkit-backdrop-filter: blur(1rem);opacity: 0;") ; "blur and opacity")]
+    fn background_style_should_be(
+        blur: Option<&str>,
+        opacity: Option<f64>,
+        expected: Option<&str>,
+    ) {
+        assert_eq!(background_style(blur, opacity).as_deref(), expected);
+    }
+}
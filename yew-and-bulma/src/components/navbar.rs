@@ -0,0 +1,1810 @@
+use std::fmt::Display;
+
+use web_sys::HtmlElement;
+use yew::{
+    function_component, html, use_context, use_state, AttrValue, Callback, Children,
+    ContextProvider, Html, KeyboardEvent, MouseEvent, Properties, TargetCast, UseStateHandle,
+};
+use yew_and_bulma_macros::base_component_properties;
+#[cfg(feature = "router")]
+use yew_router::hooks::use_navigator;
+
+#[cfg(feature = "router")]
+use crate::utils::router::RouteTarget;
+use crate::{
+    elements::image::{self, Figure, Image},
+    helpers::{
+        color::Color,
+        spacing::{Direction, Spacing},
+    },
+    utils::{class::ClassBuilder, constants::IS_PREFIX},
+};
+
+/// Defines the possible positions to which a [Bulma navbar][bd] can be fixed.
+///
+/// Defines the possible positions to which a [Bulma navbar][bd] can be fixed,
+/// either the top or the bottom of the page. Keep in mind that Bulma also
+/// expects the `has-navbar-fixed-top` or `has-navbar-fixed-bottom` class to be
+/// set on the `html` or `body` element.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Fixed, Navbar};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar fixed={Fixed::Top}>{"Some navbar items."}</Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#fixed-navbar
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Fixed {
+    Top,
+    Bottom,
+}
+
+impl Display for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fixed = match self {
+            Fixed::Top => "top",
+            Fixed::Bottom => "bottom",
+        };
+
+        write!(f, "fixed-{fixed}")
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct NavbarContext {
+    active: UseStateHandle<bool>,
+}
+
+/// Defines the properties of the [Bulma navbar component][bd].
+///
+/// Defines the properties of the navbar component, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{
+///     Navbar, NavbarBrand, NavbarBurger, NavbarItem, NavbarMenu, NavbarStart,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem href="/">{"Home"}</NavbarItem>
+///                 <NavbarBurger />
+///             </NavbarBrand>
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarItem href="/docs">{"Documentation"}</NavbarItem>
+///                 </NavbarStart>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarProperties {
+    /// Sets the color of the navbar.
+    ///
+    /// Sets the color of the [Bulma navbar component][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::navbar::Navbar, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar color={Color::Primary}>{"Some navbar items."}</Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Fixes the navbar to the top or bottom of the page.
+    ///
+    /// Fixes the [Bulma navbar component][bd] which will receive these
+    /// properties to the top or bottom of the page, as given by the
+    /// [`Fixed`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Fixed, Navbar};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar fixed={Fixed::Bottom}>{"Some navbar items."}</Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#fixed-navbar
+    #[prop_or_default]
+    pub fixed: Option<Fixed>,
+    /// Makes the navbar transparent.
+    ///
+    /// Removes any hover or active background from the items of the
+    /// [Bulma navbar component][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::Navbar;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar transparent=true>{"Some navbar items."}</Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#transparent-navbar
+    #[prop_or_default]
+    pub transparent: bool,
+    /// Adds more spacing around the navbar.
+    ///
+    /// Adds a larger horizontal padding to the [Bulma navbar component][bd]
+    /// which will receive these properties, as well as a larger vertical
+    /// padding to its direct children.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::Navbar;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar spaced=true>{"Some navbar items."}</Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-helper-classes
+    #[prop_or_default]
+    pub spaced: bool,
+    /// The list of elements found inside the navbar.
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar component][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar>
+    ///             <NavbarBrand>
+    ///                 <NavbarItem href="/">{"Home"}</NavbarItem>
+    ///             </NavbarBrand>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/
+    pub children: Children,
+    /// Whether the [navbar component][bd] should be hidden when printed.
+    ///
+    /// Whether or not the [Bulma navbar component][bd], which will receive
+    /// these properties, should be hidden when the page is printed. This
+    /// requires the styles found in [`crate::utils::constants::PRINT_STYLES`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Navbar print_hidden=true>
+    ///             <NavbarBrand>
+    ///                 <NavbarItem href="/">{"Home"}</NavbarItem>
+    ///             </NavbarBrand>
+    ///         </Navbar>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/
+    #[prop_or_default]
+    pub print_hidden: bool,
+}
+
+/// Yew implementation of the [Bulma navbar component][bd].
+///
+/// Yew implementation of the navbar component, based on the specification
+/// found in the [Bulma navbar component documentation][bd]. Keeps track of
+/// whether the menu is open on mobile devices, toggled by the
+/// [`NavbarBurger`] and used by the [`NavbarMenu`] found inside it.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{
+///     Navbar, NavbarBrand, NavbarBurger, NavbarEnd, NavbarItem, NavbarMenu,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem href="/">{"Home"}</NavbarItem>
+///                 <NavbarBurger />
+///             </NavbarBrand>
+///             <NavbarMenu>
+///                 <NavbarEnd>
+///                     <NavbarItem href="/login">{"Log in"}</NavbarItem>
+///                 </NavbarEnd>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/
+#[function_component(Navbar)]
+pub fn navbar(props: &NavbarProperties) -> Html {
+    let active = use_state(|| false);
+    let context = NavbarContext { active };
+    let fixed = props
+        .fixed
+        .map(|fixed| format!("{IS_PREFIX}-{fixed}"))
+        .unwrap_or("".to_owned());
+    let transparent = if props.transparent {
+        format!("{IS_PREFIX}-transparent")
+    } else {
+        "".to_owned()
+    };
+    let spaced = if props.spaced {
+        format!("{IS_PREFIX}-spaced")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar")
+        .with_color(props.color)
+        .with_custom_class(&fixed)
+        .with_custom_class(&transparent)
+        .with_custom_class(&spaced)
+        .is_print_hidden(Some(props.print_hidden))
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<NavbarContext> {context}>
+            <nav id={props.id.clone()} {class} role="navigation" aria-label="main navigation"
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+            </nav>
+        </ContextProvider<NavbarContext>>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar brand][bd].
+///
+/// Defines the properties of the navbar brand, the left side of the navbar
+/// which is always visible, based on the specification found in the
+/// [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem href="/">{"Home"}</NavbarItem>
+///             </NavbarBrand>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-brand
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarBrandProperties {
+    /// The list of elements found inside the navbar brand.
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar brand][bd] which will receive these properties, usually
+    /// a logo item and the [`NavbarBurger`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{NavbarBrand, NavbarBurger, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarBrand>
+    ///             <NavbarItem href="/">{"Home"}</NavbarItem>
+    ///             <NavbarBurger />
+    ///         </NavbarBrand>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-brand
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma navbar brand][bd].
+///
+/// Yew implementation of the navbar brand, based on the specification found
+/// in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarItem href="/">{"Home"}</NavbarItem>
+///             </NavbarBrand>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-brand
+#[function_component(NavbarBrand)]
+pub fn navbar_brand(props: &NavbarBrandProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-brand")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar burger][bd].
+///
+/// Defines the properties of the navbar burger, the hamburger icon which
+/// toggles the navbar menu on mobile devices, based on the specification
+/// found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarBurger};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarBurger />
+///             </NavbarBrand>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-burger
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarBurgerProperties {
+    /// Sets the accessible label of the navbar burger.
+    ///
+    /// Sets the `aria-label` of the [Bulma navbar burger][bd] which will
+    /// receive these properties. Defaults to `menu`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::NavbarBurger;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarBurger label="Open the menu" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-burger
+    #[prop_or(AttrValue::from("menu"))]
+    pub label: AttrValue,
+}
+
+/// Yew implementation of the [Bulma navbar burger][bd].
+///
+/// Yew implementation of the navbar burger, based on the specification found
+/// in the [Bulma navbar component documentation][bd]. When clicked, it
+/// toggles the `is-active` modifier on both itself and the [`NavbarMenu`] of
+/// the [`Navbar`] it is found in.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarBrand, NavbarBurger};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarBrand>
+///                 <NavbarBurger />
+///             </NavbarBrand>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-burger
+#[function_component(NavbarBurger)]
+pub fn navbar_burger(props: &NavbarBurgerProperties) -> Html {
+    let navbar = use_context::<NavbarContext>();
+    let active = navbar
+        .as_ref()
+        .map(|navbar| *navbar.active)
+        .unwrap_or_default();
+    let onclick = {
+        let onclick = props.onclick.clone();
+        Callback::from(move |event: MouseEvent| {
+            if let Some(navbar) = &navbar {
+                navbar.active.set(!*navbar.active);
+            }
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+        })
+    };
+    let is_active = if active {
+        format!("{IS_PREFIX}-active")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-burger")
+        .with_custom_class(&is_active)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <a id={props.id.clone()} {class} role="button" aria-label={props.label.clone()} aria-expanded={active.to_string()}
+            {onclick} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <span aria-hidden="true"></span>
+            <span aria-hidden="true"></span>
+            <span aria-hidden="true"></span>
+        </a>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar menu][bd].
+///
+/// Defines the properties of the navbar menu, the part of the navbar hidden
+/// on mobile devices until the [`NavbarBurger`] is clicked, based on the
+/// specification found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarItem, NavbarMenu, NavbarStart};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarStart>
+///                     <NavbarItem href="/docs">{"Documentation"}</NavbarItem>
+///                 </NavbarStart>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-menu
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarMenuProperties {
+    /// The list of elements found inside the navbar menu.
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar menu][bd] which will receive these properties, usually
+    /// a [`NavbarStart`] and a [`NavbarEnd`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{NavbarEnd, NavbarMenu, NavbarStart};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarMenu>
+    ///             <NavbarStart>{"Left side."}</NavbarStart>
+    ///             <NavbarEnd>{"Right side."}</NavbarEnd>
+    ///         </NavbarMenu>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-menu
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma navbar menu][bd].
+///
+/// Yew implementation of the navbar menu, based on the specification found
+/// in the [Bulma navbar component documentation][bd]. It is shown on mobile
+/// devices while the [`NavbarBurger`] of the same [`Navbar`] is active.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarEnd, NavbarItem, NavbarMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarEnd>
+///                     <NavbarItem href="/login">{"Log in"}</NavbarItem>
+///                 </NavbarEnd>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-menu
+#[function_component(NavbarMenu)]
+pub fn navbar_menu(props: &NavbarMenuProperties) -> Html {
+    let active = use_context::<NavbarContext>()
+        .map(|navbar| *navbar.active)
+        .unwrap_or_default();
+    let is_active = if active {
+        format!("{IS_PREFIX}-active")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-menu")
+        .with_custom_class(&is_active)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar start][bd].
+///
+/// Defines the properties of the navbar start, the left part of the
+/// [`NavbarMenu`], based on the specification found in the
+/// [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{NavbarItem, NavbarMenu, NavbarStart};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarMenu>
+///             <NavbarStart>
+///                 <NavbarItem href="/docs">{"Documentation"}</NavbarItem>
+///             </NavbarStart>
+///         </NavbarMenu>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarStartProperties {
+    /// The list of elements found inside the navbar start.
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar start][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{NavbarItem, NavbarStart};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarStart>
+    ///             <NavbarItem href="/docs">{"Documentation"}</NavbarItem>
+    ///         </NavbarStart>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma navbar start][bd].
+///
+/// Yew implementation of the navbar start, based on the specification found
+/// in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{NavbarItem, NavbarMenu, NavbarStart};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarMenu>
+///             <NavbarStart>
+///                 <NavbarItem href="/docs">{"Documentation"}</NavbarItem>
+///             </NavbarStart>
+///         </NavbarMenu>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+#[function_component(NavbarStart)]
+pub fn navbar_start(props: &NavbarStartProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-start")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar end][bd].
+///
+/// Defines the properties of the navbar end, the right part of the
+/// [`NavbarMenu`], based on the specification found in the
+/// [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{NavbarEnd, NavbarItem, NavbarMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarMenu>
+///             <NavbarEnd>
+///                 <NavbarItem href="/login">{"Log in"}</NavbarItem>
+///             </NavbarEnd>
+///         </NavbarMenu>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarEndProperties {
+    /// The list of elements found inside the navbar end.
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar end][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{NavbarEnd, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarEnd>
+    ///             <NavbarItem href="/login">{"Log in"}</NavbarItem>
+    ///         </NavbarEnd>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma navbar end][bd].
+///
+/// Yew implementation of the navbar end, based on the specification found
+/// in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{NavbarEnd, NavbarItem, NavbarMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarMenu>
+///             <NavbarEnd>
+///                 <NavbarItem href="/login">{"Log in"}</NavbarItem>
+///             </NavbarEnd>
+///         </NavbarMenu>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-start-and-navbar-end
+#[function_component(NavbarEnd)]
+pub fn navbar_end(props: &NavbarEndProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-end")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar item][bd].
+///
+/// Defines the properties of the navbar item, based on the specification
+/// found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::NavbarItem;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarItem href="/docs">{"Documentation"}</NavbarItem>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-item
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarItemProperties {
+    /// Sets the link of the navbar item.
+    ///
+    /// Sets the `href` of the [Bulma navbar item][bd] which will receive
+    /// these properties, rendering it as an `a` tag. If it is not set, the
+    /// item is rendered as a `div` tag instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::NavbarItem;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarItem href="/docs">{"Documentation"}</NavbarItem>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-item
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Sets the route of the navbar item.
+    ///
+    /// Sets the [`RouteTarget`] of the [Bulma navbar item][bd] which will receive
+    /// these properties, rendering it as an `a` tag which navigates to the
+    /// route on the client side, without a full page load. Takes precedence
+    /// over [`NavbarItemProperties::href`] when used inside a router. Only
+    /// available with the `router` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::navbar::NavbarItem, utils::router::RouteTarget};
+    /// use yew_router::prelude::*;
+    ///
+    /// #[derive(Clone, PartialEq, Routable)]
+    /// enum Route {
+    ///     #[at("/settings")]
+    ///     Settings,
+    /// }
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <BrowserRouter>
+    ///             <NavbarItem to={RouteTarget::new(Route::Settings)}>{"Settings"}</NavbarItem>
+    ///         </BrowserRouter>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-item
+    #[cfg(feature = "router")]
+    #[prop_or_default]
+    pub to: Option<RouteTarget>,
+    /// Marks the navbar item as active.
+    ///
+    /// Highlights the [Bulma navbar item][bd] which will receive these
+    /// properties, usually the one matching the current page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::NavbarItem;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarItem href="/docs" active=true>{"Documentation"}</NavbarItem>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-item
+    #[prop_or_default]
+    pub active: bool,
+    /// Styles the navbar item as a tab.
+    ///
+    /// Adds a bottom border to the [Bulma navbar item][bd] which will receive
+    /// these properties, highlighted when the item is also active.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::NavbarItem;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarItem href="/docs" tab=true>{"Documentation"}</NavbarItem>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-helper-classes
+    #[prop_or_default]
+    pub tab: bool,
+    /// The list of elements found inside the navbar item.
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma navbar item][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::NavbarItem;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarItem>{"Some text."}</NavbarItem>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#navbar-item
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma navbar item][bd].
+///
+/// Yew implementation of the navbar item, based on the specification found
+/// in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::NavbarItem;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarItem href="/docs">{"Documentation"}</NavbarItem>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#navbar-item
+#[function_component(NavbarItem)]
+pub fn navbar_item(props: &NavbarItemProperties) -> Html {
+    let active = if props.active {
+        format!("{IS_PREFIX}-active")
+    } else {
+        "".to_owned()
+    };
+    let tab = if props.tab {
+        format!("{IS_PREFIX}-tab")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-item")
+        .with_custom_class(&active)
+        .with_custom_class(&tab)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    #[cfg(feature = "router")]
+    let navigator = use_navigator();
+    #[cfg(feature = "router")]
+    let (href, onclick) = match (&props.to, navigator) {
+        (Some(to), Some(navigator)) => (
+            Some(to.href(&navigator)),
+            Some(to.onclick(navigator, props.onclick.clone())),
+        ),
+        _ => (props.href.clone(), props.onclick.clone()),
+    };
+    #[cfg(not(feature = "router"))]
+    let (href, onclick) = (props.href.clone(), props.onclick.clone());
+    let tag = if href.is_some() { "a" } else { "div" };
+
+    html! {
+        <@{tag} id={props.id.clone()} {class} {href}
+            {onclick} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </@>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar dropdown][bd].
+///
+/// Defines the properties of the navbar dropdown, a navbar item holding a
+/// link which shows a dropdown menu, based on the specification found in the
+/// [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{NavbarDivider, NavbarDropdown, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarDropdown label="More">
+///             <NavbarItem href="/about">{"About"}</NavbarItem>
+///             <NavbarDivider />
+///             <NavbarItem href="/contact">{"Contact"}</NavbarItem>
+///         </NavbarDropdown>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarDropdownProperties {
+    /// Sets the label of the navbar dropdown.
+    ///
+    /// Sets the text of the link which opens the [Bulma navbar dropdown][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{NavbarDropdown, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarDropdown label="More">
+    ///             <NavbarItem href="/about">{"About"}</NavbarItem>
+    ///         </NavbarDropdown>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    pub label: AttrValue,
+    /// Opens the navbar dropdown on hover.
+    ///
+    /// Opens the [Bulma navbar dropdown][bd] which will receive these
+    /// properties on hover instead of when its link is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{NavbarDropdown, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarDropdown label="More" hoverable=true>
+    ///             <NavbarItem href="/about">{"About"}</NavbarItem>
+    ///         </NavbarDropdown>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Opens the navbar dropdown upwards.
+    ///
+    /// Shows the menu of the [Bulma navbar dropdown][bd] which will receive
+    /// these properties above its link, useful for navbars fixed to the
+    /// bottom of the page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{NavbarDropdown, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarDropdown label="More" up=true>
+    ///             <NavbarItem href="/about">{"About"}</NavbarItem>
+    ///         </NavbarDropdown>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropup
+    #[prop_or_default]
+    pub up: bool,
+    /// Aligns the menu of the navbar dropdown to the right.
+    ///
+    /// Aligns the menu of the [Bulma navbar dropdown][bd] which will receive
+    /// these properties to the right of its link, useful inside a
+    /// [`NavbarEnd`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{NavbarDropdown, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarDropdown label="More" right=true>
+    ///             <NavbarItem href="/about">{"About"}</NavbarItem>
+    ///         </NavbarDropdown>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    #[prop_or_default]
+    pub right: bool,
+    /// Makes the menu of the navbar dropdown look like a box.
+    ///
+    /// Adds rounded corners and a shadow to the menu of the
+    /// [Bulma navbar dropdown][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{NavbarDropdown, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarDropdown label="More" boxed=true>
+    ///             <NavbarItem href="/about">{"About"}</NavbarItem>
+    ///         </NavbarDropdown>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    #[prop_or_default]
+    pub boxed: bool,
+    /// Sets the icon of the navbar dropdown.
+    ///
+    /// Sets the element shown before the label, inside the link which opens
+    /// the [Bulma navbar dropdown][bd] which will receive these properties,
+    /// usually an [`crate::elements::icon::Icon`] or an avatar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::navbar::{NavbarDropdown, NavbarItem},
+    ///     elements::icon::Icon,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let icon = html! { <Icon icon={html! { <i class="fas fa-globe"></i> }} /> };
+    ///
+    ///     html! {
+    ///         <NavbarDropdown label="Language" {icon}>
+    ///             <NavbarItem>{"English"}</NavbarItem>
+    ///         </NavbarDropdown>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    #[prop_or_default]
+    pub icon: Option<Html>,
+    /// The list of elements found inside the navbar dropdown.
+    ///
+    /// Defines the elements that will be found inside the menu of the
+    /// [Bulma navbar dropdown][bd] which will receive these properties,
+    /// usually [`NavbarItem`]s and [`NavbarDivider`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{NavbarDivider, NavbarDropdown, NavbarItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarDropdown label="More">
+    ///             <NavbarItem href="/about">{"About"}</NavbarItem>
+    ///             <NavbarDivider />
+    ///             <NavbarItem href="/contact">{"Contact"}</NavbarItem>
+    ///         </NavbarDropdown>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma navbar dropdown][bd].
+///
+/// Yew implementation of the navbar dropdown, based on the specification
+/// found in the [Bulma navbar component documentation][bd]. Unless it is
+/// hoverable, clicking its link (or pressing Enter or Space while it is
+/// focused) toggles the `is-active` modifier, opening or closing its menu.
+/// Clicking inside the opened menu closes it.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{NavbarDivider, NavbarDropdown, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarDropdown label="More">
+///             <NavbarItem href="/about">{"About"}</NavbarItem>
+///             <NavbarDivider />
+///             <NavbarItem href="/contact">{"Contact"}</NavbarItem>
+///         </NavbarDropdown>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+#[function_component(NavbarDropdown)]
+pub fn navbar_dropdown(props: &NavbarDropdownProperties) -> Html {
+    let active = use_state(|| false);
+    let onlinkclick = {
+        let active = active.clone();
+        let hoverable = props.hoverable;
+        Callback::from(move |_: MouseEvent| {
+            if !hoverable {
+                active.set(!*active);
+            }
+        })
+    };
+    let onmenuclick = {
+        let active = active.clone();
+        Callback::from(move |_: MouseEvent| active.set(false))
+    };
+    let has_dropdown = if props.up {
+        "has-dropdown has-dropdown-up"
+    } else {
+        "has-dropdown"
+    };
+    let hoverable = if props.hoverable {
+        format!("{IS_PREFIX}-hoverable")
+    } else {
+        "".to_owned()
+    };
+    let is_active = if *active {
+        format!("{IS_PREFIX}-active")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-item")
+        .with_custom_class(has_dropdown)
+        .with_custom_class(&hoverable)
+        .with_custom_class(&is_active)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let right = if props.right {
+        format!("{IS_PREFIX}-right")
+    } else {
+        "".to_owned()
+    };
+    let boxed = if props.boxed {
+        format!("{IS_PREFIX}-boxed")
+    } else {
+        "".to_owned()
+    };
+    let dropdown_class = ClassBuilder::default()
+        .with_custom_class("navbar-dropdown")
+        .with_custom_class(&right)
+        .with_custom_class(&boxed)
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <a class="navbar-link" role="button" tabindex="0" aria-haspopup="true"
+                aria-expanded={active.to_string()} onclick={onlinkclick} onkeydown={click_on_activation_key()}>
+                if let Some(icon) = &props.icon {
+                    {icon.clone()}
+                }
+                {props.label.clone()}
+            </a>
+            <div class={dropdown_class} onclick={onmenuclick}>
+                { for props.children.iter() }
+            </div>
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma navbar divider][bd].
+///
+/// Defines the properties of the navbar divider, a horizontal line used to
+/// separate the items of a [`NavbarDropdown`], based on the specification
+/// found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{NavbarDivider, NavbarDropdown, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarDropdown label="More">
+///             <NavbarItem href="/about">{"About"}</NavbarItem>
+///             <NavbarDivider />
+///             <NavbarItem href="/contact">{"Contact"}</NavbarItem>
+///         </NavbarDropdown>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarDividerProperties {}
+
+/// Yew implementation of the [Bulma navbar divider][bd].
+///
+/// Yew implementation of the navbar divider, based on the specification
+/// found in the [Bulma navbar component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{NavbarDivider, NavbarDropdown, NavbarItem};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <NavbarDropdown label="More">
+///             <NavbarItem href="/about">{"About"}</NavbarItem>
+///             <NavbarDivider />
+///             <NavbarItem href="/contact">{"Contact"}</NavbarItem>
+///         </NavbarDropdown>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+#[function_component(NavbarDivider)]
+pub fn navbar_divider(props: &NavbarDividerProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("navbar-divider")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <hr id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()} />
+    }
+}
+
+/// Defines the properties of the navbar user menu preset.
+///
+/// Defines the properties of the navbar user menu, a
+/// [Bulma navbar dropdown][bd] opened by the avatar and name of the signed in user, holding links to
+/// their profile, their settings and to sign out.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::NavbarUserMenu;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let onsignout = Callback::from(|_| ());
+///
+///     html! {
+///         <NavbarUserMenu name="Jane Doe" {onsignout} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct NavbarUserMenuProperties {
+    /// Sets the name of the user.
+    ///
+    /// Sets the name shown in the link which opens the navbar user menu
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::NavbarUserMenu;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarUserMenu name="Jane Doe" />
+    ///     }
+    /// }
+    /// ```
+    pub name: AttrValue,
+    /// Sets the avatar of the user.
+    ///
+    /// Sets the source of the image shown, rounded, before the name in the
+    /// link which opens the navbar user menu which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::NavbarUserMenu;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarUserMenu name="Jane Doe" avatar="https://bulma.io/images/placeholders/128x128.png" />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub avatar: Option<AttrValue>,
+    /// Sets the callback used when the profile item is clicked.
+    ///
+    /// Sets the callback called when the "Profile" item of the navbar user
+    /// menu, which will receive these properties, is clicked. The item is
+    /// only shown when it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::NavbarUserMenu;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onprofile = Callback::from(|_| ());
+    ///
+    ///     html! {
+    ///         <NavbarUserMenu name="Jane Doe" {onprofile} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onprofile: Option<Callback<()>>,
+    /// Sets the callback used when the settings item is clicked.
+    ///
+    /// Sets the callback called when the "Settings" item of the navbar user
+    /// menu, which will receive these properties, is clicked. The item is
+    /// only shown when it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::NavbarUserMenu;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onsettings = Callback::from(|_| ());
+    ///
+    ///     html! {
+    ///         <NavbarUserMenu name="Jane Doe" {onsettings} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onsettings: Option<Callback<()>>,
+    /// Sets the callback used when the sign out item is clicked.
+    ///
+    /// Sets the callback called when the "Sign out" item of the navbar user
+    /// menu, which will receive these properties, is clicked. The item is
+    /// only shown when it is set, separated from the others by a
+    /// [`NavbarDivider`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::NavbarUserMenu;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onsignout = Callback::from(|_| ());
+    ///
+    ///     html! {
+    ///         <NavbarUserMenu name="Jane Doe" {onsignout} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onsignout: Option<Callback<()>>,
+    /// The list of extra items found inside the navbar user menu.
+    ///
+    /// Defines the extra [`NavbarItem`]s shown after the settings item of
+    /// the navbar user menu which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::navbar::{NavbarItem, NavbarUserMenu};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <NavbarUserMenu name="Jane Doe">
+    ///             <NavbarItem href="/billing">{"Billing"}</NavbarItem>
+    ///         </NavbarUserMenu>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Navbar user menu preset.
+///
+/// A [Bulma navbar dropdown][bd], aligned to the right, opened by the avatar and name
+/// of the signed in user and holding "Profile", "Settings" and "Sign out"
+/// items, each shown when its callback is set. Restyle it through its
+/// `class` property like any other navbar item.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::navbar::{Navbar, NavbarEnd, NavbarMenu, NavbarUserMenu};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let onprofile = Callback::from(|_| ());
+///     let onsettings = Callback::from(|_| ());
+///     let onsignout = Callback::from(|_| ());
+///
+///     html! {
+///         <Navbar>
+///             <NavbarMenu>
+///                 <NavbarEnd>
+///                     <NavbarUserMenu name="Jane Doe" {onprofile} {onsettings} {onsignout} />
+///                 </NavbarEnd>
+///             </NavbarMenu>
+///         </Navbar>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/navbar/#dropdown-menu
+#[function_component(NavbarUserMenu)]
+pub fn navbar_user_menu(props: &NavbarUserMenuProperties) -> Html {
+    let icon = props.avatar.as_ref().map(|avatar| {
+        let class = ClassBuilder::default()
+            .with_margin(Direction::Right, Spacing::Two)
+            .build();
+        html! {
+            <Figure size={image::Size::Pixels24x24} {class}>
+                <Image src={avatar.clone()} alt={props.name.clone()} rounded=true />
+            </Figure>
+        }
+    });
+    let item = |label: &'static str, callback: &Option<Callback<()>>| {
+        callback.as_ref().map(|callback| {
+            let onclick = callback.reform(|_: MouseEvent| ());
+            html! {
+                <a class="navbar-item" role="menuitem" tabindex="0" {onclick}
+                    onkeydown={click_on_activation_key()}>
+                    {label}
+                </a>
+            }
+        })
+    };
+    let profile = item("Profile", &props.onprofile);
+    let settings = item("Settings", &props.onsettings);
+    let signout = item("Sign out", &props.onsignout);
+    let divider = (signout.is_some()
+        && (profile.is_some() || settings.is_some() || !props.children.is_empty()))
+    .then(|| html! { <NavbarDivider /> });
+
+    html! {
+        <NavbarDropdown id={props.id.clone()} class={props.class.clone()} label={props.name.clone()} {icon} right=true
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for profile }
+            { for settings }
+            { for props.children.iter() }
+            { for divider }
+            { for signout }
+        </NavbarDropdown>
+    }
+}
+
+// Clicks the focused element when Enter or Space is pressed, for elements
+// which are not natively activated by the keyboard.
+fn click_on_activation_key() -> Callback<KeyboardEvent> {
+    Callback::from(|event: KeyboardEvent| {
+        if matches!(event.key().as_str(), "Enter" | " ") {
+            event.prevent_default();
+            if let Some(element) = event.target_dyn_into::<HtmlElement>() {
+                element.click();
+            }
+        }
+    })
+}
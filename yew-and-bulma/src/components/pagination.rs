@@ -0,0 +1,422 @@
+use std::collections::BTreeSet;
+
+use yew::{function_component, html, AttrValue, Callback, Html, MouseEvent, Properties};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::button::Align,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the properties of the [Bulma pagination component][bd].
+///
+/// Defines the properties of the pagination component, based on the
+/// specification found in the [Bulma pagination component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::pagination::Pagination;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Pagination total_pages={20} current_page={5} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/pagination/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PaginationProperties {
+    /// Sets the number of pages.
+    ///
+    /// Sets the total number of pages of the [Bulma pagination component][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination total_pages={3} current_page={1} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    pub total_pages: usize,
+    /// Sets the current page, starting from `1`.
+    ///
+    /// Sets the page highlighted as current by the
+    /// [Bulma pagination component][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination total_pages={10} current_page={4} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    pub current_page: usize,
+    /// Sets the number of pages shown around the current one.
+    ///
+    /// Sets the number of page links shown on each side of the current page
+    /// of the [Bulma pagination component][bd] which will receive these
+    /// properties. The first and last pages are always shown, with ellipses
+    /// replacing the hidden pages. Defaults to `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination total_pages={50} current_page={25} sibling_count={2} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    #[prop_or(1)]
+    pub sibling_count: usize,
+    /// Sets the callback used when a page is selected.
+    ///
+    /// Sets the callback called with the selected page each time a page link
+    /// or the previous or next button of the [Bulma pagination component][bd],
+    /// which will receive these properties, is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let page = use_state(|| 1);
+    ///     let onpagechange = {
+    ///         let page = page.clone();
+    ///         Callback::from(move |new_page| page.set(new_page))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Pagination total_pages={10} current_page={*page} {onpagechange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    #[prop_or_default]
+    pub onpagechange: Option<Callback<usize>>,
+    /// Sets the text of the previous button.
+    ///
+    /// Sets the text of the button used to go to the previous page, found
+    /// inside the [Bulma pagination component][bd] which will receive these
+    /// properties. Defaults to `Previous`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination total_pages={10} current_page={2} previous_label="Back" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    #[prop_or(AttrValue::from("Previous"))]
+    pub previous_label: AttrValue,
+    /// Sets the text of the next button.
+    ///
+    /// Sets the text of the button used to go to the next page, found inside
+    /// the [Bulma pagination component][bd] which will receive these
+    /// properties. Defaults to `Next`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination total_pages={10} current_page={2} next_label="Forward" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/
+    #[prop_or(AttrValue::from("Next"))]
+    pub next_label: AttrValue,
+    /// Sets the size of the pagination.
+    ///
+    /// Sets the size of the [Bulma pagination component][bd] which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::pagination::Pagination, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination total_pages={10} current_page={2} size={Size::Small} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Sets the alignment of the pagination.
+    ///
+    /// Sets the alignment of the page links of the
+    /// [Bulma pagination component][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::pagination::Pagination, elements::button::Align};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination total_pages={10} current_page={2} align={Align::Center} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/#alignment
+    #[prop_or(Align::Left)]
+    pub align: Align,
+    /// Whether or not the pagination should be rounded.
+    ///
+    /// Whether or not the links of the [Bulma pagination component][bd],
+    /// which will receive these properties, will be rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::pagination::Pagination;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Pagination total_pages={10} current_page={2} rounded=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/pagination/#styles
+    #[prop_or_default]
+    pub rounded: bool,
+}
+
+/// Yew implementation of the [Bulma pagination component][bd].
+///
+/// Yew implementation of the pagination component, based on the
+/// specification found in the [Bulma pagination component documentation][bd].
+/// Renders the previous and next buttons, along with the links to the first,
+/// last and current pages and the ones around it, separated by ellipses.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::pagination::Pagination;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let page = use_state(|| 1);
+///     let onpagechange = {
+///         let page = page.clone();
+///         Callback::from(move |new_page| page.set(new_page))
+///     };
+///
+///     html! {
+///         <Pagination total_pages={20} current_page={*page} {onpagechange} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/pagination/
+#[function_component(Pagination)]
+pub fn pagination(props: &PaginationProperties) -> Html {
+    let current_page = props.current_page.clamp(1, props.total_pages.max(1));
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| {
+            if Size::Normal == *size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let rounded = if props.rounded {
+        format!("{IS_PREFIX}-rounded")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("pagination")
+        .with_custom_class(&String::from(&props.align))
+        .with_custom_class(&size)
+        .with_custom_class(&rounded)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let onpage = |page: usize| {
+        let onpagechange = props.onpagechange.clone();
+        Callback::from(move |event: MouseEvent| {
+            event.prevent_default();
+            if let Some(onpagechange) = &onpagechange {
+                onpagechange.emit(page);
+            }
+        })
+    };
+    let has_previous = current_page > 1;
+    let has_next = current_page < props.total_pages;
+    let disabled = |disabled: bool| {
+        if disabled {
+            format!("{IS_PREFIX}-disabled")
+        } else {
+            "".to_owned()
+        }
+    };
+    let previous_class = ClassBuilder::default()
+        .with_custom_class("pagination-previous")
+        .with_custom_class(&disabled(!has_previous))
+        .build();
+    let next_class = ClassBuilder::default()
+        .with_custom_class("pagination-next")
+        .with_custom_class(&disabled(!has_next))
+        .build();
+    let links = pages(props.total_pages, current_page, props.sibling_count)
+        .into_iter()
+        .map(|page| match page {
+            Some(page) if page == current_page => html! {
+                <li>
+                    <a class={format!("pagination-link {IS_PREFIX}-current")} aria-label={format!("Page {page}")} aria-current="page">{page}</a>
+                </li>
+            },
+            Some(page) => html! {
+                <li>
+                    <a class="pagination-link" aria-label={format!("Go to page {page}")} href="#" onclick={onpage(page)}>{page}</a>
+                </li>
+            },
+            None => html! {
+                <li><span class="pagination-ellipsis">{"\u{2026}"}</span></li>
+            },
+        });
+
+    html! {
+        <nav id={props.id.clone()} {class} role="navigation" aria-label="pagination"
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            if has_previous {
+                <a class={previous_class} href="#" onclick={onpage(current_page - 1)}>{props.previous_label.clone()}</a>
+            } else {
+                <a class={previous_class}>{props.previous_label.clone()}</a>
+            }
+            if has_next {
+                <a class={next_class} href="#" onclick={onpage(current_page + 1)}>{props.next_label.clone()}</a>
+            } else {
+                <a class={next_class}>{props.next_label.clone()}</a>
+            }
+            <ul class="pagination-list">
+                { for links }
+            </ul>
+        </nav>
+    }
+}
+
+fn pages(total_pages: usize, current_page: usize, sibling_count: usize) -> Vec<Option<usize>> {
+    if total_pages == 0 {
+        return Vec::new();
+    }
+
+    let start = current_page.saturating_sub(sibling_count).max(1);
+    let end = (current_page + sibling_count).min(total_pages);
+    let shown: BTreeSet<_> = [1, total_pages].into_iter().chain(start..=end).collect();
+    let mut pages = Vec::with_capacity(shown.len() + 2);
+    let mut previous = 0;
+    for page in shown {
+        if page - previous == 2 {
+            pages.push(Some(previous + 1));
+        } else if page - previous > 2 {
+            pages.push(None);
+        }
+        pages.push(Some(page));
+        previous = page;
+    }
+
+    pages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0, 1, 1, vec![] ; "no pages")]
+    #[test_case(1, 1, 1, vec![Some(1)] ; "single page")]
+    #[test_case(5, 3, 1, vec![Some(1), Some(2), Some(3), Some(4), Some(5)] ; "few pages are all shown")]
+    #[test_case(10, 1, 1, vec![Some(1), Some(2), None, Some(10)] ; "first page")]
+    #[test_case(10, 10, 1, vec![Some(1), None, Some(9), Some(10)] ; "last page")]
+    #[test_case(20, 10, 1, vec![Some(1), None, Some(9), Some(10), Some(11), None, Some(20)] ; "ellipses on both sides")]
+    #[test_case(20, 4, 1, vec![Some(1), Some(2), Some(3), Some(4), Some(5), None, Some(20)] ; "single hidden page is shown")]
+    #[test_case(20, 10, 2, vec![Some(1), None, Some(8), Some(9), Some(10), Some(11), Some(12), None, Some(20)] ; "more siblings")]
+    fn pages_values(
+        total_pages: usize,
+        current_page: usize,
+        sibling_count: usize,
+        expected: Vec<Option<usize>>,
+    ) {
+        assert_eq!(pages(total_pages, current_page, sibling_count), expected);
+    }
+}
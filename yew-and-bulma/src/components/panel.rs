@@ -0,0 +1,718 @@
+use yew::{
+    classes, function_component, html, use_context, use_state, AttrValue, Callback, Children,
+    ContextProvider, Html, MouseEvent, Properties, UseStateHandle,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::icon::Icon,
+    form::search::SearchInput,
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX},
+};
+
+#[derive(Clone, PartialEq)]
+struct PanelContext {
+    filter: UseStateHandle<String>,
+}
+
+/// Defines the properties of the [Bulma panel component][bd].
+///
+/// Defines the properties of the panel component, based on the
+/// specification found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelHeading};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelHeading>{"Repositories"}</PanelHeading>
+///             <PanelBlock href="#">{"bulma"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelProperties {
+    /// Sets the color of the [Bulma panel component][bd].
+    ///
+    /// Sets the color of the [Bulma panel component][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     components::panel::{Panel, PanelHeading},
+    ///     helpers::color::Color,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Panel color={Color::Primary}>
+    ///             <PanelHeading>{"Repositories"}</PanelHeading>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// The list of elements found inside the [Bulma panel component][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma panel component][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma panel component][bd].
+///
+/// Yew implementation of the panel component, based on the specification
+/// found in the [Bulma panel component documentation][bd]. Text typed in
+/// its [`PanelSearch`] hides the [`PanelBlock`]s whose label does not
+/// contain it.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelHeading, PanelSearch};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelHeading>{"Repositories"}</PanelHeading>
+///             <PanelSearch />
+///             <PanelBlock href="#" label="bulma">{"bulma"}</PanelBlock>
+///             <PanelBlock href="#" label="marksheet">{"marksheet"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(Panel)]
+pub fn panel(props: &PanelProperties) -> Html {
+    let filter = use_state(String::new);
+    let context = PanelContext { filter };
+    let class = ClassBuilder::default()
+        .with_custom_class("panel")
+        .with_color(props.color)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <ContextProvider<PanelContext> {context}>
+            <nav id={props.id.clone()} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for props.children.iter() }
+            </nav>
+        </ContextProvider<PanelContext>>
+    }
+}
+
+/// Defines the properties of the [Bulma panel heading][bd].
+///
+/// Defines the properties of the panel heading, based on the specification
+/// found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelHeading};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelHeading>{"Repositories"}</PanelHeading>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelHeadingProperties {
+    /// The list of elements found inside the [Bulma panel heading][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma panel heading][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma panel heading][bd].
+///
+/// Yew implementation of the panel heading, based on the specification
+/// found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelHeading};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelHeading>{"Repositories"}</PanelHeading>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(PanelHeading)]
+pub fn panel_heading(props: &PanelHeadingProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("panel-heading")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <p id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </p>
+    }
+}
+
+/// Defines the properties of the [Bulma panel tabs][bd].
+///
+/// Defines the properties of the panel tabs, based on the specification
+/// found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelTabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let tabs = vec![html! { "All" }, html! { "Public" }, html! { "Private" }];
+///
+///     html! {
+///         <Panel>
+///             <PanelTabs {tabs} />
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelTabsProperties {
+    /// The labels of the tabs, in order.
+    ///
+    /// Defines the labels of the tabs found inside the
+    /// [Bulma panel tabs][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub tabs: Vec<Html>,
+    /// Sets the index of the active tab.
+    ///
+    /// Sets the index of the active tab of the [Bulma panel tabs][bd] which
+    /// will receive these properties. The active tab is controlled by its
+    /// parent, through the [`PanelTabsProperties::ontabselect`] callback.
+    /// Defaults to the first tab.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub active: usize,
+    /// Sets the callback used when a tab is selected.
+    ///
+    /// Sets the callback called with the index of the tab clicked inside
+    /// the [Bulma panel tabs][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelTabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let active = use_state(|| 0);
+    ///     let ontabselect = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |index| active.set(index))
+    ///     };
+    ///     let tabs = vec![html! { "All" }, html! { "Public" }, html! { "Private" }];
+    ///
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelTabs {tabs} active={*active} {ontabselect} />
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub ontabselect: Option<Callback<usize>>,
+}
+
+/// Yew implementation of the [Bulma panel tabs][bd].
+///
+/// Yew implementation of the panel tabs, based on the specification found
+/// in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelTabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let tabs = vec![html! { "All" }, html! { "Public" }, html! { "Private" }];
+///
+///     html! {
+///         <Panel>
+///             <PanelTabs {tabs} active={1} />
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(PanelTabs)]
+pub fn panel_tabs(props: &PanelTabsProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("panel-tabs")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let tabs = props.tabs.iter().enumerate().map(|(index, tab)| {
+        let onclick = {
+            let ontabselect = props.ontabselect.clone();
+            Callback::from(move |event: MouseEvent| {
+                event.prevent_default();
+                if let Some(ontabselect) = &ontabselect {
+                    ontabselect.emit(index);
+                }
+            })
+        };
+        let active = (index == props.active).then(|| format!("{IS_PREFIX}-active"));
+
+        html! {
+            <a href="#" class={active} {onclick}>{tab.clone()}</a>
+        }
+    });
+
+    html! {
+        <p id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for tabs }
+        </p>
+    }
+}
+
+/// Defines the properties of the panel search.
+///
+/// Defines the properties of the panel search, a [Bulma panel block][bd]
+/// holding a search input which filters the [`PanelBlock`]s of its
+/// [`Panel`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelSearch};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelSearch placeholder="Search repositories" />
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelSearchProperties {
+    /// Sets the placeholder of the panel search.
+    ///
+    /// Sets the placeholder of the input found inside the panel search which
+    /// will receive these properties. Defaults to `Search`.
+    #[prop_or(AttrValue::from("Search"))]
+    pub placeholder: AttrValue,
+    /// Sets the callback used when the filter changes.
+    ///
+    /// Sets the callback called with the text typed inside the panel search
+    /// which will receive these properties, once the user stops typing. The
+    /// [`PanelBlock`]s of its [`Panel`] are filtered even if it is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelSearch};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let filter = use_state(String::new);
+    ///     let onfilter = {
+    ///         let filter = filter.clone();
+    ///         Callback::from(move |value| filter.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelSearch {onfilter} />
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onfilter: Option<Callback<String>>,
+}
+
+/// Yew implementation of the panel search.
+///
+/// Yew implementation of the panel search, a [Bulma panel block][bd]
+/// holding a search input. Once the user stops typing, the
+/// [`PanelBlock`]s of its [`Panel`] whose label does not contain the text,
+/// ignoring case, are hidden.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelSearch};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelSearch />
+///             <PanelBlock label="bulma">{"bulma"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(PanelSearch)]
+pub fn panel_search(props: &PanelSearchProperties) -> Html {
+    let panel = use_context::<PanelContext>();
+    let onsearch = {
+        let onfilter = props.onfilter.clone();
+        Callback::from(move |value: String| {
+            if let Some(panel) = &panel {
+                panel.filter.set(value.clone());
+            }
+            if let Some(onfilter) = &onfilter {
+                onfilter.emit(value);
+            }
+        })
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("panel-block")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <SearchInput placeholder={props.placeholder.clone()} {onsearch} />
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma panel block][bd].
+///
+/// Defines the properties of the panel block, based on the specification
+/// found in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelBlock href="#">{"bulma"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PanelBlockProperties {
+    /// Sets the link of the [Bulma panel block][bd].
+    ///
+    /// Sets the URL the [Bulma panel block][bd], which will receive these
+    /// properties, links to. The block is rendered as an `a` element when
+    /// set and as a `div` otherwise.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Sets the label used to filter the [Bulma panel block][bd].
+    ///
+    /// Sets the text matched against the filter typed in the
+    /// [`PanelSearch`] of the [`Panel`] holding the [Bulma panel block][bd]
+    /// which will receive these properties. Blocks without a label are
+    /// always shown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelBlock, PanelSearch};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelSearch />
+    ///             <PanelBlock label="bulma">{"bulma"}</PanelBlock>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
+    /// Sets the icon of the [Bulma panel block][bd].
+    ///
+    /// Sets the icon displayed before the content of the
+    /// [Bulma panel block][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::panel::{Panel, PanelBlock};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let icon = html! { <i class="fas fa-book" aria-hidden="true"></i> };
+    ///
+    ///     html! {
+    ///         <Panel>
+    ///             <PanelBlock href="#" {icon}>{"bulma"}</PanelBlock>
+    ///         </Panel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub icon: Option<Html>,
+    /// Whether or not the [Bulma panel block][bd] is active.
+    ///
+    /// Whether or not the [Bulma panel block][bd], which will receive these
+    /// properties, is highlighted as active.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub active: bool,
+    /// The list of elements found inside the [Bulma panel block][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma panel block][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/panel/
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma panel block][bd].
+///
+/// Yew implementation of the panel block, based on the specification found
+/// in the [Bulma panel component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::panel::{Panel, PanelBlock};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Panel>
+///             <PanelBlock href="#" active=true>{"bulma"}</PanelBlock>
+///         </Panel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/panel/
+#[function_component(PanelBlock)]
+pub fn panel_block(props: &PanelBlockProperties) -> Html {
+    let filter = use_context::<PanelContext>()
+        .map(|panel| (*panel.filter).clone())
+        .unwrap_or_default();
+    if let Some(label) = &props.label {
+        if !matches_filter(label, &filter) {
+            return html! {};
+        }
+    }
+
+    let active = if props.active {
+        format!("{IS_PREFIX}-active")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("panel-block")
+        .with_custom_class(&active)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let tag = (if props.href.is_some() { "a" } else { "div" }).to_owned();
+
+    html! {
+        <@{tag} id={props.id.clone()} {class} href={props.href.clone()}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            if let Some(icon) = &props.icon {
+                <Icon class={classes!("panel-icon")} icon={icon.clone()} />
+            }
+            { for props.children.iter() }
+        </@>
+    }
+}
+
+fn matches_filter(label: &str, filter: &str) -> bool {
+    label
+        .to_lowercase()
+        .contains(filter.trim().to_lowercase().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("bulma", "" ; "empty filter")]
+    #[test_case("bulma", "bul" ; "prefix")]
+    #[test_case("marksheet", "sheet" ; "inside")]
+    #[test_case("Bulma", "bULMA" ; "ignores case")]
+    #[test_case("bulma", " bulma " ; "ignores surrounding whitespace")]
+    fn label_matches_filter(label: &str, filter: &str) {
+        assert!(matches_filter(label, filter));
+    }
+
+    #[test_case("bulma", "minireset" ; "different text")]
+    #[test_case("bulma", "bulmaa" ; "longer filter")]
+    fn label_does_not_match_filter(label: &str, filter: &str) {
+        assert!(!matches_filter(label, filter));
+    }
+}
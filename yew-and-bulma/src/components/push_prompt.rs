@@ -0,0 +1,320 @@
+use js_sys::{
+    wasm_bindgen::{closure::Closure, JsCast, JsValue},
+    Function, Reflect,
+};
+use web_sys::{Notification, NotificationPermission};
+use yew::{
+    classes, function_component, html, use_state, AttrValue, Callback, Children, Html, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    components::message::{Message, MessageBody, MessageHeader},
+    elements::button::{Button, Buttons},
+    helpers::color::Color,
+};
+
+/// Defines the properties of the push prompt.
+///
+/// Defines the properties of the push prompt, a dismissible
+/// [Bulma message component][bd] asking the user to allow the page to show
+/// [notifications][notification].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::push_prompt::PushPrompt;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <PushPrompt>{"Get notified when your order ships."}</PushPrompt>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+/// [notification]: https://developer.mozilla.org/en-US/docs/Web/API/Notifications_API
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct PushPromptProperties {
+    /// Sets the color of the push prompt.
+    ///
+    /// Sets the color of the [Bulma message component][bd] which will
+    /// receive these properties. Defaults to [`Color::Info`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::push_prompt::PushPrompt, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <PushPrompt color={Color::Primary}>{"Get notified when your order ships."}</PushPrompt>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/message/#colors
+    #[prop_or(Color::Info)]
+    pub color: Color,
+    /// Sets the title of the push prompt.
+    ///
+    /// Sets the text found inside the header of the push prompt which will
+    /// receive these properties. Defaults to `Enable notifications`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::push_prompt::PushPrompt;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <PushPrompt title="Stay in the loop">{"Get notified when your order ships."}</PushPrompt>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("Enable notifications"))]
+    pub title: AttrValue,
+    /// Sets the text of the allow button.
+    ///
+    /// Sets the text of the button asking the browser for the notification
+    /// permission, found inside the push prompt which will receive these
+    /// properties. Defaults to `Allow`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::push_prompt::PushPrompt;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <PushPrompt allow_label="Notify me">{"Get notified when your order ships."}</PushPrompt>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("Allow"))]
+    pub allow_label: AttrValue,
+    /// Sets the text of the deny button.
+    ///
+    /// Sets the text of the button declining the notifications without
+    /// asking the browser, found inside the push prompt which will receive
+    /// these properties. Defaults to `Not now`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::push_prompt::PushPrompt;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <PushPrompt deny_label="No, thanks">{"Get notified when your order ships."}</PushPrompt>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("Not now"))]
+    pub deny_label: AttrValue,
+    /// Sets the callback used when the notification permission is granted.
+    ///
+    /// Sets the callback called when the user grants the notification
+    /// permission from the browser dialog opened by the allow button of the
+    /// push prompt which will receive these properties, usually used to
+    /// subscribe to push messages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::push_prompt::PushPrompt;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let subscribed = use_state(|| false);
+    ///     let ongrant = {
+    ///         let subscribed = subscribed.clone();
+    ///         Callback::from(move |_| subscribed.set(true))
+    ///     };
+    ///
+    ///     html! {
+    ///         <PushPrompt {ongrant}>{"Get notified when your order ships."}</PushPrompt>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub ongrant: Option<Callback<()>>,
+    /// Sets the callback used when the notifications are declined.
+    ///
+    /// Sets the callback called when the user clicks the deny button of the
+    /// push prompt which will receive these properties, or denies the
+    /// notification permission from the browser dialog.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::push_prompt::PushPrompt;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let declined = use_state(|| false);
+    ///     let ondeny = {
+    ///         let declined = declined.clone();
+    ///         Callback::from(move |_| declined.set(true))
+    ///     };
+    ///
+    ///     html! {
+    ///         <PushPrompt {ondeny}>{"Get notified when your order ships."}</PushPrompt>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub ondeny: Option<Callback<()>>,
+    /// Sets the callback used when the push prompt is dismissed.
+    ///
+    /// Sets the callback called when the delete button of the push prompt
+    /// which will receive these properties is clicked, without the user
+    /// making a decision.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::push_prompt::PushPrompt;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let ondismiss = Callback::from(|_| ());
+    ///
+    ///     html! {
+    ///         <PushPrompt {ondismiss}>{"Get notified when your order ships."}</PushPrompt>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub ondismiss: Option<Callback<()>>,
+    /// The content found inside the push prompt, above its buttons.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the push prompt.
+///
+/// Renders a [Bulma message component][bd] asking the user to allow
+/// notifications, whose allow button requests the notification permission
+/// from the browser. Nothing is rendered when the browser does not support
+/// notifications or the user already granted or denied the permission.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::push_prompt::PushPrompt;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let ongrant = Callback::from(|_| ());
+///
+///     html! {
+///         <PushPrompt {ongrant}>{"Get notified when your order ships."}</PushPrompt>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/message/
+#[function_component(PushPrompt)]
+pub fn push_prompt(props: &PushPromptProperties) -> Html {
+    let decided = use_state(|| !can_request_permission());
+
+    if *decided {
+        return html! {};
+    }
+
+    let onallow = {
+        let decided = decided.clone();
+        let ongrant = props.ongrant.clone();
+        let ondeny = props.ondeny.clone();
+        Callback::from(move |_| {
+            decided.set(true);
+            let ongrant = ongrant.clone();
+            let ondeny = ondeny.clone();
+            let on_permission = Closure::once_into_js(move |permission: JsValue| {
+                let callback = if NotificationPermission::from_js_value(&permission)
+                    == Some(NotificationPermission::Granted)
+                {
+                    ongrant
+                } else {
+                    ondeny
+                };
+                if let Some(callback) = callback {
+                    callback.emit(());
+                }
+            });
+            let _ = Notification::request_permission_with_permission_callback(
+                on_permission.unchecked_ref::<Function>(),
+            );
+        })
+    };
+    let ondecline = {
+        let decided = decided.clone();
+        let ondeny = props.ondeny.clone();
+        Callback::from(move |_| {
+            decided.set(true);
+            if let Some(ondeny) = &ondeny {
+                ondeny.emit(());
+            }
+        })
+    };
+    let onclose = {
+        let ondismiss = props.ondismiss.clone();
+        Callback::from(move |_| {
+            decided.set(true);
+            if let Some(ondismiss) = &ondismiss {
+                ondismiss.emit(());
+            }
+        })
+    };
+
+    html! {
+        <Message id={props.id.clone()} class={props.class.clone()} color={props.color} hide_on_close=false {onclose}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <MessageHeader delete=true>
+                <p>{&props.title}</p>
+            </MessageHeader>
+            <MessageBody>
+                { for props.children.iter() }
+                <Buttons class={classes!("mt-3")}>
+                    <Button color={props.color} onclick={onallow}>{&props.allow_label}</Button>
+                    <Button onclick={ondecline}>{&props.deny_label}</Button>
+                </Buttons>
+            </MessageBody>
+        </Message>
+    }
+}
+
+fn can_request_permission() -> bool {
+    let supported = web_sys::window()
+        .map(|window| Reflect::has(&window, &JsValue::from_str("Notification")).unwrap_or(false))
+        .unwrap_or(false);
+
+    supported && Notification::permission() == NotificationPermission::Default
+}
@@ -0,0 +1,551 @@
+use std::collections::BTreeSet;
+
+use yew::{
+    function_component, html, use_mut_ref, Callback, Children, ChildrenWithProps, Html, MouseEvent,
+    Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::button::Align,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the possible styles of the [Bulma tabs component][bd].
+///
+/// Defines the styles which can be applied to the
+/// [Bulma tabs component][bd], based on its documentation.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{Tabs, TabsStyle};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let tabs = vec![html! { "Pictures" }, html! { "Music" }];
+///
+///     html! {
+///         <Tabs {tabs} style={TabsStyle::Boxed} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/#styles
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TabsStyle {
+    Boxed,
+    Toggle,
+    ToggleRounded,
+}
+
+impl From<&TabsStyle> for String {
+    fn from(value: &TabsStyle) -> Self {
+        match value {
+            TabsStyle::Boxed => format!("{IS_PREFIX}-boxed"),
+            TabsStyle::Toggle => format!("{IS_PREFIX}-toggle"),
+            TabsStyle::ToggleRounded => format!("{IS_PREFIX}-toggle {IS_PREFIX}-toggle-rounded"),
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma tabs component][bd].
+///
+/// Defines the properties of the tabs component, based on the specification
+/// found in the [Bulma tabs component documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::Tabs;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let tabs = vec![html! { "Pictures" }, html! { "Music" }, html! { "Videos" }];
+///
+///     html! {
+///         <Tabs {tabs} active={1} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TabsProperties {
+    /// The labels of the tabs, in order.
+    ///
+    /// Defines the labels of the tabs found inside the
+    /// [Bulma tabs component][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::Tabs;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let tabs = vec![html! { "Pictures" }, html! { "Music" }, html! { "Videos" }];
+    ///
+    ///     html! {
+    ///         <Tabs {tabs} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    #[prop_or_default]
+    pub tabs: Vec<Html>,
+    /// Sets the index of the active tab.
+    ///
+    /// Sets the index of the active tab of the [Bulma tabs component][bd]
+    /// which will receive these properties. The active tab is controlled by
+    /// its parent, through the [`TabsProperties::ontabselect`] callback.
+    /// Defaults to the first tab.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::Tabs;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let tabs = vec![html! { "Pictures" }, html! { "Music" }];
+    ///
+    ///     html! {
+    ///         <Tabs {tabs} active={1} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    #[prop_or_default]
+    pub active: usize,
+    /// Sets the callback used when a tab is selected.
+    ///
+    /// Sets the callback called with the index of the tab clicked inside
+    /// the [Bulma tabs component][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::Tabs;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let active = use_state(|| 0);
+    ///     let ontabselect = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |index| active.set(index))
+    ///     };
+    ///     let tabs = vec![html! { "Pictures" }, html! { "Music" }];
+    ///
+    ///     html! {
+    ///         <Tabs {tabs} active={*active} {ontabselect} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    #[prop_or_default]
+    pub ontabselect: Option<Callback<usize>>,
+    /// Sets the alignment of the [Bulma tabs component][bd].
+    ///
+    /// Sets the alignment of the tabs found inside the
+    /// [Bulma tabs component][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::tabs::Tabs, elements::button::Align};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let tabs = vec![html! { "Pictures" }, html! { "Music" }];
+    ///
+    ///     html! {
+    ///         <Tabs {tabs} align={Align::Center} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/#alignment
+    #[prop_or(Align::Left)]
+    pub align: Align,
+    /// Sets the size of the [Bulma tabs component][bd].
+    ///
+    /// Sets the size of the [Bulma tabs component][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::tabs::Tabs, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let tabs = vec![html! { "Pictures" }, html! { "Music" }];
+    ///
+    ///     html! {
+    ///         <Tabs {tabs} size={Size::Large} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Sets the style of the [Bulma tabs component][bd].
+    ///
+    /// Sets the style of the [Bulma tabs component][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::{Tabs, TabsStyle};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let tabs = vec![html! { "Pictures" }, html! { "Music" }];
+    ///
+    ///     html! {
+    ///         <Tabs {tabs} style={TabsStyle::ToggleRounded} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/#styles
+    #[prop_or_default]
+    pub style: Option<TabsStyle>,
+    /// Whether or not the tabs take up the whole width.
+    ///
+    /// Whether or not the tabs found inside the [Bulma tabs component][bd],
+    /// which will receive these properties, take up the whole width
+    /// available.
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/#fullwidth
+    #[prop_or_default]
+    pub fullwidth: bool,
+    /// Keeps the visited panels mounted.
+    ///
+    /// Keeps the [`TabPanel`]s of the [Bulma tabs component][bd], which will
+    /// receive these properties, mounted once they are rendered, hiding them
+    /// with the `is-hidden` helper while their tab is not active. Their state,
+    /// such as the values of their inputs or their scroll position, is then
+    /// kept when switching back to them. Unless the tabs are also `lazy`,
+    /// every panel is mounted upfront.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::{TabPanel, Tabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let active = use_state(|| 0);
+    ///     let ontabselect = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |index| active.set(index))
+    ///     };
+    ///     let tabs = vec![html! { "Profile" }, html! { "Settings" }];
+    ///
+    ///     html! {
+    ///         <Tabs {tabs} active={*active} {ontabselect} keep_alive=true>
+    ///             <TabPanel><input class="input" /></TabPanel>
+    ///             <TabPanel><input class="input" /></TabPanel>
+    ///         </Tabs>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    #[prop_or_default]
+    pub keep_alive: bool,
+    /// Renders the panels on their first activation.
+    ///
+    /// Only renders a [`TabPanel`] of the [Bulma tabs component][bd], which
+    /// will receive these properties, once its tab is activated for the
+    /// first time. Used together with `keep_alive`, panels which were never
+    /// visited are not mounted, while visited ones stay mounted. Without
+    /// `keep_alive`, only the active panel is rendered anyway.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::{TabPanel, Tabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let active = use_state(|| 0);
+    ///     let ontabselect = {
+    ///         let active = active.clone();
+    ///         Callback::from(move |index| active.set(index))
+    ///     };
+    ///     let tabs = vec![html! { "Overview" }, html! { "Reports" }];
+    ///
+    ///     html! {
+    ///         <Tabs {tabs} active={*active} {ontabselect} lazy=true keep_alive=true>
+    ///             <TabPanel>{"Some overview."}</TabPanel>
+    ///             <TabPanel>{"Some expensive reports."}</TabPanel>
+    ///         </Tabs>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    #[prop_or_default]
+    pub lazy: bool,
+    /// The panels shown under the tabs, in order.
+    ///
+    /// Defines the [`TabPanel`]s of the [Bulma tabs component][bd] which
+    /// will receive these properties. Only the panel found at the index of
+    /// the active tab is shown, the others being either not rendered or,
+    /// with `keep_alive`, hidden.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::tabs::{TabPanel, Tabs};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let tabs = vec![html! { "Pictures" }, html! { "Music" }];
+    ///
+    ///     html! {
+    ///         <Tabs {tabs}>
+    ///             <TabPanel>{"Some pictures."}</TabPanel>
+    ///             <TabPanel>{"Some music."}</TabPanel>
+    ///         </Tabs>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/components/tabs/
+    #[prop_or_default]
+    pub children: ChildrenWithProps<TabPanel>,
+}
+
+/// Yew implementation of the [Bulma tabs component][bd].
+///
+/// Yew implementation of the tabs component, based on the specification
+/// found in the [Bulma tabs component documentation][bd]. When given
+/// [`TabPanel`] children, it also renders the panel matching its active tab
+/// under the tabs, optionally keeping the visited ones mounted.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{TabPanel, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let active = use_state(|| 0);
+///     let ontabselect = {
+///         let active = active.clone();
+///         Callback::from(move |index| active.set(index))
+///     };
+///     let tabs = vec![html! { "Pictures" }, html! { "Music" }];
+///
+///     html! {
+///         <Tabs {tabs} active={*active} {ontabselect}>
+///             <TabPanel>{"Some pictures."}</TabPanel>
+///             <TabPanel>{"Some music."}</TabPanel>
+///         </Tabs>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+#[function_component(Tabs)]
+pub fn tabs(props: &TabsProperties) -> Html {
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| {
+            if Size::Normal == *size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let style = props.style.as_ref().map(String::from).unwrap_or_default();
+    let fullwidth = if props.fullwidth {
+        format!("{IS_PREFIX}-fullwidth")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("tabs")
+        .with_custom_class(&String::from(&props.align))
+        .with_custom_class(&size)
+        .with_custom_class(&style)
+        .with_custom_class(&fullwidth)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let tabs = props.tabs.iter().enumerate().map(|(index, tab)| {
+        let onclick = {
+            let ontabselect = props.ontabselect.clone();
+            Callback::from(move |event: MouseEvent| {
+                event.prevent_default();
+                if let Some(ontabselect) = &ontabselect {
+                    ontabselect.emit(index);
+                }
+            })
+        };
+        let active = (index == props.active).then(|| format!("{IS_PREFIX}-active"));
+
+        html! {
+            <li class={active}>
+                <a href="#" {onclick}>{tab.clone()}</a>
+            </li>
+        }
+    });
+
+    let visited = use_mut_ref(BTreeSet::new);
+    visited.borrow_mut().insert(props.active);
+    let panels = if props.keep_alive {
+        let visited = visited.borrow();
+        props
+            .children
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !props.lazy || visited.contains(index))
+            .map(|(index, panel)| {
+                let hidden = (index != props.active).then(|| format!("{IS_PREFIX}-hidden"));
+
+                html! {
+                    <div key={index} class={hidden}>{panel}</div>
+                }
+            })
+            .collect::<Html>()
+    } else {
+        props
+            .children
+            .iter()
+            .nth(props.active)
+            .map(Html::from)
+            .unwrap_or_default()
+    };
+
+    html! {
+        <>
+            <div id={props.id.clone()} {class}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                <ul>
+                    { for tabs }
+                </ul>
+            </div>
+            { panels }
+        </>
+    }
+}
+
+/// Defines the properties of a tab panel.
+///
+/// Defines the properties of a tab panel, the content shown under a
+/// [Bulma tabs component][bd] while its matching tab is active.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{TabPanel, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let tabs = vec![html! { "Pictures" }];
+///
+///     html! {
+///         <Tabs {tabs}>
+///             <TabPanel>{"Some pictures."}</TabPanel>
+///         </Tabs>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TabPanelProperties {
+    /// The list of elements found inside the tab panel.
+    ///
+    /// Defines the elements that will be found inside the tab panel which
+    /// will receive these properties.
+    pub children: Children,
+}
+
+/// Yew implementation of a tab panel.
+///
+/// Yew implementation of a tab panel, the content shown under a
+/// [Bulma tabs component][bd] while its matching tab is active.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::tabs::{TabPanel, Tabs};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let tabs = vec![html! { "Pictures" }];
+///
+///     html! {
+///         <Tabs {tabs}>
+///             <TabPanel>{"Some pictures."}</TabPanel>
+///         </Tabs>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/components/tabs/
+#[function_component(TabPanel)]
+pub fn tab_panel(props: &TabPanelProperties) -> Html {
+    html! {
+        <div id={props.id.clone()} class={props.class.clone()} role="tabpanel"
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
@@ -0,0 +1,577 @@
+use std::{fmt::Display, rc::Rc};
+
+use js_sys::wasm_bindgen::JsCast;
+use web_sys::Element;
+#[cfg(feature = "storage")]
+use yew::use_effect_with_deps;
+use yew::{
+    function_component, hook, html, use_context, use_reducer, AttrValue, Callback, Children,
+    ContextProvider, Html, MouseEvent, Properties, Reducible, UseReducerHandle,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{elements::notification::Notification, helpers::color::Color};
+
+/// Defines the possible positions of a toast.
+///
+/// Defines the corners and edges of the viewport where a [`Toast`] can be
+/// shown. Toasts sharing a position are stacked, in the order they were
+/// pushed.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::toast::{use_toast, Toast, ToastPosition};
+///
+/// #[function_component(Save)]
+/// fn save() -> Html {
+///     let toast = use_toast();
+///     let onclick = Callback::from(move |_| {
+///         if let Some(toast) = &toast {
+///             toast.push(Toast {
+///                 body: html! { "Saved." },
+///                 position: ToastPosition::BottomCenter,
+///                 ..Default::default()
+///             });
+///         }
+///     });
+///
+///     html! {
+///         <button {onclick}>{"Save"}</button>
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastPosition {
+    // TODO: use #[default] when updating the MSRV
+    TopRight,
+    TopLeft,
+    TopCenter,
+    BottomRight,
+    BottomLeft,
+    BottomCenter,
+}
+
+impl Default for ToastPosition {
+    fn default() -> Self {
+        ToastPosition::TopRight
+    }
+}
+
+impl Display for ToastPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let position = match self {
+            ToastPosition::TopRight => "top-right",
+            ToastPosition::TopLeft => "top-left",
+            ToastPosition::TopCenter => "top-center",
+            ToastPosition::BottomRight => "bottom-right",
+            ToastPosition::BottomLeft => "bottom-left",
+            ToastPosition::BottomCenter => "bottom-center",
+        };
+
+        write!(f, "{position}")
+    }
+}
+
+impl ToastPosition {
+    const ALL: [ToastPosition; 6] = [
+        ToastPosition::TopRight,
+        ToastPosition::TopLeft,
+        ToastPosition::TopCenter,
+        ToastPosition::BottomRight,
+        ToastPosition::BottomLeft,
+        ToastPosition::BottomCenter,
+    ];
+
+    fn style(&self) -> &'static str {
+        match self {
+            ToastPosition::TopRight => "top: 1rem; right: 1rem;",
+            ToastPosition::TopLeft => "top: 1rem; left: 1rem;",
+            ToastPosition::TopCenter => "top: 1rem; left: 50%; transform: translateX(-50%);",
+            ToastPosition::BottomRight => "bottom: 1rem; right: 1rem;",
+            ToastPosition::BottomLeft => "bottom: 1rem; left: 1rem;",
+            ToastPosition::BottomCenter => "bottom: 1rem; left: 50%; transform: translateX(-50%);",
+        }
+    }
+}
+
+/// A message shown by a [`ToastProvider`].
+///
+/// Defines a message pushed through a [`ToastHandle`], rendered as a
+/// [Bulma notification element][bd] at the given position. Toasts without a
+/// duration stay until their delete button is clicked.
+///
+/// The content of a toast is either its `body` or, when set, its plain text
+/// `message`. Since `Html` cannot be saved, only toasts with a `message` are
+/// kept across page reloads by a [`ToastProvider`] with a `storage_key`.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{components::toast::Toast, helpers::color::Color};
+///
+/// let toast = Toast {
+///     color: Some(Color::Success),
+///     body: html! { "Saved." },
+///     duration: Some(3000),
+///     ..Default::default()
+/// };
+/// let saved = Toast {
+///     color: Some(Color::Success),
+///     message: Some("Saved.".into()),
+///     ..Default::default()
+/// };
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/notification/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Toast {
+    pub color: Option<Color>,
+    pub body: Html,
+    pub message: Option<AttrValue>,
+    pub duration: Option<u32>,
+    pub position: ToastPosition,
+}
+
+#[cfg(feature = "storage")]
+type StoredToast = (Option<String>, String, Option<u32>, String);
+
+#[cfg(feature = "storage")]
+impl Toast {
+    fn to_stored(&self) -> Option<StoredToast> {
+        self.message.as_ref().map(|message| {
+            (
+                self.color.map(|color| color.to_string()),
+                message.to_string(),
+                self.duration,
+                self.position.to_string(),
+            )
+        })
+    }
+
+    fn from_stored((color, message, duration, position): StoredToast) -> Self {
+        Toast {
+            color: color.and_then(|color| {
+                Color::ALL
+                    .iter()
+                    .copied()
+                    .find(|known| known.to_string() == color)
+            }),
+            message: Some(message.into()),
+            duration,
+            position: ToastPosition::ALL
+                .iter()
+                .copied()
+                .find(|known| known.to_string() == position)
+                .unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Default, PartialEq)]
+struct Toasts {
+    next_id: usize,
+    shown: Vec<(usize, Toast)>,
+}
+
+#[cfg(feature = "storage")]
+impl Toasts {
+    fn restore(stored: Vec<StoredToast>) -> Self {
+        let shown: Vec<_> = stored
+            .into_iter()
+            .map(Toast::from_stored)
+            .enumerate()
+            .collect();
+
+        Self {
+            next_id: shown.len(),
+            shown,
+        }
+    }
+
+    fn stored(&self) -> Vec<StoredToast> {
+        self.shown
+            .iter()
+            .filter_map(|(_, toast)| toast.to_stored())
+            .collect()
+    }
+}
+
+enum ToastAction {
+    Push(Toast),
+    Dismiss(usize),
+}
+
+impl Reducible for Toasts {
+    type Action = ToastAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut shown = self.shown.clone();
+        let mut next_id = self.next_id;
+        match action {
+            ToastAction::Push(toast) => {
+                shown.push((next_id, toast));
+                next_id += 1;
+            }
+            ToastAction::Dismiss(id) => shown.retain(|(shown_id, _)| *shown_id != id),
+        }
+
+        Rc::new(Self { next_id, shown })
+    }
+}
+
+/// Shows toasts inside the [`ToastProvider`] it was obtained from.
+///
+/// Handle provided as context by the [`ToastProvider`] component, used to
+/// show [`Toast`] messages from anywhere inside it. Retrieve it using
+/// [`use_toast`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::toast::{use_toast, Toast};
+///
+/// #[function_component(Save)]
+/// fn save() -> Html {
+///     let toast = use_toast();
+///     let onclick = Callback::from(move |_| {
+///         if let Some(toast) = &toast {
+///             toast.push(Toast {
+///                 body: html! { "Saved." },
+///                 duration: Some(3000),
+///                 ..Default::default()
+///             });
+///         }
+///     });
+///
+///     html! {
+///         <button {onclick}>{"Save"}</button>
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct ToastHandle {
+    toasts: UseReducerHandle<Toasts>,
+}
+
+impl ToastHandle {
+    /// Shows the given toast.
+    pub fn push(&self, toast: Toast) {
+        self.toasts.dispatch(ToastAction::Push(toast));
+    }
+}
+
+/// Defines the properties of the toast provider.
+///
+/// Defines the properties of the toast provider, which shows the toasts
+/// pushed by its children as [Bulma notification elements][bd] stacked in
+/// fixed containers over the page.
+///
+/// > _Place the provider above the router of the application, so that its
+/// > toasts survive route changes, ie a success message pushed right before
+/// > navigating away._
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::toast::ToastProvider;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ToastProvider>{"The rest of the application."}</ToastProvider>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/notification/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ToastProviderProperties {
+    /// The list of elements found inside the toast provider.
+    ///
+    /// Defines the elements that will be found inside the toast provider
+    /// which will receive these properties, usually the whole application.
+    /// Any of them can push toasts using [`use_toast`].
+    #[prop_or_default]
+    pub children: Children,
+    /// The key under which the shown toasts are saved.
+    ///
+    /// Saves the toasts shown by the toast provider which will receive these
+    /// properties in the [session storage][ss] under this key, restoring them
+    /// once the page is reloaded. Only the toasts with a `message` are saved.
+    /// Only available with the `storage` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::toast::ToastProvider;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ToastProvider storage_key="toasts">{"The rest of the application."}</ToastProvider>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [ss]: https://developer.mozilla.org/en-US/docs/Web/API/Window/sessionStorage
+    #[cfg(feature = "storage")]
+    #[prop_or_default]
+    pub storage_key: Option<AttrValue>,
+}
+
+/// Yew implementation of the toast provider.
+///
+/// Yew implementation of the toast provider, providing a [`ToastHandle`] to
+/// its children and rendering the toasts pushed through it as
+/// [Bulma notification elements][bd]. Each toast is removed once its
+/// duration elapses or its delete button is clicked.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::toast::{use_toast, Toast, ToastProvider};
+///
+/// #[function_component(Save)]
+/// fn save() -> Html {
+///     let toast = use_toast();
+///     let onclick = Callback::from(move |_| {
+///         if let Some(toast) = &toast {
+///             toast.push(Toast {
+///                 body: html! { "Saved." },
+///                 duration: Some(3000),
+///                 ..Default::default()
+///             });
+///         }
+///     });
+///
+///     html! {
+///         <button {onclick}>{"Save"}</button>
+///     }
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ToastProvider>
+///             <Save />
+///         </ToastProvider>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/notification/
+#[function_component(ToastProvider)]
+pub fn toast_provider(props: &ToastProviderProperties) -> Html {
+    #[cfg(feature = "storage")]
+    let toasts = {
+        use gloo::storage::{SessionStorage, Storage};
+
+        let storage_key = props.storage_key.clone();
+        let toasts = use_reducer(|| {
+            storage_key
+                .and_then(|key| SessionStorage::get::<Vec<StoredToast>>(key.as_str()).ok())
+                .map(Toasts::restore)
+                .unwrap_or_default()
+        });
+        let storage_key = props.storage_key.clone();
+        use_effect_with_deps(
+            move |stored| {
+                if let Some(key) = storage_key {
+                    let _ = SessionStorage::set(key.as_str(), stored);
+                }
+            },
+            toasts.stored(),
+        );
+        toasts
+    };
+    #[cfg(not(feature = "storage"))]
+    let toasts = use_reducer(Toasts::default);
+    let containers = ToastPosition::ALL.iter().filter_map(|position| {
+        let notifications = toasts
+            .shown
+            .iter()
+            .filter(|(_, toast)| toast.position == *position)
+            .map(|(id, toast)| {
+                let id = *id;
+                let ondismiss = {
+                    let toasts = toasts.clone();
+                    Callback::from(move |_| toasts.dispatch(ToastAction::Dismiss(id)))
+                };
+                let onclick = {
+                    let toasts = toasts.clone();
+                    Callback::from(move |event: MouseEvent| {
+                        let deleted = event
+                            .target()
+                            .and_then(|target| target.dyn_into::<Element>().ok())
+                            .map(|target| target.class_list().contains("delete"))
+                            .unwrap_or_default();
+                        if deleted {
+                            toasts.dispatch(ToastAction::Dismiss(id));
+                        }
+                    })
+                };
+
+                html! {
+                    <Notification key={id} color={toast.color} duration={toast.duration} {ondismiss} {onclick}>
+                        if let Some(message) = &toast.message {
+                            { message.clone() }
+                        } else {
+                            { toast.body.clone() }
+                        }
+                    </Notification>
+                }
+            })
+            .collect::<Vec<_>>();
+
+        (!notifications.is_empty()).then(|| {
+            html! {
+                <div style={format!("position: fixed; z-index: 50; width: 24rem; max-width: calc(100% - 2rem); {}", position.style())}>
+                    { for notifications }
+                </div>
+            }
+        })
+    });
+    let context = ToastHandle {
+        toasts: toasts.clone(),
+    };
+
+    html! {
+        <ContextProvider<ToastHandle> {context}>
+            { for props.children.iter() }
+            <div id={props.id.clone()} class={props.class.clone()}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+                { for containers }
+            </div>
+        </ContextProvider<ToastHandle>>
+    }
+}
+
+/// Returns the handle of the closest [`ToastProvider`].
+///
+/// Returns the [`ToastHandle`] provided by the closest [`ToastProvider`]
+/// found above the component using this hook, if there is one.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::toast::{use_toast, Toast};
+///
+/// #[function_component(Save)]
+/// fn save() -> Html {
+///     let toast = use_toast();
+///     let onclick = Callback::from(move |_| {
+///         if let Some(toast) = &toast {
+///             toast.push(Toast {
+///                 body: html! { "Saved." },
+///                 ..Default::default()
+///             });
+///         }
+///     });
+///
+///     html! {
+///         <button {onclick}>{"Save"}</button>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_toast() -> Option<ToastHandle> {
+    use_context::<ToastHandle>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toasts_are_pushed_with_increasing_ids() {
+        let toasts = Rc::new(Toasts::default())
+            .reduce(ToastAction::Push(Toast::default()))
+            .reduce(ToastAction::Push(Toast::default()));
+
+        let ids = toasts.shown.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        assert_eq!(ids, vec![0, 1]);
+        assert_eq!(toasts.next_id, 2);
+    }
+
+    #[test]
+    fn dismissed_toasts_are_removed() {
+        let toasts = Rc::new(Toasts::default())
+            .reduce(ToastAction::Push(Toast::default()))
+            .reduce(ToastAction::Push(Toast::default()))
+            .reduce(ToastAction::Dismiss(0))
+            .reduce(ToastAction::Push(Toast::default()));
+
+        let ids = toasts.shown.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn toasts_with_messages_are_stored() {
+        let toasts = Rc::new(Toasts::default())
+            .reduce(ToastAction::Push(Toast {
+                color: Some(Color::Success),
+                message: Some("Saved.".into()),
+                duration: Some(3000),
+                position: ToastPosition::BottomLeft,
+                ..Default::default()
+            }))
+            .reduce(ToastAction::Push(Toast::default()));
+
+        assert_eq!(
+            toasts.stored(),
+            vec![(
+                Some("success".to_owned()),
+                "Saved.".to_owned(),
+                Some(3000),
+                "bottom-left".to_owned()
+            )]
+        );
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn stored_toasts_are_restored() {
+        let toast = Toast {
+            color: Some(Color::Danger),
+            message: Some("Failed.".into()),
+            position: ToastPosition::TopCenter,
+            ..Default::default()
+        };
+        let toasts = Rc::new(Toasts::restore(vec![toast.to_stored().unwrap()]))
+            .reduce(ToastAction::Push(Toast::default()));
+
+        let ids = toasts.shown.iter().map(|(id, _)| *id).collect::<Vec<_>>();
+        assert_eq!(ids, vec![0, 1]);
+        assert_eq!(toasts.shown[0].1, toast);
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn stored_toasts_with_unknown_positions_are_restored_at_the_default_position() {
+        let toast = Toast::from_stored((None, "Saved.".to_owned(), None, "middle".to_owned()));
+
+        assert_eq!(toast.position, ToastPosition::TopRight);
+    }
+}
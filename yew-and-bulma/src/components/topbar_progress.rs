@@ -0,0 +1,421 @@
+use gloo::timers::callback::Timeout;
+use yew::{
+    classes, function_component, hook, html, use_context, use_effect_with_deps, use_state,
+    Children, ContextProvider, Html, Properties, UseStateHandle,
+};
+#[cfg(feature = "router")]
+use yew::{use_mut_ref, use_state_eq};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{elements::progress::ProgressBar, helpers::color::Color, utils::size::Size};
+
+#[derive(Clone, Copy, PartialEq)]
+enum TopProgressState {
+    Hidden,
+    Indeterminate,
+    Value(f64),
+}
+
+/// Controls the [`TopProgress`] bar it was obtained from.
+///
+/// Handle provided as context by the [`TopProgress`] component, used to
+/// show, update and hide its progress bar, for example during route
+/// transitions or while fetching data. Retrieve it using
+/// [`use_top_progress`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::topbar_progress::use_top_progress;
+///
+/// #[function_component(Reload)]
+/// fn reload() -> Html {
+///     let progress = use_top_progress();
+///     let onclick = Callback::from(move |_| {
+///         if let Some(progress) = &progress {
+///             progress.start();
+///         }
+///     });
+///
+///     html! {
+///         <button {onclick}>{"Reload"}</button>
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct TopProgressHandle {
+    state: UseStateHandle<TopProgressState>,
+}
+
+impl TopProgressHandle {
+    /// Shows the progress bar, without a known progress.
+    pub fn start(&self) {
+        self.state.set(TopProgressState::Indeterminate);
+    }
+
+    /// Shows the progress bar, filled up to the given fraction, between `0.0`
+    /// and `1.0`.
+    pub fn set(&self, value: f64) {
+        self.state
+            .set(TopProgressState::Value(value.clamp(0.0, 1.0)));
+    }
+
+    /// Fills the progress bar and hides it shortly afterwards.
+    pub fn finish(&self) {
+        self.state.set(TopProgressState::Value(1.0));
+    }
+}
+
+/// Defines the properties of the top progress bar.
+///
+/// Defines the properties of the top progress bar, a thin
+/// [Bulma progress bar][bd] fixed to the top of the viewport.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::topbar_progress::TopProgress;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <TopProgress>{"The rest of the application."}</TopProgress>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/progress/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TopProgressProperties {
+    /// Sets the color of the top progress bar.
+    ///
+    /// Sets the color of the [Bulma progress bar][bd] used by the top
+    /// progress bar which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{components::topbar_progress::TopProgress, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TopProgress color={Color::Info}>{"The rest of the application."}</TopProgress>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/progress/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// The list of elements which can control the top progress bar.
+    ///
+    /// Defines the elements found under the top progress bar which will
+    /// receive these properties. They can retrieve its
+    /// [`TopProgressHandle`] using [`use_top_progress`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::topbar_progress::TopProgress;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TopProgress>{"The rest of the application."}</TopProgress>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the top progress bar.
+///
+/// Yew implementation of the top progress bar, a thin
+/// [Bulma progress bar][bd] fixed to the top of the viewport, used as a
+/// global busy indicator. It is hidden until started through the
+/// [`TopProgressHandle`] it provides to its children.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::topbar_progress::{use_top_progress, TopProgress};
+///
+/// #[function_component(Page)]
+/// fn page() -> Html {
+///     let progress = use_top_progress();
+///     use_effect_with_deps(
+///         move |_| {
+///             if let Some(progress) = &progress {
+///                 progress.set(0.6);
+///             }
+///         },
+///         (),
+///     );
+///
+///     html! {
+///         <p>{"Loading..."}</p>
+///     }
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <TopProgress>
+///             <Page />
+///         </TopProgress>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/progress/
+#[function_component(TopProgress)]
+pub fn top_progress(props: &TopProgressProperties) -> Html {
+    let state = use_state(|| TopProgressState::Hidden);
+
+    {
+        let current = *state;
+        let state = state.clone();
+        use_effect_with_deps(
+            move |current| {
+                let timeout = matches!(current, TopProgressState::Value(value) if *value >= 1.0)
+                    .then(|| Timeout::new(300, move || state.set(TopProgressState::Hidden)));
+
+                move || drop(timeout)
+            },
+            current,
+        );
+    }
+
+    let value = match *state {
+        TopProgressState::Value(value) => Some(value),
+        _ => None,
+    };
+    let class = classes!("mb-0", props.class.clone());
+    let context = TopProgressHandle {
+        state: state.clone(),
+    };
+
+    html! {
+        <ContextProvider<TopProgressHandle> {context}>
+            if *state != TopProgressState::Hidden {
+                <div style="position: fixed; top: 0; right: 0; left: 0; z-index: 50;">
+                    <ProgressBar id={props.id.clone()} {class} color={props.color} size={Size::Small} {value} max={1.0}
+                        onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                        onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                        ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                        oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                        onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                        onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                        onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                        ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                        onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                        onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                        onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                        ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()} />
+                </div>
+            }
+            { for props.children.iter() }
+        </ContextProvider<TopProgressHandle>>
+    }
+}
+
+/// Returns the handle of the closest [`TopProgress`] bar.
+///
+/// Returns the [`TopProgressHandle`] provided by the closest [`TopProgress`]
+/// found above the component using it, or `None` if there is no such
+/// component.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::topbar_progress::use_top_progress;
+///
+/// #[function_component(Done)]
+/// fn done() -> Html {
+///     let progress = use_top_progress();
+///     let onclick = Callback::from(move |_| {
+///         if let Some(progress) = &progress {
+///             progress.finish();
+///         }
+///     });
+///
+///     html! {
+///         <button {onclick}>{"Done"}</button>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_top_progress() -> Option<TopProgressHandle> {
+    use_context::<TopProgressHandle>()
+}
+
+/// Defines the properties of the router progress.
+///
+/// Defines the properties of the router progress, which drives the closest
+/// [`TopProgress`] bar whenever the route changes. Only available with the
+/// `router` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::topbar_progress::{RouterProgress, TopProgress};
+/// use yew_router::prelude::*;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <BrowserRouter>
+///             <TopProgress>
+///                 <RouterProgress>{"The routed pages."}</RouterProgress>
+///             </TopProgress>
+///         </BrowserRouter>
+///     }
+/// }
+/// ```
+#[cfg(feature = "router")]
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct RouterProgressProperties {
+    /// Sets the duration of a route transition, in milliseconds.
+    ///
+    /// Sets how long the [`TopProgress`] bar is shown, and the content is
+    /// faded, after each route change seen by the router progress which will
+    /// receive these properties. Defaults to `300`.
+    #[prop_or(300)]
+    pub duration: u32,
+    /// Whether or not to fade the content during route transitions.
+    ///
+    /// Whether or not the content of the router progress, which will receive
+    /// these properties, is partially faded out while a route transition is
+    /// in progress.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::components::topbar_progress::{RouterProgress, TopProgress};
+    /// use yew_router::prelude::*;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <BrowserRouter>
+    ///             <TopProgress>
+    ///                 <RouterProgress fade=true>{"The routed pages."}</RouterProgress>
+    ///             </TopProgress>
+    ///         </BrowserRouter>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub fade: bool,
+    /// The content found inside the router progress, usually a `Switch`.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the router progress.
+///
+/// Starts the closest [`TopProgress`] bar whenever the location of the
+/// surrounding router changes, finishing it once the transition `duration`
+/// ends, and optionally fades its content meanwhile. Only available with the
+/// `router` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::components::topbar_progress::{RouterProgress, TopProgress};
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <BrowserRouter>
+///             <TopProgress>
+///                 <RouterProgress fade=true duration={500}>
+///                     <Switch<Route> render={|_| html! { "Home" }} />
+///                 </RouterProgress>
+///             </TopProgress>
+///         </BrowserRouter>
+///     }
+/// }
+/// ```
+#[cfg(feature = "router")]
+#[function_component(RouterProgress)]
+pub fn router_progress(props: &RouterProgressProperties) -> Html {
+    let location = yew_router::hooks::use_location()
+        .map(|location| format!("{}{}", location.path(), location.query_str()));
+    let top_progress = use_top_progress();
+    let transitioning = use_state_eq(|| false);
+    let first_render = use_mut_ref(|| true);
+
+    {
+        let transitioning = transitioning.clone();
+        let duration = props.duration;
+        use_effect_with_deps(
+            move |_| {
+                let timeout = (!first_render.replace(false)).then(|| {
+                    if let Some(top_progress) = &top_progress {
+                        top_progress.start();
+                    }
+                    transitioning.set(true);
+
+                    Timeout::new(duration, move || {
+                        if let Some(top_progress) = &top_progress {
+                            top_progress.finish();
+                        }
+                        transitioning.set(false);
+                    })
+                });
+
+                move || drop(timeout)
+            },
+            location,
+        );
+    }
+
+    let opacity = if props.fade && *transitioning {
+        "0.5"
+    } else {
+        "1"
+    };
+    let style = format!(
+        "transition: opacity {}ms ease; opacity: {opacity};",
+        props.duration
+    );
+
+    html! {
+        <div id={props.id.clone()} class={props.class.clone()} {style}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
@@ -1,7 +1,10 @@
 use yew::{function_component, html, Children, Html, Properties};
 use yew_and_bulma_macros::base_component_properties;
 
-use crate::utils::class::ClassBuilder;
+use crate::{
+    helpers::spacing::{Direction, Spacing},
+    utils::class::ClassBuilder,
+};
 
 /// Defines the properties of the [Bulma block element][bd].
 ///
@@ -26,6 +29,30 @@ use crate::utils::class::ClassBuilder;
 #[base_component_properties]
 #[derive(Properties, PartialEq)]
 pub struct BlockProperties {
+    /// Overrides the bottom spacing of the [block element][bd].
+    ///
+    /// Overrides the default bottom margin of the [Bulma block element][bd]
+    /// which will receive these properties, using the
+    /// [Bulma spacing helpers][sh].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::block::Block, helpers::spacing::Spacing};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Block spacing={Spacing::Two}>{"This is some text in a block."}</Block>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/block/
+    /// [sh]: https://bulma.io/documentation/helpers/spacing-helpers/
+    #[prop_or_default]
+    pub spacing: Option<Spacing>,
     /// The list of elements found inside the [block element][bd].
     ///
     /// Defines the elements that will be found inside the
@@ -57,8 +84,11 @@ pub struct BlockProperties {
 /// [bd]: https://bulma.io/documentation/elements/block/
 #[function_component(Block)]
 pub fn block(props: &BlockProperties) -> Html {
-    let class = ClassBuilder::default()
-        .with_custom_class("block")
+    let mut class = ClassBuilder::default().with_custom_class("block");
+    if let Some(spacing) = &props.spacing {
+        class = class.with_margin(Direction::Bottom, spacing.clone());
+    }
+    let class = class
         .with_custom_class(
             &props
                 .class
@@ -1,7 +1,14 @@
-use yew::{function_component, html, Children, Html, Properties};
+use web_sys::HtmlElement;
+use yew::{
+    function_component, html, use_node_ref, Callback, Children, Html, KeyboardEvent, Properties,
+    TargetCast,
+};
 use yew_and_bulma_macros::base_component_properties;
 
-use crate::utils::class::ClassBuilder;
+use crate::{
+    helpers::color::{contrast::text_color_for, BackgroundColor},
+    utils::class::ClassBuilder,
+};
 
 /// Defines the properties of the [Bulma box element][bd].
 ///
@@ -26,6 +33,80 @@ use crate::utils::class::ClassBuilder;
 #[base_component_properties]
 #[derive(Properties, PartialEq)]
 pub struct BoxProperties {
+    /// Sets the background color of the [Bulma box element][bd].
+    ///
+    /// Sets the background color of the [Bulma box element][bd] which will
+    /// receive these properties, using the [Bulma color helpers][ch]. The
+    /// text color, white or black, is picked to contrast with it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::r#box::Box, helpers::color::BackgroundColor};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Box color={BackgroundColor::PrimaryLight}>{"This is some text in a box."}</Box>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/box/
+    /// [ch]: https://bulma.io/documentation/helpers/color-helpers/#background-color
+    #[prop_or_default]
+    pub color: Option<BackgroundColor>,
+    /// Whether the [box element][bd] should react when hovered.
+    ///
+    /// Whether or not the [Bulma box element][bd], which will receive these
+    /// properties, should react when hovered or focused. When set, the box is
+    /// rendered as an `<a>` element, for which Bulma adds a shadow and a
+    /// colored border on hover and focus.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::r#box::Box;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Box hoverable=true>{"This is some text in a box."}</Box>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/box/
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Whether the [box element][bd] should behave as a clickable tile.
+    ///
+    /// Whether or not the [Bulma box element][bd], which will receive these
+    /// properties, should behave as a clickable tile. When set, the box shows
+    /// a pointer cursor, can be focused using the keyboard and activates its
+    /// `onclick` callback when `Enter` or `Space` is pressed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::r#box::Box;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onclick = Callback::from(|_| {});
+    ///
+    ///     html! {
+    ///         <Box clickable=true {onclick}>{"This is some text in a box."}</Box>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/box/
+    #[prop_or_default]
+    pub clickable: bool,
     /// The list of elements found inside the [box element][bd].
     ///
     /// Defines the elements that will be found inside the
@@ -57,8 +138,12 @@ pub struct BoxProperties {
 /// [bd]: https://bulma.io/documentation/elements/box/
 #[function_component(Box)]
 pub fn block(props: &BoxProperties) -> Html {
+    let node_ref = use_node_ref();
     let class = ClassBuilder::default()
         .with_custom_class("box")
+        .with_background_color(props.color)
+        .with_text_color(props.color.map(text_color_for))
+        .is_clickable(Some(props.clickable))
         .with_custom_class(
             &props
                 .class
@@ -67,14 +152,40 @@ pub fn block(props: &BoxProperties) -> Html {
                 .unwrap_or("".to_owned()),
         )
         .build();
+    let tag = (if props.hoverable { "a" } else { "div" }).to_string();
+    let (role, tabindex, onkeydown) = if props.clickable {
+        let onkeydown = props.onkeydown.clone();
+        let box_ref = node_ref.clone();
+        let onkeydown = Callback::from(move |e: KeyboardEvent| {
+            if let Some(onkeydown) = &onkeydown {
+                onkeydown.emit(e.clone());
+            }
+
+            let key = e.key();
+            if key != "Enter" && key != " " {
+                return;
+            }
+            let target = e.target_dyn_into::<HtmlElement>();
+            if let Some(element) = box_ref.cast::<HtmlElement>() {
+                if target.as_ref() == Some(&element) {
+                    e.prevent_default();
+                    element.click();
+                }
+            }
+        });
+
+        (Some("button"), Some("0"), Some(onkeydown))
+    } else {
+        (None, None, props.onkeydown.clone())
+    };
 
     html! {
-        <div id={props.id.clone()} {class}
+        <@{tag} id={props.id.clone()} {class} ref={node_ref} {role} {tabindex}
             onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
             onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
             ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
             oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
-            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            {onkeydown} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
             onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
             onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
             ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
@@ -83,6 +194,6 @@ pub fn block(props: &BoxProperties) -> Html {
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
             ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
             { for props.children.iter() }
-        </div>
+        </@>
     }
 }
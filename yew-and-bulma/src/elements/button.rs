@@ -1,4 +1,4 @@
-use yew::{function_component, html, Children, Classes, Html, Properties};
+use yew::{function_component, html, AttrValue, Children, Classes, Html, Properties};
 use yew_and_bulma_macros::base_component_properties;
 
 use crate::{
@@ -303,12 +303,15 @@ impl From<&State> for String {
 /// ```
 ///
 /// [bd]: https://bulma.io/documentation/elements/button/#style
-#[derive(PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 pub enum Style {
     Outlined,
     Inverted,
     InvertedOutlined,
     Rounded,
+    OutlinedRounded,
+    InvertedRounded,
+    InvertedOutlinedRounded,
 }
 
 impl From<&Style> for String {
@@ -318,6 +321,51 @@ impl From<&Style> for String {
             Style::Inverted => format!("{IS_PREFIX}-inverted"),
             Style::InvertedOutlined => format!("{IS_PREFIX}-inverted {IS_PREFIX}-outlined"),
             Style::Rounded => format!("{IS_PREFIX}-rounded"),
+            Style::OutlinedRounded => format!("{IS_PREFIX}-outlined {IS_PREFIX}-rounded"),
+            Style::InvertedRounded => format!("{IS_PREFIX}-inverted {IS_PREFIX}-rounded"),
+            Style::InvertedOutlinedRounded => {
+                format!("{IS_PREFIX}-inverted {IS_PREFIX}-outlined {IS_PREFIX}-rounded")
+            }
+        }
+    }
+}
+
+/// Defines the possible HTML types of a [button element][bd].
+///
+/// Defines the possible values of the `type` attribute of a
+/// [Bulma button element][bd], deciding what happens when it is clicked
+/// inside a form.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::button::{Button, ButtonType};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <form>
+///             <Button button_type={ButtonType::Submit}>{"Save"}</Button>
+///         </form>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/button/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ButtonType {
+    Button,
+    Submit,
+    Reset,
+}
+
+impl From<&ButtonType> for String {
+    fn from(value: &ButtonType) -> Self {
+        match value {
+            ButtonType::Button => "button".to_owned(),
+            ButtonType::Submit => "submit".to_owned(),
+            ButtonType::Reset => "reset".to_owned(),
         }
     }
 }
@@ -536,6 +584,75 @@ pub struct ButtonProperties {
     /// [bd]: https://bulma.io/documentation/elements/button/#displays
     #[prop_or_default]
     pub disabled: bool,
+    /// Whether or not the [Bulma button element][bd] should be selected.
+    ///
+    /// Whether or not the [Bulma button element][bd], which will receive these
+    /// properties, will be marked as selected, usually inside a group of
+    /// [Bulma buttons][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::button::{Button};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Button selected=true>{"Button"}</Button>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/#list-of-buttons
+    #[prop_or_default]
+    pub selected: bool,
+    /// Sets the type of the [Bulma button element][bd].
+    ///
+    /// Sets the HTML `type` of the [Bulma button element][bd] which will
+    /// receive these properties. If it is not set, the browser default is
+    /// used, which submits the form the button is found in.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::button::{Button, ButtonType};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Button button_type={ButtonType::Reset}>{"Reset"}</Button>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or_default]
+    pub button_type: Option<ButtonType>,
+    /// Sets the link of the [Bulma button element][bd].
+    ///
+    /// Sets the `href` of the [Bulma button element][bd] which will receive
+    /// these properties, rendering it as an `a` tag instead of a `button`
+    /// one, so that it can be used as a link. The `type` is not set on links.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::button::{Button};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Button href="/sign-up">{"Sign up"}</Button>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
     /// The list of elements found inside the [button element][bd].
     ///
     /// Defines the elements that will be found inside the
@@ -578,6 +695,12 @@ impl From<&ButtonProperties> for Classes {
             .as_ref()
             .map(String::from)
             .unwrap_or("".to_owned());
+        let flags = [(value.selected, "selected")]
+            .iter()
+            .filter(|(set, _)| *set)
+            .map(|(_, flag)| format!("{IS_PREFIX}-{flag}"))
+            .collect::<Vec<_>>()
+            .join(" ");
 
         ClassBuilder::default()
             .with_custom_class("button")
@@ -588,6 +711,7 @@ impl From<&ButtonProperties> for Classes {
             .with_custom_class(&fullwidth)
             .with_custom_class(&style)
             .with_custom_class(&state)
+            .with_custom_class(&flags)
             .with_custom_class(
                 &value
                     .class
@@ -622,9 +746,13 @@ impl From<&ButtonProperties> for Classes {
 #[function_component(Button)]
 pub fn button(props: &ButtonProperties) -> Html {
     let class: Classes = props.into();
+    let (tag, button_type) = match &props.href {
+        Some(_) => ("a", None),
+        None => ("button", props.button_type.as_ref().map(String::from)),
+    };
 
     html! {
-        <button id={props.id.clone()} {class} disabled={props.disabled}
+        <@{tag} id={props.id.clone()} {class} disabled={props.disabled} href={props.href.clone()} type={button_type}
             onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
             onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
             ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
@@ -638,6 +766,6 @@ pub fn button(props: &ButtonProperties) -> Html {
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
             ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
             { for props.children.iter() }
-        </button>
+        </@>
     }
 }
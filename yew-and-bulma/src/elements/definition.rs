@@ -0,0 +1,213 @@
+use yew::{function_component, html, Html, Properties};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    columns::Size,
+    helpers::typography::TextWeight,
+    utils::{class::ClassBuilder, constants::IS_PREFIX},
+};
+
+/// Defines the properties of the description list element.
+///
+/// Defines the properties of the description list element, which renders a
+/// [`<dl>` HTML element][dl] styled using [Bulma helpers][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::definition::DescriptionList;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let items = vec![
+///         (html! {"Name"}, html! {"Jane Doe"}),
+///         (html! {"Email"}, html! {"jane@doe.com"}),
+///     ];
+///
+///     html! {
+///         <DescriptionList {items} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/helpers/
+/// [dl]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dl
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DescriptionListProperties {
+    /// The list of terms and their descriptions.
+    ///
+    /// Defines the pairs of terms and descriptions found inside the
+    /// description list element which will receive these properties. Each
+    /// term is rendered as a `<dt>`, followed by its description, rendered
+    /// as a `<dd>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::definition::DescriptionList;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let items = vec![(html! {"Status"}, html! {"Active"})];
+    ///
+    ///     html! {
+    ///         <DescriptionList {items} />
+    ///     }
+    /// }
+    /// ```
+    pub items: Vec<(Html, Html)>,
+    /// Whether the terms should be displayed next to their descriptions.
+    ///
+    /// Whether or not the terms of the description list element, which will
+    /// receive these properties, should be displayed on the same line as their
+    /// descriptions, using [Bulma columns][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::definition::DescriptionList;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let items = vec![(html! {"Status"}, html! {"Active"})];
+    ///
+    ///     html! {
+    ///         <DescriptionList {items} horizontal=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/columns/
+    #[prop_or_default]
+    pub horizontal: bool,
+    /// Sets the size of the terms when displayed horizontally.
+    ///
+    /// Sets the [Bulma column size][bd] of the terms of the description list
+    /// element, which will receive these properties, when it is displayed
+    /// horizontally. Defaults to one third of the available width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{columns::Size, elements::definition::DescriptionList};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let items = vec![(html! {"Status"}, html! {"Active"})];
+    ///
+    ///     html! {
+    ///         <DescriptionList {items} horizontal=true term_size={Size::OneQuarter} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/columns/sizes/
+    #[prop_or_default]
+    pub term_size: Option<Size>,
+}
+
+/// Yew implementation of a description list element.
+///
+/// Renders a [`<dl>` HTML element][dl] from a list of terms and their
+/// descriptions, styled using [Bulma helpers][bd]. When displayed
+/// horizontally, each term and its description are placed in
+/// [Bulma columns][columns].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::definition::DescriptionList;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let items = vec![
+///         (html! {"Name"}, html! {"Jane Doe"}),
+///         (html! {"Email"}, html! {"jane@doe.com"}),
+///     ];
+///
+///     html! {
+///         <DescriptionList {items} horizontal=true />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/helpers/
+/// [columns]: https://bulma.io/documentation/columns/
+/// [dl]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dl
+#[function_component(DescriptionList)]
+pub fn description_list(props: &DescriptionListProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let term_class = ClassBuilder::default()
+        .with_text_weight(Some(TextWeight::SemiBold))
+        .build();
+    let items = if props.horizontal {
+        let term_size = props
+            .term_size
+            .as_ref()
+            .map(|size| format!("{IS_PREFIX}-{size}"))
+            .unwrap_or(format!("{IS_PREFIX}-{}", Size::OneThird));
+        let term_class = ClassBuilder::default()
+            .with_custom_class("column")
+            .with_custom_class(&term_size)
+            .with_text_weight(Some(TextWeight::SemiBold))
+            .build();
+
+        props
+            .items
+            .iter()
+            .map(|(term, description)| {
+                html! {
+                    <div class="columns is-mobile">
+                        <dt class={term_class.clone()}>{term.clone()}</dt>
+                        <dd class="column">{description.clone()}</dd>
+                    </div>
+                }
+            })
+            .collect::<Html>()
+    } else {
+        props
+            .items
+            .iter()
+            .map(|(term, description)| {
+                html! {
+                    <>
+                        <dt class={term_class.clone()}>{term.clone()}</dt>
+                        <dd class="mb-3">{description.clone()}</dd>
+                    </>
+                }
+            })
+            .collect::<Html>()
+    };
+
+    html! {
+        <dl id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { items }
+        </dl>
+    }
+}
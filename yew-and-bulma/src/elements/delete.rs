@@ -1,4 +1,7 @@
-use yew::{function_component, html, Html, Properties};
+use yew::{
+    function_component, hook, html, use_state, Callback, Children, Html, MouseEvent, Properties,
+    UseStateHandle,
+};
 use yew_and_bulma_macros::base_component_properties;
 
 use crate::utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size};
@@ -115,3 +118,237 @@ pub fn delete(props: &DeleteProperties) -> Html {
         </button>
     }
 }
+
+/// Handle to the dismissed state of an element.
+///
+/// Handle returned by [`use_dismiss`], telling whether an element, such as a
+/// [Bulma notification][bd] or tag, was dismissed, along with the callback
+/// used to dismiss it, usually set on a [`Delete`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{delete::{use_dismiss, Delete}, notification::Notification};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let dismiss = use_dismiss(None);
+///
+///     html! {
+///         if !dismiss.dismissed() {
+///             <Notification>
+///                 <Delete onclick={dismiss.onclick()} />
+///                 {"Your changes were saved."}
+///             </Notification>
+///         }
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/notification/
+#[derive(Clone, PartialEq)]
+pub struct UseDismissHandle {
+    dismissed: UseStateHandle<bool>,
+    ondismiss: Option<Callback<()>>,
+}
+
+impl UseDismissHandle {
+    /// Returns whether or not the element was dismissed.
+    pub fn dismissed(&self) -> bool {
+        *self.dismissed
+    }
+
+    /// Returns the callback dismissing the element, to be set on a [`Delete`].
+    pub fn onclick(&self) -> Callback<MouseEvent> {
+        let handle = self.clone();
+        Callback::from(move |_| handle.dismiss())
+    }
+
+    /// Dismisses the element, emitting the `ondismiss` callback.
+    pub fn dismiss(&self) {
+        self.dismissed.set(true);
+        if let Some(ondismiss) = &self.ondismiss {
+            ondismiss.emit(());
+        }
+    }
+
+    /// Shows the element again.
+    pub fn restore(&self) {
+        self.dismissed.set(false);
+    }
+}
+
+/// Keeps track of whether an element was dismissed.
+///
+/// Stores the shown/hidden state of an element closed by a [`Delete`] (ie a
+/// tag, notification or modal), calling the given callback when it is
+/// dismissed. See [`Dismissable`] for a ready made wrapper.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::{delete::{use_dismiss, Delete}, tag::Tag};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let dismiss = use_dismiss(Some(Callback::from(|_| ())));
+///
+///     html! {
+///         if !dismiss.dismissed() {
+///             <Tag>{"Rust"}<Delete onclick={dismiss.onclick()} /></Tag>
+///         }
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_dismiss(ondismiss: Option<Callback<()>>) -> UseDismissHandle {
+    let dismissed = use_state(|| false);
+
+    UseDismissHandle {
+        dismissed,
+        ondismiss,
+    }
+}
+
+/// Defines the properties of the dismissable wrapper.
+///
+/// Defines the properties of the dismissable wrapper, pairing a
+/// [Bulma delete element][bd] with any content, which is removed once the
+/// delete element is clicked.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::delete::Dismissable;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dismissable class={classes!("notification", "is-info")}>
+///             {"Your changes were saved."}
+///         </Dismissable>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/delete/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DismissableProperties {
+    /// Sets the size of the [Bulma delete element][bd] of the wrapper.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/delete/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not to only hide the content once dismissed.
+    ///
+    /// Whether or not the dismissable wrapper, which will receive these
+    /// properties, is kept in the page, using the `is-hidden` class, once
+    /// dismissed, instead of being removed, keeping the state of its content.
+    #[prop_or_default]
+    pub hide: bool,
+    /// Sets the callback used when the content is dismissed.
+    ///
+    /// Sets the callback called when the [Bulma delete element][bd] of the
+    /// dismissable wrapper, which will receive these properties, is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::delete::Dismissable;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let dismissed = use_state(|| 0);
+    ///     let ondismiss = {
+    ///         let dismissed = dismissed.clone();
+    ///         Callback::from(move |_| dismissed.set(*dismissed + 1))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Dismissable class={classes!("notification")} {ondismiss}>
+    ///             {"Your changes were saved."}
+    ///         </Dismissable>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/delete/
+    #[prop_or_default]
+    pub ondismiss: Option<Callback<()>>,
+    /// The content found inside the dismissable wrapper, after its
+    /// [Bulma delete element][bd].
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/delete/
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the dismissable wrapper.
+///
+/// Renders a `div`, holding a [`Delete`] followed by its children, which is
+/// removed (or hidden) once the delete element is clicked. Giving it the
+/// class of a Bulma element whose delete element is positioned by Bulma (ie
+/// `notification`) places it in the expected spot.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{elements::delete::Dismissable, utils::size::Size};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Dismissable class={classes!("notification", "is-warning")} size={Size::Small} hide=true>
+///             {"Your trial ends in 3 days."}
+///         </Dismissable>
+///     }
+/// }
+/// ```
+#[function_component(Dismissable)]
+pub fn dismissable(props: &DismissableProperties) -> Html {
+    let dismiss = use_dismiss(props.ondismiss.clone());
+    let hidden = if dismiss.dismissed() {
+        format!("{IS_PREFIX}-hidden")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class(&hidden)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    if dismiss.dismissed() && !props.hide {
+        return html! {};
+    }
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <Delete size={props.size} onclick={dismiss.onclick()} />
+            { for props.children.iter() }
+        </div>
+    }
+}
@@ -0,0 +1,226 @@
+use yew::{function_component, html, use_state, AttrValue, Callback, Html, MouseEvent, Properties};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::image::{Figure, Size},
+    utils::constants::IS_PREFIX,
+};
+
+/// Defines the possible kinds of media of an [embed][Embed].
+///
+/// Defines whether the source of an [`Embed`] is a page shown in an
+/// `iframe` (ie a video hosting service player) or a video file played by a
+/// native `video` tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::embed::{Embed, EmbedKind};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Embed src="media/videos/intro.mp4" kind={EmbedKind::Video} />
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmbedKind {
+    Iframe,
+    Video,
+}
+
+// TODO: use #[default] when updating the MSRV
+impl Default for EmbedKind {
+    fn default() -> Self {
+        EmbedKind::Iframe
+    }
+}
+
+/// Defines the properties of the embed element.
+///
+/// Defines the properties of the embed element, an `iframe` or a video kept
+/// at a fixed aspect ratio by a [Bulma image element][bd], which can wait
+/// behind a thumbnail until it is clicked before being loaded.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::embed::Embed;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Embed src="https://www.youtube.com/embed/YE7VzlLtp-4" title="Bulma in 100 seconds" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/image/#arbitrary-elements-in-fixed-ratio
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct EmbedProperties {
+    /// Sets the source of the embedded media.
+    pub src: AttrValue,
+    /// Sets the kind of the embedded media. Defaults to [`EmbedKind::Iframe`].
+    #[prop_or_default]
+    pub kind: EmbedKind,
+    /// Sets the accessible title of the embedded media.
+    #[prop_or_default]
+    pub title: Option<AttrValue>,
+    /// Sets the aspect ratio of the embed element.
+    ///
+    /// Sets the [ratio size][bd] of the [Bulma image element][bd] wrapping the
+    /// media of the embed element which will receive these properties.
+    /// Defaults to [`Size::Ratio16x9`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{embed::Embed, image::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Embed src="https://www.youtube.com/embed/YE7VzlLtp-4" ratio={Size::Ratio4x3} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/image/#responsive-images-with-ratios
+    #[prop_or(Size::Ratio16x9)]
+    pub ratio: Size,
+    /// Sets the thumbnail shown before the media is loaded.
+    ///
+    /// Sets the source of the image shown, along with a play button, in place
+    /// of the media of the embed element which will receive these properties.
+    /// The media is only loaded, and started, once it is clicked. If it is not
+    /// set, the media is loaded right away.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::embed::Embed;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Embed src="https://www.youtube.com/embed/YE7VzlLtp-4"
+    ///             thumbnail="media/images/thumbnail.png" />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub thumbnail: Option<AttrValue>,
+    /// Sets the text of the play button. Defaults to `▶`.
+    #[prop_or(AttrValue::from("▶"))]
+    pub play_label: AttrValue,
+    /// Sets the callback used when the thumbnail is clicked.
+    ///
+    /// Sets the callback called when the thumbnail of the embed element,
+    /// which will receive these properties, is clicked and its media starts
+    /// loading.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::embed::Embed;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let plays = use_state(|| 0);
+    ///     let onstart = {
+    ///         let plays = plays.clone();
+    ///         Callback::from(move |_| plays.set(*plays + 1))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Embed src="https://www.youtube.com/embed/YE7VzlLtp-4"
+    ///             thumbnail="media/images/thumbnail.png" {onstart} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onstart: Option<Callback<()>>,
+}
+
+/// Yew implementation of the embed element.
+///
+/// Renders its media inside a [`Figure`] using a ratio size, giving it the
+/// `has-ratio` class so that it fills the figure, as described in the
+/// [Bulma image element documentation][bd]. When a thumbnail is set, it is
+/// shown with a play button instead, and the media is only loaded, and
+/// started, once clicked.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::embed::Embed;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Embed src="https://www.youtube.com/embed/YE7VzlLtp-4"
+///             thumbnail="media/images/thumbnail.png" title="Bulma in 100 seconds" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/image/#arbitrary-elements-in-fixed-ratio
+#[function_component(Embed)]
+pub fn embed(props: &EmbedProperties) -> Html {
+    let started = use_state(|| false);
+    let onclick = {
+        let started = started.clone();
+        let onstart = props.onstart.clone();
+        Callback::from(move |_: MouseEvent| {
+            started.set(true);
+            if let Some(onstart) = &onstart {
+                onstart.emit(());
+            }
+        })
+    };
+    let media = match (&props.thumbnail, *started, props.kind) {
+        (Some(thumbnail), false, _) => html! {
+            <button type="button" class="has-ratio" aria-label={props.title.clone()} {onclick}
+                style={format!("padding: 0; border: 0; cursor: pointer; background: center / cover url(\"{thumbnail}\");")}>
+                <span class={format!("button {IS_PREFIX}-large {IS_PREFIX}-rounded")}
+                    style="position: absolute; top: 50%; left: 50%; transform: translate(-50%, -50%);">
+                    {props.play_label.clone()}
+                </span>
+            </button>
+        },
+        (_, _, EmbedKind::Iframe) => html! {
+            <iframe class="has-ratio" src={props.src.clone()} title={props.title.clone()} loading="lazy"
+                allow="autoplay; encrypted-media; fullscreen; picture-in-picture" allowfullscreen=true></iframe>
+        },
+        (_, started, EmbedKind::Video) => html! {
+            <video class="has-ratio" src={props.src.clone()} title={props.title.clone()} controls=true
+                autoplay={started} preload="metadata"></video>
+        },
+    };
+
+    html! {
+        <Figure id={props.id.clone()} class={props.class.clone()} size={props.ratio}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            {media}
+        </Figure>
+    }
+}
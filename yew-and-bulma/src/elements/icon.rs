@@ -372,3 +372,277 @@ pub fn icon(props: &IconProperties) -> Html {
         </>
     }
 }
+
+/// Defines the possible styles of a [Font Awesome][fa] icon.
+///
+/// Defines the styles in which a [Font Awesome][fa] icon can be rendered by a
+/// [`FaIcon`], each mapping to its own class (ie `fas` for solid icons). Only
+/// available with the `icons-fontawesome` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::icon::{FaIcon, FaStyle};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FaIcon name="github" style={FaStyle::Brands} />
+///     }
+/// }
+/// ```
+///
+/// [fa]: https://fontawesome.com/
+#[cfg(feature = "icons-fontawesome")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FaStyle {
+    Solid,
+    Regular,
+    Brands,
+}
+
+// TODO: use #[default] when updating the MSRV
+#[cfg(feature = "icons-fontawesome")]
+impl Default for FaStyle {
+    fn default() -> Self {
+        FaStyle::Solid
+    }
+}
+
+#[cfg(feature = "icons-fontawesome")]
+impl From<&FaStyle> for String {
+    fn from(value: &FaStyle) -> Self {
+        match value {
+            FaStyle::Solid => "fas".to_owned(),
+            FaStyle::Regular => "far".to_owned(),
+            FaStyle::Brands => "fab".to_owned(),
+        }
+    }
+}
+
+/// Defines the properties of the [Font Awesome][fa] icon adapter.
+///
+/// Defines the properties of the [Font Awesome][fa] icon adapter, which
+/// renders the markup expected by Font Awesome inside a
+/// [Bulma icon element][bd]. Only available with the `icons-fontawesome`
+/// feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::icon::FaIcon;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FaIcon name="home" text="Home" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/icon/
+/// [fa]: https://fontawesome.com/
+#[cfg(feature = "icons-fontawesome")]
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct FaIconProperties {
+    /// Sets the name of the icon, without the `fa-` prefix (ie `home`).
+    pub name: AttrValue,
+    /// Sets the style of the icon. Defaults to [`FaStyle::Solid`].
+    #[prop_or_default]
+    pub style: FaStyle,
+    /// Sets the text shown next to the icon, as for [`Icon`].
+    #[prop_or_default]
+    pub text: AttrValue,
+    /// Sets the color of the icon, as for [`Icon`].
+    #[prop_or_default]
+    pub color: Option<TextColor>,
+    /// Sets the size of the icon, as for [`Icon`].
+    #[prop_or_default]
+    pub size: Option<Size>,
+}
+
+/// Yew adapter for [Font Awesome][fa] icons.
+///
+/// Renders the `<i class="fas fa-...">` markup expected by
+/// [Font Awesome][fa] inside an [`Icon`], so that icons can be referenced by
+/// name. The Font Awesome styles have to be added to the page separately.
+/// Only available with the `icons-fontawesome` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{elements::icon::FaIcon, utils::size::Size};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FaIcon name="check" size={Size::Large} />
+///     }
+/// }
+/// ```
+///
+/// [fa]: https://fontawesome.com/
+#[cfg(feature = "icons-fontawesome")]
+#[function_component(FaIcon)]
+pub fn fa_icon(props: &FaIconProperties) -> Html {
+    let class = format!("{} fa-{}", String::from(&props.style), props.name);
+    let icon = html! { <i {class}></i> };
+
+    html! {
+        <Icon id={props.id.clone()} class={props.class.clone()} {icon} text={props.text.clone()}
+            color={props.color} size={props.size}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}/>
+    }
+}
+
+/// Defines the possible styles of a [Material Symbols][ms] icon.
+///
+/// Defines the styles in which a [Material Symbols][ms] icon can be rendered
+/// by a [`MaterialIcon`], each mapping to its own font class (ie
+/// `material-symbols-outlined`). Only available with the `icons-material`
+/// feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::icon::{MaterialIcon, MaterialStyle};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <MaterialIcon name="favorite" style={MaterialStyle::Rounded} />
+///     }
+/// }
+/// ```
+///
+/// [ms]: https://fonts.google.com/icons
+#[cfg(feature = "icons-material")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MaterialStyle {
+    Outlined,
+    Rounded,
+    Sharp,
+}
+
+// TODO: use #[default] when updating the MSRV
+#[cfg(feature = "icons-material")]
+impl Default for MaterialStyle {
+    fn default() -> Self {
+        MaterialStyle::Outlined
+    }
+}
+
+#[cfg(feature = "icons-material")]
+impl From<&MaterialStyle> for String {
+    fn from(value: &MaterialStyle) -> Self {
+        match value {
+            MaterialStyle::Outlined => "material-symbols-outlined".to_owned(),
+            MaterialStyle::Rounded => "material-symbols-rounded".to_owned(),
+            MaterialStyle::Sharp => "material-symbols-sharp".to_owned(),
+        }
+    }
+}
+
+/// Defines the properties of the [Material Symbols][ms] icon adapter.
+///
+/// Defines the properties of the [Material Symbols][ms] icon adapter, which
+/// renders the markup expected by Material Symbols inside a
+/// [Bulma icon element][bd]. Only available with the `icons-material`
+/// feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::icon::MaterialIcon;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <MaterialIcon name="home" text="Home" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/icon/
+/// [ms]: https://fonts.google.com/icons
+#[cfg(feature = "icons-material")]
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct MaterialIconProperties {
+    /// Sets the name of the icon (ie `home`).
+    pub name: AttrValue,
+    /// Sets the style of the icon. Defaults to [`MaterialStyle::Outlined`].
+    #[prop_or_default]
+    pub style: MaterialStyle,
+    /// Sets the text shown next to the icon, as for [`Icon`].
+    #[prop_or_default]
+    pub text: AttrValue,
+    /// Sets the color of the icon, as for [`Icon`].
+    #[prop_or_default]
+    pub color: Option<TextColor>,
+    /// Sets the size of the icon, as for [`Icon`].
+    #[prop_or_default]
+    pub size: Option<Size>,
+}
+
+/// Yew adapter for [Material Symbols][ms] icons.
+///
+/// Renders the `<span class="material-symbols-outlined">` markup expected
+/// by [Material Symbols][ms] inside an [`Icon`], so that icons can be
+/// referenced by name. The Material Symbols font has to be added to the page
+/// separately. Only available with the `icons-material` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{elements::icon::MaterialIcon, helpers::color::TextColor};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <MaterialIcon name="delete" color={TextColor::Danger} />
+///     }
+/// }
+/// ```
+///
+/// [ms]: https://fonts.google.com/icons
+#[cfg(feature = "icons-material")]
+#[function_component(MaterialIcon)]
+pub fn material_icon(props: &MaterialIconProperties) -> Html {
+    let icon = html! { <span class={String::from(&props.style)}>{props.name.clone()}</span> };
+
+    html! {
+        <Icon id={props.id.clone()} class={props.class.clone()} {icon} text={props.text.clone()}
+            color={props.color} size={props.size}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}/>
+    }
+}
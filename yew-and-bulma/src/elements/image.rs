@@ -1,4 +1,6 @@
-use yew::{function_component, html, AttrValue, Children, Html, Properties};
+use yew::{
+    function_component, html, use_state, AttrValue, Callback, Children, Event, Html, Properties,
+};
 use yew_and_bulma_macros::base_component_properties;
 
 use crate::utils::{class::ClassBuilder, constants::IS_PREFIX};
@@ -99,6 +101,132 @@ pub struct ImageProperties {
     ///
     /// [bd]: https://bulma.io/documentation/elements/image/
     pub src: AttrValue,
+    /// Sets the alternative text of the [Bulma image element][bd].
+    ///
+    /// Sets the `alt` of the [Bulma image element][bd] which will receive
+    /// these properties, shown when it can not be loaded and read by screen
+    /// readers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::image::{Figure, Image};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Figure>
+    ///             <Image src={"media/images/img.png"} alt="A placeholder image" />
+    ///         </Figure>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/image/
+    #[prop_or_default]
+    pub alt: Option<AttrValue>,
+    /// Sets the sources of the [Bulma image element][bd] for other resolutions.
+    ///
+    /// Sets the native `srcset` of the [Bulma image element][bd] which will
+    /// receive these properties, letting the browser pick the source best
+    /// fitting the screen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::image::{Figure, Image};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Figure>
+    ///             <Image src={"media/images/img.png"} srcset="media/images/img-2x.png 2x" />
+    ///         </Figure>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/image/
+    #[prop_or_default]
+    pub srcset: Option<AttrValue>,
+    /// Sets the display sizes of the [Bulma image element][bd].
+    ///
+    /// Sets the native `sizes` of the [Bulma image element][bd] which will
+    /// receive these properties, used along with `srcset` width descriptors.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::image::{Figure, Image};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Figure>
+    ///             <Image src={"media/images/img.png"} srcset="media/images/img-480.png 480w, media/images/img-960.png 960w"
+    ///                 sizes="(max-width: 600px) 480px, 960px" />
+    ///         </Figure>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/image/
+    #[prop_or_default]
+    pub sizes: Option<AttrValue>,
+    /// Whether or not the [Bulma image element][bd] should be lazy loaded.
+    ///
+    /// Whether or not the [Bulma image element][bd], which will receive these
+    /// properties, is only loaded once it gets close to the viewport, by
+    /// setting its `loading` attribute to `lazy`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::image::{Figure, Image};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Figure>
+    ///             <Image src={"media/images/img.png"} lazy=true />
+    ///         </Figure>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/image/
+    #[prop_or_default]
+    pub lazy: bool,
+    /// Sets the source used when the [Bulma image element][bd] fails to load.
+    ///
+    /// Sets the source which replaces the one of the
+    /// [Bulma image element][bd], which will receive these properties, once
+    /// its `error` event is fired (ie for a broken link). The `srcset` is
+    /// dropped along with the original source.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::image::{Figure, Image};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Figure>
+    ///             <Image src={"media/images/img.png"} fallback_src="media/images/placeholder.png" />
+    ///         </Figure>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/image/
+    #[prop_or_default]
+    pub fallback_src: Option<AttrValue>,
 }
 
 /// Yew helper for the [Bulma image element][bd].
@@ -125,6 +253,26 @@ pub struct ImageProperties {
 /// [bd]: https://bulma.io/documentation/elements/image/
 #[function_component(Image)]
 pub fn image(props: &ImageProperties) -> Html {
+    let failed = use_state(|| None::<AttrValue>);
+    let fallback = props
+        .fallback_src
+        .clone()
+        .filter(|_| failed.as_ref() == Some(&props.src));
+    let (src, srcset) = match fallback {
+        Some(fallback) => (fallback, None),
+        None => (props.src.clone(), props.srcset.clone()),
+    };
+    let onerror = {
+        let onerror = props.onerror.clone();
+        let src = props.src.clone();
+        Callback::from(move |event: Event| {
+            failed.set(Some(src.clone()));
+            if let Some(onerror) = &onerror {
+                onerror.emit(event);
+            }
+        })
+    };
+    let loading = props.lazy.then(|| AttrValue::from("lazy"));
     let fullwidth = if props.fullwidth { "is-fullwidth" } else { "" };
     let rounded = if props.rounded { "is-rounded" } else { "" };
     let class = ClassBuilder::default()
@@ -140,7 +288,7 @@ pub fn image(props: &ImageProperties) -> Html {
         .build();
 
     html! {
-        <img id={props.id.clone()} {class} src={props.src.clone()}
+        <img id={props.id.clone()} {class} {src} {srcset} sizes={props.sizes.clone()} alt={props.alt.clone()} {loading}
             onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
             onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
             ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
@@ -148,7 +296,7 @@ pub fn image(props: &ImageProperties) -> Html {
             onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
             onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
             onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
-            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} {onerror}
             onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
             onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
@@ -181,7 +329,7 @@ pub fn image(props: &ImageProperties) -> Html {
 /// [bd]: https://bulma.io/documentation/elements/image/
 /// [f-sizes]: https://bulma.io/documentation/elements/image/#fixed-square-images
 /// [r-sizes]: https://bulma.io/documentation/elements/image/#responsive-images-with-ratios
-#[derive(PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Size {
     Pixels16x16,
     Pixels24x24,
@@ -287,6 +435,31 @@ pub struct FigureProperties {
     /// [bd]: https://bulma.io/documentation/elements/image/
     #[prop_or_default]
     pub size: Option<Size>,
+    /// Whether or not the [Bulma figure element][bd] should have the width of its parent.
+    ///
+    /// Whether or not the [Bulma figure element][bd], which will receive these
+    /// properties, will have the same width as its parent, usually along with
+    /// a ratio size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::image::{Figure, Image, Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Figure size={Size::Ratio16x9} fullwidth=true>
+    ///             <Image src={"media/images/img.png"} />
+    ///         </Figure>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/image/#responsive-images-with-ratios
+    #[prop_or_default]
+    pub fullwidth: bool,
     /// The list of elements found inside the [image element][bd].
     ///
     /// Defines the elements that will be found inside the
@@ -325,9 +498,11 @@ pub fn figure(props: &FigureProperties) -> Html {
         .as_ref()
         .map(String::from)
         .unwrap_or("".to_owned());
+    let fullwidth = if props.fullwidth { "is-fullwidth" } else { "" };
     let class = ClassBuilder::default()
         .with_custom_class("image")
         .with_custom_class(&size)
+        .with_custom_class(fullwidth)
         .with_custom_class(
             &props
                 .class
@@ -0,0 +1,278 @@
+use yew::{function_component, html, AttrValue, Children, Html, Properties};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size};
+
+/// Defines the properties of the keyboard key element.
+///
+/// Defines the properties of the keyboard key element, a `kbd` tag styled
+/// as a keycap using the [Bulma tag element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::kbd::Kbd;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Kbd>{"Esc"}</Kbd>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/tag/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct KbdProperties {
+    /// Sets the size of the keyboard key.
+    ///
+    /// Sets the size of the [Bulma tag element][bd] used by the keyboard key
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::kbd::Kbd, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Kbd size={Size::Medium}>{"Esc"}</Kbd>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// The list of elements found inside the keyboard key.
+    ///
+    /// Defines the elements that will be found inside the keyboard key which
+    /// will receive these properties, usually the name of the key.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::kbd::Kbd;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Kbd>{"Enter"}</Kbd>
+    ///     }
+    /// }
+    /// ```
+    pub children: Children,
+}
+
+/// Yew implementation of the keyboard key element.
+///
+/// Yew implementation of the keyboard key element, rendering a `kbd` tag
+/// styled as a keycap using the [Bulma tag element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::kbd::Kbd;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <p>{"Press "}<Kbd>{"Esc"}</Kbd>{" to close."}</p>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/tag/
+#[function_component(Kbd)]
+pub fn kbd(props: &KbdProperties) -> Html {
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| {
+            if Size::Small == *size {
+                "".to_owned()
+            } else {
+                format!("{IS_PREFIX}-{size}")
+            }
+        })
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("tag")
+        .with_custom_class(&size)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <kbd id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </kbd>
+    }
+}
+
+/// Defines the properties of the shortcut hint element.
+///
+/// Defines the properties of the shortcut hint element, a list of
+/// [keyboard keys][`Kbd`] making up a keyboard shortcut.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::kbd::ShortcutHint;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ShortcutHint keys={vec!["Ctrl".into(), "K".into()]} />
+///     }
+/// }
+/// ```
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ShortcutHintProperties {
+    /// The keys making up the shortcut.
+    ///
+    /// Defines the keys, in order, of the shortcut displayed by the shortcut
+    /// hint which will receive these properties. Each key is rendered as a
+    /// [`Kbd`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::kbd::ShortcutHint;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ShortcutHint keys={vec!["Ctrl".into(), "Shift".into(), "P".into()]} />
+    ///     }
+    /// }
+    /// ```
+    pub keys: Vec<AttrValue>,
+    /// Sets the separator placed between the keys.
+    ///
+    /// Sets the text placed between each two keys of the shortcut hint which
+    /// will receive these properties. Defaults to `+`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::kbd::ShortcutHint;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ShortcutHint keys={vec!["g".into(), "i".into()]} separator="then" />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("+"))]
+    pub separator: AttrValue,
+    /// Sets the size of the keys.
+    ///
+    /// Sets the size of each [`Kbd`] found inside the shortcut hint which
+    /// will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::kbd::ShortcutHint, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ShortcutHint keys={vec!["Ctrl".into(), "K".into()]} size={Size::Medium} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub size: Option<Size>,
+}
+
+/// Yew implementation of the shortcut hint element.
+///
+/// Yew implementation of the shortcut hint element, rendering each key of a
+/// keyboard shortcut as a [`Kbd`], separated by the given separator.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::kbd::ShortcutHint;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <p>{"Search "}<ShortcutHint keys={vec!["Ctrl".into(), "K".into()]} /></p>
+///     }
+/// }
+/// ```
+#[function_component(ShortcutHint)]
+pub fn shortcut_hint(props: &ShortcutHintProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class(&format!("{IS_PREFIX}-inline-flex"))
+        .with_custom_class(&format!("{IS_PREFIX}-align-items-center"))
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let keys = props.keys.iter().enumerate().map(|(index, key)| {
+        html! {
+            <>
+                if index > 0 {
+                    <span class="mx-1">{props.separator.clone()}</span>
+                }
+                <Kbd size={props.size}>{key.clone()}</Kbd>
+            </>
+        }
+    });
+
+    html! {
+        <span id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for keys }
+        </span>
+    }
+}
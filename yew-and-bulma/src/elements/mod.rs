@@ -84,6 +84,30 @@ pub mod button;
 ///
 /// [bd]: https://bulma.io/documentation/elements/content/
 pub mod content;
+/// Provides utilities for creating description list elements in Yew.
+///
+/// Defines the necessary components to build a [`<dl>` HTML element][dl],
+/// styled using [Bulma helpers][bd], from a list of terms and descriptions.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::definition::DescriptionList;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let items = vec![(html! {"Name"}, html! {"Jane Doe"})];
+///
+///     html! {
+///         <DescriptionList {items} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/helpers/
+/// [dl]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/dl
+pub mod definition;
 /// Provides utilities for creating [delete elements][bd] in Yew.
 ///
 /// Defines the necessary components to build, style and modify
@@ -105,6 +129,28 @@ pub mod content;
 ///
 /// [bd]: https://bulma.io/documentation/elements/delete/
 pub mod delete;
+/// Provides utilities for embedding media in Yew.
+///
+/// Defines the necessary components to embed videos and `iframe`s at a fixed
+/// aspect ratio, using [Bulma image elements][bd], optionally loading them
+/// only once their thumbnail is clicked.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::embed::Embed;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Embed src="https://www.youtube.com/embed/YE7VzlLtp-4" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/image/#arbitrary-elements-in-fixed-ratio
+pub mod embed;
 /// Provides utilities for creating [icon elements][bd] in Yew.
 ///
 /// Defines the necessary components to build, style and modify
@@ -152,6 +198,30 @@ pub mod icon;
 ///
 /// [bd]: https://bulma.io/documentation/elements/image/
 pub mod image;
+/// Provides utilities for creating keyboard key elements in Yew.
+///
+/// Defines the necessary components to display keyboard keys and shortcuts,
+/// styled as keycaps using the [Bulma tag element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::kbd::{Kbd, ShortcutHint};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <>
+///             <Kbd>{"Esc"}</Kbd>
+///             <ShortcutHint keys={vec!["Ctrl".into(), "K".into()]} />
+///         </>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/tag/
+pub mod kbd;
 /// Provides utilities for creating [notification elements][bd] in Yew.
 ///
 /// Defines the necessary components to build, style and modify
@@ -252,6 +322,27 @@ pub mod table;
 ///
 /// [bd]: https://bulma.io/documentation/elements/tag/
 pub mod tag;
+/// Provides utilities for creating time related elements in Yew.
+///
+/// Defines the necessary components to display countdowns and relative
+/// times, styled using the [Bulma typography helpers][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::time::RelativeTime;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <RelativeTime timestamp={1_672_531_200_000.0} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/helpers/typography-helpers/
+pub mod time;
 /// Provides utilities for creating [title elements][bd] in Yew.
 ///
 /// Defines the necessary components to build, style and modify
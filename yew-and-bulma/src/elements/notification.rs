@@ -1,7 +1,15 @@
-use yew::{function_component, html, Children, Html, Properties};
+use gloo::timers::callback::Timeout;
+use yew::{
+    function_component, html, use_effect_with_deps, use_mut_ref, use_state, Callback, Children,
+    Html, Properties,
+};
 use yew_and_bulma_macros::base_component_properties;
 
-use crate::{elements::delete::Delete, helpers::color::Color, utils::class::ClassBuilder};
+use crate::{
+    elements::delete::Delete,
+    helpers::{color::Color, flexbox::JustifyContent, visibility::Display},
+    utils::class::ClassBuilder,
+};
 
 /// Defines the properties of the [Bulma notification element][bd].
 ///
@@ -92,6 +100,81 @@ pub struct NotificationProperties {
     /// [bd]: https://bulma.io/documentation/elements/notification/
     #[prop_or(true)]
     pub delete_button: bool,
+    /// Sets the actions displayed inside the [notification element][bd].
+    ///
+    /// Sets the actions, such as "Undo" or "Retry" buttons, which will be
+    /// displayed right-aligned, after the content of the
+    /// [Bulma notification element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::{button::Button, notification::Notification};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let actions = html! { <Button>{"Undo"}</Button> };
+    ///
+    ///     html! {
+    ///         <Notification {actions}>{"The item was deleted."}</Notification>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/notification/
+    #[prop_or_default]
+    pub actions: Option<Html>,
+    /// Sets the time after which the [notification element][bd] is dismissed.
+    ///
+    /// Sets the time, in milliseconds, after which the
+    /// [Bulma notification element][bd], which will receive these properties,
+    /// is automatically dismissed. The countdown is paused while the
+    /// notification is hovered and restarts once the pointer leaves it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::notification::Notification;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Notification duration={5000}>{"Hello, world!"}</Notification>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/notification/
+    #[prop_or_default]
+    pub duration: Option<u32>,
+    /// Sets the callback used when the [notification element][bd] is dismissed.
+    ///
+    /// Sets the callback which will be called once the
+    /// [Bulma notification element][bd], which will receive these properties,
+    /// is automatically dismissed after its
+    /// [`crate::elements::notification::NotificationProperties::duration`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::notification::Notification;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let ondismiss = Callback::from(|_| {});
+    ///
+    ///     html! {
+    ///         <Notification duration={5000} {ondismiss}>{"Hello, world!"}</Notification>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/notification/
+    #[prop_or_default]
+    pub ondismiss: Option<Callback<()>>,
     /// The list of elements found inside the [notification element][bd].
     ///
     /// Defines the elements that will be found inside the
@@ -123,6 +206,45 @@ pub struct NotificationProperties {
 /// [bd]: https://bulma.io/documentation/elements/notification/
 #[function_component(Notification)]
 pub fn notification(props: &NotificationProperties) -> Html {
+    let hovered = use_state(|| false);
+    let dismissed = use_state(|| false);
+    // Read when the notification is dismissed, so the latest callback is used
+    // without restarting the timer each time a new one is given.
+    let ondismiss = use_mut_ref(|| None);
+    *ondismiss.borrow_mut() = props.ondismiss.clone();
+    {
+        let dismissed = dismissed.clone();
+        use_effect_with_deps(
+            move |(duration, hovered)| {
+                let timeout = match (duration, hovered) {
+                    (Some(duration), false) => Some(Timeout::new(*duration, move || {
+                        dismissed.set(true);
+                        if let Some(ondismiss) = &*ondismiss.borrow() {
+                            ondismiss.emit(());
+                        }
+                    })),
+                    _ => None,
+                };
+
+                move || drop(timeout)
+            },
+            (props.duration, *hovered),
+        );
+    }
+
+    if *dismissed {
+        return html! {};
+    }
+
+    let onmouseenter = {
+        let hovered = hovered.clone();
+        Callback::from(move |_| hovered.set(true))
+    };
+    let onmouseleave = Callback::from(move |_| hovered.set(false));
+    let actions_class = ClassBuilder::default()
+        .with_display(Some(Display::Flex))
+        .with_justify_content(Some(JustifyContent::FlexEnd))
+        .build();
     let class = ClassBuilder::default()
         .with_custom_class("notification")
         .with_color(props.color)
@@ -137,7 +259,7 @@ pub fn notification(props: &NotificationProperties) -> Html {
         .build();
 
     html! {
-        <div id={props.id.clone()} {class}
+        <div id={props.id.clone()} {class} {onmouseenter} {onmouseleave}
             onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
             onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
             ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
@@ -154,6 +276,11 @@ pub fn notification(props: &NotificationProperties) -> Html {
                 <Delete />
             }
             { for props.children.iter() }
+            if let Some(actions) = &props.actions {
+                <div class={actions_class}>
+                    { actions.clone() }
+                </div>
+            }
         </div>
     }
 }
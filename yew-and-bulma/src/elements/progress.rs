@@ -1,4 +1,4 @@
-use yew::{function_component, html, Html, Properties};
+use yew::{function_component, html, Callback, Html, Properties};
 use yew_and_bulma_macros::base_component_properties;
 
 use crate::{
@@ -123,9 +123,83 @@ pub struct ProgressBarProperties {
     /// ```
     ///
     /// [bd]: https://bulma.io/documentation/elements/progress/
-    /// [none]: https://bulma.io/documentation/elements/progress/#indeterminate
     #[prop_or(100.0)]
     pub max: f64,
+    /// Makes the [Bulma progress bar element][bd] an indeterminate one.
+    ///
+    /// Renders the [Bulma progress bar element][bd], which will receive these
+    /// properties, as an [indeterminate][none] one, without a `value`
+    /// attribute, even if its value is set. Useful to switch to an animated
+    /// bar while the progress can not be computed, without losing the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::progress::ProgressBar;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ProgressBar value={32.0} indeterminate=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/progress/
+    /// [none]: https://bulma.io/documentation/elements/progress/#indeterminate
+    #[prop_or_default]
+    pub indeterminate: bool,
+    /// Whether or not to show the percentage of the progress bar.
+    ///
+    /// Shows the percentage that the value represents out of the maximum
+    /// value (ie `50%` for a value of `30.0` out of `60.0`) next to the
+    /// [Bulma progress bar element][bd] which will receive these properties.
+    /// Nothing is shown while it is indeterminate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::progress::ProgressBar;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ProgressBar value={30.0} max={60.0} show_label=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/progress/
+    #[prop_or_default]
+    pub show_label: bool,
+    /// Sets how the percentage of the progress bar is shown.
+    ///
+    /// Sets the callback called with the percentage, between `0.0` and
+    /// `100.0`, of the [Bulma progress bar element][bd] which will receive
+    /// these properties, returning the label shown next to it when
+    /// `show_label` is set. By default, the rounded percentage is shown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::progress::ProgressBar;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let label = Callback::from(|percentage: f64| html! { <b>{format!("{percentage:.1}%")}</b> });
+    ///
+    ///     html! {
+    ///         <ProgressBar value={1.0} max={3.0} show_label=true {label} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/progress/
+    #[prop_or_default]
+    pub label: Option<Callback<f64, Html>>,
 }
 
 /// Yew implementation of the [Bulma progress bar element][bd].
@@ -155,10 +229,23 @@ pub fn progress_bar(props: &ProgressBarProperties) -> Html {
         .as_ref()
         .map(|size| format!("{IS_PREFIX}-{size}"))
         .unwrap_or("".to_owned());
+    let value = props.value.filter(|_| !props.indeterminate);
+    let percentage = value.and_then(|value| percentage(value, props.max));
+    let text = percentage
+        .map(|percentage| format!("{percentage:.0}%"))
+        .unwrap_or_default();
+    let label = percentage.filter(|_| props.show_label).map(|percentage| {
+        props
+            .label
+            .as_ref()
+            .map(|label| label.emit(percentage))
+            .unwrap_or_else(|| html! { {text.clone()} })
+    });
     let class = ClassBuilder::default()
         .with_custom_class("progress")
         .with_color(props.color)
         .with_custom_class(&size)
+        .with_custom_class(if label.is_some() { "mb-0" } else { "" })
         .with_custom_class(
             &props
                 .class
@@ -167,9 +254,8 @@ pub fn progress_bar(props: &ProgressBarProperties) -> Html {
                 .unwrap_or("".to_owned()),
         )
         .build();
-
-    html! {
-        <progress id={props.id.clone()} {class} value={props.value.map(|n| n.to_string())} max={props.max.to_string()}
+    let progress = html! {
+        <progress id={props.id.clone()} {class} value={value.map(|n| n.to_string())} max={props.max.to_string()}
             onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
             onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
             ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
@@ -182,7 +268,36 @@ pub fn progress_bar(props: &ProgressBarProperties) -> Html {
             onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
             ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
-            { props.value.unwrap_or(15.0) }{"%"}
+            { text }
         </progress>
+    };
+
+    match label {
+        Some(label) => html! {
+            <div class="is-flex is-align-items-center">
+                {progress}
+                <span class="ml-3">{label}</span>
+            </div>
+        },
+        None => progress,
+    }
+}
+
+fn percentage(value: f64, max: f64) -> Option<f64> {
+    (max > 0.0).then(|| (value / max * 100.0).clamp(0.0, 100.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(30.0, 60.0, Some(50.0) ; "values are relative to the maximum")]
+    #[test_case(15.0, 100.0, Some(15.0) ; "default maximum is a percentage")]
+    #[test_case(120.0, 100.0, Some(100.0) ; "values over the maximum are clamped")]
+    #[test_case(-5.0, 100.0, Some(0.0) ; "negative values are clamped")]
+    #[test_case(5.0, 0.0, None ; "empty maximum has no percentage")]
+    fn percentage_values(value: f64, max: f64, expected: Option<f64>) {
+        assert_eq!(percentage(value, max), expected);
     }
 }
@@ -1,9 +1,22 @@
-use yew::{function_component, html};
+use std::{cmp::Ordering, collections::HashMap, rc::Rc};
+
+use gloo::{events::EventListener, timers::callback::Timeout};
+use js_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, HtmlInputElement, HtmlSelectElement};
+use yew::{
+    classes, function_component, html, use_effect_with_deps, use_mut_ref, use_node_ref, use_state,
+    Callback, FocusEvent, KeyboardEvent, MouseEvent, TargetCast,
+};
 use yew::{html::ChildrenRenderer, virtual_dom::VChild, AttrValue, Children, Html, Properties};
 use yew_and_bulma_macros::base_component_properties;
 
+use crate::elements::icon::Icon;
+use crate::helpers::color::Color;
+use crate::helpers::typography::TextAlignment;
 use crate::utils::class::ClassBuilder;
-use crate::utils::constants::IS_NARROW;
+use crate::utils::constants::{IS_NARROW, IS_PREFIX};
+use crate::utils::format::NumberFormat;
+use crate::utils::size::Size;
 
 /// Defines the properties of the [Bulma table element][bd].
 ///
@@ -272,6 +285,37 @@ pub struct TableProperties {
     /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
     #[prop_or_default]
     pub full_width: bool,
+    /// Sets the [ARIA role][role] of the [Bulma table element][bd].
+    ///
+    /// Sets the [ARIA role][role] of the [Bulma table element][bd] which
+    /// will receive these properties, such as `grid` for interactive tables.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table role="grid">
+    ///             <TableHeader>{"One"}</TableHeader>
+    ///             <TableHeader>{"Two"}</TableHeader>
+    ///
+    ///             <TableRow>
+    ///                 <TableData>{ "Three" }</TableData>
+    ///                 <TableData>{ "Four" }</TableData>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    /// [role]: https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles
+    #[prop_or_default]
+    pub role: Option<AttrValue>,
     /// The list of elements found inside the [table element][bd].
     ///
     /// Defines the elements that will be found inside the
@@ -348,6 +392,39 @@ pub enum TableItem {
     TableFooter(VChild<TableFooter>),
     TableRow(VChild<TableRow>),
     TableData(VChild<TableData>),
+    TableHead(VChild<TableHead>),
+    TableBody(VChild<TableBody>),
+    TableFoot(VChild<TableFoot>),
+    /// Holds any other HTML, such as rows built using an iterator. It is
+    /// rendered inside the body of the table.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = vec![("Three", "Four"), ("Five", "Six")];
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{"One"}</TableHeader>
+    ///             <TableHeader>{"Two"}</TableHeader>
+    ///
+    ///             {
+    ///                 rows.iter().map(|(left, right)| html! {
+    ///                     <TableRow>
+    ///                         <TableData>{ left }</TableData>
+    ///                         <TableData>{ right }</TableData>
+    ///                     </TableRow>
+    ///                 }).collect::<Html>()
+    ///             }
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    Html(Html),
 }
 
 impl TableItem {
@@ -370,6 +447,26 @@ impl TableItem {
     pub fn is_data(&self) -> bool {
         matches!(self, TableItem::TableData(_))
     }
+
+    /// Determines if the table item is a [`crate::elements::table::TableHead`].
+    pub fn is_head(&self) -> bool {
+        matches!(self, TableItem::TableHead(_))
+    }
+
+    /// Determines if the table item is a [`crate::elements::table::TableBody`].
+    pub fn is_body(&self) -> bool {
+        matches!(self, TableItem::TableBody(_))
+    }
+
+    /// Determines if the table item is a [`crate::elements::table::TableFoot`].
+    pub fn is_foot(&self) -> bool {
+        matches!(self, TableItem::TableFoot(_))
+    }
+
+    /// Determines if the table item is any other HTML.
+    pub fn is_html(&self) -> bool {
+        matches!(self, TableItem::Html(_))
+    }
 }
 
 impl From<VChild<TableHeader>> for TableItem {
@@ -396,6 +493,30 @@ impl From<VChild<TableData>> for TableItem {
     }
 }
 
+impl From<VChild<TableHead>> for TableItem {
+    fn from(value: VChild<TableHead>) -> Self {
+        TableItem::TableHead(value)
+    }
+}
+
+impl From<VChild<TableBody>> for TableItem {
+    fn from(value: VChild<TableBody>) -> Self {
+        TableItem::TableBody(value)
+    }
+}
+
+impl From<VChild<TableFoot>> for TableItem {
+    fn from(value: VChild<TableFoot>) -> Self {
+        TableItem::TableFoot(value)
+    }
+}
+
+impl From<Html> for TableItem {
+    fn from(value: Html) -> Self {
+        TableItem::Html(value)
+    }
+}
+
 #[allow(clippy::from_over_into)]
 impl Into<Html> for TableItem {
     fn into(self) -> Html {
@@ -404,6 +525,10 @@ impl Into<Html> for TableItem {
             TableItem::TableFooter(tf) => tf.into(),
             TableItem::TableRow(tr) => tr.into(),
             TableItem::TableData(td) => td.into(),
+            TableItem::TableHead(thead) => thead.into(),
+            TableItem::TableBody(tbody) => tbody.into(),
+            TableItem::TableFoot(tfoot) => tfoot.into(),
+            TableItem::Html(html) => html,
         }
     }
 }
@@ -461,11 +586,14 @@ pub fn table(props: &TableProperties) -> Html {
     let data: Vec<_> = props
         .children
         .iter()
-        .filter(|ti| ti.is_row() || ti.is_data())
+        .filter(|ti| ti.is_row() || ti.is_data() || ti.is_html())
         .collect();
+    let heads: Vec<_> = props.children.iter().filter(|ti| ti.is_head()).collect();
+    let bodies: Vec<_> = props.children.iter().filter(|ti| ti.is_body()).collect();
+    let foots: Vec<_> = props.children.iter().filter(|ti| ti.is_foot()).collect();
 
     let table_html = html! {
-        <table id={props.id.clone()} {class}
+        <table id={props.id.clone()} {class} role={props.role.clone()}
             onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
             onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
             ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
@@ -483,16 +611,21 @@ pub fn table(props: &TableProperties) -> Html {
                     { for headers }
                 </thead>
             }
+            { for heads }
 
             if !footers.is_empty() {
                 <tfoot>
                     { for footers }
                 </tfoot>
             }
+            { for foots }
 
-            <tbody>
-                { for data }
-            </tbody>
+            if !data.is_empty() || bodies.is_empty() {
+                <tbody>
+                    { for data }
+                </tbody>
+            }
+            { for bodies }
         </table>
     };
 
@@ -507,6 +640,67 @@ pub fn table(props: &TableProperties) -> Html {
     }
 }
 
+/// Defines the possible orders in which a table column can be sorted.
+///
+/// Defines the possible orders in which the rows of a table can be sorted
+/// by one of its columns, emitted by sortable [`TableHeader`]s and
+/// [`DataTable`] columns.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{SortOrder, Table, TableHeader};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let onsort = Callback::from(|(column, order): (AttrValue, SortOrder)| {
+///         let _ = (column, order == SortOrder::Ascending);
+///     });
+///
+///     html! {
+///         <Table>
+///             <TableHeader name="name" sortable=true {onsort}>{"Name"}</TableHeader>
+///         </Table>
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the opposite sort order.
+    pub fn reversed(self) -> Self {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+}
+
+fn aria_sort(order: Option<SortOrder>) -> &'static str {
+    match order {
+        Some(SortOrder::Ascending) => "ascending",
+        Some(SortOrder::Descending) => "descending",
+        None => "none",
+    }
+}
+
+fn sort_indicator(order: Option<SortOrder>) -> Html {
+    let arrow = match order {
+        Some(SortOrder::Ascending) => "\u{25b2}",
+        Some(SortOrder::Descending) => "\u{25bc}",
+        None => "\u{21c5}",
+    };
+
+    html! {
+        <Icon size={Size::Small} icon={html! { arrow }} />
+    }
+}
+
 /// Defines the properties of the [Bulma table header element][bd].
 ///
 /// Defines the properties of the table header element, based on the
@@ -584,209 +778,120 @@ pub struct TableHeaderProperties {
     /// [bd]: https://bulma.io/documentation/elements/table/
     #[prop_or_default]
     pub abbreviation: Option<AttrValue>,
-    /// The list of elements found inside the [table header element][bd].
+    /// Whether or not the [Bulma table header element][bd] should be sortable.
     ///
-    /// Defines the elements that will be found inside the
-    /// [Bulma table header element][bd] which will receive these properties.
+    /// Whether or not the [Bulma table header element][bd], which will
+    /// receive these properties, can be clicked to toggle between ascending
+    /// and descending order, showing a sort indicator icon for its
+    /// [`TableHeaderProperties::order`]. Each click emits the
+    /// [`TableHeaderProperties::onsort`] callback. The header can also be
+    /// focused and sorted using `Enter` or `Space`, and exposes its order
+    /// through `aria-sort`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader name="number" sortable=true>{"Number"}</TableHeader>
+    ///
+    ///             <TableRow>
+    ///                 <TableData>{ "One" }</TableData>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
     ///
     /// [bd]: https://bulma.io/documentation/elements/table/
-    pub children: Children,
-}
-
-/// Yew implementation of the [Bulma table header element][bd].
-///
-/// Yew implementation of the table header element, based on the specification
-/// found in the [Bulma table element documentation][bd].
-///
-/// # Examples
-///
-/// ```rust
-/// use yew::prelude::*;
-/// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
-///
-/// #[function_component(App)]
-/// fn app() -> Html {
-///     html! {
-///         <Table>
-///             <TableHeader>{"One"}</TableHeader>
-///             <TableHeader>{"Two"}</TableHeader>
-///
-///             <TableRow>
-///                 <TableData>{ "Three" }</TableData>
-///                 <TableData>{ "Four" }</TableData>
-///             </TableRow>
-///             <TableRow>
-///                 <TableData>{ "Five" }</TableData>
-///                 <TableData>{ "Six" }</TableData>
-///             </TableRow>
-///             <TableRow>
-///                 <TableData>{ "Seven" }</TableData>
-///                 <TableData>{ "Eight" }</TableData>
-///             </TableRow>
-///         </Table>
-///     }
-/// }
-/// ```
-///
-/// [bd]: https://bulma.io/documentation/elements/table/
-#[function_component(TableHeader)]
-pub fn table_header(props: &TableHeaderProperties) -> Html {
-    let class = ClassBuilder::default()
-        .with_custom_class(
-            &props
-                .class
-                .as_ref()
-                .map(|c| c.to_string())
-                .unwrap_or("".to_owned()),
-        )
-        .build();
-    let abbr = &props.abbreviation;
-
-    html! {
-        <th id={props.id.clone()} {class}
-            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
-            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
-            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
-            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
-            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
-            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
-            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
-            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
-            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
-            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
-            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
-            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
-            if let Some(abbr) = &abbr {
-                <abbr {abbr}>{ for props.children.iter() }</abbr>
-            } else {
-                { for props.children.iter() }
-            }
-        </th>
-    }
-}
-
-/// Yew implementation of the [Bulma table footer element][bd].
-///
-/// Yew implementation of the table footer element, based on the specification
-/// found in the [Bulma table element documentation][bd]. Has the same
-/// properties as [`crate::elements::table::TableHeader`].
-///
-/// # Examples
-///
-/// ```rust
-/// use yew::prelude::*;
-/// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
-///
-/// #[function_component(App)]
-/// fn app() -> Html {
-///     html! {
-///         <Table>
-///             <TableHeader>{"One"}</TableHeader>
-///             <TableHeader>{"Two"}</TableHeader>
-///
-///             <TableRow>
-///                 <TableData>{ "Three" }</TableData>
-///                 <TableData>{ "Four" }</TableData>
-///             </TableRow>
-///             <TableRow>
-///                 <TableData>{ "Five" }</TableData>
-///                 <TableData>{ "Six" }</TableData>
-///             </TableRow>
-///             <TableRow>
-///                 <TableData>{ "Seven" }</TableData>
-///                 <TableData>{ "Eight" }</TableData>
-///             </TableRow>
-///         </Table>
-///     }
-/// }
-/// ```
-///
-/// [bd]: https://bulma.io/documentation/elements/table/
-#[function_component(TableFooter)]
-pub fn table_footer(props: &TableHeaderProperties) -> Html {
-    let class = ClassBuilder::default()
-        .with_custom_class(
-            &props
-                .class
-                .as_ref()
-                .map(|c| c.to_string())
-                .unwrap_or("".to_owned()),
-        )
-        .build();
-    let abbr = &props.abbreviation;
-
-    html! {
-        <th id={props.id.clone()} {class}
-            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
-            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
-            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
-            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
-            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
-            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
-            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
-            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
-            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
-            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
-            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
-            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
-            if let Some(abbr) = &abbr {
-                <abbr {abbr}>{ for props.children.iter() }</abbr>
-            } else {
-                { for props.children.iter() }
-            }
-        </th>
-    }
-}
-
-/// Defines the properties of the [Bulma table row element][bd].
-///
-/// Defines the properties of the table row element, based on the
-/// specification found in the [Bulma table element documentation][bd].
-///
-/// # Examples
-///
-/// ```rust
-/// use yew::prelude::*;
-/// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
-///
-/// #[function_component(App)]
-/// fn app() -> Html {
-///     html! {
-///         <Table>
-///             <TableHeader>{"One"}</TableHeader>
-///             <TableHeader>{"Two"}</TableHeader>
-///
-///             <TableRow>
-///                 <TableData>{ "Three" }</TableData>
-///                 <TableData>{ "Four" }</TableData>
-///             </TableRow>
-///             <TableRow>
-///                 <TableData>{ "Five" }</TableData>
-///                 <TableData>{ "Six" }</TableData>
-///             </TableRow>
-///             <TableRow>
-///                 <TableData>{ "Seven" }</TableData>
-///                 <TableData>{ "Eight" }</TableData>
-///             </TableRow>
-///         </Table>
-///     }
-/// }
-/// ```
-///
-/// [bd]: https://bulma.io/documentation/elements/table/
-#[base_component_properties]
-#[derive(Properties, PartialEq)]
-pub struct TableRowProperties {
-    /// Whether or not the [Bulma table row element][bd] should be selected.
+    #[prop_or_default]
+    pub sortable: bool,
+    /// Sets the name of the column of the [Bulma table header element][bd].
     ///
-    /// Whether or not the [Bulma table row element][bd], which will receive these
-    /// properties, will be selected.
+    /// Sets the name identifying the column of the
+    /// [Bulma table header element][bd] which will receive these properties,
+    /// emitted along with the sort order when it is sorted.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// Sets the sort order of the [Bulma table header element][bd].
+    ///
+    /// Sets the order in which the column of the sortable
+    /// [Bulma table header element][bd], which will receive these properties,
+    /// is currently sorted, deciding its sort indicator. It is controlled by
+    /// the parent, usually from the [`TableHeaderProperties::onsort`]
+    /// callback, so that only one column of a table shows an order.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use yew::prelude::*;
-    /// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
+    /// use yew_and_bulma::elements::table::{SortOrder, Table, TableHeader};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader name="name" sortable=true order={SortOrder::Descending}>{"Name"}</TableHeader>
+    ///             <TableHeader name="age" sortable=true>{"Age"}</TableHeader>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub order: Option<SortOrder>,
+    /// Sets the callback used when the column is sorted.
+    ///
+    /// Sets the callback called with the name of the column and its new sort
+    /// order, the reverse of [`TableHeaderProperties::order`] or ascending if
+    /// it is not sorted, each time the sortable [Bulma table header element][bd],
+    /// which will receive these properties, is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{SortOrder, Table, TableHeader};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let order = use_state(|| None);
+    ///     let onsort = {
+    ///         let order = order.clone();
+    ///         Callback::from(move |(_, sort_order): (AttrValue, SortOrder)| {
+    ///             order.set(Some(sort_order));
+    ///         })
+    ///     };
+    ///
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader name="name" sortable=true order={*order} {onsort}>{"Name"}</TableHeader>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub onsort: Option<Callback<(AttrValue, SortOrder)>>,
+    /// Sets the number of columns the [Bulma table header element][bd] spans.
+    ///
+    /// Sets the number of columns spanned by the [Bulma table header element][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableHeader, TableRow};
     ///
     /// #[function_component(App)]
     /// fn app() -> Html {
@@ -796,16 +901,125 @@ pub struct TableRowProperties {
     ///             <TableHeader>{"Two"}</TableHeader>
     ///
     ///             <TableRow>
-    ///                 <TableData>{ "Three" }</TableData>
-    ///                 <TableData>{ "Four" }</TableData>
+    ///                 <TableHeader colspan={2}>{ "Three and four" }</TableHeader>
     ///             </TableRow>
-    ///             <TableRow selected=true>
-    ///                 <TableData>{ "Five" }</TableData>
-    ///                 <TableData>{ "Six" }</TableData>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub colspan: Option<u32>,
+    /// Sets the number of rows the [Bulma table header element][bd] spans.
+    ///
+    /// Sets the number of rows spanned by the [Bulma table header element][bd] which
+    /// will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{"One"}</TableHeader>
+    ///             <TableHeader>{"Two"}</TableHeader>
+    ///
+    ///             <TableRow>
+    ///                 <TableHeader rowspan={2}>{ "Three and four" }</TableHeader>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub rowspan: Option<u32>,
+    /// Sets the width of the [Bulma table header element][bd].
+    ///
+    /// Sets the width of the [Bulma table header element][bd] which will
+    /// receive these properties, as a CSS length (ie `10em` or `20%`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableHeader};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader width="70%">{"One"}</TableHeader>
+    ///             <TableHeader>{"Two"}</TableHeader>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub width: Option<AttrValue>,
+    /// Sets the color of the [Bulma table header element][bd].
+    ///
+    /// Sets the color of the [Bulma table header element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::table::{Table, TableData, TableHeader, TableRow},
+    ///     helpers::color::Color,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{"One"}</TableHeader>
+    ///             <TableHeader>{"Two"}</TableHeader>
+    ///
+    ///             <TableRow>
+    ///                 <TableHeader colspan={2} color={Color::Primary}>{ "Three and four" }</TableHeader>
     ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the text alignment of the [Bulma table header element][bd].
+    ///
+    /// Sets the text alignment of the [Bulma table header element][bd] which will
+    /// receive these properties, using the [Bulma typography helpers][th].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::table::{Table, TableData, TableHeader, TableRow},
+    ///     helpers::typography::TextAlignment,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{"One"}</TableHeader>
+    ///             <TableHeader>{"Two"}</TableHeader>
+    ///
     ///             <TableRow>
-    ///                 <TableData>{ "Seven" }</TableData>
-    ///                 <TableData>{ "Eight" }</TableData>
+    ///                 <TableHeader colspan={2} alignment={TextAlignment::Centered}>{ "Three and four" }</TableHeader>
     ///             </TableRow>
     ///         </Table>
     ///     }
@@ -813,30 +1027,21 @@ pub struct TableRowProperties {
     /// ```
     ///
     /// [bd]: https://bulma.io/documentation/elements/table/
+    /// [th]: https://bulma.io/documentation/helpers/typography-helpers/#alignment
     #[prop_or_default]
-    pub selected: bool,
-    /// The list of elements found inside the [table row element][bd].
+    pub alignment: Option<TextAlignment>,
+    /// The list of elements found inside the [table header element][bd].
     ///
     /// Defines the elements that will be found inside the
-    /// [Bulma table row element][bd] which will receive these properties.
+    /// [Bulma table header element][bd] which will receive these properties.
     ///
     /// [bd]: https://bulma.io/documentation/elements/table/
     pub children: Children,
 }
 
-impl From<&TableRowProperties> for String {
-    fn from(value: &TableRowProperties) -> Self {
-        if value.selected {
-            "is-selected".to_owned()
-        } else {
-            String::new()
-        }
-    }
-}
-
-/// Yew implementation of the [Bulma table row element][bd].
+/// Yew implementation of the [Bulma table header element][bd].
 ///
-/// Yew implementation of the table row element, based on the specification
+/// Yew implementation of the table header element, based on the specification
 /// found in the [Bulma table element documentation][bd].
 ///
 /// # Examples
@@ -870,10 +1075,54 @@ impl From<&TableRowProperties> for String {
 /// ```
 ///
 /// [bd]: https://bulma.io/documentation/elements/table/
-#[function_component(TableRow)]
-pub fn table_row(props: &TableRowProperties) -> Html {
+#[function_component(TableHeader)]
+pub fn table_header(props: &TableHeaderProperties) -> Html {
+    let sort = {
+        let onsort = props.onsort.clone();
+        let name = props.name.clone().unwrap_or_default();
+        let order = props.order;
+        let sortable = props.sortable;
+        Callback::from(move |_: ()| {
+            if sortable {
+                let new_order = order
+                    .map(SortOrder::reversed)
+                    .unwrap_or(SortOrder::Ascending);
+                if let Some(onsort) = &onsort {
+                    onsort.emit((name.clone(), new_order));
+                }
+            }
+        })
+    };
+    let onclick = {
+        let onclick = props.onclick.clone();
+        let sort = sort.clone();
+        Callback::from(move |event: MouseEvent| {
+            sort.emit(());
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+        })
+    };
+    let onkeydown = {
+        let onkeydown = props.onkeydown.clone();
+        let sortable = props.sortable;
+        Callback::from(move |event: KeyboardEvent| {
+            if sortable && (event.key() == "Enter" || event.key() == " ") {
+                event.prevent_default();
+                sort.emit(());
+            }
+            if let Some(onkeydown) = &onkeydown {
+                onkeydown.emit(event);
+            }
+        })
+    };
+    let tabindex = props.sortable.then(|| "0");
+    let aria_sort = props.sortable.then(|| aria_sort(props.order));
+    let clickable = if props.sortable { "is-clickable" } else { "" };
     let class = ClassBuilder::default()
-        .with_custom_class(&String::from(props))
+        .with_custom_class(clickable)
+        .with_color(props.color)
+        .with_text_alignment(props.alignment.clone())
         .with_custom_class(
             &props
                 .class
@@ -882,14 +1131,16 @@ pub fn table_row(props: &TableRowProperties) -> Html {
                 .unwrap_or("".to_owned()),
         )
         .build();
+    let abbr = &props.abbreviation;
+    let style = props.width.as_ref().map(|width| format!("width: {width};"));
 
     html! {
-        <tr id={props.id.clone()} {class}
-            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+        <th id={props.id.clone()} {class} {style} {tabindex} aria-sort={aria_sort} colspan={props.colspan.map(|colspan| colspan.to_string())} rowspan={props.rowspan.map(|rowspan| rowspan.to_string())}
+            {onclick} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
             onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
             ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
             oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
-            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            {onkeydown} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
             onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
             onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
             ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
@@ -897,15 +1148,23 @@ pub fn table_row(props: &TableRowProperties) -> Html {
             onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
             ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
-            { for props.children.iter() }
-        </tr>
+            if let Some(abbr) = &abbr {
+                <abbr {abbr}>{ for props.children.iter() }</abbr>
+            } else {
+                { for props.children.iter() }
+            }
+            if props.sortable {
+                { sort_indicator(props.order) }
+            }
+        </th>
     }
 }
 
-/// Defines the properties of the [Bulma table data element][bd].
+/// Yew implementation of the [Bulma table footer element][bd].
 ///
-/// Defines the properties of the table data element, based on the
-/// specification found in the [Bulma table element documentation][bd].
+/// Yew implementation of the table footer element, based on the specification
+/// found in the [Bulma table element documentation][bd]. Has the same
+/// properties as [`crate::elements::table::TableHeader`].
 ///
 /// # Examples
 ///
@@ -938,56 +1197,142 @@ pub fn table_row(props: &TableRowProperties) -> Html {
 /// ```
 ///
 /// [bd]: https://bulma.io/documentation/elements/table/
-#[base_component_properties]
-#[derive(Properties, PartialEq)]
-pub struct TableDataProperties {
-    /// The list of elements found inside the [table data element][bd].
-    ///
-    /// Defines the elements that will be found inside the
-    /// [Bulma table data element][bd] which will receive these properties.
-    ///
-    /// [bd]: https://bulma.io/documentation/elements/table/
-    pub children: Children,
-}
+#[function_component(TableFooter)]
+pub fn table_footer(props: &TableHeaderProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_color(props.color)
+        .with_text_alignment(props.alignment.clone())
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let abbr = &props.abbreviation;
 
-/// Yew implementation of the [Bulma table data element][bd].
+    html! {
+        <th id={props.id.clone()} {class} colspan={props.colspan.map(|colspan| colspan.to_string())} rowspan={props.rowspan.map(|rowspan| rowspan.to_string())}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            if let Some(abbr) = &abbr {
+                <abbr {abbr}>{ for props.children.iter() }</abbr>
+            } else {
+                { for props.children.iter() }
+            }
+        </th>
+    }
+}
+
+/// Defines the properties of the table head, body and foot elements.
 ///
-/// Yew implementation of the table data element, based on the specification
-/// found in the [Bulma table element documentation][bd].
+/// Defines the properties of the sections of a [Bulma table element][bd],
+/// used by the [`TableHead`], [`TableBody`] and [`TableFoot`] components.
 ///
 /// # Examples
 ///
 /// ```rust
 /// use yew::prelude::*;
-/// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
+/// use yew_and_bulma::elements::table::{
+///     Table, TableBody, TableData, TableFoot, TableHead, TableHeader, TableRow,
+/// };
 ///
 /// #[function_component(App)]
 /// fn app() -> Html {
 ///     html! {
 ///         <Table>
-///             <TableHeader>{"One"}</TableHeader>
-///             <TableHeader>{"Two"}</TableHeader>
+///             <TableHead>
+///                 <TableRow>
+///                     <TableHeader>{"Item"}</TableHeader>
+///                     <TableHeader>{"Price"}</TableHeader>
+///                 </TableRow>
+///             </TableHead>
+///             <TableBody>
+///                 <TableRow>
+///                     <TableData>{"Coffee"}</TableData>
+///                     <TableData>{"3"}</TableData>
+///                 </TableRow>
+///             </TableBody>
+///             <TableFoot>
+///                 <TableRow>
+///                     <TableHeader>{"Total"}</TableHeader>
+///                     <TableData>{"3"}</TableData>
+///                 </TableRow>
+///             </TableFoot>
+///         </Table>
+///     }
+/// }
+/// ```
 ///
-///             <TableRow>
-///                 <TableData>{ "Three" }</TableData>
-///                 <TableData>{ "Four" }</TableData>
-///             </TableRow>
-///             <TableRow>
-///                 <TableData>{ "Five" }</TableData>
-///                 <TableData>{ "Six" }</TableData>
-///             </TableRow>
-///             <TableRow>
-///                 <TableData>{ "Seven" }</TableData>
-///                 <TableData>{ "Eight" }</TableData>
-///             </TableRow>
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TableSectionProperties {
+    /// The list of elements found inside the table section.
+    ///
+    /// Defines the elements that will be found inside the section of the
+    /// [Bulma table element][bd] which will receive these properties, usually
+    /// [`TableRow`]s. The rows can hold both [`TableHeader`]s and
+    /// [`TableData`]s, regardless of the section.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    pub children: Children,
+}
+
+/// Yew implementation of the table head element.
+///
+/// Yew implementation of the table head element, rendering a `thead` section
+/// inside the [Bulma table element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{
+///     Table, TableBody, TableData, TableFoot, TableHead, TableHeader, TableRow,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table>
+///             <TableHead>
+///                 <TableRow>
+///                     <TableHeader>{"Item"}</TableHeader>
+///                     <TableHeader>{"Price"}</TableHeader>
+///                 </TableRow>
+///             </TableHead>
+///             <TableBody>
+///                 <TableRow>
+///                     <TableData>{"Coffee"}</TableData>
+///                     <TableData>{"3"}</TableData>
+///                 </TableRow>
+///             </TableBody>
+///             <TableFoot>
+///                 <TableRow>
+///                     <TableHeader>{"Total"}</TableHeader>
+///                     <TableData>{"3"}</TableData>
+///                 </TableRow>
+///             </TableFoot>
 ///         </Table>
 ///     }
 /// }
 /// ```
 ///
 /// [bd]: https://bulma.io/documentation/elements/table/
-#[function_component(TableData)]
-pub fn table_data(props: &TableDataProperties) -> Html {
+#[function_component(TableHead)]
+pub fn table_head(props: &TableSectionProperties) -> Html {
     let class = ClassBuilder::default()
         .with_custom_class(
             &props
@@ -999,7 +1344,7 @@ pub fn table_data(props: &TableDataProperties) -> Html {
         .build();
 
     html! {
-        <td id={props.id.clone()} {class}
+        <thead id={props.id.clone()} {class}
             onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
             onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
             ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
@@ -1013,6 +1358,2007 @@ pub fn table_data(props: &TableDataProperties) -> Html {
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
             ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
             { for props.children.iter() }
-        </td>
+        </thead>
+    }
+}
+
+/// Yew implementation of the table body element.
+///
+/// Yew implementation of the table body element, rendering a `tbody` section
+/// inside the [Bulma table element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{
+///     Table, TableBody, TableData, TableFoot, TableHead, TableHeader, TableRow,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table>
+///             <TableHead>
+///                 <TableRow>
+///                     <TableHeader>{"Item"}</TableHeader>
+///                     <TableHeader>{"Price"}</TableHeader>
+///                 </TableRow>
+///             </TableHead>
+///             <TableBody>
+///                 <TableRow>
+///                     <TableData>{"Coffee"}</TableData>
+///                     <TableData>{"3"}</TableData>
+///                 </TableRow>
+///             </TableBody>
+///             <TableFoot>
+///                 <TableRow>
+///                     <TableHeader>{"Total"}</TableHeader>
+///                     <TableData>{"3"}</TableData>
+///                 </TableRow>
+///             </TableFoot>
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[function_component(TableBody)]
+pub fn table_body(props: &TableSectionProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <tbody id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </tbody>
+    }
+}
+
+/// Yew implementation of the table foot element.
+///
+/// Yew implementation of the table foot element, rendering a `tfoot` section
+/// inside the [Bulma table element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{
+///     Table, TableBody, TableData, TableFoot, TableHead, TableHeader, TableRow,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table>
+///             <TableHead>
+///                 <TableRow>
+///                     <TableHeader>{"Item"}</TableHeader>
+///                     <TableHeader>{"Price"}</TableHeader>
+///                 </TableRow>
+///             </TableHead>
+///             <TableBody>
+///                 <TableRow>
+///                     <TableData>{"Coffee"}</TableData>
+///                     <TableData>{"3"}</TableData>
+///                 </TableRow>
+///             </TableBody>
+///             <TableFoot>
+///                 <TableRow>
+///                     <TableHeader>{"Total"}</TableHeader>
+///                     <TableData>{"3"}</TableData>
+///                 </TableRow>
+///             </TableFoot>
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[function_component(TableFoot)]
+pub fn table_foot(props: &TableSectionProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <tfoot id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </tfoot>
+    }
+}
+
+/// Defines the properties of the [Bulma table row element][bd].
+///
+/// Defines the properties of the table row element, based on the
+/// specification found in the [Bulma table element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table>
+///             <TableHeader>{"One"}</TableHeader>
+///             <TableHeader>{"Two"}</TableHeader>
+///
+///             <TableRow>
+///                 <TableData>{ "Three" }</TableData>
+///                 <TableData>{ "Four" }</TableData>
+///             </TableRow>
+///             <TableRow>
+///                 <TableData>{ "Five" }</TableData>
+///                 <TableData>{ "Six" }</TableData>
+///             </TableRow>
+///             <TableRow>
+///                 <TableData>{ "Seven" }</TableData>
+///                 <TableData>{ "Eight" }</TableData>
+///             </TableRow>
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TableRowProperties {
+    /// Whether or not the [Bulma table row element][bd] should be selected.
+    ///
+    /// Whether or not the [Bulma table row element][bd], which will receive these
+    /// properties, will be selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{"One"}</TableHeader>
+    ///             <TableHeader>{"Two"}</TableHeader>
+    ///
+    ///             <TableRow>
+    ///                 <TableData>{ "Three" }</TableData>
+    ///                 <TableData>{ "Four" }</TableData>
+    ///             </TableRow>
+    ///             <TableRow selected=true>
+    ///                 <TableData>{ "Five" }</TableData>
+    ///                 <TableData>{ "Six" }</TableData>
+    ///             </TableRow>
+    ///             <TableRow>
+    ///                 <TableData>{ "Seven" }</TableData>
+    ///                 <TableData>{ "Eight" }</TableData>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub selected: bool,
+    /// Sets the color of the [Bulma table row element][bd].
+    ///
+    /// Sets the color of the [Bulma table row element][bd] which will receive
+    /// these properties, useful to highlight rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::table::{Table, TableData, TableHeader, TableRow},
+    ///     helpers::color::Color,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{"One"}</TableHeader>
+    ///
+    ///             <TableRow color={Color::Warning}>
+    ///                 <TableData>{ "Two" }</TableData>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// The list of elements found inside the [table row element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma table row element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    pub children: Children,
+}
+
+impl From<&TableRowProperties> for String {
+    fn from(value: &TableRowProperties) -> Self {
+        if value.selected {
+            "is-selected".to_owned()
+        } else {
+            String::new()
+        }
+    }
+}
+
+/// Yew implementation of the [Bulma table row element][bd].
+///
+/// Yew implementation of the table row element, based on the specification
+/// found in the [Bulma table element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table>
+///             <TableHeader>{"One"}</TableHeader>
+///             <TableHeader>{"Two"}</TableHeader>
+///
+///             <TableRow>
+///                 <TableData>{ "Three" }</TableData>
+///                 <TableData>{ "Four" }</TableData>
+///             </TableRow>
+///             <TableRow>
+///                 <TableData>{ "Five" }</TableData>
+///                 <TableData>{ "Six" }</TableData>
+///             </TableRow>
+///             <TableRow>
+///                 <TableData>{ "Seven" }</TableData>
+///                 <TableData>{ "Eight" }</TableData>
+///             </TableRow>
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[function_component(TableRow)]
+pub fn table_row(props: &TableRowProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class(&String::from(props))
+        .with_color(props.color)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <tr id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </tr>
+    }
+}
+
+/// Defines the properties of the [Bulma table data element][bd].
+///
+/// Defines the properties of the table data element, based on the
+/// specification found in the [Bulma table element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table>
+///             <TableHeader>{"One"}</TableHeader>
+///             <TableHeader>{"Two"}</TableHeader>
+///
+///             <TableRow>
+///                 <TableData>{ "Three" }</TableData>
+///                 <TableData>{ "Four" }</TableData>
+///             </TableRow>
+///             <TableRow>
+///                 <TableData>{ "Five" }</TableData>
+///                 <TableData>{ "Six" }</TableData>
+///             </TableRow>
+///             <TableRow>
+///                 <TableData>{ "Seven" }</TableData>
+///                 <TableData>{ "Eight" }</TableData>
+///             </TableRow>
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TableDataProperties {
+    /// Sets the number of columns the [Bulma table data element][bd] spans.
+    ///
+    /// Sets the number of columns spanned by the [Bulma table data element][bd]
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{"One"}</TableHeader>
+    ///             <TableHeader>{"Two"}</TableHeader>
+    ///
+    ///             <TableRow>
+    ///                 <TableData colspan={2}>{ "Three and four" }</TableData>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub colspan: Option<u32>,
+    /// Sets the number of rows the [Bulma table data element][bd] spans.
+    ///
+    /// Sets the number of rows spanned by the [Bulma table data element][bd] which
+    /// will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Table, TableData, TableHeader, TableRow};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{"One"}</TableHeader>
+    ///             <TableHeader>{"Two"}</TableHeader>
+    ///
+    ///             <TableRow>
+    ///                 <TableData rowspan={2}>{ "Three and four" }</TableData>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub rowspan: Option<u32>,
+    /// Sets the color of the [Bulma table data element][bd].
+    ///
+    /// Sets the color of the [Bulma table data element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::table::{Table, TableData, TableHeader, TableRow},
+    ///     helpers::color::Color,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{"One"}</TableHeader>
+    ///             <TableHeader>{"Two"}</TableHeader>
+    ///
+    ///             <TableRow>
+    ///                 <TableData colspan={2} color={Color::Primary}>{ "Three and four" }</TableData>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the text alignment of the [Bulma table data element][bd].
+    ///
+    /// Sets the text alignment of the [Bulma table data element][bd] which will
+    /// receive these properties, using the [Bulma typography helpers][th].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     elements::table::{Table, TableData, TableHeader, TableRow},
+    ///     helpers::typography::TextAlignment,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Table>
+    ///             <TableHeader>{"One"}</TableHeader>
+    ///             <TableHeader>{"Two"}</TableHeader>
+    ///
+    ///             <TableRow>
+    ///                 <TableData colspan={2} alignment={TextAlignment::Centered}>{ "Three and four" }</TableData>
+    ///             </TableRow>
+    ///         </Table>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    /// [th]: https://bulma.io/documentation/helpers/typography-helpers/#alignment
+    #[prop_or_default]
+    pub alignment: Option<TextAlignment>,
+    /// The list of elements found inside the [table data element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma table data element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma table data element][bd].
+///
+/// Yew implementation of the table data element, based on the specification
+/// found in the [Bulma table element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{Table, TableHeader, TableRow, TableData};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Table>
+///             <TableHeader>{"One"}</TableHeader>
+///             <TableHeader>{"Two"}</TableHeader>
+///
+///             <TableRow>
+///                 <TableData>{ "Three" }</TableData>
+///                 <TableData>{ "Four" }</TableData>
+///             </TableRow>
+///             <TableRow>
+///                 <TableData>{ "Five" }</TableData>
+///                 <TableData>{ "Six" }</TableData>
+///             </TableRow>
+///             <TableRow>
+///                 <TableData>{ "Seven" }</TableData>
+///                 <TableData>{ "Eight" }</TableData>
+///             </TableRow>
+///         </Table>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[function_component(TableData)]
+pub fn table_data(props: &TableDataProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_color(props.color)
+        .with_text_alignment(props.alignment.clone())
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <td id={props.id.clone()} {class} colspan={props.colspan.map(|colspan| colspan.to_string())} rowspan={props.rowspan.map(|rowspan| rowspan.to_string())}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </td>
+    }
+}
+
+type Compare<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+type Editor<T> = Rc<dyn Fn(&T) -> String>;
+
+/// The minimum width, in pixels, of the columns of a resizable [`DataTable`]
+/// which do not set their own.
+pub const MIN_COLUMN_WIDTH: f64 = 40.0;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Resize {
+    column: AttrValue,
+    pointer: i32,
+    width: f64,
+    min: f64,
+    max: Option<f64>,
+}
+
+impl Resize {
+    fn width_at(&self, x: i32) -> f64 {
+        let width = (self.width + f64::from(x - self.pointer)).max(self.min);
+
+        self.max.map(|max| width.min(max)).unwrap_or(width)
+    }
+}
+
+/// Defines a column of the [`DataTable`].
+///
+/// Defines a column of the [`DataTable`] component, made up of its header,
+/// the function used to render the cell of each row, its text alignment and
+/// its width.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     elements::table::{Column, DataTable},
+///     helpers::typography::TextAlignment,
+/// };
+///
+/// #[derive(PartialEq)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let data = vec![Person { name: "Ada".to_owned(), age: 36 }];
+///     let columns = vec![
+///         Column::new("Name", |person: &Person| html! { person.name.clone() }).with_width("70%"),
+///         Column::new("Age", |person: &Person| html! { person.age })
+///             .with_alignment(TextAlignment::Right),
+///     ];
+///
+///     html! {
+///         <DataTable<Person> {data} {columns} />
+///     }
+/// }
+/// ```
+pub struct Column<T> {
+    id: AttrValue,
+    header: AttrValue,
+    cell: Rc<dyn Fn(&T) -> Html>,
+    alignment: Option<TextAlignment>,
+    width: Option<AttrValue>,
+    min_width: Option<f64>,
+    max_width: Option<f64>,
+    sortable: bool,
+    compare: Option<Compare<T>>,
+    editor: Option<Editor<T>>,
+    options: Vec<AttrValue>,
+}
+
+impl<T> Column<T> {
+    /// Creates a column with the given header, rendering the cell of each
+    /// row using the given function.
+    pub fn new(header: impl Into<AttrValue>, cell: impl Fn(&T) -> Html + 'static) -> Self {
+        let header = header.into();
+        Self {
+            id: header.clone(),
+            header,
+            cell: Rc::new(cell),
+            alignment: None,
+            width: None,
+            min_width: None,
+            max_width: None,
+            sortable: false,
+            compare: None,
+            editor: None,
+            options: Vec::new(),
+        }
+    }
+
+    /// Creates a right aligned, sortable column with the given header,
+    /// rendering the value of each row using the given number format and
+    /// locale (ie `en-US`).
+    pub fn number(
+        header: impl Into<AttrValue>,
+        value: impl Fn(&T) -> f64 + 'static,
+        format: NumberFormat,
+        locale: impl Into<AttrValue>,
+    ) -> Self {
+        let value = Rc::new(value);
+        let locale = locale.into();
+        let cell = {
+            let value = value.clone();
+            move |row: &T| html! { format.format(value(row), &locale) }
+        };
+
+        Self::new(header, cell)
+            .with_alignment(TextAlignment::Right)
+            .with_sort(move |a, b| value(a).partial_cmp(&value(b)).unwrap_or(Ordering::Equal))
+    }
+
+    /// Sets the identifier of the column, emitted when it is sorted.
+    /// Defaults to its header.
+    pub fn with_id(mut self, id: impl Into<AttrValue>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Makes the column sortable, without sorting the rows. Clicking its
+    /// header only emits the [`DataTableProperties::onsort`] callback, for
+    /// rows sorted elsewhere (ie by a server).
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+
+    /// Makes the column sortable, sorting the rows using the given function
+    /// when its header is clicked.
+    pub fn with_sort(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.sortable = true;
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Sets the text alignment of the header and cells of the column.
+    pub fn with_alignment(mut self, alignment: TextAlignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Sets the width of the column, as a CSS length (ie `10em` or `20%`).
+    pub fn with_width(mut self, width: impl Into<AttrValue>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Sets the minimum width, in pixels, the column can be resized to in a
+    /// resizable [`DataTable`]. Defaults to [`MIN_COLUMN_WIDTH`].
+    pub fn with_min_width(mut self, min_width: f64) -> Self {
+        self.min_width = Some(min_width);
+        self
+    }
+
+    /// Sets the maximum width, in pixels, the column can be resized to in a
+    /// resizable [`DataTable`].
+    pub fn with_max_width(mut self, max_width: f64) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Makes the cells of the column editable. Double-clicking a cell
+    /// replaces it with an input holding the value returned by the given
+    /// function, emitting [`DataTableProperties::oncelledit`] once edited.
+    pub fn with_editor(mut self, value: impl Fn(&T) -> String + 'static) -> Self {
+        self.editor = Some(Rc::new(value));
+        self
+    }
+
+    /// Sets the options the cells of an editable column are picked from,
+    /// editing them using a select instead of an input.
+    pub fn with_options<V: Into<AttrValue>>(
+        mut self,
+        options: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.options = options.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            header: self.header.clone(),
+            cell: self.cell.clone(),
+            alignment: self.alignment.clone(),
+            width: self.width.clone(),
+            min_width: self.min_width,
+            max_width: self.max_width,
+            sortable: self.sortable,
+            compare: self.compare.clone(),
+            editor: self.editor.clone(),
+            options: self.options.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for Column<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.header == other.header
+            && Rc::ptr_eq(&self.cell, &other.cell)
+            && self.alignment == other.alignment
+            && self.width == other.width
+            && self.min_width == other.min_width
+            && self.max_width == other.max_width
+            && self.sortable == other.sortable
+            && match (&self.compare, &other.compare) {
+                (Some(compare), Some(other_compare)) => Rc::ptr_eq(compare, other_compare),
+                (None, None) => true,
+                _ => false,
+            }
+            && match (&self.editor, &other.editor) {
+                (Some(editor), Some(other_editor)) => Rc::ptr_eq(editor, other_editor),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.options == other.options
+    }
+}
+
+/// Defines the properties of the data table.
+///
+/// Defines the properties of the data table, a [Bulma table element][bd]
+/// rendered from a list of rows and a list of [`Column`] definitions.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{Column, DataTable};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let data = vec![1, 2];
+///     let columns = vec![Column::new("Number", |number: &i32| html! { *number })];
+///
+///     html! {
+///         <DataTable<i32> {data} {columns} striped=true />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+// Comparing the `expanded_render` function pointers is only used to decide
+// whether to render again, so an unreliable result is harmless.
+#[allow(unpredictable_function_pointer_comparisons)]
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DataTableProperties<T: PartialEq> {
+    /// The rows of the data table.
+    ///
+    /// Defines the rows, in order, of the data table which will receive these
+    /// properties. Each row is rendered using the [`Column`] definitions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = vec![1, 2, 3];
+    ///     let columns = vec![Column::new("Number", |number: &i32| html! { *number })];
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} />
+    ///     }
+    /// }
+    /// ```
+    pub data: Vec<T>,
+    /// The columns of the data table.
+    ///
+    /// Defines the columns, in order, of the data table which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = vec![1, 2, 3];
+    ///     let columns = vec![
+    ///         Column::new("Number", |number: &i32| html! { *number }),
+    ///         Column::new("Square", |number: &i32| html! { number * number }),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} />
+    ///     }
+    /// }
+    /// ```
+    pub columns: Vec<Column<T>>,
+    /// Whether or not the [Bulma table element][bd] should be scrollable.
+    ///
+    /// Whether or not the [Bulma table element][bd] used by the data table,
+    /// which will receive these properties, will be scrollable.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#table-container
+    #[prop_or_default]
+    pub scrollable: bool,
+    /// Whether or not the [Bulma table element][bd] should be bordered.
+    ///
+    /// Whether or not the [Bulma table element][bd] used by the data table,
+    /// which will receive these properties, will be bordered.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub bordered: bool,
+    /// Whether or not the [Bulma table element][bd] should be striped.
+    ///
+    /// Whether or not the [Bulma table element][bd] used by the data table,
+    /// which will receive these properties, will be striped.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub striped: bool,
+    /// Whether or not the [Bulma table element][bd] should be narrow.
+    ///
+    /// Whether or not the [Bulma table element][bd] used by the data table,
+    /// which will receive these properties, will be narrow.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub narrow: bool,
+    /// Whether or not the [Bulma table element][bd] should be hoverable.
+    ///
+    /// Whether or not the [Bulma table element][bd] used by the data table,
+    /// which will receive these properties, will be hoverable.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub hoverable: bool,
+    /// Whether or not the [Bulma table element][bd] should be full width.
+    ///
+    /// Whether or not the [Bulma table element][bd] used by the data table,
+    /// which will receive these properties, will be full width.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/#modifiers
+    #[prop_or_default]
+    pub full_width: bool,
+    /// Sets the callback used when a column is sorted.
+    ///
+    /// Sets the callback called with the identifier of the column and its
+    /// new sort order each time the header of a sortable [`Column`] of the
+    /// data table, which will receive these properties, is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable, SortOrder};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = vec![3, 1, 2];
+    ///     let columns = vec![
+    ///         Column::new("Number", |number: &i32| html! { *number }).with_sort(i32::cmp),
+    ///     ];
+    ///     let onsort = Callback::from(|(column, order): (AttrValue, SortOrder)| {
+    ///         let _ = (column, order);
+    ///     });
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} {onsort} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onsort: Option<Callback<(AttrValue, SortOrder)>>,
+    /// Whether or not the columns of the data table can be resized.
+    ///
+    /// Whether or not a drag handle is shown on the right edge of each header
+    /// of the data table which will receive these properties, resizing its
+    /// [`Column`] between its minimum and maximum widths. The new widths are
+    /// kept as inline styles on the headers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = vec![1, 2, 3];
+    ///     let columns = vec![
+    ///         Column::new("Number", |number: &i32| html! { *number })
+    ///             .with_min_width(60.0)
+    ///             .with_max_width(300.0),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} resizable=true />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub resizable: bool,
+    /// Sets the callback used when a column is resized.
+    ///
+    /// Sets the callback called with the identifier of the column and its
+    /// new width, in pixels, each time a [`Column`] of the resizable data
+    /// table, which will receive these properties, is resized.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = vec![1, 2, 3];
+    ///     let columns = vec![Column::new("Number", |number: &i32| html! { *number })];
+    ///     let onresize = Callback::from(|(column, width): (AttrValue, f64)| {
+    ///         let _ = (column, width);
+    ///     });
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} resizable=true {onresize} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onresize: Option<Callback<(AttrValue, f64)>>,
+    /// Whether or not to show a toolbar to choose the visible columns.
+    ///
+    /// Whether or not a toolbar holding a dropdown with a checkbox for each
+    /// [`Column`] is rendered above the data table which will receive these
+    /// properties, letting users hide and show its columns. The last visible
+    /// column cannot be hidden.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = vec![1, 2, 3];
+    ///     let columns = vec![
+    ///         Column::new("Number", |number: &i32| html! { *number }),
+    ///         Column::new("Double", |number: &i32| html! { number * 2 }),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} column_chooser=true />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub column_chooser: bool,
+    /// Sets the callback used when the visible columns change.
+    ///
+    /// Sets the callback called with the identifiers of the visible
+    /// [`Column`]s each time one of them is hidden or shown using the column
+    /// chooser of the data table which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = vec![1, 2, 3];
+    ///     let columns = vec![
+    ///         Column::new("Number", |number: &i32| html! { *number }),
+    ///         Column::new("Double", |number: &i32| html! { number * 2 }),
+    ///     ];
+    ///     let oncolumnschange = Callback::from(|visible: Vec<AttrValue>| {
+    ///         let _ = visible;
+    ///     });
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} column_chooser=true {oncolumnschange} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub oncolumnschange: Option<Callback<Vec<AttrValue>>>,
+    /// Sets the key used to persist the hidden columns.
+    ///
+    /// Sets the [local storage][ls] key under which the identifiers of the
+    /// [`Column`]s hidden using the column chooser of the data table, which
+    /// will receive these properties, are saved and restored from.
+    ///
+    /// > _Only available with the `storage` feature enabled._
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = vec![1, 2, 3];
+    ///     let columns = vec![
+    ///         Column::new("Number", |number: &i32| html! { *number }),
+    ///         Column::new("Double", |number: &i32| html! { number * 2 }),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} column_chooser=true storage_key="numbers-columns" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [ls]: https://developer.mozilla.org/en-US/docs/Web/API/Window/localStorage
+    #[cfg(feature = "storage")]
+    #[prop_or_default]
+    pub storage_key: Option<AttrValue>,
+    /// Sets the function rendering the details of a row.
+    ///
+    /// Sets the function used to render the detail panel of an expanded row
+    /// of the data table which will receive these properties. When set, each
+    /// row starts with a chevron toggling a panel spanning all the columns
+    /// under it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// fn details(number: &i32) -> Html {
+    ///     html! { format!("{number} squared is {}", number * number) }
+    /// }
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = vec![1, 2, 3];
+    ///     let columns = vec![Column::new("Number", |number: &i32| html! { *number })];
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} expanded_render={details as fn(&i32) -> Html} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub expanded_render: Option<fn(&T) -> Html>,
+    /// The expanded rows of the data table.
+    ///
+    /// Sets the indices, in the data, of the rows of the data table which
+    /// will receive these properties whose details are shown. When set, the
+    /// expansion is controlled and the chevrons only call
+    /// [`DataTableProperties::onexpandedchange`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// fn details(number: &i32) -> Html {
+    ///     html! { number * number }
+    /// }
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let expanded = use_state(|| vec![0]);
+    ///     let onexpandedchange = {
+    ///         let expanded = expanded.clone();
+    ///         Callback::from(move |rows: Vec<usize>| expanded.set(rows))
+    ///     };
+    ///     let data = vec![1, 2, 3];
+    ///     let columns = vec![Column::new("Number", |number: &i32| html! { *number })];
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} expanded_render={details as fn(&i32) -> Html}
+    ///             expanded={(*expanded).clone()} {onexpandedchange} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub expanded: Option<Vec<usize>>,
+    /// Sets the callback used when the expanded rows change.
+    ///
+    /// Sets the callback called with the indices, in the data, of the
+    /// expanded rows each time a chevron of the data table, which will
+    /// receive these properties, is clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// fn details(number: &i32) -> Html {
+    ///     html! { number * number }
+    /// }
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = vec![1, 2, 3];
+    ///     let columns = vec![Column::new("Number", |number: &i32| html! { *number })];
+    ///     let onexpandedchange = Callback::from(|rows: Vec<usize>| {
+    ///         let _ = rows;
+    ///     });
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} expanded_render={details as fn(&i32) -> Html}
+    ///             {onexpandedchange} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onexpandedchange: Option<Callback<Vec<usize>>>,
+    /// Sets the callback used when a cell is edited.
+    ///
+    /// Sets the callback called with the index of the row, in the data, the
+    /// identifier of the [`Column`] and the new value each time an editable
+    /// cell of the data table, which will receive these properties, is
+    /// edited. Edits are committed by pressing Enter or leaving the input and
+    /// dropped by pressing Escape.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = use_state(|| vec!["Ada".to_owned(), "Alan".to_owned()]);
+    ///     let oncelledit = {
+    ///         let data = data.clone();
+    ///         Callback::from(move |(row, _, value): (usize, AttrValue, String)| {
+    ///             let mut names = (*data).clone();
+    ///             names[row] = value;
+    ///             data.set(names);
+    ///         })
+    ///     };
+    ///     let columns = vec![
+    ///         Column::new("Name", |name: &String| html! { name.clone() })
+    ///             .with_editor(|name: &String| name.clone()),
+    ///         Column::new("Role", |_: &String| html! { "Admin" })
+    ///             .with_editor(|_: &String| "Admin".to_owned())
+    ///             .with_options(["Admin", "Editor", "Viewer"]),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <DataTable<String> data={(*data).clone()} {columns} {oncelledit} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub oncelledit: Option<Callback<(usize, AttrValue, String)>>,
+    /// Whether or not the cells can be navigated using the keyboard.
+    ///
+    /// Whether or not the data table, which will receive these properties,
+    /// is rendered as an [ARIA grid][grid] whose body cells are focused using
+    /// the arrow, Home and End keys (along with Ctrl for the first and last
+    /// cells). Pressing Enter edits the focused cell of an editable
+    /// [`Column`] or activates the first link, button or input inside it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::table::{Column, DataTable};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let data = vec![1, 2, 3];
+    ///     let columns = vec![
+    ///         Column::new("Number", |number: &i32| html! { *number }),
+    ///         Column::new("Double", |number: &i32| html! { number * 2 }),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <DataTable<i32> {data} {columns} keyboard_navigation=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [grid]: https://www.w3.org/WAI/ARIA/apg/patterns/grid/
+    #[prop_or_default]
+    pub keyboard_navigation: bool,
+}
+
+/// Yew implementation of the data table.
+///
+/// Yew implementation of the data table, rendering a
+/// [Bulma table element][bd] with a header row made up of the headers of its
+/// [`Column`]s and a body row for each of its rows.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::table::{Column, DataTable};
+///
+/// #[derive(PartialEq)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let data = vec![
+///         Person { name: "Ada".to_owned(), age: 36 },
+///         Person { name: "Alan".to_owned(), age: 41 },
+///     ];
+///     let columns = vec![
+///         Column::new("Name", |person: &Person| html! { person.name.clone() }),
+///         Column::new("Age", |person: &Person| html! { person.age }),
+///     ];
+///
+///     html! {
+///         <DataTable<Person> {data} {columns} hoverable=true />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/table/
+#[function_component(DataTable)]
+pub fn data_table<T: PartialEq + 'static>(props: &DataTableProperties<T>) -> Html {
+    let sort = use_state(|| None::<(AttrValue, SortOrder)>);
+    let widths = use_state(HashMap::<AttrValue, f64>::new);
+    let resize = use_state(|| None::<Resize>);
+    let resized = use_mut_ref(|| false);
+    let hidden = use_state(|| {
+        #[cfg(feature = "storage")]
+        if let Some(saved) = props.storage_key.as_ref().and_then(|key| {
+            use gloo::storage::{LocalStorage, Storage};
+
+            LocalStorage::get::<Vec<String>>(key.as_str()).ok()
+        }) {
+            return saved.into_iter().map(AttrValue::from).collect();
+        }
+
+        Vec::<AttrValue>::new()
+    });
+    let chooser_open = use_state(|| false);
+    let expanded_state = use_state(Vec::<usize>::new);
+    let editing = use_state(|| None::<(usize, AttrValue)>);
+    let focus = use_state(|| (0, 0));
+    let expanded = props
+        .expanded
+        .clone()
+        .unwrap_or_else(|| (*expanded_state).clone());
+
+    {
+        let widths = widths.clone();
+        let resize_handle = resize.clone();
+        let resized = resized.clone();
+        let onresize = props.onresize.clone();
+        use_effect_with_deps(
+            move |current| {
+                let listeners = current
+                    .clone()
+                    .zip(web_sys::window().and_then(|window| window.document()))
+                    .map(|(start, document)| {
+                        let onmousemove = {
+                            let start = start.clone();
+                            EventListener::new(&document, "mousemove", move |event| {
+                                if let Some(event) = event.dyn_ref::<MouseEvent>() {
+                                    let mut new_widths = (*widths).clone();
+                                    new_widths.insert(
+                                        start.column.clone(),
+                                        start.width_at(event.client_x()),
+                                    );
+                                    widths.set(new_widths);
+                                }
+                            })
+                        };
+                        let onmouseup = EventListener::new(&document, "mouseup", move |event| {
+                            if let (Some(event), Some(onresize)) =
+                                (event.dyn_ref::<MouseEvent>(), &onresize)
+                            {
+                                onresize
+                                    .emit((start.column.clone(), start.width_at(event.client_x())));
+                            }
+                            // The click ending the drag must not sort the column.
+                            *resized.borrow_mut() = true;
+                            let resized = resized.clone();
+                            Timeout::new(0, move || *resized.borrow_mut() = false).forget();
+                            resize_handle.set(None);
+                        });
+
+                        (onmousemove, onmouseup)
+                    });
+
+                move || drop(listeners)
+            },
+            (*resize).clone(),
+        );
+    }
+
+    let alignment = |column: &Column<T>| {
+        ClassBuilder::default()
+            .with_text_alignment(column.alignment.clone())
+            .build()
+    };
+    let onsort = {
+        let sort = sort.clone();
+        let onsort = props.onsort.clone();
+        let resized = resized.clone();
+        Callback::from(move |(id, order): (AttrValue, SortOrder)| {
+            if *resized.borrow() {
+                return;
+            }
+            sort.set(Some((id.clone(), order)));
+            if let Some(onsort) = &onsort {
+                onsort.emit((id, order));
+            }
+        })
+    };
+    let columns = props
+        .columns
+        .iter()
+        .filter(|column| !hidden.contains(&column.id))
+        .collect::<Vec<_>>();
+    let chooser = props.column_chooser.then(|| {
+        let ontoggle = {
+            let chooser_open = chooser_open.clone();
+            Callback::from(move |_: MouseEvent| chooser_open.set(!*chooser_open))
+        };
+        let items = props.columns.iter().map(|column| {
+            let visible = !hidden.contains(&column.id);
+            let onchange = {
+                let hidden = hidden.clone();
+                let id = column.id.clone();
+                let ids = props
+                    .columns
+                    .iter()
+                    .map(|column| column.id.clone())
+                    .collect::<Vec<_>>();
+                let oncolumnschange = props.oncolumnschange.clone();
+                #[cfg(feature = "storage")]
+                let storage_key = props.storage_key.clone();
+                Callback::from(move |_: yew::Event| {
+                    let new_hidden = toggle(&hidden, &id);
+                    #[cfg(feature = "storage")]
+                    if let Some(key) = &storage_key {
+                        use gloo::storage::{LocalStorage, Storage};
+
+                        let saved = new_hidden
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>();
+                        let _ = LocalStorage::set(key.as_str(), saved);
+                    }
+                    if let Some(oncolumnschange) = &oncolumnschange {
+                        oncolumnschange.emit(
+                            ids.iter()
+                                .filter(|id| !new_hidden.contains(id))
+                                .cloned()
+                                .collect(),
+                        );
+                    }
+                    hidden.set(new_hidden);
+                })
+            };
+            let disabled = visible && columns.len() == 1;
+
+            html! {
+                <label class="checkbox dropdown-item">
+                    <input type="checkbox" checked={visible} {disabled} {onchange} />
+                    {" "}{column.header.clone()}
+                </label>
+            }
+        });
+        let class = classes!(
+            "dropdown",
+            (*chooser_open).then(|| format!("{IS_PREFIX}-active"))
+        );
+
+        html! {
+            <div class="level">
+                <div class="level-left" />
+                <div class="level-right">
+                    <div {class}>
+                        <div class="dropdown-trigger">
+                            <button type="button" class="button" aria-haspopup="true"
+                                aria-expanded={chooser_open.to_string()} onclick={ontoggle}>
+                                {"Columns"}
+                            </button>
+                        </div>
+                        <div class="dropdown-menu" role="menu">
+                            <div class="dropdown-content">
+                                { for items }
+                            </div>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        }
+    });
+    let headers = columns.iter().map(|column| {
+        let order = (*sort)
+            .as_ref()
+            .filter(|(id, _)| *id == column.id)
+            .map(|(_, order)| *order);
+        let width = widths
+            .get(&column.id)
+            .map(|width| AttrValue::from(format!("{width}px")))
+            .or_else(|| column.width.clone());
+        let handle = props.resizable.then(|| {
+            let onmousedown = {
+                let resize = resize.clone();
+                let column_id = column.id.clone();
+                let min = column.min_width.unwrap_or(MIN_COLUMN_WIDTH);
+                let max = column.max_width;
+                Callback::from(move |event: MouseEvent| {
+                    event.prevent_default();
+                    event.stop_propagation();
+                    let width = event
+                        .target_dyn_into::<Element>()
+                        .and_then(|handle| handle.parent_element())
+                        .map(|header| header.get_bounding_client_rect().width())
+                        .unwrap_or(min);
+                    resize.set(Some(Resize {
+                        column: column_id.clone(),
+                        pointer: event.client_x(),
+                        width,
+                        min,
+                        max,
+                    }));
+                })
+            };
+
+            html! {
+                <span role="separator" aria-orientation="vertical" {onmousedown}
+                    style="position: absolute; top: 0; right: 0; bottom: 0; width: 0.5rem; cursor: col-resize;">
+                </span>
+            }
+        });
+        let class = ClassBuilder::default()
+            .is_relative(Some(props.resizable))
+            .build();
+
+        html! {
+            <TableHeader name={column.id.clone()} sortable={column.sortable} {order} onsort={onsort.clone()}
+                alignment={column.alignment.clone()} {width} {class}>
+                {column.header.clone()}
+                { for handle }
+            </TableHeader>
+        }
+    });
+    let mut indices: Vec<usize> = (0..props.data.len()).collect();
+    if let Some((id, order)) = &*sort {
+        let compare = props
+            .columns
+            .iter()
+            .find(|column| column.id == *id)
+            .and_then(|column| column.compare.clone());
+        if let Some(compare) = compare {
+            let data = &props.data;
+            match order {
+                SortOrder::Ascending => indices.sort_by(|a, b| compare(&data[*a], &data[*b])),
+                SortOrder::Descending => indices.sort_by(|a, b| compare(&data[*b], &data[*a])),
+            }
+        }
+    }
+    let offset = usize::from(props.expanded_render.is_some());
+    let size = (indices.len(), columns.len() + offset);
+    let focused = (props.keyboard_navigation && size.0 > 0 && size.1 > 0)
+        .then(|| (focus.0.min(size.0 - 1), focus.1.min(size.1 - 1)));
+    let navigation = |position: (usize, usize)| {
+        if !props.keyboard_navigation {
+            return (None, None, None);
+        }
+
+        let tabindex = if focused == Some(position) { "0" } else { "-1" };
+        let onfocus = {
+            let focus = focus.clone();
+            Callback::from(move |_: FocusEvent| focus.set(position))
+        };
+
+        (
+            Some(tabindex),
+            Some(format!("{}-{}", position.0, position.1)),
+            Some(onfocus),
+        )
+    };
+    let cell = |position: (usize, usize), index: usize, row: &T, column: &Column<T>| {
+        let (tabindex, data_cell, onfocus) = navigation(position);
+        let editor = match &column.editor {
+            Some(editor) => editor,
+            None => {
+                return html! {
+                    <td class={alignment(column)} {tabindex} data-cell={data_cell} {onfocus}>
+                        {(column.cell)(row)}
+                    </td>
+                };
+            }
+        };
+        let is_editing = editing
+            .as_ref()
+            .map(|(row, id)| *row == index && *id == column.id)
+            .unwrap_or_default();
+
+        if is_editing {
+            let oncommit = {
+                let editing = editing.clone();
+                let id = column.id.clone();
+                let oncelledit = props.oncelledit.clone();
+                Callback::from(move |value: String| {
+                    editing.set(None);
+                    if let Some(oncelledit) = &oncelledit {
+                        oncelledit.emit((index, id.clone(), value));
+                    }
+                })
+            };
+            let oncancel = {
+                let editing = editing.clone();
+                Callback::from(move |_| editing.set(None))
+            };
+
+            html! {
+                <td class={alignment(column)} {tabindex} data-cell={data_cell} {onfocus}>
+                    <CellEditor value={editor(row)} options={column.options.clone()} {oncommit} {oncancel} />
+                </td>
+            }
+        } else {
+            let ondblclick = {
+                let editing = editing.clone();
+                let id = column.id.clone();
+                Callback::from(move |_: MouseEvent| editing.set(Some((index, id.clone()))))
+            };
+
+            html! {
+                <td class={alignment(column)} {tabindex} data-cell={data_cell} {onfocus} {ondblclick}>
+                    {(column.cell)(row)}
+                </td>
+            }
+        }
+    };
+    let onkeydown = {
+        let focus = focus.clone();
+        let editing = editing.clone();
+        let onkeydown = props.onkeydown.clone();
+        let indices = indices.clone();
+        let editable = std::iter::repeat(None)
+            .take(offset)
+            .chain(
+                columns
+                    .iter()
+                    .map(|column| column.editor.as_ref().map(|_| column.id.clone())),
+            )
+            .collect::<Vec<_>>();
+        Callback::from(move |event: KeyboardEvent| {
+            if let Some(onkeydown) = &onkeydown {
+                onkeydown.emit(event.clone());
+            }
+
+            // Only keys pressed on the cells themselves, not inside editors
+            // or other controls, navigate the grid.
+            let (position, cell) = match focused.zip(
+                event
+                    .target_dyn_into::<HtmlElement>()
+                    .filter(|target| target.tag_name() == "TD"),
+            ) {
+                Some(focused) => focused,
+                None => return,
+            };
+
+            if event.key() == "Enter" {
+                event.prevent_default();
+                match &editable[position.1] {
+                    Some(id) => editing.set(Some((indices[position.0], id.clone()))),
+                    None => {
+                        if let Some(control) = cell
+                            .query_selector("a, button, input, select, textarea")
+                            .ok()
+                            .flatten()
+                            .and_then(|control| control.dyn_into::<HtmlElement>().ok())
+                        {
+                            control.click();
+                        }
+                    }
+                }
+            } else if let Some(next) = move_focus(&event.key(), event.ctrl_key(), position, size) {
+                event.prevent_default();
+                focus.set(next);
+                if let Some(next_cell) = cell
+                    .closest("table")
+                    .ok()
+                    .flatten()
+                    .and_then(|table| {
+                        table
+                            .query_selector(&format!("[data-cell=\"{}-{}\"]", next.0, next.1))
+                            .ok()
+                            .flatten()
+                    })
+                    .and_then(|next_cell| next_cell.dyn_into::<HtmlElement>().ok())
+                {
+                    let _ = next_cell.focus();
+                }
+            }
+        })
+    };
+    let expander_header = props.expanded_render.map(|_| {
+        html! {
+            <th aria-label="Details" />
+        }
+    });
+    let rows = indices.into_iter().enumerate().map(|(position, index)| {
+        let row = &props.data[index];
+        let is_expanded = expanded.contains(&index);
+        let expander = props.expanded_render.map(|_| {
+            let onclick = {
+                let expanded_state = expanded_state.clone();
+                let new_expanded = toggle(&expanded, &index);
+                let controlled = props.expanded.is_some();
+                let onexpandedchange = props.onexpandedchange.clone();
+                Callback::from(move |_: MouseEvent| {
+                    if let Some(onexpandedchange) = &onexpandedchange {
+                        onexpandedchange.emit(new_expanded.clone());
+                    }
+                    if !controlled {
+                        expanded_state.set(new_expanded.clone());
+                    }
+                })
+            };
+            let chevron = if is_expanded { "\u{25be}" } else { "\u{25b8}" };
+            let label = if is_expanded {
+                "Collapse row"
+            } else {
+                "Expand row"
+            };
+
+            let (tabindex, data_cell, onfocus) = navigation((position, 0));
+
+            html! {
+                <td {tabindex} data-cell={data_cell} {onfocus}>
+                    <button type="button" class="button is-small is-white" aria-label={label}
+                        aria-expanded={is_expanded.to_string()} {onclick}>
+                        <Icon size={Size::Small} icon={html! { chevron }} />
+                    </button>
+                </td>
+            }
+        });
+        let details = props.expanded_render.filter(|_| is_expanded).map(|render| {
+            let colspan = (columns.len() + 1).to_string();
+
+            html! {
+                <tr>
+                    <td {colspan}>{render(row)}</td>
+                </tr>
+            }
+        });
+
+        html! {
+            <>
+            <TableRow>
+                { for expander }
+                { for columns.iter().enumerate().map(|(column_position, column)| {
+                    cell((position, column_position + offset), index, row, column)
+                }) }
+            </TableRow>
+            { for details }
+            </>
+        }
+    });
+
+    html! {
+        <>
+        { for chooser }
+        <Table id={props.id.clone()} class={props.class.clone()}
+            scrollable={props.scrollable} bordered={props.bordered} striped={props.striped}
+            narrow={props.narrow} hoverable={props.hoverable} full_width={props.full_width}
+            role={props.keyboard_navigation.then(|| AttrValue::from("grid"))}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            {onkeydown} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <TableHead>
+                <tr>
+                    { for expander_header }
+                    { for headers }
+                </tr>
+            </TableHead>
+            <TableBody>
+                { for rows }
+            </TableBody>
+        </Table>
+        </>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct CellEditorProperties {
+    value: String,
+    options: Vec<AttrValue>,
+    oncommit: Callback<String>,
+    oncancel: Callback<()>,
+}
+
+#[function_component(CellEditor)]
+fn cell_editor(props: &CellEditorProperties) -> Html {
+    let node = use_node_ref();
+    // Committing or cancelling removes the editor, which may blur it again.
+    let done = use_mut_ref(|| false);
+
+    {
+        let node = node.clone();
+        use_effect_with_deps(
+            move |_| {
+                if let Some(element) = node.cast::<HtmlElement>() {
+                    let _ = element.focus();
+                }
+                || ()
+            },
+            (),
+        );
+    }
+
+    let commit = {
+        let done = done.clone();
+        let oncommit = props.oncommit.clone();
+        Callback::from(move |value: String| {
+            if !done.replace(true) {
+                oncommit.emit(value);
+            }
+        })
+    };
+    let value_of = |event: &yew::Event| {
+        event
+            .target_dyn_into::<HtmlInputElement>()
+            .map(|input| input.value())
+            .or_else(|| {
+                event
+                    .target_dyn_into::<HtmlSelectElement>()
+                    .map(|select| select.value())
+            })
+            .unwrap_or_default()
+    };
+    // Gives the focus back to the cell being edited, for keyboard navigation.
+    let focus_cell = |event: &KeyboardEvent| {
+        if let Some(cell) = event
+            .target_dyn_into::<Element>()
+            .and_then(|editor| editor.closest("td").ok().flatten())
+            .and_then(|cell| cell.dyn_into::<HtmlElement>().ok())
+        {
+            let _ = cell.focus();
+        }
+    };
+    let onkeydown = {
+        let commit = commit.clone();
+        let oncancel = props.oncancel.clone();
+        Callback::from(move |event: KeyboardEvent| match event.key().as_str() {
+            "Enter" => {
+                event.prevent_default();
+                commit.emit(value_of(&event));
+                focus_cell(&event);
+            }
+            "Escape" => {
+                event.prevent_default();
+                if !done.replace(true) {
+                    oncancel.emit(());
+                }
+                focus_cell(&event);
+            }
+            _ => {}
+        })
+    };
+    let onblur = {
+        let commit = commit.clone();
+        Callback::from(move |event: FocusEvent| commit.emit(value_of(&event)))
+    };
+
+    if props.options.is_empty() {
+        html! {
+            <input ref={node} class="input is-small" type="text" value={props.value.clone()}
+                {onkeydown} {onblur} />
+        }
+    } else {
+        let onchange = Callback::from(move |event: yew::Event| commit.emit(value_of(&event)));
+
+        html! {
+            <div class="select is-small">
+                <select ref={node} {onkeydown} {onblur} {onchange}>
+                    { for props.options.iter().map(|option| html! {
+                        <option value={option.clone()} selected={*option == props.value}>{option.clone()}</option>
+                    }) }
+                </select>
+            </div>
+        }
+    }
+}
+
+fn move_focus(
+    key: &str,
+    ctrl: bool,
+    (row, column): (usize, usize),
+    (rows, columns): (usize, usize),
+) -> Option<(usize, usize)> {
+    match key {
+        "ArrowUp" => Some((row.saturating_sub(1), column)),
+        "ArrowDown" => Some(((row + 1).min(rows - 1), column)),
+        "ArrowLeft" => Some((row, column.saturating_sub(1))),
+        "ArrowRight" => Some((row, (column + 1).min(columns - 1))),
+        "Home" if ctrl => Some((0, 0)),
+        "Home" => Some((row, 0)),
+        "End" if ctrl => Some((rows - 1, columns - 1)),
+        "End" => Some((row, columns - 1)),
+        _ => None,
+    }
+}
+
+fn toggle<V: Clone + PartialEq>(values: &[V], value: &V) -> Vec<V> {
+    if values.contains(value) {
+        values
+            .iter()
+            .filter(|other| *other != value)
+            .cloned()
+            .collect()
+    } else {
+        values
+            .iter()
+            .cloned()
+            .chain(std::iter::once(value.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case(Some(SortOrder::Ascending), "ascending" ; "ascending")]
+    #[test_case(Some(SortOrder::Descending), "descending" ; "descending")]
+    #[test_case(None, "none" ; "unsorted")]
+    fn aria_sort_should_follow_the_order(order: Option<SortOrder>, expected: &str) {
+        assert_eq!(expected, aria_sort(order));
+    }
+
+    const RESIZE: Resize = Resize {
+        column: AttrValue::Static("name"),
+        pointer: 100,
+        width: 120.0,
+        min: 40.0,
+        max: Some(200.0),
+    };
+
+    #[test_case(100, 120.0 ; "pointer did not move")]
+    #[test_case(150, 170.0 ; "grows with the pointer")]
+    #[test_case(60, 80.0 ; "shrinks with the pointer")]
+    #[test_case(0, 40.0 ; "stops at the minimum width")]
+    #[test_case(500, 200.0 ; "stops at the maximum width")]
+    fn resize_width_at(x: i32, expected: f64) {
+        assert_eq!(RESIZE.width_at(x), expected);
+    }
+
+    #[test_case(&[], "age", &["age"] ; "hides a visible column")]
+    #[test_case(&["age"], "age", &[] ; "shows a hidden column")]
+    #[test_case(&["name", "age"], "name", &["age"] ; "keeps the other hidden columns")]
+    fn toggle_column_should_flip_visibility(
+        hidden: &[&'static str],
+        id: &'static str,
+        expected: &[&'static str],
+    ) {
+        let hidden = hidden
+            .iter()
+            .map(|id| AttrValue::Static(id))
+            .collect::<Vec<_>>();
+        let expected = expected
+            .iter()
+            .map(|id| AttrValue::Static(id))
+            .collect::<Vec<_>>();
+
+        assert_eq!(toggle(&hidden, &AttrValue::Static(id)), expected);
+    }
+
+    #[test_case("ArrowUp", false, (0, 1) ; "up stops at the first row")]
+    #[test_case("ArrowDown", false, (2, 1) ; "down moves to the next row")]
+    #[test_case("ArrowLeft", false, (1, 0) ; "left moves to the previous column")]
+    #[test_case("ArrowRight", false, (1, 2) ; "right moves to the next column")]
+    #[test_case("Home", false, (1, 0) ; "home moves to the first column")]
+    #[test_case("End", false, (1, 2) ; "end moves to the last column")]
+    #[test_case("Home", true, (0, 0) ; "ctrl home moves to the first cell")]
+    #[test_case("End", true, (3, 2) ; "ctrl end moves to the last cell")]
+    fn move_focus_should_stay_inside_the_grid(key: &str, ctrl: bool, expected: (usize, usize)) {
+        let start = if key == "ArrowUp" { (0, 1) } else { (1, 1) };
+
+        assert_eq!(move_focus(key, ctrl, start, (4, 3)), Some(expected));
+    }
+
+    #[test_case("ArrowDown", (3, 2) ; "down stops at the last row")]
+    #[test_case("ArrowRight", (3, 2) ; "right stops at the last column")]
+    fn move_focus_should_stop_at_the_last_cell(key: &str, expected: (usize, usize)) {
+        assert_eq!(move_focus(key, false, (3, 2), (4, 3)), Some(expected));
+    }
+
+    #[test]
+    fn move_focus_should_ignore_other_keys() {
+        assert_eq!(move_focus("a", false, (1, 1), (4, 3)), None);
+    }
+
+    #[test_case(&[], 2, &[2] ; "expands a collapsed row")]
+    #[test_case(&[0, 2], 2, &[0] ; "collapses an expanded row")]
+    fn toggle_should_flip_row_expansion(expanded: &[usize], index: usize, expected: &[usize]) {
+        assert_eq!(toggle(expanded, &index), expected);
     }
 }
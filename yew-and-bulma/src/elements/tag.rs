@@ -1,8 +1,16 @@
-use yew::{function_component, html, Children, Html, Properties};
+use yew::{function_component, html, AttrValue, Callback, Children, Html, MouseEvent, Properties};
 use yew_and_bulma_macros::base_component_properties;
 
 use crate::{
-    helpers::color::Color,
+    elements::{
+        delete::Delete,
+        icon::Icon,
+        image::{self, Figure, Image},
+    },
+    helpers::{
+        color::Color,
+        spacing::{Direction, Spacing},
+    },
     utils::{
         class::ClassBuilder,
         constants::{ARE_PREFIX, IS_PREFIX},
@@ -313,6 +321,133 @@ pub struct TagProperties {
     /// [bd]: https://bulma.io/documentation/elements/tag/#modifiers
     #[prop_or_default]
     pub delete: bool,
+    /// Sets the icon displayed before the label of the [tag element][bd].
+    ///
+    /// Sets the icon which will be displayed before the label of the
+    /// [Bulma tag element][bd] which will receive these properties. The icon
+    /// is wrapped inside an [`crate::elements::icon::Icon`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::tag::Tag;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tag icon_left={html! { <i class="fas fa-filter"></i> }}>{"Tag label"}</Tag>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/
+    #[prop_or_default]
+    pub icon_left: Option<Html>,
+    /// Sets the icon displayed after the label of the [tag element][bd].
+    ///
+    /// Sets the icon which will be displayed after the label of the
+    /// [Bulma tag element][bd] which will receive these properties. The icon
+    /// is wrapped inside an [`crate::elements::icon::Icon`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::tag::Tag;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tag icon_right={html! { <i class="fas fa-check"></i> }}>{"Tag label"}</Tag>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/
+    #[prop_or_default]
+    pub icon_right: Option<Html>,
+    /// Sets the avatar image displayed inside the [tag element][bd].
+    ///
+    /// Sets the source of a tiny, rounded avatar image, which will be
+    /// displayed at the start of the [Bulma tag element][bd] which will
+    /// receive these properties. Useful for user chips.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::tag::Tag;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tag image={"media/images/avatar.png"}>{"John Doe"}</Tag>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/
+    #[prop_or_default]
+    pub image: Option<AttrValue>,
+    /// Sets the link of the [tag element][bd].
+    ///
+    /// Sets the URL to which the [Bulma tag element][bd], which will receive
+    /// these properties, links, rendering it as an anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::tag::Tag;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Tag href="/tags/rust">{"Rust"}</Tag>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Sets the callback used when the delete button of the tag is clicked.
+    ///
+    /// Adds a small [Bulma delete element][delete] after the label of the
+    /// [Bulma tag element][bd] which will receive these properties, calling
+    /// this callback when it is clicked. The click does not reach the
+    /// `onclick` callback of the tag. Since a button cannot be nested inside
+    /// a link, a tag with an `href` is instead followed by a delete tag,
+    /// grouped together as [Bulma tags addons][addons].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::tag::Tag;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let removed = use_state(|| false);
+    ///     let ondelete = {
+    ///         let removed = removed.clone();
+    ///         Callback::from(move |_| removed.set(true))
+    ///     };
+    ///
+    ///     html! {
+    ///         if !*removed {
+    ///             <Tag {ondelete}>{"Rust"}</Tag>
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/#combinations
+    /// [delete]: https://bulma.io/documentation/elements/delete/
+    /// [addons]: https://bulma.io/documentation/elements/tag/#list-of-tags
+    #[prop_or_default]
+    pub ondelete: Option<Callback<MouseEvent>>,
     /// The list of elements found inside the [tag element][bd].
     ///
     /// Defines the elements that will be found inside the
@@ -373,10 +508,28 @@ pub fn tag(props: &TagProperties) -> Html {
                 .unwrap_or("".to_owned()),
         )
         .build();
-    let tag = (if props.delete { "a" } else { "span" }).to_string();
+    let anchor = props.delete || props.href.is_some();
+    let tag = (if anchor { "a" } else { "span" }).to_string();
+    let delete_size = if props.size == Some(Size::Large) {
+        None
+    } else {
+        Some(Size::Small)
+    };
+    let ondelete = props.ondelete.clone().filter(|_| !anchor).map(|ondelete| {
+        Callback::from(move |event: MouseEvent| {
+            event.stop_propagation();
+            event.prevent_default();
+            ondelete.emit(event);
+        })
+    });
+    let has_slots =
+        props.icon_left.is_some() || props.icon_right.is_some() || props.image.is_some();
+    let image_class = ClassBuilder::default()
+        .with_margin(Direction::Right, Spacing::One)
+        .build();
 
-    html! {
-        <@{tag} id={props.id.clone()} {class}
+    let tag_html = html! {
+        <@{tag} id={props.id.clone()} {class} href={props.href.clone()}
             onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
             onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
             ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
@@ -389,7 +542,195 @@ pub fn tag(props: &TagProperties) -> Html {
             onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
             ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
-            { for props.children.iter() }
+            if let Some(src) = &props.image {
+                <Figure size={image::Size::Pixels16x16} class={image_class}>
+                    <Image src={src.clone()} rounded=true />
+                </Figure>
+            }
+            if let Some(icon) = &props.icon_left {
+                <Icon icon={icon.clone()} />
+            }
+            if has_slots {
+                <span>{ for props.children.iter() }</span>
+            } else {
+                { for props.children.iter() }
+            }
+            if let Some(icon) = &props.icon_right {
+                <Icon icon={icon.clone()} />
+            }
+            if let Some(onclick) = ondelete {
+                <Delete size={delete_size} {onclick} />
+            }
         </@>
+    };
+
+    match props.ondelete.clone().filter(|_| anchor) {
+        Some(ondelete) => html! {
+            <Tags size={props.size} addons=true>
+                { tag_html }
+                <Tag delete=true onclick={ondelete} />
+            </Tags>
+        },
+        None => tag_html,
+    }
+}
+
+/// Defines the properties of the tag addons.
+///
+/// Defines the properties of the tag addons, the common pair of
+/// [Bulma tag elements][bd] grouped as addons, made of a label tag followed
+/// by a delete tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::tag::TagAddons;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <TagAddons>{"Alex Smith"}</TagAddons>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/tag/#list-of-tags
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TagAddonsProperties {
+    /// Sets the size of the [tag elements][bd].
+    ///
+    /// Sets the size of both [Bulma tag elements][bd] found inside the tag
+    /// addons which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::tag::TagAddons, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TagAddons size={Size::Medium}>{"Alex Smith"}</TagAddons>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Sets the color of the label [tag element][bd].
+    ///
+    /// Sets the color of the label [Bulma tag element][bd] found inside the
+    /// tag addons which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::tag::TagAddons, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TagAddons color={Color::Danger}>{"Alex Smith"}</TagAddons>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Whether or not the label [tag element][bd] uses the light color.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/#colors
+    #[prop_or_default]
+    pub light: Option<bool>,
+    /// Sets the link of the label [tag element][bd].
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/
+    #[prop_or_default]
+    pub href: Option<AttrValue>,
+    /// Sets the callback used when the delete [tag element][bd] is clicked.
+    ///
+    /// Sets the callback called when the delete [Bulma tag element][bd]
+    /// found inside the tag addons which will receive these properties is
+    /// clicked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::tag::TagAddons;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let removed = use_state(|| false);
+    ///     let ondelete = {
+    ///         let removed = removed.clone();
+    ///         Callback::from(move |_| removed.set(true))
+    ///     };
+    ///
+    ///     html! {
+    ///         if !*removed {
+    ///             <TagAddons {ondelete}>{"Alex Smith"}</TagAddons>
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/#combinations
+    #[prop_or_default]
+    pub ondelete: Option<Callback<MouseEvent>>,
+    /// The label found inside the first [tag element][bd].
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/tag/
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the tag addons.
+///
+/// Renders a [Bulma tags element][bd] with addons, holding a label tag,
+/// built from its children, followed by a delete tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{elements::tag::TagAddons, helpers::color::Color};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <TagAddons color={Color::Link} href="/users/alex">{"Alex Smith"}</TagAddons>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/tag/#list-of-tags
+#[function_component(TagAddons)]
+pub fn tag_addons(props: &TagAddonsProperties) -> Html {
+    html! {
+        <Tags id={props.id.clone()} class={props.class.clone()} size={props.size} addons=true
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <Tag color={props.color} light={props.light} href={props.href.clone()}>
+                { for props.children.iter() }
+            </Tag>
+            <Tag delete=true onclick={props.ondelete.clone()} />
+        </Tags>
     }
 }
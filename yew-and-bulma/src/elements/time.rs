@@ -0,0 +1,474 @@
+use gloo::timers::callback::Interval;
+use js_sys::Date;
+use yew::{
+    function_component, hook, html, use_effect_with_deps, use_state, Callback, Html, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    helpers::typography::{TextSize, TextWeight},
+    utils::class::ClassBuilder,
+};
+
+/// Defines the properties of the countdown element.
+///
+/// Defines the properties of the countdown element, which displays the time
+/// left until a given moment, styled using the
+/// [Bulma typography helpers][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::time::Countdown;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Countdown until={1_893_456_000_000.0} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/helpers/typography-helpers/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CountdownProperties {
+    /// Sets the moment the countdown ends at.
+    ///
+    /// Sets the moment the countdown element, which will receive these
+    /// properties, counts down to, as the number of milliseconds elapsed
+    /// since the UNIX epoch (ie as returned by [`Date.now()`][now]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::time::Countdown;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Countdown until={1_893_456_000_000.0} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [now]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/now
+    pub until: f64,
+    /// Sets the size of the text of the countdown element.
+    ///
+    /// Sets the size of the text of the countdown element, which will receive
+    /// these properties, using the [Bulma typography helpers][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::time::Countdown, helpers::typography::TextSize};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Countdown until={1_893_456_000_000.0} size={TextSize::One} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/typography-helpers/#size
+    #[prop_or_default]
+    pub size: Option<TextSize>,
+    /// Sets the weight of the text of the countdown element.
+    ///
+    /// Sets the weight of the text of the countdown element, which will
+    /// receive these properties, using the [Bulma typography helpers][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::time::Countdown, helpers::typography::TextWeight};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Countdown until={1_893_456_000_000.0} weight={TextWeight::Bold} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/typography-helpers/#text-weight
+    #[prop_or_default]
+    pub weight: Option<TextWeight>,
+    /// The callback to be used when the countdown ends.
+    ///
+    /// Defines the callback called once the countdown element, which will
+    /// receive these properties, reaches zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::time::Countdown;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onfinish = Callback::from(|_| ());
+    ///
+    ///     html! {
+    ///         <Countdown until={1_893_456_000_000.0} {onfinish} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onfinish: Option<Callback<()>>,
+}
+
+/// Yew implementation of the countdown element.
+///
+/// Renders a [`<time>` HTML element][time] displaying the time left until the
+/// given moment (ie `1d 02:03:04`), updated every second. Updates are skipped
+/// while the page is hidden.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::time::Countdown;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Countdown until={1_893_456_000_000.0} />
+///     }
+/// }
+/// ```
+///
+/// [time]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/time
+#[function_component(Countdown)]
+pub fn countdown(props: &CountdownProperties) -> Html {
+    let now = use_now(1_000);
+    let remaining = props.until - now;
+    let finished = remaining <= 0.0;
+
+    {
+        let onfinish = props.onfinish.clone();
+        use_effect_with_deps(
+            move |finished| {
+                if *finished {
+                    if let Some(onfinish) = onfinish {
+                        onfinish.emit(());
+                    }
+                }
+                || ()
+            },
+            finished,
+        );
+    }
+
+    let class = ClassBuilder::default()
+        .with_text_size(props.size.clone())
+        .with_text_weight(props.weight.clone())
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let datetime = iso_datetime(props.until);
+
+    html! {
+        <time id={props.id.clone()} {class} {datetime}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { format_duration(remaining) }
+        </time>
+    }
+}
+
+/// Defines the properties of the relative time element.
+///
+/// Defines the properties of the relative time element, which displays how
+/// long ago (or in how long) a given moment is, styled using the
+/// [Bulma typography helpers][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::time::RelativeTime;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <RelativeTime timestamp={1_672_531_200_000.0} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/helpers/typography-helpers/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct RelativeTimeProperties {
+    /// Sets the moment described by the relative time element.
+    ///
+    /// Sets the moment described by the relative time element, which will
+    /// receive these properties, as the number of milliseconds elapsed since
+    /// the UNIX epoch (ie as returned by [`Date.now()`][now]).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::time::RelativeTime;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <RelativeTime timestamp={1_672_531_200_000.0} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [now]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/now
+    pub timestamp: f64,
+    /// Sets how often the relative time element is updated.
+    ///
+    /// Sets the number of milliseconds between the updates of the relative
+    /// time element which will receive these properties. Defaults to one
+    /// minute.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::elements::time::RelativeTime;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <RelativeTime timestamp={1_672_531_200_000.0} interval={10_000} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(60_000)]
+    pub interval: u32,
+    /// Sets the size of the text of the relative time element.
+    ///
+    /// Sets the size of the text of the relative time element, which will
+    /// receive these properties, using the [Bulma typography helpers][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::time::RelativeTime, helpers::typography::TextSize};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <RelativeTime timestamp={1_672_531_200_000.0} size={TextSize::Seven} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/typography-helpers/#size
+    #[prop_or_default]
+    pub size: Option<TextSize>,
+    /// Sets the weight of the text of the relative time element.
+    ///
+    /// Sets the weight of the text of the relative time element, which will
+    /// receive these properties, using the [Bulma typography helpers][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::time::RelativeTime, helpers::typography::TextWeight};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <RelativeTime timestamp={1_672_531_200_000.0} weight={TextWeight::Light} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/typography-helpers/#text-weight
+    #[prop_or_default]
+    pub weight: Option<TextWeight>,
+}
+
+/// Yew implementation of the relative time element.
+///
+/// Renders a [`<time>` HTML element][time] describing the given moment
+/// relative to the current time (ie `3 minutes ago` or `in 2 days`), updated
+/// periodically. Updates are skipped while the page is hidden.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::elements::time::RelativeTime;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <RelativeTime timestamp={1_672_531_200_000.0} />
+///     }
+/// }
+/// ```
+///
+/// [time]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/time
+#[function_component(RelativeTime)]
+pub fn relative_time(props: &RelativeTimeProperties) -> Html {
+    let now = use_now(props.interval);
+    let class = ClassBuilder::default()
+        .with_text_size(props.size.clone())
+        .with_text_weight(props.weight.clone())
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let datetime = iso_datetime(props.timestamp);
+
+    html! {
+        <time id={props.id.clone()} {class} {datetime}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { format_relative(props.timestamp - now) }
+        </time>
+    }
+}
+
+#[hook]
+fn use_now(interval: u32) -> f64 {
+    let now = use_state(Date::now);
+
+    {
+        let now = now.clone();
+        use_effect_with_deps(
+            move |interval| {
+                let interval = Interval::new(*interval, move || {
+                    let hidden = web_sys::window()
+                        .and_then(|window| window.document())
+                        .map(|document| document.hidden())
+                        .unwrap_or_default();
+                    if !hidden {
+                        now.set(Date::now());
+                    }
+                });
+
+                move || drop(interval)
+            },
+            interval,
+        );
+    }
+
+    *now
+}
+
+// `Date::to_iso_string` throws for invalid dates, such as NaN or out of range
+// timestamps, which are left without a `datetime` attribute instead.
+fn iso_datetime(timestamp: f64) -> Option<String> {
+    let date = Date::new(&timestamp.into());
+
+    date.get_time()
+        .is_finite()
+        .then(|| String::from(date.to_iso_string()))
+}
+
+fn format_duration(milliseconds: f64) -> String {
+    let seconds = (milliseconds / 1_000.0).ceil().max(0.0) as u64;
+    let (days, hours, minutes, seconds) = (
+        seconds / 86_400,
+        seconds % 86_400 / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60,
+    );
+
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+fn format_relative(milliseconds: f64) -> String {
+    const UNITS: [(&str, u64); 6] = [
+        ("year", 31_536_000),
+        ("month", 2_592_000),
+        ("day", 86_400),
+        ("hour", 3_600),
+        ("minute", 60),
+        ("second", 1),
+    ];
+
+    let seconds = (milliseconds.abs() / 1_000.0).round() as u64;
+    if seconds < 10 {
+        return "just now".to_owned();
+    }
+
+    let (unit, count) = UNITS
+        .iter()
+        .find(|(_, unit_seconds)| seconds >= *unit_seconds)
+        .map(|(unit, unit_seconds)| (*unit, seconds / unit_seconds))
+        .unwrap_or(("second", seconds));
+    let plural = if count == 1 { "" } else { "s" };
+
+    if milliseconds < 0.0 {
+        format!("{count} {unit}{plural} ago")
+    } else {
+        format!("in {count} {unit}{plural}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(-5_000.0, "00:00:00" ; "elapsed durations convert to zero")]
+    #[test_case(1_500.0, "00:00:02" ; "partial seconds are rounded up")]
+    #[test_case(3_723_000.0, "01:02:03" ; "hours minutes and seconds are padded")]
+    #[test_case(93_784_000.0, "1d 02:03:04" ; "days are prefixed")]
+    fn format_duration_values(milliseconds: f64, expected: &str) {
+        assert_eq!(format_duration(milliseconds), expected);
+    }
+
+    #[test_case(-3_000.0, "just now" ; "recent moments are just now")]
+    #[test_case(-60_000.0, "1 minute ago" ; "singular past units")]
+    #[test_case(-180_000.0, "3 minutes ago" ; "plural past units")]
+    #[test_case(7_200_000.0, "in 2 hours" ; "future units")]
+    #[test_case(-172_800_000.0, "2 days ago" ; "days")]
+    #[test_case(-63_072_000_000.0, "2 years ago" ; "years")]
+    fn format_relative_values(milliseconds: f64, expected: &str) {
+        assert_eq!(format_relative(milliseconds), expected);
+    }
+}
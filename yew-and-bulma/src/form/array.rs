@@ -0,0 +1,501 @@
+use std::rc::Rc;
+
+use yew::{
+    function_component, hook, html, use_reducer, virtual_dom::Key, AttrValue, Callback, Html,
+    Properties, Reducible, UseReducerHandle,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::button::{Button, ButtonType},
+    form::general::{Control, Field},
+};
+
+struct FieldValues<T> {
+    next_key: usize,
+    keys: Vec<usize>,
+    values: Vec<T>,
+}
+
+enum FieldArrayAction<T> {
+    Push(T),
+    Update(usize, T),
+    Remove(usize),
+}
+
+impl<T: Clone> Reducible for FieldValues<T> {
+    type Action = FieldArrayAction<T>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut next_key = self.next_key;
+        let mut keys = self.keys.clone();
+        let mut values = self.values.clone();
+        match action {
+            FieldArrayAction::Push(value) => {
+                keys.push(next_key);
+                values.push(value);
+                next_key += 1;
+            }
+            FieldArrayAction::Update(index, value) => {
+                if let Some(current) = values.get_mut(index) {
+                    *current = value;
+                }
+            }
+            FieldArrayAction::Remove(index) => {
+                if index < values.len() {
+                    keys.remove(index);
+                    values.remove(index);
+                }
+            }
+        }
+
+        Rc::new(Self {
+            next_key,
+            keys,
+            values,
+        })
+    }
+}
+
+impl<T: PartialEq> PartialEq for FieldValues<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.keys == other.keys && self.values == other.values
+    }
+}
+
+/// Handle to a dynamic list of form values.
+///
+/// Handle returned by [`use_field_array`], holding an ordered list of values
+/// bound to a [`FieldArray`], along with the methods needed to add, update
+/// or remove them. Setting a value re-renders the component using the hook.
+/// Changes are applied in order, so several of them can be made at once.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::array::use_field_array;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let phones = use_field_array(vec![String::from("555-0100")]);
+///
+///     html! {
+///         <p>{ format!("{} phone numbers", phones.values().len()) }</p>
+///     }
+/// }
+/// ```
+pub struct UseFieldArrayHandle<T: Clone + 'static> {
+    values: UseReducerHandle<FieldValues<T>>,
+}
+
+impl<T: Clone + 'static> UseFieldArrayHandle<T> {
+    /// Returns the values, in order.
+    pub fn values(&self) -> &[T] {
+        &self.values.values
+    }
+
+    /// Returns the keys identifying each value, in order. A value keeps its
+    /// key when other values are added or removed, making it suited for the
+    /// rows of a [`FieldArray`].
+    pub fn keys(&self) -> &[usize] {
+        &self.values.keys
+    }
+
+    /// Adds a value at the end of the list.
+    pub fn push(&self, value: T) {
+        self.values.dispatch(FieldArrayAction::Push(value));
+    }
+
+    /// Replaces the value found at the given index, if there is one.
+    pub fn update(&self, index: usize, value: T) {
+        self.values.dispatch(FieldArrayAction::Update(index, value));
+    }
+
+    /// Removes the value found at the given index, if there is one.
+    pub fn remove(&self, index: usize) {
+        self.values.dispatch(FieldArrayAction::Remove(index));
+    }
+}
+
+impl<T: Clone + 'static> Clone for UseFieldArrayHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + 'static> PartialEq for UseFieldArrayHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.values == other.values
+    }
+}
+
+/// Keeps track of a dynamic list of form values.
+///
+/// Stores an ordered list of values, starting with the given ones, for forms
+/// where the user can add or remove inputs (ie "add another phone number").
+/// Pair it with a [`FieldArray`] to render the inputs.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::{prelude::*, virtual_dom::Key};
+/// use yew_and_bulma::form::array::{use_field_array, FieldArray};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let phones = use_field_array(vec![String::new()]);
+///     let rows: Vec<(Key, Html)> = phones
+///         .keys()
+///         .iter()
+///         .zip(phones.values())
+///         .enumerate()
+///         .map(|(index, (key, phone))| {
+///             let phones = phones.clone();
+///             let oninput = Callback::from(move |event: InputEvent| {
+///                 let input: web_sys::HtmlInputElement = event.target_unchecked_into();
+///                 phones.update(index, input.value());
+///             });
+///             (
+///                 Key::from(*key),
+///                 html! { <input class="input" value={phone.clone()} {oninput} /> },
+///             )
+///         })
+///         .collect();
+///     let onadd = {
+///         let phones = phones.clone();
+///         Callback::from(move |_| phones.push(String::new()))
+///     };
+///     let onremove = Callback::from(move |index| phones.remove(index));
+///
+///     html! {
+///         <FieldArray {rows} {onadd} {onremove} />
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_field_array<T>(initial: Vec<T>) -> UseFieldArrayHandle<T>
+where
+    T: Clone + 'static,
+{
+    let values = use_reducer(|| FieldValues {
+        next_key: initial.len(),
+        keys: (0..initial.len()).collect(),
+        values: initial,
+    });
+
+    UseFieldArrayHandle { values }
+}
+
+/// Defines the properties of the field array.
+///
+/// Defines the properties of the field array, a list of
+/// [Bulma fields][bd] holding one input each, along with the buttons used to
+/// add and remove them.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::array::FieldArray;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = vec![(0.into(), html! { <input class="input" /> })];
+///
+///     html! {
+///         <FieldArray {rows} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct FieldArrayProperties {
+    /// The inputs found inside the field array, in order.
+    ///
+    /// Defines the inputs of the field array which will receive these
+    /// properties, each rendered inside its own [Bulma field][bd], next to
+    /// its remove button. Each input comes with a unique key, such as the
+    /// ones returned by [`UseFieldArrayHandle::keys`], so removing a row
+    /// does not reuse the inputs of the ones after it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::array::FieldArray;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = vec![
+    ///         ("home".into(), html! { <input class="input" value="555-0100" /> }),
+    ///         ("work".into(), html! { <input class="input" value="555-0101" /> }),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <FieldArray {rows} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/
+    #[prop_or_default]
+    pub rows: Vec<(Key, Html)>,
+    /// Sets the callback used when the add button is clicked.
+    ///
+    /// Sets the callback called when the add button of the field array which
+    /// will receive these properties is clicked, usually adding a value to
+    /// the list returned by [`use_field_array`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::array::FieldArray;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onadd = Callback::from(|_| ());
+    ///
+    ///     html! {
+    ///         <FieldArray {onadd} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onadd: Option<Callback<()>>,
+    /// Sets the callback used when a remove button is clicked.
+    ///
+    /// Sets the callback called with the index of the row whose remove
+    /// button was clicked, inside the field array which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::array::FieldArray;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = vec![(0.into(), html! { <input class="input" /> })];
+    ///     let onremove = Callback::from(|_index: usize| ());
+    ///
+    ///     html! {
+    ///         <FieldArray {rows} {onremove} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onremove: Option<Callback<usize>>,
+    /// Sets the text of the add button.
+    ///
+    /// Sets the text of the button used to add a row, found inside the field
+    /// array which will receive these properties. Defaults to `Add`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::array::FieldArray;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FieldArray add_label="Add another phone number" />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("Add"))]
+    pub add_label: AttrValue,
+    /// Sets the text of the remove buttons.
+    ///
+    /// Sets the text of the buttons used to remove a row, found inside the
+    /// field array which will receive these properties. Defaults to `Remove`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::array::FieldArray;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = vec![(0.into(), html! { <input class="input" /> })];
+    ///
+    ///     html! {
+    ///         <FieldArray {rows} remove_label="Delete" />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("Remove"))]
+    pub remove_label: AttrValue,
+    /// Sets the minimum number of rows.
+    ///
+    /// Disables the remove buttons of the field array which will receive
+    /// these properties while it holds this many rows or less. Defaults to
+    /// `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::array::FieldArray;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = vec![(0.into(), html! { <input class="input" /> })];
+    ///
+    ///     html! {
+    ///         <FieldArray {rows} min=1 />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub min: usize,
+    /// Sets the maximum number of rows.
+    ///
+    /// Hides the add button of the field array which will receive these
+    /// properties once it holds this many rows or more.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::array::FieldArray;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let rows = vec![(0.into(), html! { <input class="input" /> })];
+    ///
+    ///     html! {
+    ///         <FieldArray {rows} max={3} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub max: Option<usize>,
+}
+
+/// Yew implementation of the field array.
+///
+/// Yew implementation of the field array, rendering each of its rows inside
+/// a [Bulma field][bd] with addons, next to a button used to remove it, and
+/// a button used to add another row at the end.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::array::FieldArray;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = vec![(0.into(), html! { <input class="input" /> })];
+///
+///     html! {
+///         <FieldArray {rows} add_label="Add another phone number" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[function_component(FieldArray)]
+pub fn field_array(props: &FieldArrayProperties) -> Html {
+    let removable = props.rows.len() > props.min;
+    let addable = props.max.map(|max| props.rows.len() < max).unwrap_or(true);
+    let rows = props.rows.iter().enumerate().map(|(index, (key, row))| {
+        let onclick = props
+            .onremove
+            .clone()
+            .map(|onremove| Callback::from(move |_| onremove.emit(index)));
+
+        html! {
+            <Field key={key.clone()} addons=true>
+                <Control expanded=true>{row.clone()}</Control>
+                <Control>
+                    <Button button_type={ButtonType::Button} {onclick} disabled={!removable}>
+                        {props.remove_label.clone()}
+                    </Button>
+                </Control>
+            </Field>
+        }
+    });
+    let onadd = props
+        .onadd
+        .clone()
+        .map(|onadd| Callback::from(move |_| onadd.emit(())));
+
+    html! {
+        <div id={props.id.clone()} class={props.class.clone()}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for rows }
+            if addable {
+                <Field>
+                    <Control>
+                        <Button button_type={ButtonType::Button} onclick={onadd}>
+                            {props.add_label.clone()}
+                        </Button>
+                    </Control>
+                </Field>
+            }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field_values(values: Vec<&'static str>) -> Rc<FieldValues<&'static str>> {
+        Rc::new(FieldValues {
+            next_key: values.len(),
+            keys: (0..values.len()).collect(),
+            values,
+        })
+    }
+
+    #[test]
+    fn reduce_should_apply_consecutive_actions() {
+        let values = field_values(vec!["a"])
+            .reduce(FieldArrayAction::Push("b"))
+            .reduce(FieldArrayAction::Update(0, "c"));
+
+        assert_eq!(values.values, vec!["c", "b"]);
+    }
+
+    #[test]
+    fn reduce_should_not_reuse_keys_of_removed_values() {
+        let values = field_values(vec!["a", "b"])
+            .reduce(FieldArrayAction::Remove(0))
+            .reduce(FieldArrayAction::Push("c"));
+
+        assert_eq!(values.keys, vec![1, 2]);
+        assert_eq!(values.values, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn reduce_should_ignore_missing_indices() {
+        let values = field_values(vec!["a"])
+            .reduce(FieldArrayAction::Update(3, "b"))
+            .reduce(FieldArrayAction::Remove(3));
+
+        assert_eq!(values.keys, vec![0]);
+        assert_eq!(values.values, vec!["a"]);
+    }
+}
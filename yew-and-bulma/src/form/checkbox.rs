@@ -0,0 +1,209 @@
+use web_sys::HtmlInputElement;
+use yew::{
+    function_component, html, AttrValue, Callback, Children, Event, Html, Properties, TargetCast,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::utils::class::ClassBuilder;
+
+/// Defines the properties of the [Bulma checkbox element][bd].
+///
+/// Defines the properties of the checkbox element, based on the
+/// specification found in the [Bulma checkbox element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::checkbox::Checkbox;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Checkbox>{"Remember me"}</Checkbox>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/checkbox/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CheckboxProperties {
+    /// Sets whether the [Bulma checkbox element][bd] is checked.
+    ///
+    /// Sets whether the [Bulma checkbox element][bd] which will receive these
+    /// properties is checked. Used together with `oncheckedchange` to create
+    /// controlled checkboxes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::checkbox::Checkbox;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let checked = use_state(|| false);
+    ///     let oncheckedchange = {
+    ///         let checked = checked.clone();
+    ///         Callback::from(move |value| checked.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Checkbox checked={*checked} {oncheckedchange}>{"Remember me"}</Checkbox>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/
+    #[prop_or_default]
+    pub checked: bool,
+    /// Sets the name of the [Bulma checkbox element][bd].
+    ///
+    /// Sets the `name` attribute of the [Bulma checkbox element][bd] which
+    /// will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::checkbox::Checkbox;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Checkbox name="remember">{"Remember me"}</Checkbox>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// Disables the [Bulma checkbox element][bd].
+    ///
+    /// Disables the [Bulma checkbox element][bd] which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::checkbox::Checkbox;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Checkbox disabled=true>{"Remember me"}</Checkbox>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Sets the callback used when the checkbox is toggled.
+    ///
+    /// Sets the callback called with whether the
+    /// [Bulma checkbox element][bd], which will receive these properties, is
+    /// now checked, whenever the user toggles it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::checkbox::Checkbox;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let remember = use_state(|| false);
+    ///     let oncheckedchange = {
+    ///         let remember = remember.clone();
+    ///         Callback::from(move |checked| remember.set(checked))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Checkbox {oncheckedchange}>{"Remember me"}</Checkbox>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/
+    #[prop_or_default]
+    pub oncheckedchange: Option<Callback<bool>>,
+    /// The label of the [Bulma checkbox element][bd].
+    ///
+    /// Defines the elements shown next to the [Bulma checkbox element][bd]
+    /// which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/checkbox/
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma checkbox element][bd].
+///
+/// Yew implementation of the checkbox element, based on the specification
+/// found in the [Bulma checkbox element documentation][bd]. The checkbox is
+/// wrapped in its `label`, so clicking the text toggles it as well.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{
+///     checkbox::Checkbox,
+///     general::{Control, Field},
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Control>
+///                 <Checkbox>{" I agree to the terms and conditions"}</Checkbox>
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/checkbox/
+#[function_component(Checkbox)]
+pub fn checkbox(props: &CheckboxProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("checkbox")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let oninputchange = props.oncheckedchange.clone().map(|oncheckedchange| {
+        Callback::from(move |event: Event| {
+            oncheckedchange.emit(event.target_unchecked_into::<HtmlInputElement>().checked());
+        })
+    });
+
+    html! {
+        <label id={props.id.clone()} {class} disabled={props.disabled}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <input type="checkbox" name={props.name.clone()} checked={props.checked}
+                disabled={props.disabled} onchange={oninputchange} />
+            { for props.children.iter() }
+        </label>
+    }
+}
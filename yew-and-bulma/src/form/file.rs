@@ -0,0 +1,424 @@
+use web_sys::{FileList, HtmlInputElement};
+use yew::{
+    function_component, html, use_state, AttrValue, Callback, Event, Html, Properties, TargetCast,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    form::general::Align,
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the properties of the [Bulma file element][bd].
+///
+/// Defines the properties of the file element, based on the specification
+/// found in the [Bulma file element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::file::FileUpload;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FileUpload />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/file/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct FileUploadProperties {
+    /// Sets the text of the call to action of the [file element][bd].
+    ///
+    /// Sets the text of the call to action found inside the
+    /// [Bulma file element][bd] which will receive these properties.
+    /// Defaults to `Choose a file…`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileUpload;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileUpload label="Upload your resume" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    #[prop_or(AttrValue::from("Choose a file…"))]
+    pub label: AttrValue,
+    /// Sets the icon of the [Bulma file element][bd].
+    ///
+    /// Sets the icon displayed next to the call to action of the
+    /// [Bulma file element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileUpload;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileUpload icon={html! { <i class="fas fa-upload"></i> }} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    #[prop_or_default]
+    pub icon: Option<Html>,
+    /// Sets the name of the input of the [file element][bd].
+    ///
+    /// Sets the name, used when submitting forms, of the file input found
+    /// inside the [Bulma file element][bd] which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileUpload;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileUpload name="resume" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// Sets the file types accepted by the [file element][bd].
+    ///
+    /// Sets the [file types][accept] which can be selected using the
+    /// [Bulma file element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileUpload;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileUpload accept="image/*" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    /// [accept]: https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/accept
+    #[prop_or_default]
+    pub accept: Option<AttrValue>,
+    /// Whether or not multiple files can be selected.
+    ///
+    /// Whether or not multiple files can be selected using the
+    /// [Bulma file element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileUpload;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileUpload multiple=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    #[prop_or_default]
+    pub multiple: bool,
+    /// Whether or not the [file element][bd] displays the selected file name.
+    ///
+    /// Whether or not the [Bulma file element][bd], which will receive these
+    /// properties, should display the name of the selected file (or the
+    /// number of selected files, if more than one).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileUpload;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileUpload has_name=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#file-name
+    #[prop_or_default]
+    pub has_name: bool,
+    /// Whether or not the [file element][bd] is boxed.
+    ///
+    /// Whether or not the [Bulma file element][bd], which will receive these
+    /// properties, should be displayed as a box.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileUpload;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileUpload boxed=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#boxed-block
+    #[prop_or_default]
+    pub boxed: bool,
+    /// Whether or not the [file element][bd] takes up the full width.
+    ///
+    /// Whether or not the [Bulma file element][bd], which will receive these
+    /// properties, should take up the full width of its parent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileUpload;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileUpload has_name=true fullwidth=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#fullwidth
+    #[prop_or_default]
+    pub fullwidth: bool,
+    /// Sets the alignment of the [Bulma file element][bd].
+    ///
+    /// Sets the alignment of the [Bulma file element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::{file::FileUpload, general::Align};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileUpload align={Align::Right} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#alignment
+    #[prop_or(Align::Left)]
+    pub align: Align,
+    /// Sets the color of the [Bulma file element][bd].
+    ///
+    /// Sets the color of the [Bulma file element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::file::FileUpload, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileUpload color={Color::Primary} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma file element][bd].
+    ///
+    /// Sets the size of the [Bulma file element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::file::FileUpload, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FileUpload size={Size::Large} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// The callback to be used when the selected files change.
+    ///
+    /// Defines the callback called with the [list of files][fl] selected
+    /// using the [Bulma file element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::file::FileUpload;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onfileschange = Callback::from(|files: web_sys::FileList| {
+    ///         let _count = files.length();
+    ///     });
+    ///
+    ///     html! {
+    ///         <FileUpload {onfileschange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/file/
+    /// [fl]: https://developer.mozilla.org/en-US/docs/Web/API/FileList
+    #[prop_or_default]
+    pub onfileschange: Option<Callback<FileList>>,
+}
+
+/// Yew implementation of the [Bulma file element][bd].
+///
+/// Yew implementation of the file element, based on the specification found
+/// in the [Bulma file element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{form::file::FileUpload, helpers::color::Color};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FileUpload has_name=true boxed=true color={Color::Info}
+///             icon={html! { <i class="fas fa-upload"></i> }} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/file/
+#[function_component(FileUpload)]
+pub fn file_upload(props: &FileUploadProperties) -> Html {
+    let file_name = use_state(|| None::<String>);
+    let onchange = {
+        let file_name = file_name.clone();
+        let onchange = props.onchange.clone();
+        let onfileschange = props.onfileschange.clone();
+        Callback::from(move |event: Event| {
+            let input = event.target_unchecked_into::<HtmlInputElement>();
+            if let Some(files) = input.files() {
+                let name = match files.length() {
+                    0 => None,
+                    1 => files.get(0).map(|file| file.name()),
+                    count => Some(format!("{count} files")),
+                };
+                file_name.set(name);
+                if let Some(onfileschange) = &onfileschange {
+                    onfileschange.emit(files);
+                }
+            }
+            if let Some(onchange) = &onchange {
+                onchange.emit(event);
+            }
+        })
+    };
+    let has_name = if props.has_name { "has-name" } else { "" };
+    let boxed = if props.boxed {
+        format!("{IS_PREFIX}-boxed")
+    } else {
+        "".to_owned()
+    };
+    let fullwidth = if props.fullwidth {
+        format!("{IS_PREFIX}-fullwidth")
+    } else {
+        "".to_owned()
+    };
+    let align = match props.align {
+        Align::Left => "".to_owned(),
+        Align::Center => format!("{IS_PREFIX}-centered"),
+        Align::Right => format!("{IS_PREFIX}-right"),
+    };
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| format!("{IS_PREFIX}-{size}"))
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("file")
+        .with_color(props.color)
+        .with_custom_class(has_name)
+        .with_custom_class(&boxed)
+        .with_custom_class(&fullwidth)
+        .with_custom_class(&align)
+        .with_custom_class(&size)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <label class="file-label">
+                <input class="file-input" type="file" name={props.name.clone()} accept={props.accept.clone()}
+                    multiple={props.multiple} {onchange} />
+                <span class="file-cta">
+                    if let Some(icon) = &props.icon {
+                        <span class="file-icon">{icon.clone()}</span>
+                    }
+                    <span class="file-label">{&props.label}</span>
+                </span>
+                if props.has_name {
+                    <span class="file-name">{(*file_name).clone().unwrap_or_default()}</span>
+                }
+            </label>
+        </div>
+    }
+}
@@ -0,0 +1,1078 @@
+use yew::{function_component, html, AttrValue, Children, Html, Properties};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the possible alignment of the controls from a [field element][bd].
+///
+/// Defines the possible alignment of the controls found inside a grouped
+/// [Bulma field element][bd] or one with addons.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::{Align, Control, Field};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field grouped=true align={Align::Center}>
+///             <Control>{"First control"}</Control>
+///             <Control>{"Second control"}</Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#form-group
+#[derive(PartialEq)]
+pub enum Align {
+    // TODO: use #[default] when updating the MSRV
+    Left,
+    Center,
+    Right,
+}
+
+fn aligned_classes(base: &str, align: &Align) -> Vec<String> {
+    let modifier = match align {
+        Align::Left => None,
+        Align::Center => Some(format!("{base}-centered")),
+        Align::Right => Some(format!("{base}-right")),
+    };
+
+    std::iter::once(base.to_owned()).chain(modifier).collect()
+}
+
+/// Defines the properties of the [Bulma field element][bd].
+///
+/// Defines the properties of the field element, based on the specification
+/// found in the [Bulma form field documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::{Control, Field, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Label>{"Name"}</Label>
+///             <Control>
+///                 <input class="input" type="text" />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct FieldProperties {
+    /// Whether or not the controls of the [field element][bd] are grouped.
+    ///
+    /// Whether or not the controls found inside the [Bulma field element][bd],
+    /// which will receive these properties, should be grouped on the same
+    /// line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::general::{Control, Field};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field grouped=true>
+    ///             <Control>{"First control"}</Control>
+    ///             <Control>{"Second control"}</Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-group
+    #[prop_or_default]
+    pub grouped: bool,
+    /// Whether or not the grouped controls should span multiple lines.
+    ///
+    /// Whether or not the grouped controls found inside the
+    /// [Bulma field element][bd], which will receive these properties, should
+    /// wrap on multiple lines. Has no effect unless `grouped` is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::general::{Control, Field};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field grouped=true multiline=true>
+    ///             <Control>{"First control"}</Control>
+    ///             <Control>{"Second control"}</Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-group
+    #[prop_or_default]
+    pub multiline: bool,
+    /// Whether or not the controls of the [field element][bd] are addons.
+    ///
+    /// Whether or not the controls found inside the [Bulma field element][bd],
+    /// which will receive these properties, should be attached together.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::general::{Control, Field};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field addons=true>
+    ///             <Control>
+    ///                 <input class="input" type="text" />
+    ///             </Control>
+    ///             <Control>
+    ///                 <button class="button">{"Search"}</button>
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-addons
+    #[prop_or_default]
+    pub addons: bool,
+    /// Sets the alignment of the controls of the [field element][bd].
+    ///
+    /// Sets the alignment of the grouped controls or addons found inside the
+    /// [Bulma field element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::general::{Align, Control, Field};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field grouped=true align={Align::Right}>
+    ///             <Control>{"First control"}</Control>
+    ///             <Control>{"Second control"}</Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-group
+    #[prop_or(Align::Left)]
+    pub align: Align,
+    /// Whether or not the [field element][bd] is horizontal.
+    ///
+    /// Whether or not the [Bulma field element][bd], which will receive these
+    /// properties, should display its label next to its body, using
+    /// [`crate::form::general::FieldLabel`] and
+    /// [`crate::form::general::FieldBody`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::general::{Control, Field, FieldBody, FieldLabel, Label};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field horizontal=true>
+    ///             <FieldLabel>
+    ///                 <Label>{"Name"}</Label>
+    ///             </FieldLabel>
+    ///             <FieldBody>
+    ///                 <Field>
+    ///                     <Control>
+    ///                         <input class="input" type="text" />
+    ///                     </Control>
+    ///                 </Field>
+    ///             </FieldBody>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+    #[prop_or_default]
+    pub horizontal: bool,
+    /// Whether or not the [field element][bd] should be expanded.
+    ///
+    /// Whether or not the [Bulma field element][bd], which will receive these
+    /// properties, should take up all the available space inside a
+    /// [`crate::form::general::FieldBody`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::general::{Control, Field, FieldBody};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FieldBody>
+    ///             <Field expanded=true>
+    ///                 <Control>
+    ///                     <input class="input" type="text" />
+    ///                 </Control>
+    ///             </Field>
+    ///         </FieldBody>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+    #[prop_or_default]
+    pub expanded: bool,
+    /// The list of elements found inside the [field element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma field element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma field element][bd].
+///
+/// Yew implementation of the field element, based on the specification found
+/// in the [Bulma form field documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::{Control, Field, Help, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Label>{"Name"}</Label>
+///             <Control>
+///                 <input class="input" type="text" />
+///             </Control>
+///             <Help>{"Your full name."}</Help>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[function_component(Field)]
+pub fn field(props: &FieldProperties) -> Html {
+    let grouped = if props.grouped {
+        aligned_classes(&format!("{IS_PREFIX}-grouped"), &props.align)
+    } else {
+        vec![]
+    };
+    let multiline = if props.grouped && props.multiline {
+        format!("{IS_PREFIX}-grouped-multiline")
+    } else {
+        "".to_owned()
+    };
+    let addons = if props.addons {
+        aligned_classes("has-addons", &props.align)
+    } else {
+        vec![]
+    };
+    let horizontal = if props.horizontal {
+        format!("{IS_PREFIX}-horizontal")
+    } else {
+        "".to_owned()
+    };
+    let expanded = if props.expanded {
+        format!("{IS_PREFIX}-expanded")
+    } else {
+        "".to_owned()
+    };
+    let class = grouped
+        .iter()
+        .chain(addons.iter())
+        .fold(ClassBuilder::default(), |builder, class| {
+            builder.with_custom_class(class)
+        })
+        .with_custom_class("field")
+        .with_custom_class(&multiline)
+        .with_custom_class(&horizontal)
+        .with_custom_class(&expanded)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma label element][bd].
+///
+/// Defines the properties of the label element, based on the specification
+/// found in the [Bulma form label documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::Label;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Label>{"Name"}</Label>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct LabelProperties {
+    /// Sets the size of the [Bulma label element][bd].
+    ///
+    /// Sets the size of the [Bulma label element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::general::Label, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Label size={Size::Large}>{"Name"}</Label>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Sets the element the [label element][bd] describes.
+    ///
+    /// Sets the id of the form element described by the
+    /// [Bulma label element][bd] which will receive these properties, using
+    /// the [`for` HTML attribute][for].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::general::{Control, Field, Label};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field>
+    ///             <Label html_for="name">{"Name"}</Label>
+    ///             <Control>
+    ///                 <input id="name" class="input" type="text" />
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/
+    /// [for]: https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/for
+    #[prop_or_default]
+    pub html_for: Option<AttrValue>,
+    /// The list of elements found inside the [label element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma label element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma label element][bd].
+///
+/// Yew implementation of the label element, based on the specification found
+/// in the [Bulma form label documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::Label;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Label>{"Name"}</Label>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[function_component(Label)]
+pub fn label(props: &LabelProperties) -> Html {
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| format!("{IS_PREFIX}-{size}"))
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("label")
+        .with_custom_class(&size)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <label id={props.id.clone()} {class} for={props.html_for.clone()}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </label>
+    }
+}
+
+/// Defines the properties of the [Bulma control element][bd].
+///
+/// Defines the properties of the control element, based on the specification
+/// found in the [Bulma form control documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::Control;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Control>
+///             <input class="input" type="text" />
+///         </Control>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#form-control
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ControlProperties {
+    /// Sets the size of the [Bulma control element][bd].
+    ///
+    /// Sets the size of the [Bulma control element][bd] which will receive
+    /// these properties, which also sizes its loading indicator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::general::Control, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Control size={Size::Large} loading=true>
+    ///             <input class="input is-large" type="text" />
+    ///         </Control>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the [control element][bd] should be expanded.
+    ///
+    /// Whether or not the [Bulma control element][bd], which will receive
+    /// these properties, should take up all the available space inside a
+    /// grouped field or one with addons.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::general::{Control, Field};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Field grouped=true>
+    ///             <Control expanded=true>
+    ///                 <input class="input" type="text" />
+    ///             </Control>
+    ///             <Control>
+    ///                 <button class="button">{"Search"}</button>
+    ///             </Control>
+    ///         </Field>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-group
+    #[prop_or_default]
+    pub expanded: bool,
+    /// Whether or not the [control element][bd] holds a left icon.
+    ///
+    /// Whether or not the [Bulma control element][bd], which will receive
+    /// these properties, holds an icon placed to the left of its input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::general::Control;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Control has_icons_left=true>
+    ///             <input class="input" type="email" />
+    ///             <span class="icon is-small is-left">
+    ///                 <i class="fas fa-envelope"></i>
+    ///             </span>
+    ///         </Control>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#with-icons
+    #[prop_or_default]
+    pub has_icons_left: bool,
+    /// Whether or not the [control element][bd] holds a right icon.
+    ///
+    /// Whether or not the [Bulma control element][bd], which will receive
+    /// these properties, holds an icon placed to the right of its input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::general::Control;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Control has_icons_right=true>
+    ///             <input class="input" type="email" />
+    ///             <span class="icon is-small is-right">
+    ///                 <i class="fas fa-check"></i>
+    ///             </span>
+    ///         </Control>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#with-icons
+    #[prop_or_default]
+    pub has_icons_right: bool,
+    /// Whether or not the [control element][bd] is loading.
+    ///
+    /// Whether or not the [Bulma control element][bd], which will receive
+    /// these properties, should display a loading indicator.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::general::Control;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Control loading=true>
+    ///             <input class="input" type="text" />
+    ///         </Control>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-control
+    #[prop_or_default]
+    pub loading: bool,
+    /// The list of elements found inside the [control element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma control element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#form-control
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma control element][bd].
+///
+/// Yew implementation of the control element, based on the specification
+/// found in the [Bulma form control documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::Control;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Control>
+///             <input class="input" type="text" />
+///         </Control>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#form-control
+#[function_component(Control)]
+pub fn control(props: &ControlProperties) -> Html {
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| format!("{IS_PREFIX}-{size}"))
+        .unwrap_or("".to_owned());
+    let expanded = if props.expanded {
+        format!("{IS_PREFIX}-expanded")
+    } else {
+        "".to_owned()
+    };
+    let has_icons_left = if props.has_icons_left {
+        "has-icons-left"
+    } else {
+        ""
+    };
+    let has_icons_right = if props.has_icons_right {
+        "has-icons-right"
+    } else {
+        ""
+    };
+    let loading = if props.loading {
+        format!("{IS_PREFIX}-loading")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("control")
+        .with_custom_class(&size)
+        .with_custom_class(&expanded)
+        .with_custom_class(has_icons_left)
+        .with_custom_class(has_icons_right)
+        .with_custom_class(&loading)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma help element][bd].
+///
+/// Defines the properties of the help element, based on the specification
+/// found in the [Bulma form help documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::Help;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Help>{"This username is available."}</Help>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#complete-form-example
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct HelpProperties {
+    /// Sets the color of the [Bulma help element][bd].
+    ///
+    /// Sets the color of the [Bulma help element][bd] which will receive
+    /// these properties (ie danger for validation errors).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::general::Help, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Help color={Color::Danger}>{"This email is invalid."}</Help>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#complete-form-example
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// The list of elements found inside the [help element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma help element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#complete-form-example
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma help element][bd].
+///
+/// Yew implementation of the help element, based on the specification found
+/// in the [Bulma form help documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{form::general::Help, helpers::color::Color};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Help color={Color::Success}>{"This username is available."}</Help>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#complete-form-example
+#[function_component(Help)]
+pub fn help(props: &HelpProperties) -> Html {
+    let color = props
+        .color
+        .as_ref()
+        .map(|color| format!("{IS_PREFIX}-{color}"))
+        .unwrap_or("".to_owned());
+    let class = ClassBuilder::default()
+        .with_custom_class("help")
+        .with_custom_class(&color)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <p id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </p>
+    }
+}
+
+/// Defines the properties of the [Bulma field label element][bd].
+///
+/// Defines the properties of the field label element, based on the
+/// specification found in the [Bulma horizontal form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::{FieldLabel, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FieldLabel>
+///             <Label>{"Name"}</Label>
+///         </FieldLabel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct FieldLabelProperties {
+    /// Sets the size of the [Bulma field label element][bd].
+    ///
+    /// Sets the size of the [Bulma field label element][bd], which will
+    /// receive these properties, used to vertically align the label with the
+    /// controls of the same size. Defaults to the normal size.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     form::general::{FieldLabel, Label},
+    ///     utils::size::Size,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <FieldLabel size={Size::Large}>
+    ///             <Label>{"Name"}</Label>
+    ///         </FieldLabel>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+    #[prop_or(Size::Normal)]
+    pub size: Size,
+    /// The list of elements found inside the [field label element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma field label element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma field label element][bd].
+///
+/// Yew implementation of the field label element, based on the specification
+/// found in the [Bulma horizontal form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::{FieldLabel, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FieldLabel>
+///             <Label>{"Name"}</Label>
+///         </FieldLabel>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+#[function_component(FieldLabel)]
+pub fn field_label(props: &FieldLabelProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("field-label")
+        .with_custom_class(&format!("{IS_PREFIX}-{}", props.size))
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+/// Defines the properties of the [Bulma field body element][bd].
+///
+/// Defines the properties of the field body element, based on the
+/// specification found in the [Bulma horizontal form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::{Control, Field, FieldBody};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FieldBody>
+///             <Field>
+///                 <Control>
+///                     <input class="input" type="text" />
+///                 </Control>
+///             </Field>
+///         </FieldBody>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct FieldBodyProperties {
+    /// The list of elements found inside the [field body element][bd].
+    ///
+    /// Defines the elements that will be found inside the
+    /// [Bulma field body element][bd] which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+    pub children: Children,
+}
+
+/// Yew implementation of the [Bulma field body element][bd].
+///
+/// Yew implementation of the field body element, based on the specification
+/// found in the [Bulma horizontal form documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::{Control, Field, FieldBody};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FieldBody>
+///             <Field>
+///                 <Control>
+///                     <input class="input" type="text" />
+///                 </Control>
+///             </Field>
+///         </FieldBody>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/#horizontal-form
+#[function_component(FieldBody)]
+pub fn field_body(props: &FieldBodyProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("field-body")
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("has-addons", Align::Left, vec!["has-addons"] ; "left addons keep the base class")]
+    #[test_case("has-addons", Align::Center, vec!["has-addons", "has-addons-centered"] ; "centered addons keep the base class")]
+    #[test_case("has-addons", Align::Right, vec!["has-addons", "has-addons-right"] ; "right addons keep the base class")]
+    #[test_case("is-grouped", Align::Left, vec!["is-grouped"] ; "left group keeps the base class")]
+    #[test_case("is-grouped", Align::Center, vec!["is-grouped", "is-grouped-centered"] ; "centered group keeps the base class")]
+    #[test_case("is-grouped", Align::Right, vec!["is-grouped", "is-grouped-right"] ; "right group keeps the base class")]
+    fn aligned_classes_values(base: &str, align: Align, expected_classes: Vec<&str>) {
+        assert_eq!(aligned_classes(base, &align), expected_classes);
+    }
+}
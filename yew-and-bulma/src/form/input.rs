@@ -0,0 +1,561 @@
+use web_sys::HtmlInputElement;
+use yew::{
+    function_component, html, use_effect_with_deps, use_state, AttrValue, Callback, Html,
+    InputEvent, Properties, TargetCast,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    form::general::Help,
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the properties of the [Bulma input element][bd].
+///
+/// Defines the properties of the input element, based on the specification
+/// found in the [Bulma input element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::input::Input;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Input placeholder="Text input" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/input/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct InputProperties {
+    /// Sets the value of the [Bulma input element][bd].
+    ///
+    /// Sets the value of the [Bulma input element][bd] which will receive
+    /// these properties. Used together with `onvaluechange` to create
+    /// controlled inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let value = use_state(String::new);
+    ///     let onvaluechange = {
+    ///         let value = value.clone();
+    ///         Callback::from(move |new_value| value.set(new_value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <Input value={(*value).clone()} {onvaluechange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    #[prop_or_default]
+    pub value: AttrValue,
+    /// Sets the type of the [Bulma input element][bd].
+    ///
+    /// Sets the [HTML input type][type] of the [Bulma input element][bd]
+    /// which will receive these properties. Defaults to `text`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input input_type="email" placeholder="Email input" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    /// [type]: https://developer.mozilla.org/en-US/docs/Web/HTML/Element/input#input_types
+    #[prop_or(AttrValue::from("text"))]
+    pub input_type: AttrValue,
+    /// Sets the name of the [Bulma input element][bd].
+    ///
+    /// Sets the name, used when submitting forms, of the
+    /// [Bulma input element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input name="username" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// Sets the placeholder of the [Bulma input element][bd].
+    ///
+    /// Sets the placeholder text shown by the [Bulma input element][bd],
+    /// which will receive these properties, while it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input placeholder="Text input" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    /// Sets the color of the [Bulma input element][bd].
+    ///
+    /// Sets the color of the [Bulma input element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::input::Input, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input color={Color::Danger} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma input element][bd].
+    ///
+    /// Sets the size of the [Bulma input element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::input::Input, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input size={Size::Large} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the [input element][bd] should be rounded.
+    ///
+    /// Whether or not the [Bulma input element][bd], which will receive these
+    /// properties, should have rounded corners.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input rounded=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#styles
+    #[prop_or_default]
+    pub rounded: bool,
+    /// Whether or not the [input element][bd] is loading.
+    ///
+    /// Whether or not the [Bulma input element][bd], which will receive these
+    /// properties, should display a loading indicator. Since Bulma displays
+    /// it using the control element, the input is wrapped inside a loading
+    /// [`crate::form::general::Control`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input loading=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#states
+    #[prop_or_default]
+    pub loading: bool,
+    /// Whether or not the [input element][bd] is disabled.
+    ///
+    /// Whether or not the [Bulma input element][bd], which will receive these
+    /// properties, should be disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input disabled=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#disabled
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Whether or not the [input element][bd] is read only.
+    ///
+    /// Whether or not the value of the [Bulma input element][bd], which will
+    /// receive these properties, can be changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input value="This text is readonly" readonly=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#readonly-and-static-inputs
+    #[prop_or_default]
+    pub readonly: bool,
+    /// Whether or not the [input element][bd] is static.
+    ///
+    /// Whether or not the [Bulma input element][bd], which will receive these
+    /// properties, should look like plain text. Static inputs are also read
+    /// only.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input value="me@example.com" is_static=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#readonly-and-static-inputs
+    #[prop_or_default]
+    pub is_static: bool,
+    /// Sets the maximum number of characters of the [input element][bd].
+    ///
+    /// Sets the maximum number of characters of the [Bulma input element][bd]
+    /// which will receive these properties, counted in UTF-16 code units like
+    /// the HTML `maxlength` attribute. Going over it turns the input red,
+    /// unless [`InputProperties::hard_limit`] is set, in which case no more
+    /// characters can be typed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input max_length={140} show_counter=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    #[prop_or_default]
+    pub max_length: Option<usize>,
+    /// Whether or not the maximum length of the [input element][bd] is enforced.
+    ///
+    /// Whether or not the [Bulma input element][bd], which will receive these
+    /// properties, prevents typing more characters than its
+    /// [`InputProperties::max_length`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input max_length={8} hard_limit=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    #[prop_or_default]
+    pub hard_limit: bool,
+    /// Whether or not to show a character counter under the [input element][bd].
+    ///
+    /// Whether or not to show a [Bulma help text][help] under the
+    /// [Bulma input element][bd], which will receive these properties,
+    /// counting its characters (ie `123/500`). The counter turns red once the
+    /// [`InputProperties::max_length`] is exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Input max_length={500} show_counter=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    /// [help]: https://bulma.io/documentation/form/general/#form-field
+    #[prop_or_default]
+    pub show_counter: bool,
+    /// The callback to be used when the value of the input changes.
+    ///
+    /// Defines the callback called with the current value of the
+    /// [Bulma input element][bd], which will receive these properties, each
+    /// time it is changed by the user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::input::Input;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onvaluechange = Callback::from(|value: String| {
+    ///         let _length = value.len();
+    ///     });
+    ///
+    ///     html! {
+    ///         <Input {onvaluechange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    #[prop_or_default]
+    pub onvaluechange: Option<Callback<String>>,
+}
+
+/// Yew implementation of the [Bulma input element][bd].
+///
+/// Yew implementation of the input element, based on the specification found
+/// in the [Bulma input element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{
+///     general::{Control, Field, Label},
+///     input::Input,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Label>{"Name"}</Label>
+///             <Control>
+///                 <Input placeholder="Text input" />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/input/
+#[function_component(Input)]
+pub fn input(props: &InputProperties) -> Html {
+    let length = use_state(|| text_length(&props.value));
+    {
+        let length = length.clone();
+        use_effect_with_deps(
+            move |value| length.set(text_length(value)),
+            props.value.clone(),
+        );
+    }
+    let exceeded = props
+        .max_length
+        .map(|max_length| *length > max_length)
+        .unwrap_or_default();
+    let color = if exceeded {
+        Some(Color::Danger)
+    } else {
+        props.color
+    };
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| format!("{IS_PREFIX}-{size}"))
+        .unwrap_or("".to_owned());
+    let rounded = if props.rounded {
+        format!("{IS_PREFIX}-rounded")
+    } else {
+        "".to_owned()
+    };
+    let is_static = if props.is_static {
+        format!("{IS_PREFIX}-static")
+    } else {
+        "".to_owned()
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("input")
+        .with_color(color)
+        .with_custom_class(&size)
+        .with_custom_class(&rounded)
+        .with_custom_class(&is_static)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let oninput = {
+        let oninput = props.oninput.clone();
+        let onvaluechange = props.onvaluechange.clone();
+        let length = length.clone();
+        Callback::from(move |event: InputEvent| {
+            let value = event.target_unchecked_into::<HtmlInputElement>().value();
+            length.set(text_length(&value));
+            if let Some(onvaluechange) = &onvaluechange {
+                onvaluechange.emit(value);
+            }
+            if let Some(oninput) = &oninput {
+                oninput.emit(event);
+            }
+        })
+    };
+
+    let input = html! {
+        <input id={props.id.clone()} {class} type={&props.input_type} value={&props.value}
+            name={props.name.clone()} placeholder={props.placeholder.clone()}
+            disabled={props.disabled} readonly={props.readonly || props.is_static}
+            maxlength={props.max_length.filter(|_| props.hard_limit).map(|max_length| max_length.to_string())}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} {oninput} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()} />
+    };
+
+    let input = if props.loading {
+        let class = ClassBuilder::default()
+            .with_custom_class("control")
+            .with_custom_class(&format!("{IS_PREFIX}-loading"))
+            .build();
+
+        html! {
+            <div {class}>{ input }</div>
+        }
+    } else {
+        input
+    };
+
+    if props.show_counter {
+        with_counter(input, *length, props.max_length)
+    } else {
+        input
+    }
+}
+
+/// Returns the length of a value, as counted by the `maxlength` attribute.
+///
+/// Browsers count UTF-16 code units, so an emoji counts as two characters.
+pub(crate) fn text_length(value: &str) -> usize {
+    value.encode_utf16().count()
+}
+
+/// Renders the given field followed by its character counter (ie `123/500`),
+/// which turns red once the maximum length is exceeded.
+pub(crate) fn with_counter(field: Html, length: usize, max_length: Option<usize>) -> Html {
+    let counter = max_length
+        .map(|max_length| format!("{length}/{max_length}"))
+        .unwrap_or(length.to_string());
+    let color = max_length
+        .filter(|max_length| length > *max_length)
+        .map(|_| Color::Danger);
+
+    html! {
+        <>
+            { field }
+            <Help {color}>{ counter }</Help>
+        </>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("", 0 ; "empty value")]
+    #[test_case("Bulma", 5 ; "ascii value")]
+    #[test_case("ăîș", 3 ; "accented value")]
+    #[test_case("\u{1f600}", 2 ; "emoji counts as two code units")]
+    fn text_length_counts_utf16_code_units(value: &str, expected: usize) {
+        assert_eq!(text_length(value), expected);
+    }
+}
@@ -0,0 +1,200 @@
+/// Provides utilities for creating dynamic lists of form fields in Yew.
+///
+/// Defines the necessary hook and components to build forms holding an
+/// ordered list of inputs which can be added or removed, made up of
+/// [Bulma fields][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::array::FieldArray;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let rows = vec![(0.into(), html! { <input class="input" /> })];
+///
+///     html! {
+///         <FieldArray {rows} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+pub mod array;
+/// Provides utilities for creating [checkbox elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma checkbox elements][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::checkbox::Checkbox;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Checkbox>{"Remember me"}</Checkbox>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/checkbox/
+pub mod checkbox;
+/// Provides utilities for creating [file elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma file elements][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::file::FileUpload;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <FileUpload has_name=true />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/file/
+pub mod file;
+/// Provides utilities for creating [general form elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify the
+/// [Bulma general form elements][bd] (ie field, label, control and help) in
+/// Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::general::{Control, Field, Label};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Label>{"Name"}</Label>
+///             <Control>
+///                 <input class="input" type="text" />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+pub mod general;
+/// Provides utilities for creating [input elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma input elements][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::input::Input;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Input placeholder="Text input" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/input/
+pub mod input;
+/// Provides ready-made groups of form fields in Yew.
+///
+/// Defines components rendering common groups of [Bulma fields][bd], such as
+/// the fields of a postal address or of a payment card, bound to a single
+/// value, as well as ready-made login and signup forms.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::presets::AddressFields;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <AddressFields id="shipping" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+pub mod presets;
+/// Provides utilities for creating search inputs in Yew.
+///
+/// Defines the necessary components to build a search box, made up of a
+/// [Bulma input element][bd] with a search icon and a clear button, which
+/// emits its value once the user stops typing.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::search::SearchInput;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <SearchInput />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/input/
+pub mod search;
+/// Provides utilities for creating quantity steppers in Yew.
+///
+/// Defines the necessary components to build a plus/minus control, made up
+/// of [Bulma buttons][bd] attached around a value.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::stepper::QuantityStepper;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <QuantityStepper value={1} min={1} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/button/#list-of-buttons
+pub mod stepper;
+/// Provides utilities for creating [textarea elements][bd] in Yew.
+///
+/// Defines the necessary components to build, style and modify
+/// [Bulma textarea elements][bd] in Yew.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::textarea::TextArea;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <TextArea placeholder="e.g. Hello world" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/textarea/
+pub mod textarea;
@@ -0,0 +1,1531 @@
+use std::fmt::{Display, Formatter};
+
+use js_sys::Date;
+use yew::{
+    classes, function_component, html, use_state, AttrValue, Callback, FocusEvent, Html,
+    Properties, SubmitEvent, UseStateHandle,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::{
+        button::{Button, ButtonType, State},
+        icon::Icon,
+    },
+    form::{
+        checkbox::Checkbox,
+        general::{Control, Field, Help, Label},
+        input::Input,
+    },
+    helpers::color::Color,
+    utils::size::Size,
+};
+
+/// A postal address.
+///
+/// Holds the values of the fields rendered by [`AddressFields`]. The
+/// `country` is expected to be an [ISO 3166-1 alpha-2 code][iso] (ie `US` or
+/// `GB`), which is used to adjust the labels of the other fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::presets::{Address, AddressFields};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let value = Address {
+///         country: "US".to_owned(),
+///         ..Default::default()
+///     };
+///
+///     html! {
+///         <AddressFields {value} />
+///     }
+/// }
+/// ```
+///
+/// [iso]: https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Address {
+    pub street: String,
+    pub city: String,
+    pub region: String,
+    pub postal_code: String,
+    pub country: String,
+}
+
+/// Defines the properties of the address fields.
+///
+/// Defines the properties of the address fields, a group of
+/// [Bulma fields][bd] holding the street, city, region, postal code and
+/// country of an [`Address`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::presets::AddressFields;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <AddressFields id="shipping" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct AddressFieldsProperties {
+    /// Sets the address shown by the address fields.
+    ///
+    /// Sets the address whose values are shown inside the address fields
+    /// which will receive these properties. The value is controlled by its
+    /// parent, through the [`AddressFieldsProperties::onaddresschange`]
+    /// callback.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::{Address, AddressFields};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let value = Address {
+    ///         city: "London".to_owned(),
+    ///         country: "GB".to_owned(),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     html! {
+    ///         <AddressFields {value} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub value: Address,
+    /// Sets the callback used when the address changes.
+    ///
+    /// Sets the callback called with the whole, updated address whenever
+    /// one of the inputs of the address fields which will receive these
+    /// properties changes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::{Address, AddressFields};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let address = use_state(Address::default);
+    ///     let onaddresschange = {
+    ///         let address = address.clone();
+    ///         Callback::from(move |value| address.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <AddressFields value={(*address).clone()} {onaddresschange} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onaddresschange: Option<Callback<Address>>,
+}
+
+/// Yew implementation of the address fields.
+///
+/// Yew implementation of the address fields, rendering the street on its
+/// own [Bulma field][bd], followed by the city, region and postal code
+/// grouped on a single field and the country. The labels follow the
+/// conventions of the country of the address, such as `State` and
+/// `ZIP code` for `US` or `County` and `Postcode` for `GB`.
+///
+/// When an `id` is set, it is used as a prefix for the ids of the inputs (ie
+/// `shipping-street`), which are also named after the field they hold.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::presets::AddressFields;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <AddressFields id="shipping" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[function_component(AddressFields)]
+pub fn address_fields(props: &AddressFieldsProperties) -> Html {
+    let labels = AddressLabels::for_country(&props.value.country);
+    let input_id = |name: &str| {
+        props
+            .id
+            .as_ref()
+            .map(|id| AttrValue::from(format!("{id}-{name}")))
+    };
+    let update = |set: fn(&mut Address, String)| {
+        let address = props.value.clone();
+        props.onaddresschange.clone().map(|onaddresschange| {
+            Callback::from(move |value: String| {
+                let mut address = address.clone();
+                set(&mut address, value);
+                onaddresschange.emit(address);
+            })
+        })
+    };
+
+    html! {
+        <div id={props.id.clone()} class={props.class.clone()}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <Field>
+                <Label html_for={input_id("street")}>{labels.street}</Label>
+                <Control>
+                    <Input id={input_id("street")} name="street" value={props.value.street.clone()}
+                        onvaluechange={update(|address, value| address.street = value)} />
+                </Control>
+            </Field>
+            <Field grouped=true>
+                <Control expanded=true>
+                    <Label html_for={input_id("city")}>{labels.city}</Label>
+                    <Input id={input_id("city")} name="city" value={props.value.city.clone()}
+                        onvaluechange={update(|address, value| address.city = value)} />
+                </Control>
+                <Control expanded=true>
+                    <Label html_for={input_id("region")}>{labels.region}</Label>
+                    <Input id={input_id("region")} name="region" value={props.value.region.clone()}
+                        onvaluechange={update(|address, value| address.region = value)} />
+                </Control>
+                <Control>
+                    <Label html_for={input_id("postal-code")}>{labels.postal_code}</Label>
+                    <Input id={input_id("postal-code")} name="postal-code" value={props.value.postal_code.clone()}
+                        onvaluechange={update(|address, value| address.postal_code = value)} />
+                </Control>
+            </Field>
+            <Field>
+                <Label html_for={input_id("country")}>{"Country"}</Label>
+                <Control>
+                    <Input id={input_id("country")} name="country" value={props.value.country.clone()}
+                        onvaluechange={update(|address, value| address.country = value)} />
+                </Control>
+            </Field>
+        </div>
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct AddressLabels {
+    street: &'static str,
+    city: &'static str,
+    region: &'static str,
+    postal_code: &'static str,
+}
+
+impl AddressLabels {
+    fn for_country(country: &str) -> Self {
+        let (city, region, postal_code) = match country.trim().to_uppercase().as_str() {
+            "US" => ("City", "State", "ZIP code"),
+            "CA" => ("City", "Province", "Postal code"),
+            "GB" => ("Town/City", "County", "Postcode"),
+            "IE" => ("Town/City", "County", "Eircode"),
+            "AU" => ("Suburb", "State", "Postcode"),
+            "IN" => ("City", "State", "PIN code"),
+            "JP" => ("City", "Prefecture", "Postal code"),
+            _ => ("City", "Region", "Postal code"),
+        };
+
+        Self {
+            street: "Street address",
+            city,
+            region,
+            postal_code,
+        }
+    }
+}
+
+/// Defines the card brands recognized by [`CardFields`].
+///
+/// Defines the payment card brands which can be detected from the first
+/// digits of a card number, used to format the number, validate the length
+/// of the number and security code and pick the icon shown next to it.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::presets::{CardBrand, CardFields};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let brand_icon = Callback::from(|brand: CardBrand| match brand {
+///         CardBrand::Visa => html! { <i class="fab fa-cc-visa"></i> },
+///         _ => html! { <i class="fas fa-credit-card"></i> },
+///     });
+///
+///     html! {
+///         <CardFields {brand_icon} />
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CardBrand {
+    Visa,
+    Mastercard,
+    AmericanExpress,
+    Discover,
+}
+
+impl CardBrand {
+    /// Detects the brand of a card from the first digits of its number.
+    pub fn detect(number: &str) -> Option<CardBrand> {
+        let number = digits(number);
+        let prefix = |length: usize| number.get(..length).and_then(|prefix| prefix.parse().ok());
+
+        match (prefix(1), prefix(2), prefix(3), prefix(4)) {
+            (Some(4), ..) => Some(CardBrand::Visa),
+            (_, Some(34 | 37), ..) => Some(CardBrand::AmericanExpress),
+            (_, Some(51..=55), ..) | (.., Some(2221..=2720)) => Some(CardBrand::Mastercard),
+            (_, Some(65), ..) | (_, _, Some(644..=649), _) | (.., Some(6011)) => {
+                Some(CardBrand::Discover)
+            }
+            _ => None,
+        }
+    }
+
+    fn number_lengths(brand: Option<CardBrand>) -> &'static [usize] {
+        match brand {
+            Some(CardBrand::Visa) => &[13, 16, 19],
+            Some(CardBrand::Mastercard) => &[16],
+            Some(CardBrand::AmericanExpress) => &[15],
+            Some(CardBrand::Discover) => &[16, 19],
+            None => &[12, 13, 14, 15, 16, 17, 18, 19],
+        }
+    }
+
+    fn cvc_length(brand: Option<CardBrand>) -> usize {
+        match brand {
+            Some(CardBrand::AmericanExpress) => 4,
+            _ => 3,
+        }
+    }
+}
+
+impl Display for CardBrand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let brand = match self {
+            CardBrand::Visa => "Visa",
+            CardBrand::Mastercard => "Mastercard",
+            CardBrand::AmericanExpress => "American Express",
+            CardBrand::Discover => "Discover",
+        };
+
+        write!(f, "{brand}")
+    }
+}
+
+/// The details of a payment card.
+///
+/// Holds the values entered in [`CardFields`], emitted once all of them are
+/// valid. The number only holds digits, without the spaces shown while
+/// typing it.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::presets::{CardDetails, CardFields};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let expiry = use_state(String::new);
+///     let oncardchange = {
+///         let expiry = expiry.clone();
+///         Callback::from(move |details: Option<CardDetails>| {
+///             if let Some(details) = details {
+///                 expiry.set(format!("{}/{}", details.expiry_month, details.expiry_year));
+///             }
+///         })
+///     };
+///
+///     html! {
+///         <>
+///             <CardFields {oncardchange} />
+///             <p>{ format!("Expires on {}", *expiry) }</p>
+///         </>
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CardDetails {
+    pub number: String,
+    pub brand: Option<CardBrand>,
+    pub expiry_month: u32,
+    pub expiry_year: u32,
+    pub cvc: String,
+}
+
+/// Defines the properties of the card fields.
+///
+/// Defines the properties of the card fields, a group of
+/// [Bulma fields][bd] holding the number, expiry date and security code of
+/// a payment card.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::presets::CardFields;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <CardFields id="card" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CardFieldsProperties {
+    /// Sets the icon shown for a detected card brand.
+    ///
+    /// Sets the callback returning the icon shown next to the number of the
+    /// card fields which will receive these properties, once its brand is
+    /// detected. By default, the name of the brand is shown.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::{CardBrand, CardFields};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let brand_icon = Callback::from(|brand: CardBrand| match brand {
+    ///         CardBrand::Visa => html! { <i class="fab fa-cc-visa"></i> },
+    ///         CardBrand::Mastercard => html! { <i class="fab fa-cc-mastercard"></i> },
+    ///         CardBrand::AmericanExpress => html! { <i class="fab fa-cc-amex"></i> },
+    ///         CardBrand::Discover => html! { <i class="fab fa-cc-discover"></i> },
+    ///     });
+    ///
+    ///     html! {
+    ///         <CardFields {brand_icon} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub brand_icon: Option<Callback<CardBrand, Html>>,
+    /// Sets the callback used when the card details change.
+    ///
+    /// Sets the callback called whenever one of the inputs of the card
+    /// fields which will receive these properties changes, with the
+    /// [`CardDetails`] if all of them are valid or [`None`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::{CardDetails, CardFields};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let details = use_state(|| None::<CardDetails>);
+    ///     let oncardchange = {
+    ///         let details = details.clone();
+    ///         Callback::from(move |value| details.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <>
+    ///             <CardFields {oncardchange} />
+    ///             <button class="button" disabled={details.is_none()}>{"Pay"}</button>
+    ///         </>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub oncardchange: Option<Callback<Option<CardDetails>>>,
+}
+
+/// Yew implementation of the card fields.
+///
+/// Yew implementation of the card fields, rendering the number of a payment
+/// card on its own [Bulma field][bd], followed by its expiry date and
+/// security code grouped on a single field. The number is spaced as it is
+/// typed, with an icon for its detected brand, and the expiry date is
+/// formatted as `MM/YY`. Each input is validated once it loses focus.
+///
+/// The values are only formatted and validated in the browser, they are not
+/// sent anywhere.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::presets::CardFields;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <CardFields id="card" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[function_component(CardFields)]
+pub fn card_fields(props: &CardFieldsProperties) -> Html {
+    let number = use_state(String::new);
+    let expiry = use_state(String::new);
+    let cvc = use_state(String::new);
+    let touched = use_state(|| (false, false, false));
+
+    let brand = CardBrand::detect(&number);
+    let today = Date::new_0();
+    let now = (today.get_full_year(), today.get_month() + 1);
+    let number_valid = card_number_valid(&number);
+    let expiry_valid = parse_expiry(&expiry)
+        .map(|(month, year)| (year, month) >= now)
+        .unwrap_or_default();
+    let cvc_valid = cvc.len() == CardBrand::cvc_length(brand);
+
+    let details = move |number: &str, expiry: &str, cvc: &str| {
+        let brand = CardBrand::detect(number);
+        let expiry = parse_expiry(expiry).filter(|(month, year)| (*year, *month) >= now);
+        match expiry {
+            Some((expiry_month, expiry_year))
+                if card_number_valid(number) && cvc.len() == CardBrand::cvc_length(brand) =>
+            {
+                Some(CardDetails {
+                    number: number.to_owned(),
+                    brand,
+                    expiry_month,
+                    expiry_year,
+                    cvc: cvc.to_owned(),
+                })
+            }
+            _ => None,
+        }
+    };
+    let onnumberchange = {
+        let number = number.clone();
+        let expiry = (*expiry).clone();
+        let cvc = (*cvc).clone();
+        let oncardchange = props.oncardchange.clone();
+        Callback::from(move |value: String| {
+            let value = digits(&value);
+            let lengths = CardBrand::number_lengths(CardBrand::detect(&value));
+            let value = value
+                .chars()
+                .take(lengths.last().copied().unwrap_or_default())
+                .collect::<String>();
+            if let Some(oncardchange) = &oncardchange {
+                oncardchange.emit(details(&value, &expiry, &cvc));
+            }
+            number.set(value);
+        })
+    };
+    let onexpirychange = {
+        let number = (*number).clone();
+        let expiry = expiry.clone();
+        let cvc = (*cvc).clone();
+        let oncardchange = props.oncardchange.clone();
+        Callback::from(move |value: String| {
+            let value = format_expiry(&value);
+            if let Some(oncardchange) = &oncardchange {
+                oncardchange.emit(details(&number, &value, &cvc));
+            }
+            expiry.set(value);
+        })
+    };
+    let oncvcchange = {
+        let number = (*number).clone();
+        let expiry = (*expiry).clone();
+        let cvc = cvc.clone();
+        let oncardchange = props.oncardchange.clone();
+        Callback::from(move |value: String| {
+            let value = digits(&value)
+                .chars()
+                .take(CardBrand::cvc_length(CardBrand::detect(&number)))
+                .collect::<String>();
+            if let Some(oncardchange) = &oncardchange {
+                oncardchange.emit(details(&number, &expiry, &value));
+            }
+            cvc.set(value);
+        })
+    };
+    let onblur = |field: usize| {
+        let touched = touched.clone();
+        Callback::from(move |_: FocusEvent| {
+            let mut fields = *touched;
+            match field {
+                0 => fields.0 = true,
+                1 => fields.1 = true,
+                _ => fields.2 = true,
+            }
+            touched.set(fields);
+        })
+    };
+
+    let show_number_error = touched.0 && !number.is_empty() && !number_valid;
+    let show_expiry_error = touched.1 && !expiry.is_empty() && !expiry_valid;
+    let show_cvc_error = touched.2 && !cvc.is_empty() && !cvc_valid;
+    let error_color = |shown: bool| shown.then(|| Color::Danger);
+    let input_id = |name: &str| {
+        props
+            .id
+            .as_ref()
+            .map(|id| AttrValue::from(format!("{id}-{name}")))
+    };
+    let icon = match (brand, &props.brand_icon) {
+        (Some(brand), Some(brand_icon)) => brand_icon.emit(brand),
+        (Some(brand), None) => html! { <span class="is-size-7">{brand.to_string()}</span> },
+        (None, _) => html! { "\u{1f4b3}" },
+    };
+
+    html! {
+        <div id={props.id.clone()} class={props.class.clone()}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <Field>
+                <Label html_for={input_id("number")}>{"Card number"}</Label>
+                <Control has_icons_right=true>
+                    <Input id={input_id("number")} name="cc-number" value={format_card_number(&number)}
+                        placeholder="1234 1234 1234 1234" color={error_color(show_number_error)}
+                        onvaluechange={onnumberchange} onblur={onblur(0)} />
+                    <Icon class={classes!("is-right")} size={Size::Small} {icon} />
+                </Control>
+                if show_number_error {
+                    <Help color={Color::Danger}>{"Invalid card number"}</Help>
+                }
+            </Field>
+            <Field grouped=true>
+                <Control expanded=true>
+                    <Label html_for={input_id("expiry")}>{"Expiry date"}</Label>
+                    <Input id={input_id("expiry")} name="cc-exp" value={(*expiry).clone()} placeholder="MM/YY"
+                        color={error_color(show_expiry_error)} onvaluechange={onexpirychange} onblur={onblur(1)} />
+                    if show_expiry_error {
+                        <Help color={Color::Danger}>{"Invalid expiry date"}</Help>
+                    }
+                </Control>
+                <Control expanded=true>
+                    <Label html_for={input_id("cvc")}>{"Security code"}</Label>
+                    <Input id={input_id("cvc")} name="cc-csc" value={(*cvc).clone()} placeholder="CVC"
+                        color={error_color(show_cvc_error)} onvaluechange={oncvcchange} onblur={onblur(2)} />
+                    if show_cvc_error {
+                        <Help color={Color::Danger}>{"Invalid security code"}</Help>
+                    }
+                </Control>
+            </Field>
+        </div>
+    }
+}
+
+fn digits(value: &str) -> String {
+    value.chars().filter(char::is_ascii_digit).collect()
+}
+
+fn card_number_valid(number: &str) -> bool {
+    let number = digits(number);
+    let lengths = CardBrand::number_lengths(CardBrand::detect(&number));
+    let checksum = number
+        .chars()
+        .rev()
+        .filter_map(|digit| digit.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| match (index % 2, digit * 2) {
+            (0, _) => digit,
+            (_, doubled) if doubled > 9 => doubled - 9,
+            (_, doubled) => doubled,
+        })
+        .sum::<u32>();
+
+    lengths.contains(&number.len()) && checksum % 10 == 0
+}
+
+fn format_card_number(number: &str) -> String {
+    let number = digits(number);
+    let groups: &[usize] = match CardBrand::detect(&number) {
+        Some(CardBrand::AmericanExpress) => &[4, 6, 5],
+        _ => &[4, 4, 4, 4, 3],
+    };
+    let mut rest = number.as_str();
+    let mut formatted = Vec::new();
+    for group in groups {
+        if rest.is_empty() {
+            break;
+        }
+        let (head, tail) = rest.split_at((*group).min(rest.len()));
+        formatted.push(head);
+        rest = tail;
+    }
+
+    formatted.join(" ")
+}
+
+fn format_expiry(value: &str) -> String {
+    let value = digits(value).chars().take(4).collect::<String>();
+    let value = match value.chars().next() {
+        Some(month) if month > '1' => format!("0{value}").chars().take(4).collect(),
+        _ => value,
+    };
+
+    if value.len() > 2 {
+        format!("{}/{}", &value[..2], &value[2..])
+    } else {
+        value
+    }
+}
+
+fn parse_expiry(value: &str) -> Option<(u32, u32)> {
+    let (month, year) = value.split_once('/')?;
+    let month = month
+        .parse::<u32>()
+        .ok()
+        .filter(|month| (1..=12).contains(month))?;
+    let year = year.parse::<u32>().ok().filter(|_| year.len() == 2)?;
+
+    Some((month, 2000 + year))
+}
+
+/// The credentials entered in an authentication form.
+///
+/// Holds the values entered in a [`LoginForm`] or a [`SignupForm`], emitted
+/// once they are submitted and valid. The email is trimmed, while the
+/// password is kept exactly as it was typed.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::presets::{Credentials, LoginForm};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let user = use_state(String::new);
+///     let onsignin = {
+///         let user = user.clone();
+///         Callback::from(move |credentials: Credentials| user.set(credentials.email))
+///     };
+///
+///     html! {
+///         <>
+///             <LoginForm {onsignin} />
+///             <p>{ format!("Signed in as {}", *user) }</p>
+///         </>
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Credentials {
+    pub email: String,
+    pub password: String,
+    pub remember: bool,
+}
+
+/// Defines the properties of the login form.
+///
+/// Defines the properties of the login form, a form made up of
+/// [Bulma fields][bd] holding an email, a password and, optionally, a
+/// "Remember me" checkbox.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::presets::LoginForm;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <LoginForm id="login" remember=true />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct LoginFormProperties {
+    /// Shows the "Remember me" checkbox.
+    ///
+    /// Shows a checkbox under the password of the login form which will
+    /// receive these properties, its value being sent as
+    /// [`Credentials::remember`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::LoginForm;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <LoginForm remember=true />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub remember: bool,
+    /// Sets the label of the submit button.
+    ///
+    /// Sets the text of the button submitting the login form which will
+    /// receive these properties. Defaults to "Sign in".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::LoginForm;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <LoginForm submit_label="Log in" />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("Sign in"))]
+    pub submit_label: AttrValue,
+    /// Sets the color of the submit button.
+    ///
+    /// Sets the color of the button submitting the login form which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::presets::LoginForm, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <LoginForm color={Color::Primary} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the login form.
+    ///
+    /// Sets the size of the inputs and of the submit button of the login
+    /// form which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::presets::LoginForm, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <LoginForm size={Size::Large} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Shows the login form as loading.
+    ///
+    /// Shows a spinner on the submit button of the login form which will
+    /// receive these properties, which cannot be submitted again meanwhile.
+    /// Usually set while the credentials are being checked.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::LoginForm;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <LoginForm loading=true />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub loading: bool,
+    /// Sets the error of the login form.
+    ///
+    /// Sets the message shown above the submit button of the login form
+    /// which will receive these properties, usually the reason the
+    /// credentials were rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::LoginForm;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <LoginForm error="Wrong email or password" />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub error: Option<AttrValue>,
+    /// Sets the callback used when the login form is submitted.
+    ///
+    /// Sets the callback called with the [`Credentials`] entered in the
+    /// login form which will receive these properties, when it is submitted
+    /// and its fields are valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::{Credentials, LoginForm};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let credentials = use_state(|| None::<Credentials>);
+    ///     let onsignin = {
+    ///         let credentials = credentials.clone();
+    ///         Callback::from(move |value| credentials.set(Some(value)))
+    ///     };
+    ///
+    ///     html! {
+    ///         <LoginForm {onsignin} loading={credentials.is_some()} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onsignin: Option<Callback<Credentials>>,
+}
+
+/// Yew implementation of the login form.
+///
+/// Yew implementation of the login form, rendering an email and a password
+/// on their own [Bulma fields][bd], an optional "Remember me" checkbox and a
+/// submit button. The fields are validated when the form is submitted, the
+/// browser not being allowed to submit it on its own.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{form::presets::LoginForm, helpers::color::Color};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let onsignin = Callback::from(|_| ());
+///
+///     html! {
+///         <LoginForm id="login" remember=true color={Color::Primary} {onsignin} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[function_component(LoginForm)]
+pub fn login_form(props: &LoginFormProperties) -> Html {
+    let email = use_state(String::new);
+    let password = use_state(String::new);
+    let remember = use_state(|| false);
+    let submitted = use_state(|| false);
+
+    let email_error = (*submitted).then(|| validate_email(&email)).flatten();
+    let password_error = (*submitted)
+        .then(|| validate_password(&password, 0))
+        .flatten();
+    let onsubmit = {
+        let email = email.clone();
+        let password = password.clone();
+        let remember = remember.clone();
+        let submitted = submitted.clone();
+        let loading = props.loading;
+        let onsubmit = props.onsubmit.clone();
+        let onsignin = props.onsignin.clone();
+        Callback::from(move |event: SubmitEvent| {
+            event.prevent_default();
+            submitted.set(true);
+            let valid =
+                validate_email(&email).is_none() && validate_password(&password, 0).is_none();
+            if let (true, false, Some(onsignin)) = (valid, loading, &onsignin) {
+                onsignin.emit(Credentials {
+                    email: email.trim().to_owned(),
+                    password: (*password).clone(),
+                    remember: *remember,
+                });
+            }
+            if let Some(onsubmit) = &onsubmit {
+                onsubmit.emit(event);
+            }
+        })
+    };
+    let onremember = {
+        let remember = remember.clone();
+        Callback::from(move |checked| remember.set(checked))
+    };
+    let input_id = |name: &str| {
+        props
+            .id
+            .as_ref()
+            .map(|id| AttrValue::from(format!("{id}-{name}")))
+    };
+
+    html! {
+        <form id={props.id.clone()} class={props.class.clone()} novalidate=true
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} {onsubmit}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { credential_field(input_id("email"), "email", "Email", "email", &email, email_error, props.size) }
+            { credential_field(input_id("password"), "password", "Password", "password", &password, password_error, props.size) }
+            if props.remember {
+                <Field>
+                    <Control>
+                        <Checkbox name="remember" checked={*remember} oncheckedchange={onremember}>
+                            {" Remember me"}
+                        </Checkbox>
+                    </Control>
+                </Field>
+            }
+            { submit_field(&props.submit_label, props.color, props.size, props.loading, props.error.as_ref()) }
+        </form>
+    }
+}
+
+/// Defines the properties of the signup form.
+///
+/// Defines the properties of the signup form, a form made up of
+/// [Bulma fields][bd] holding an email, a password, its confirmation and,
+/// optionally, a checkbox accepting some terms.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::presets::SignupForm;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <SignupForm id="signup" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct SignupFormProperties {
+    /// Sets the minimum length of the password.
+    ///
+    /// Sets the minimum number of characters of the password entered in the
+    /// signup form which will receive these properties. Defaults to 8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::SignupForm;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <SignupForm min_password_length={12} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(8)]
+    pub min_password_length: usize,
+    /// Sets the terms which have to be accepted.
+    ///
+    /// Sets the label of a checkbox shown under the password confirmation
+    /// of the signup form which will receive these properties, which has to
+    /// be checked for the form to be submitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::SignupForm;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let terms = html! {
+    ///         <>{" I agree to the "}<a href="/terms">{"terms and conditions"}</a></>
+    ///     };
+    ///
+    ///     html! {
+    ///         <SignupForm {terms} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub terms: Option<Html>,
+    /// Sets the label of the submit button.
+    ///
+    /// Sets the text of the button submitting the signup form which will
+    /// receive these properties. Defaults to "Sign up".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::SignupForm;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <SignupForm submit_label="Create account" />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("Sign up"))]
+    pub submit_label: AttrValue,
+    /// Sets the color of the submit button.
+    ///
+    /// Sets the color of the button submitting the signup form which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::presets::SignupForm, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <SignupForm color={Color::Success} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the signup form.
+    ///
+    /// Sets the size of the inputs and of the submit button of the signup
+    /// form which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::presets::SignupForm, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <SignupForm size={Size::Medium} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Shows the signup form as loading.
+    ///
+    /// Shows a spinner on the submit button of the signup form which will
+    /// receive these properties, which cannot be submitted again meanwhile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::SignupForm;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <SignupForm loading=true />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub loading: bool,
+    /// Sets the error of the signup form.
+    ///
+    /// Sets the message shown above the submit button of the signup form
+    /// which will receive these properties, usually the reason the account
+    /// could not be created.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::SignupForm;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <SignupForm error="This email is already used" />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub error: Option<AttrValue>,
+    /// Sets the callback used when the signup form is submitted.
+    ///
+    /// Sets the callback called with the [`Credentials`] entered in the
+    /// signup form which will receive these properties, when it is
+    /// submitted and its fields are valid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::presets::{Credentials, SignupForm};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let credentials = use_state(|| None::<Credentials>);
+    ///     let onsignup = {
+    ///         let credentials = credentials.clone();
+    ///         Callback::from(move |value| credentials.set(Some(value)))
+    ///     };
+    ///
+    ///     html! {
+    ///         <SignupForm {onsignup} loading={credentials.is_some()} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onsignup: Option<Callback<Credentials>>,
+}
+
+/// Yew implementation of the signup form.
+///
+/// Yew implementation of the signup form, rendering an email, a password
+/// and its confirmation on their own [Bulma fields][bd], an optional
+/// checkbox accepting some terms and a submit button. The fields are
+/// validated when the form is submitted, the browser not being allowed to
+/// submit it on its own.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{form::presets::SignupForm, helpers::color::Color};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let onsignup = Callback::from(|_| ());
+///
+///     html! {
+///         <SignupForm id="signup" color={Color::Primary} {onsignup} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/general/
+#[function_component(SignupForm)]
+pub fn signup_form(props: &SignupFormProperties) -> Html {
+    let email = use_state(String::new);
+    let password = use_state(String::new);
+    let confirmation = use_state(String::new);
+    let accepted = use_state(|| false);
+    let submitted = use_state(|| false);
+
+    let min_length = props.min_password_length;
+    let needs_terms = props.terms.is_some();
+    let email_error = (*submitted).then(|| validate_email(&email)).flatten();
+    let password_error = (*submitted)
+        .then(|| validate_password(&password, min_length))
+        .flatten();
+    let confirmation_error = (*submitted)
+        .then(|| validate_confirmation(&password, &confirmation))
+        .flatten();
+    let show_terms_error = *submitted && needs_terms && !*accepted;
+    let onsubmit = {
+        let email = email.clone();
+        let password = password.clone();
+        let confirmation = confirmation.clone();
+        let accepted = accepted.clone();
+        let submitted = submitted.clone();
+        let loading = props.loading;
+        let onsubmit = props.onsubmit.clone();
+        let onsignup = props.onsignup.clone();
+        Callback::from(move |event: SubmitEvent| {
+            event.prevent_default();
+            submitted.set(true);
+            let valid = validate_email(&email).is_none()
+                && validate_password(&password, min_length).is_none()
+                && validate_confirmation(&password, &confirmation).is_none()
+                && (!needs_terms || *accepted);
+            if let (true, false, Some(onsignup)) = (valid, loading, &onsignup) {
+                onsignup.emit(Credentials {
+                    email: email.trim().to_owned(),
+                    password: (*password).clone(),
+                    remember: false,
+                });
+            }
+            if let Some(onsubmit) = &onsubmit {
+                onsubmit.emit(event);
+            }
+        })
+    };
+    let onaccept = {
+        let accepted = accepted.clone();
+        Callback::from(move |checked| accepted.set(checked))
+    };
+    let input_id = |name: &str| {
+        props
+            .id
+            .as_ref()
+            .map(|id| AttrValue::from(format!("{id}-{name}")))
+    };
+
+    html! {
+        <form id={props.id.clone()} class={props.class.clone()} novalidate=true
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} {onsubmit}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { credential_field(input_id("email"), "email", "Email", "email", &email, email_error, props.size) }
+            { credential_field(input_id("password"), "password", "Password", "password", &password, password_error, props.size) }
+            { credential_field(input_id("confirmation"), "confirmation", "Confirm password", "password", &confirmation, confirmation_error, props.size) }
+            if let Some(terms) = &props.terms {
+                <Field>
+                    <Control>
+                        <Checkbox name="terms" checked={*accepted} oncheckedchange={onaccept}>
+                            {terms.clone()}
+                        </Checkbox>
+                    </Control>
+                    if show_terms_error {
+                        <Help color={Color::Danger}>{"The terms have to be accepted"}</Help>
+                    }
+                </Field>
+            }
+            { submit_field(&props.submit_label, props.color, props.size, props.loading, props.error.as_ref()) }
+        </form>
+    }
+}
+
+fn credential_field(
+    id: Option<AttrValue>,
+    name: &'static str,
+    label: &'static str,
+    input_type: &'static str,
+    value: &UseStateHandle<String>,
+    error: Option<&'static str>,
+    size: Option<Size>,
+) -> Html {
+    let onvaluechange = {
+        let value = value.clone();
+        Callback::from(move |new_value| value.set(new_value))
+    };
+
+    html! {
+        <Field>
+            <Label html_for={id.clone()} {size}>{label}</Label>
+            <Control>
+                <Input {id} {name} input_type={input_type} value={(**value).clone()} {size}
+                    color={error.map(|_| Color::Danger)} {onvaluechange} />
+            </Control>
+            if let Some(error) = error {
+                <Help color={Color::Danger}>{error}</Help>
+            }
+        </Field>
+    }
+}
+
+fn submit_field(
+    label: &AttrValue,
+    color: Option<Color>,
+    size: Option<Size>,
+    loading: bool,
+    error: Option<&AttrValue>,
+) -> Html {
+    let state = loading.then(|| State::Loading);
+
+    html! {
+        <Field>
+            if let Some(error) = error {
+                <Help color={Color::Danger}>{error.clone()}</Help>
+            }
+            <Control>
+                <Button button_type={ButtonType::Submit} {color} {size} {state} fullwidth=true>
+                    {label.clone()}
+                </Button>
+            </Control>
+        </Field>
+    }
+}
+
+fn validate_email(email: &str) -> Option<&'static str> {
+    let email = email.trim();
+    let valid = email.split_once('@').map_or(false, |(local, domain)| {
+        !local.is_empty()
+            && !domain.contains('@')
+            && domain.split('.').count() > 1
+            && domain.split('.').all(|label| !label.is_empty())
+            && !email.contains(char::is_whitespace)
+    });
+
+    if email.is_empty() {
+        Some("Email is required")
+    } else if !valid {
+        Some("Invalid email address")
+    } else {
+        None
+    }
+}
+
+fn validate_password(password: &str, min_length: usize) -> Option<&'static str> {
+    if password.is_empty() {
+        Some("Password is required")
+    } else if password.chars().count() < min_length {
+        Some("Password is too short")
+    } else {
+        None
+    }
+}
+
+fn validate_confirmation(password: &str, confirmation: &str) -> Option<&'static str> {
+    (password != confirmation).then(|| "Passwords do not match")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("US", "City", "State", "ZIP code" ; "united states")]
+    #[test_case("gb", "Town/City", "County", "Postcode" ; "lowercase code")]
+    #[test_case(" AU ", "Suburb", "State", "Postcode" ; "surrounding whitespace")]
+    #[test_case("RO", "City", "Region", "Postal code" ; "unknown country")]
+    #[test_case("", "City", "Region", "Postal code" ; "no country")]
+    fn address_labels_for_country(
+        country: &str,
+        city: &'static str,
+        region: &'static str,
+        postal_code: &'static str,
+    ) {
+        let expected = AddressLabels {
+            street: "Street address",
+            city,
+            region,
+            postal_code,
+        };
+
+        assert_eq!(AddressLabels::for_country(country), expected);
+    }
+
+    #[test_case("4242424242424242", Some(CardBrand::Visa) ; "visa")]
+    #[test_case("5555 5555 5555 4444", Some(CardBrand::Mastercard) ; "mastercard")]
+    #[test_case("2223003122003222", Some(CardBrand::Mastercard) ; "mastercard 2 series")]
+    #[test_case("378282246310005", Some(CardBrand::AmericanExpress) ; "american express")]
+    #[test_case("6011111111111117", Some(CardBrand::Discover) ; "discover")]
+    #[test_case("3530111333300000", None ; "unknown brand")]
+    #[test_case("", None ; "empty number")]
+    fn card_brand_detect(number: &str, expected: Option<CardBrand>) {
+        assert_eq!(CardBrand::detect(number), expected);
+    }
+
+    #[test_case("4242424242424242", true ; "valid visa")]
+    #[test_case("4242 4242 4242 4242", true ; "spaces are ignored")]
+    #[test_case("4242424242424241", false ; "wrong checksum")]
+    #[test_case("378282246310005", true ; "valid american express")]
+    #[test_case("42424242424242", false ; "wrong length")]
+    #[test_case("", false ; "empty number")]
+    fn card_number_validity(number: &str, expected: bool) {
+        assert_eq!(card_number_valid(number), expected);
+    }
+
+    #[test_case("4242424242424242", "4242 4242 4242 4242" ; "groups of four")]
+    #[test_case("424242", "4242 42" ; "partial number")]
+    #[test_case("378282246310005", "3782 822463 10005" ; "american express groups")]
+    #[test_case("4242-4242", "4242 4242" ; "other characters are removed")]
+    #[test_case("", "" ; "empty number")]
+    fn card_number_format(number: &str, expected: &str) {
+        assert_eq!(format_card_number(number), expected);
+    }
+
+    #[test_case("1", "1" ; "single digit month")]
+    #[test_case("4", "04" ; "month is padded")]
+    #[test_case("123", "12/3" ; "slash is added")]
+    #[test_case("12/345", "12/34" ; "extra digits are removed")]
+    #[test_case("", "" ; "empty expiry")]
+    fn expiry_format(value: &str, expected: &str) {
+        assert_eq!(format_expiry(value), expected);
+    }
+
+    #[test_case("12/34", Some((12, 2034)) ; "valid expiry")]
+    #[test_case("13/34", None ; "invalid month")]
+    #[test_case("12/3", None ; "incomplete year")]
+    #[test_case("1234", None ; "no slash")]
+    fn expiry_parse(value: &str, expected: Option<(u32, u32)>) {
+        assert_eq!(parse_expiry(value), expected);
+    }
+
+    #[test_case("jane@example.com", None ; "valid email")]
+    #[test_case("  jane@example.com ", None ; "surrounding whitespace")]
+    #[test_case("", Some("Email is required") ; "empty email")]
+    #[test_case("jane", Some("Invalid email address") ; "no at sign")]
+    #[test_case("@example.com", Some("Invalid email address") ; "no local part")]
+    #[test_case("jane@example", Some("Invalid email address") ; "no top level domain")]
+    #[test_case("jane@example.", Some("Invalid email address") ; "empty top level domain")]
+    #[test_case("jane@@example.com", Some("Invalid email address") ; "two at signs")]
+    #[test_case("jane doe@example.com", Some("Invalid email address") ; "inner whitespace")]
+    fn email_validation(email: &str, expected: Option<&str>) {
+        assert_eq!(validate_email(email), expected);
+    }
+
+    #[test_case("secret", 0, None ; "no minimum length")]
+    #[test_case("", 0, Some("Password is required") ; "empty password")]
+    #[test_case("secret", 8, Some("Password is too short") ; "too short")]
+    #[test_case("pässwörd", 8, None ; "counts characters")]
+    fn password_validation(password: &str, min_length: usize, expected: Option<&str>) {
+        assert_eq!(validate_password(password, min_length), expected);
+    }
+
+    #[test_case("secret", "secret", None ; "matching")]
+    #[test_case("secret", "Secret", Some("Passwords do not match") ; "different")]
+    fn confirmation_validation(password: &str, confirmation: &str, expected: Option<&str>) {
+        assert_eq!(validate_confirmation(password, confirmation), expected);
+    }
+}
@@ -0,0 +1,254 @@
+use yew::{
+    classes, function_component, html, use_effect_with_deps, use_mut_ref, use_state, AttrValue,
+    Callback, Html, MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::{delete::Delete, icon::Icon},
+    form::{general::Control, input::Input},
+    helpers::color::Color,
+    utils::{hooks::use_debounce, size::Size},
+};
+
+/// Defines the properties of the search input.
+///
+/// Defines the properties of the search input, a
+/// [Bulma input element][bd] with a search icon and a clear button, which
+/// emits its value once the user stops typing.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::search::SearchInput;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let onsearch = Callback::from(|query: String| {
+///         let _ = query;
+///     });
+///
+///     html! {
+///         <SearchInput {onsearch} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/input/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct SearchInputProperties {
+    /// Sets the initial value of the search input.
+    ///
+    /// Sets the text found inside the [Bulma input element][bd] used by the
+    /// search input which will receive these properties, when it is first
+    /// rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::search::SearchInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <SearchInput value="bulma" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    #[prop_or_default]
+    pub value: AttrValue,
+    /// Sets the placeholder of the search input.
+    ///
+    /// Sets the placeholder of the [Bulma input element][bd] used by the
+    /// search input which will receive these properties. Defaults to
+    /// `Search`.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/
+    #[prop_or(AttrValue::from("Search"))]
+    pub placeholder: AttrValue,
+    /// Sets the delay after which the search is emitted.
+    ///
+    /// Sets the number of milliseconds the user must stop typing for, before
+    /// the search input which will receive these properties emits the
+    /// [`SearchInputProperties::onsearch`] callback. Defaults to `300`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::search::SearchInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <SearchInput delay={500} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(300)]
+    pub delay: u32,
+    /// Sets the icon of the search input.
+    ///
+    /// Sets the icon shown on the left of the search input which will
+    /// receive these properties. Defaults to a magnifying glass.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::search::SearchInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <SearchInput icon={html! { <i class="fas fa-search"></i> }} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub icon: Option<Html>,
+    /// Sets the color of the search input.
+    ///
+    /// Sets the color of the [Bulma input element][bd] used by the search
+    /// input which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the search input.
+    ///
+    /// Sets the size of the [Bulma input element][bd] used by the search
+    /// input which will receive these properties.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the search input should be rounded.
+    ///
+    /// Whether or not the [Bulma input element][bd] used by the search input,
+    /// which will receive these properties, will be rounded.
+    ///
+    /// [bd]: https://bulma.io/documentation/form/input/#styles
+    #[prop_or_default]
+    pub rounded: bool,
+    /// Sets the callback used when the user searches.
+    ///
+    /// Sets the callback called with the text of the search input, which will
+    /// receive these properties, once the user stopped typing for its
+    /// [`SearchInputProperties::delay`], or right away when it is cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::search::SearchInput;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let query = use_state(String::new);
+    ///     let onsearch = {
+    ///         let query = query.clone();
+    ///         Callback::from(move |value| query.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <SearchInput {onsearch} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onsearch: Option<Callback<String>>,
+}
+
+/// Yew implementation of the search input.
+///
+/// Yew implementation of the search input, a [Bulma input element][bd] with
+/// a search icon on its left and, while it is not empty, a button used to
+/// clear it on its right. Its value is emitted only after the user stops
+/// typing, using [`use_debounce`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::search::SearchInput;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <SearchInput placeholder="Search the docs" delay={200} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/input/
+#[function_component(SearchInput)]
+pub fn search_input(props: &SearchInputProperties) -> Html {
+    let text = use_state(|| props.value.to_string());
+    let emitted = use_mut_ref(|| props.value.to_string());
+    let debounced = use_debounce((*text).clone(), props.delay);
+
+    {
+        let emitted = emitted.clone();
+        let onsearch = props.onsearch.clone();
+        use_effect_with_deps(
+            move |debounced| {
+                if *emitted.borrow() != *debounced {
+                    *emitted.borrow_mut() = debounced.clone();
+                    if let Some(onsearch) = &onsearch {
+                        onsearch.emit(debounced.clone());
+                    }
+                }
+            },
+            debounced,
+        );
+    }
+
+    let onvaluechange = {
+        let text = text.clone();
+        Callback::from(move |value: String| text.set(value))
+    };
+    let onclear = {
+        let text = text.clone();
+        let onsearch = props.onsearch.clone();
+        Callback::from(move |_: MouseEvent| {
+            text.set(String::new());
+            *emitted.borrow_mut() = String::new();
+            if let Some(onsearch) = &onsearch {
+                onsearch.emit(String::new());
+            }
+        })
+    };
+    let icon = props.icon.clone().unwrap_or_else(|| html! { "\u{1f50d}" });
+
+    html! {
+        <Control has_icons_left=true has_icons_right=true>
+            <Input id={props.id.clone()} class={props.class.clone()} input_type="search"
+                value={AttrValue::from((*text).clone())} placeholder={props.placeholder.clone()}
+                color={props.color} size={props.size} rounded={props.rounded} {onvaluechange}
+                onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+                onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+                ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+                oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+                onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+                onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+                onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+                ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+                onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+                onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+                onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+                ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()} />
+            <Icon class={classes!("is-left")} size={Size::Small} {icon} />
+            if !text.is_empty() {
+                <span class="icon is-small is-right" style="pointer-events: auto;">
+                    <Delete size={Size::Small} onclick={onclear} />
+                </span>
+            }
+        </Control>
+    }
+}
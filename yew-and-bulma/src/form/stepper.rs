@@ -0,0 +1,269 @@
+use yew::{function_component, html, Callback, Html, MouseEvent, Properties};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::button::{Button, Buttons, State},
+    helpers::color::Color,
+    utils::size::Size,
+};
+
+/// Defines the properties of the quantity stepper.
+///
+/// Defines the properties of the quantity stepper, a value placed between a
+/// minus and a plus button, attached together inside a
+/// [Bulma buttons element][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::stepper::QuantityStepper;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <QuantityStepper value={1} min={1} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/button/#list-of-buttons
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct QuantityStepperProperties {
+    /// Sets the value of the quantity stepper.
+    ///
+    /// Sets the value shown between the buttons of the quantity stepper
+    /// which will receive these properties. The value is controlled by its
+    /// parent, through the [`QuantityStepperProperties::onquantitychange`]
+    /// callback.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::stepper::QuantityStepper;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <QuantityStepper value={3} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub value: i64,
+    /// Sets the minimum value of the quantity stepper.
+    ///
+    /// Sets the smallest value the quantity stepper which will receive these
+    /// properties can step to. Its minus button is disabled once it is
+    /// reached.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::stepper::QuantityStepper;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <QuantityStepper value={1} min={1} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub min: Option<i64>,
+    /// Sets the maximum value of the quantity stepper.
+    ///
+    /// Sets the largest value the quantity stepper which will receive these
+    /// properties can step to. Its plus button is disabled once it is
+    /// reached.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::stepper::QuantityStepper;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <QuantityStepper value={1} min={1} max={10} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub max: Option<i64>,
+    /// Sets the step of the quantity stepper.
+    ///
+    /// Sets the amount added or subtracted when clicking the buttons of the
+    /// quantity stepper which will receive these properties. Defaults to
+    /// `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::stepper::QuantityStepper;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <QuantityStepper value={0} step={5} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(1)]
+    pub step: i64,
+    /// Sets the color of the buttons of the quantity stepper.
+    ///
+    /// Sets the color of the minus and plus buttons of the quantity stepper
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::stepper::QuantityStepper, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <QuantityStepper color={Color::Primary} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the quantity stepper.
+    ///
+    /// Sets the size of the buttons and value of the quantity stepper which
+    /// will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::stepper::QuantityStepper, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <QuantityStepper size={Size::Small} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the quantity stepper is disabled.
+    ///
+    /// Whether or not both buttons of the quantity stepper which will
+    /// receive these properties are disabled.
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Sets the callback used when the value changes.
+    ///
+    /// Sets the callback called with the new value when one of the buttons
+    /// of the quantity stepper which will receive these properties is
+    /// clicked. The new value is always kept between the minimum and
+    /// maximum values, if set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::stepper::QuantityStepper;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let quantity = use_state(|| 1);
+    ///     let onquantitychange = {
+    ///         let quantity = quantity.clone();
+    ///         Callback::from(move |value| quantity.set(value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <QuantityStepper value={*quantity} min={1} {onquantitychange} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onquantitychange: Option<Callback<i64>>,
+}
+
+/// Yew implementation of the quantity stepper.
+///
+/// Yew implementation of the quantity stepper, a compact control rendering
+/// a minus button, the current value and a plus button attached together
+/// inside a [Bulma buttons element][bd], commonly used in shopping carts.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::stepper::QuantityStepper;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <QuantityStepper value={1} min={1} max={10} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/button/#list-of-buttons
+#[function_component(QuantityStepper)]
+pub fn quantity_stepper(props: &QuantityStepperProperties) -> Html {
+    let decreased = stepped(props.value, -props.step, props.min, props.max);
+    let increased = stepped(props.value, props.step, props.min, props.max);
+    let onchange = |value: i64| {
+        props.onquantitychange.clone().map(|onquantitychange| {
+            Callback::from(move |_: MouseEvent| onquantitychange.emit(value))
+        })
+    };
+
+    html! {
+        <Buttons id={props.id.clone()} class={props.class.clone()} addons=true size={props.size}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <Button color={props.color} disabled={props.disabled || decreased == props.value} onclick={onchange(decreased)}>{"\u{2212}"}</Button>
+            <Button state={State::Static}>{props.value}</Button>
+            <Button color={props.color} disabled={props.disabled || increased == props.value} onclick={onchange(increased)}>{"+"}</Button>
+        </Buttons>
+    }
+}
+
+fn stepped(value: i64, step: i64, min: Option<i64>, max: Option<i64>) -> i64 {
+    let value = value.saturating_add(step);
+    let value = min.map(|min| value.max(min)).unwrap_or(value);
+
+    max.map(|max| value.min(max)).unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(1, 1, None, None, 2 ; "increase without bounds")]
+    #[test_case(1, -1, None, None, 0 ; "decrease without bounds")]
+    #[test_case(1, -1, Some(1), None, 1 ; "stops at min")]
+    #[test_case(9, 5, None, Some(10), 10 ; "stops at max")]
+    #[test_case(0, 5, Some(1), Some(10), 5 ; "step inside bounds")]
+    #[test_case(-3, 1, Some(0), None, 0 ; "value below min is brought back")]
+    #[test_case(i64::MAX, 1, None, None, i64::MAX ; "does not overflow")]
+    fn stepped_values(value: i64, step: i64, min: Option<i64>, max: Option<i64>, expected: i64) {
+        assert_eq!(stepped(value, step, min, max), expected);
+    }
+}
@@ -0,0 +1,493 @@
+use web_sys::HtmlTextAreaElement;
+use yew::{
+    function_component, html, use_effect_with_deps, use_state, AttrValue, Callback, Html,
+    InputEvent, Properties, TargetCast,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    form::input::{text_length, with_counter},
+    helpers::color::Color,
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
+
+/// Defines the properties of the [Bulma textarea element][bd].
+///
+/// Defines the properties of the textarea element, based on the
+/// specification found in the [Bulma textarea element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::textarea::TextArea;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <TextArea placeholder="e.g. Hello world" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/textarea/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TextAreaProperties {
+    /// Sets the value of the [Bulma textarea element][bd].
+    ///
+    /// Sets the value of the [Bulma textarea element][bd] which will receive
+    /// these properties. Used together with `onvaluechange` to create
+    /// controlled textareas.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let value = use_state(String::new);
+    ///     let onvaluechange = {
+    ///         let value = value.clone();
+    ///         Callback::from(move |new_value| value.set(new_value))
+    ///     };
+    ///
+    ///     html! {
+    ///         <TextArea value={(*value).clone()} {onvaluechange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/
+    #[prop_or_default]
+    pub value: AttrValue,
+    /// Sets the name of the [Bulma textarea element][bd].
+    ///
+    /// Sets the name, used when submitting forms, of the
+    /// [Bulma textarea element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea name="message" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/
+    #[prop_or_default]
+    pub name: Option<AttrValue>,
+    /// Sets the placeholder of the [Bulma textarea element][bd].
+    ///
+    /// Sets the placeholder text shown by the [Bulma textarea element][bd],
+    /// which will receive these properties, while it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea placeholder="e.g. Hello world" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+    /// Sets the number of rows of the [Bulma textarea element][bd].
+    ///
+    /// Sets the number of visible text lines of the
+    /// [Bulma textarea element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea rows={10} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#rows
+    #[prop_or_default]
+    pub rows: Option<u32>,
+    /// Sets the color of the [Bulma textarea element][bd].
+    ///
+    /// Sets the color of the [Bulma textarea element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::textarea::TextArea, helpers::color::Color};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea color={Color::Primary} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+    /// Sets the size of the [Bulma textarea element][bd].
+    ///
+    /// Sets the size of the [Bulma textarea element][bd] which will receive
+    /// these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{form::textarea::TextArea, utils::size::Size};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea size={Size::Large} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#sizes
+    #[prop_or_default]
+    pub size: Option<Size>,
+    /// Whether or not the [textarea element][bd] has a fixed size.
+    ///
+    /// Whether or not the [Bulma textarea element][bd], which will receive
+    /// these properties, can not be resized by the user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea fixed_size=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#fixed-size
+    #[prop_or_default]
+    pub fixed_size: bool,
+    /// Whether or not the [textarea element][bd] is loading.
+    ///
+    /// Whether or not the [Bulma textarea element][bd], which will receive
+    /// these properties, should display a loading indicator. Since Bulma
+    /// displays it using the control element, the textarea is wrapped inside
+    /// a loading [`crate::form::general::Control`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea loading=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#states
+    #[prop_or_default]
+    pub loading: bool,
+    /// Whether or not the [textarea element][bd] is disabled.
+    ///
+    /// Whether or not the [Bulma textarea element][bd], which will receive
+    /// these properties, should be disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea disabled=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#states
+    #[prop_or_default]
+    pub disabled: bool,
+    /// Whether or not the [textarea element][bd] is read only.
+    ///
+    /// Whether or not the value of the [Bulma textarea element][bd], which
+    /// will receive these properties, can be changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea value="This text is readonly" readonly=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/#states
+    #[prop_or_default]
+    pub readonly: bool,
+    /// Sets the maximum number of characters of the [textarea element][bd].
+    ///
+    /// Sets the maximum number of characters of the
+    /// [Bulma textarea element][bd] which will receive these properties,
+    /// counted in UTF-16 code units like the HTML `maxlength` attribute.
+    /// Going over it turns the textarea red, unless
+    /// [`TextAreaProperties::hard_limit`] is set, in which case no more
+    /// characters can be typed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea max_length={500} show_counter=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/
+    #[prop_or_default]
+    pub max_length: Option<usize>,
+    /// Whether or not the maximum length of the [textarea element][bd] is enforced.
+    ///
+    /// Whether or not the [Bulma textarea element][bd], which will receive
+    /// these properties, prevents typing more characters than its
+    /// [`TextAreaProperties::max_length`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea max_length={280} hard_limit=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/
+    #[prop_or_default]
+    pub hard_limit: bool,
+    /// Whether or not to show a character counter under the [textarea element][bd].
+    ///
+    /// Whether or not to show a [Bulma help text][help] under the
+    /// [Bulma textarea element][bd], which will receive these properties,
+    /// counting its characters (ie `123/500`). The counter turns red once the
+    /// [`TextAreaProperties::max_length`] is exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <TextArea max_length={500} show_counter=true />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/
+    /// [help]: https://bulma.io/documentation/form/general/#form-field
+    #[prop_or_default]
+    pub show_counter: bool,
+    /// The callback to be used when the value of the textarea changes.
+    ///
+    /// Defines the callback called with the current value of the
+    /// [Bulma textarea element][bd], which will receive these properties,
+    /// each time it is changed by the user.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::form::textarea::TextArea;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onvaluechange = Callback::from(|value: String| {
+    ///         let _lines = value.lines().count();
+    ///     });
+    ///
+    ///     html! {
+    ///         <TextArea {onvaluechange} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/form/textarea/
+    #[prop_or_default]
+    pub onvaluechange: Option<Callback<String>>,
+}
+
+/// Yew implementation of the [Bulma textarea element][bd].
+///
+/// Yew implementation of the textarea element, based on the specification
+/// found in the [Bulma textarea element documentation][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::form::{
+///     general::{Control, Field, Label},
+///     textarea::TextArea,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Field>
+///             <Label>{"Message"}</Label>
+///             <Control>
+///                 <TextArea placeholder="Textarea" />
+///             </Control>
+///         </Field>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/form/textarea/
+#[function_component(TextArea)]
+pub fn textarea(props: &TextAreaProperties) -> Html {
+    let length = use_state(|| text_length(&props.value));
+    {
+        let length = length.clone();
+        use_effect_with_deps(
+            move |value| length.set(text_length(value)),
+            props.value.clone(),
+        );
+    }
+    let exceeded = props
+        .max_length
+        .map(|max_length| *length > max_length)
+        .unwrap_or_default();
+    let color = if exceeded {
+        Some(Color::Danger)
+    } else {
+        props.color
+    };
+    let size = props
+        .size
+        .as_ref()
+        .map(|size| format!("{IS_PREFIX}-{size}"))
+        .unwrap_or("".to_owned());
+    let fixed_size = if props.fixed_size {
+        "has-fixed-size"
+    } else {
+        ""
+    };
+    let class = ClassBuilder::default()
+        .with_custom_class("textarea")
+        .with_color(color)
+        .with_custom_class(&size)
+        .with_custom_class(fixed_size)
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let oninput = {
+        let oninput = props.oninput.clone();
+        let onvaluechange = props.onvaluechange.clone();
+        let length = length.clone();
+        Callback::from(move |event: InputEvent| {
+            let value = event.target_unchecked_into::<HtmlTextAreaElement>().value();
+            length.set(text_length(&value));
+            if let Some(onvaluechange) = &onvaluechange {
+                onvaluechange.emit(value);
+            }
+            if let Some(oninput) = &oninput {
+                oninput.emit(event);
+            }
+        })
+    };
+
+    let textarea = html! {
+        <textarea id={props.id.clone()} {class} value={&props.value}
+            name={props.name.clone()} placeholder={props.placeholder.clone()}
+            rows={props.rows.map(|rows| rows.to_string())}
+            disabled={props.disabled} readonly={props.readonly}
+            maxlength={props.max_length.filter(|_| props.hard_limit).map(|max_length| max_length.to_string())}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} {oninput} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()} />
+    };
+
+    let textarea = if props.loading {
+        let class = ClassBuilder::default()
+            .with_custom_class("control")
+            .with_custom_class(&format!("{IS_PREFIX}-loading"))
+            .build();
+
+        html! {
+            <div {class}>{ textarea }</div>
+        }
+    } else {
+        textarea
+    };
+
+    if props.show_counter {
+        with_counter(textarea, *length, props.max_length)
+    } else {
+        textarea
+    }
+}
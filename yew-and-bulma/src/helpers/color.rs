@@ -1,5 +1,22 @@
 use std::fmt::Display;
 
+/// Provides utilities for computing the contrast between colors.
+///
+/// Defines functions to compute [WCAG contrast ratios][wcag] between the
+/// default Bulma colors, or custom ones, and to pick a readable text color
+/// for a background.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::helpers::color::{contrast::text_color_for, BackgroundColor, TextColor};
+///
+/// assert_eq!(text_color_for(BackgroundColor::Black), TextColor::White);
+/// ```
+///
+/// [wcag]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+pub mod contrast;
+
 /// Enum defining the possible text colors, as described in the
 /// [Bulma documentation][bd].
 ///
@@ -55,6 +72,7 @@ use std::fmt::Display;
 ///
 /// [bd]: https://bulma.io/documentation/helpers/color-helpers/#text-color
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum TextColor {
     White,
     Black,
@@ -75,6 +93,12 @@ pub enum TextColor {
     GreyLighter,
     WhiteTer,
     WhiteBis,
+    /// A shade of a color, from the [Bulma v1 color palettes][cp] (ie
+    /// `has-text-primary-35`). Requires the `bulma-v1` feature.
+    ///
+    /// [cp]: https://bulma.io/documentation/features/color-palettes/
+    #[cfg(feature = "bulma-v1")]
+    Shade(PaletteColor, ColorShade),
 }
 
 impl Display for TextColor {
@@ -99,6 +123,8 @@ impl Display for TextColor {
             TextColor::GreyLighter => "grey-lighter",
             TextColor::WhiteTer => "white-ter",
             TextColor::WhiteBis => "white-bis",
+            #[cfg(feature = "bulma-v1")]
+            TextColor::Shade(color, shade) => return write!(f, "{color}-{shade}"),
         };
         write!(f, "{color_name}")
     }
@@ -158,7 +184,8 @@ impl Display for TextColor {
 /// ```
 ///
 /// [bd]: https://bulma.io/documentation/helpers/color-helpers/#background-color
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum BackgroundColor {
     White,
     Black,
@@ -191,6 +218,12 @@ pub enum BackgroundColor {
     SuccessDark,
     WarningDark,
     DangerDark,
+    /// A shade of a color, from the [Bulma v1 color palettes][cp] (ie
+    /// `has-background-danger-90`). Requires the `bulma-v1` feature.
+    ///
+    /// [cp]: https://bulma.io/documentation/features/color-palettes/
+    #[cfg(feature = "bulma-v1")]
+    Shade(PaletteColor, ColorShade),
 }
 
 impl Display for BackgroundColor {
@@ -227,6 +260,8 @@ impl Display for BackgroundColor {
             BackgroundColor::SuccessDark => "success-dark",
             BackgroundColor::WarningDark => "warning-dark",
             BackgroundColor::DangerDark => "danger-dark",
+            #[cfg(feature = "bulma-v1")]
+            BackgroundColor::Shade(color, shade) => return write!(f, "{color}-{shade}"),
         };
         write!(f, "{color_name}")
     }
@@ -303,6 +338,24 @@ pub enum Color {
     Danger,
 }
 
+impl Color {
+    /// Every color, in the order they are declared.
+    pub const ALL: [Color; 12] = [
+        Color::White,
+        Color::Black,
+        Color::Light,
+        Color::Dark,
+        Color::Text,
+        Color::Ghost,
+        Color::Primary,
+        Color::Link,
+        Color::Info,
+        Color::Success,
+        Color::Warning,
+        Color::Danger,
+    ];
+}
+
 impl Display for Color {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let color = match self {
@@ -324,6 +377,132 @@ impl Display for Color {
     }
 }
 
+/// Enum defining the colors which have shades, as described in the
+/// [Bulma documentation][bd].
+///
+/// Defines the colors of the [Bulma v1 color palettes][bd], which are the
+/// only ones providing shades. Used together with a
+/// [`crate::helpers::color::ColorShade`] to create the
+/// [`crate::helpers::color::TextColor::Shade`] and
+/// [`crate::helpers::color::BackgroundColor::Shade`] colors. Requires the
+/// `bulma-v1` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::helpers::color::{ColorShade, PaletteColor, TextColor};
+///
+/// let color = ColorShade::new(35).map(|shade| TextColor::Shade(PaletteColor::Primary, shade));
+///
+/// assert_eq!(color.map(|color| color.to_string()).as_deref(), Some("primary-35"));
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/features/color-palettes/
+#[cfg(feature = "bulma-v1")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PaletteColor {
+    Text,
+    Primary,
+    Link,
+    Info,
+    Success,
+    Warning,
+    Danger,
+}
+
+#[cfg(feature = "bulma-v1")]
+impl From<PaletteColor> for Color {
+    fn from(color: PaletteColor) -> Self {
+        match color {
+            PaletteColor::Text => Color::Text,
+            PaletteColor::Primary => Color::Primary,
+            PaletteColor::Link => Color::Link,
+            PaletteColor::Info => Color::Info,
+            PaletteColor::Success => Color::Success,
+            PaletteColor::Warning => Color::Warning,
+            PaletteColor::Danger => Color::Danger,
+        }
+    }
+}
+
+#[cfg(feature = "bulma-v1")]
+impl Display for PaletteColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Color::from(*self))
+    }
+}
+
+/// The shade of a color, as described in the [Bulma documentation][bd].
+///
+/// Defines the lightness of a color from the [Bulma v1 color palettes][bd],
+/// which provide shades from `00` (black) to `100` (white) in steps of `5`,
+/// for each [`crate::helpers::color::PaletteColor`]. Used together with
+/// [`crate::helpers::color::TextColor::Shade`] or
+/// [`crate::helpers::color::BackgroundColor::Shade`] to create classes such
+/// as `has-text-primary-35` or `has-background-danger-90`. Requires the
+/// `bulma-v1` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     helpers::color::{BackgroundColor, ColorShade, PaletteColor, TextColor},
+///     utils::class::ClassBuilder,
+/// };
+///
+/// // Create a `<div>` HTML element that uses shades of the danger color.
+/// #[function_component(ShadedDiv)]
+/// fn shaded_div() -> Html {
+///     let class = ClassBuilder::default()
+///         .with_text_color(ColorShade::new(35).map(|shade| TextColor::Shade(PaletteColor::Danger, shade)))
+///         .with_background_color(
+///             ColorShade::new(90).map(|shade| BackgroundColor::Shade(PaletteColor::Danger, shade)),
+///         )
+///         .build();
+///     html!{
+///         <div class={class}>{ "Lorem ispum..." }</div>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/features/color-palettes/
+#[cfg(feature = "bulma-v1")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColorShade(u8);
+
+#[cfg(feature = "bulma-v1")]
+impl ColorShade {
+    /// Creates a shade, if it is part of the Bulma color palettes.
+    ///
+    /// Returns the shade with the given lightness, as a percentage, if it is
+    /// a multiple of `5` between `0` and `100`, or `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_and_bulma::helpers::color::ColorShade;
+    ///
+    /// assert!(ColorShade::new(25).is_some());
+    /// assert!(ColorShade::new(33).is_none());
+    /// ```
+    pub fn new(lightness: u8) -> Option<Self> {
+        (lightness <= 100 && lightness % 5 == 0).then(|| Self(lightness))
+    }
+
+    /// Returns the lightness of the shade, as a percentage.
+    pub fn lightness(&self) -> u8 {
+        self.0
+    }
+}
+
+#[cfg(feature = "bulma-v1")]
+impl Display for ColorShade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -408,4 +587,32 @@ mod tests {
 
         assert_eq!(converted_color, expected_color);
     }
+
+    #[cfg(feature = "bulma-v1")]
+    #[test_case(0, Some("00") ; "zero is a shade")]
+    #[test_case(5, Some("05") ; "five is a shade")]
+    #[test_case(35, Some("35") ; "thirty five is a shade")]
+    #[test_case(100, Some("100") ; "one hundred is a shade")]
+    #[test_case(33, None ; "thirty three is not a shade")]
+    #[test_case(105, None ; "one hundred five is not a shade")]
+    fn color_shade_new(lightness: u8, expected_shade: Option<&str>) {
+        let shade = ColorShade::new(lightness).map(|shade| shade.to_string());
+
+        assert_eq!(shade.as_deref(), expected_shade);
+    }
+
+    #[cfg(feature = "bulma-v1")]
+    #[test]
+    fn shade_colors_to_string() {
+        let shade = ColorShade::new(35).unwrap();
+
+        assert_eq!(
+            TextColor::Shade(PaletteColor::Primary, shade).to_string(),
+            "primary-35"
+        );
+        assert_eq!(
+            BackgroundColor::Shade(PaletteColor::Danger, shade).to_string(),
+            "danger-35"
+        );
+    }
 }
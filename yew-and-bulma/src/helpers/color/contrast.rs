@@ -0,0 +1,321 @@
+use super::{BackgroundColor, Color, TextColor};
+
+/// Defines a color using its red, green and blue channels.
+///
+/// Defines an opaque color, used to compute the [WCAG contrast ratio][wcag]
+/// between a text and its background.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::helpers::color::contrast::Rgb;
+///
+/// assert_eq!(Rgb::from_hex("#00d1b2"), Some(Rgb(0, 209, 178)));
+/// assert_eq!(Rgb::from_hex("fff"), Some(Rgb(255, 255, 255)));
+/// ```
+///
+/// [wcag]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// Parses a color written in hexadecimal notation (ie `#00d1b2` or
+    /// `#fff`), with or without the leading `#`.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.trim_start_matches('#');
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+
+        match hex.len() {
+            3 => {
+                let mut channels = hex
+                    .chars()
+                    .map(|digit| channel(&digit.to_string().repeat(2)));
+                Some(Rgb(channels.next()??, channels.next()??, channels.next()??))
+            }
+            6 => Some(Rgb(
+                channel(hex.get(0..2)?)?,
+                channel(hex.get(2..4)?)?,
+                channel(hex.get(4..6)?)?,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Returns the [relative luminance][rl] of the color, between `0.0`
+    /// (black) and `1.0` (white).
+    ///
+    /// [rl]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    pub fn luminance(&self) -> f64 {
+        let linear = |channel: u8| {
+            let channel = f64::from(channel) / 255.0;
+            if channel <= 0.03928 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * linear(self.0) + 0.7152 * linear(self.1) + 0.0722 * linear(self.2)
+    }
+
+    #[cfg(feature = "bulma-v1")]
+    fn with_lightness(self, lightness: f64) -> Self {
+        let (red, green, blue) = (
+            f64::from(self.0) / 255.0,
+            f64::from(self.1) / 255.0,
+            f64::from(self.2) / 255.0,
+        );
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let delta = max - min;
+        let saturation = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (max + min - 1.0).abs())
+        };
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == red {
+            ((green - blue) / delta).rem_euclid(6.0)
+        } else if max == green {
+            (blue - red) / delta + 2.0
+        } else {
+            (red - green) / delta + 4.0
+        };
+
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let second = chroma * (1.0 - (hue.rem_euclid(2.0) - 1.0).abs());
+        let (red, green, blue) = match hue as u8 {
+            0 => (chroma, second, 0.0),
+            1 => (second, chroma, 0.0),
+            2 => (0.0, chroma, second),
+            3 => (0.0, second, chroma),
+            4 => (second, 0.0, chroma),
+            _ => (chroma, 0.0, second),
+        };
+        let offset = lightness - chroma / 2.0;
+        let channel = |value: f64| ((value + offset) * 255.0).round() as u8;
+
+        Rgb(channel(red), channel(green), channel(blue))
+    }
+}
+
+impl From<Color> for Rgb {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::White => TextColor::White.into(),
+            Color::Black => TextColor::Black.into(),
+            Color::Light => TextColor::Light.into(),
+            Color::Dark => TextColor::Dark.into(),
+            Color::Text => TextColor::GreyDark.into(),
+            Color::Ghost => TextColor::Link.into(),
+            Color::Primary => TextColor::Primary.into(),
+            Color::Link => TextColor::Link.into(),
+            Color::Info => TextColor::Info.into(),
+            Color::Success => TextColor::Success.into(),
+            Color::Warning => TextColor::Warning.into(),
+            Color::Danger => TextColor::Danger.into(),
+        }
+    }
+}
+
+impl From<TextColor> for Rgb {
+    fn from(color: TextColor) -> Self {
+        match color {
+            TextColor::White => Rgb(255, 255, 255),
+            TextColor::Black => Rgb(10, 10, 10),
+            TextColor::Light => Rgb(245, 245, 245),
+            TextColor::Dark => Rgb(54, 54, 54),
+            TextColor::Primary => Rgb(0, 209, 178),
+            TextColor::Link => Rgb(72, 95, 199),
+            TextColor::Info => Rgb(62, 142, 208),
+            TextColor::Success => Rgb(72, 199, 142),
+            TextColor::Warning => Rgb(255, 224, 138),
+            TextColor::Danger => Rgb(241, 70, 104),
+            TextColor::BlackBis => Rgb(18, 18, 18),
+            TextColor::BlackTer => Rgb(36, 36, 36),
+            TextColor::GreyDarker => Rgb(54, 54, 54),
+            TextColor::GreyDark => Rgb(74, 74, 74),
+            TextColor::Grey => Rgb(122, 122, 122),
+            TextColor::GreyLight => Rgb(181, 181, 181),
+            TextColor::GreyLighter => Rgb(219, 219, 219),
+            TextColor::WhiteTer => Rgb(245, 245, 245),
+            TextColor::WhiteBis => Rgb(250, 250, 250),
+            #[cfg(feature = "bulma-v1")]
+            TextColor::Shade(color, shade) => {
+                Rgb::from(Color::from(color)).with_lightness(f64::from(shade.lightness()) / 100.0)
+            }
+        }
+    }
+}
+
+impl From<BackgroundColor> for Rgb {
+    fn from(color: BackgroundColor) -> Self {
+        match color {
+            BackgroundColor::White => TextColor::White.into(),
+            BackgroundColor::Black => TextColor::Black.into(),
+            BackgroundColor::Light => TextColor::Light.into(),
+            BackgroundColor::Dark => TextColor::Dark.into(),
+            BackgroundColor::Primary => TextColor::Primary.into(),
+            BackgroundColor::Link => TextColor::Link.into(),
+            BackgroundColor::Info => TextColor::Info.into(),
+            BackgroundColor::Success => TextColor::Success.into(),
+            BackgroundColor::Warning => TextColor::Warning.into(),
+            BackgroundColor::Danger => TextColor::Danger.into(),
+            BackgroundColor::BlackBis => TextColor::BlackBis.into(),
+            BackgroundColor::BlackTer => TextColor::BlackTer.into(),
+            BackgroundColor::GreyDarker => TextColor::GreyDarker.into(),
+            BackgroundColor::GreyDark => TextColor::GreyDark.into(),
+            BackgroundColor::Grey => TextColor::Grey.into(),
+            BackgroundColor::GreyLight => TextColor::GreyLight.into(),
+            BackgroundColor::GreyLighter => TextColor::GreyLighter.into(),
+            BackgroundColor::WhiteTer => TextColor::WhiteTer.into(),
+            BackgroundColor::WhiteBis => TextColor::WhiteBis.into(),
+            BackgroundColor::PrimaryLight => Rgb(235, 255, 252),
+            BackgroundColor::LinkLight => Rgb(239, 241, 250),
+            BackgroundColor::InfoLight => Rgb(239, 245, 251),
+            BackgroundColor::SuccessLight => Rgb(239, 250, 245),
+            BackgroundColor::WarningLight => Rgb(255, 250, 235),
+            BackgroundColor::DangerLight => Rgb(254, 236, 240),
+            BackgroundColor::PrimaryDark => Rgb(0, 148, 126),
+            BackgroundColor::LinkDark => Rgb(56, 80, 183),
+            BackgroundColor::InfoDark => Rgb(41, 111, 168),
+            BackgroundColor::SuccessDark => Rgb(37, 121, 83),
+            BackgroundColor::WarningDark => Rgb(148, 108, 0),
+            BackgroundColor::DangerDark => Rgb(204, 15, 53),
+            #[cfg(feature = "bulma-v1")]
+            BackgroundColor::Shade(color, shade) => TextColor::Shade(color, shade).into(),
+        }
+    }
+}
+
+/// Computes the contrast ratio between two colors.
+///
+/// Computes the [WCAG contrast ratio][wcag] between the given colors, from
+/// `1.0` (no contrast) to `21.0` (black on white). The order of the colors
+/// does not matter. The WCAG AA level requires a ratio of at least `4.5` for
+/// normal text.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::helpers::color::{contrast::contrast_ratio, BackgroundColor, TextColor};
+///
+/// let ratio = contrast_ratio(TextColor::White, BackgroundColor::Link);
+/// assert!(ratio > 4.5);
+/// ```
+///
+/// [wcag]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+pub fn contrast_ratio(first: impl Into<Rgb>, second: impl Into<Rgb>) -> f64 {
+    let (first, second) = (first.into().luminance(), second.into().luminance());
+    let (lighter, darker) = if first > second {
+        (first, second)
+    } else {
+        (second, first)
+    };
+
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Picks the most readable text color for a background.
+///
+/// Returns either [`TextColor::White`] or [`TextColor::Black`], whichever has
+/// the highest [WCAG contrast ratio][wcag] against the given background,
+/// which can be a [`BackgroundColor`] or a custom [`Rgb`] color.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     helpers::color::{contrast::{text_color_for, Rgb}, BackgroundColor, TextColor},
+///     utils::class::ClassBuilder,
+/// };
+///
+/// assert_eq!(text_color_for(BackgroundColor::Dark), TextColor::White);
+/// assert_eq!(text_color_for(Rgb(255, 221, 87)), TextColor::Black);
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let class = ClassBuilder::default()
+///         .with_background_color(Some(BackgroundColor::Danger))
+///         .with_text_color(Some(text_color_for(BackgroundColor::Danger)))
+///         .build();
+///
+///     html! {
+///         <div {class}>{"Readable text"}</div>
+///     }
+/// }
+/// ```
+///
+/// [wcag]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+pub fn text_color_for(background: impl Into<Rgb>) -> TextColor {
+    let background = background.into();
+
+    if contrast_ratio(TextColor::White, background) >= contrast_ratio(TextColor::Black, background)
+    {
+        TextColor::White
+    } else {
+        TextColor::Black
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "bulma-v1")]
+    use crate::helpers::color::{ColorShade, PaletteColor};
+    use test_case::test_case;
+
+    #[test_case("#00d1b2", Some(Rgb(0, 209, 178)) ; "six digits with hash")]
+    #[test_case("485FC7", Some(Rgb(72, 95, 199)) ; "six uppercase digits without hash")]
+    #[test_case("#fff", Some(Rgb(255, 255, 255)) ; "three digits")]
+    #[test_case("#ffff", None ; "four digits")]
+    #[test_case("#gggggg", None ; "invalid digits")]
+    fn rgb_from_hex(hex: &str, expected: Option<Rgb>) {
+        assert_eq!(Rgb::from_hex(hex), expected);
+    }
+
+    #[test_case(Rgb(0, 0, 0), Rgb(255, 255, 255), 21.0 ; "black on white")]
+    #[test_case(Rgb(255, 255, 255), Rgb(0, 0, 0), 21.0 ; "order does not matter")]
+    #[test_case(Rgb(122, 122, 122), Rgb(122, 122, 122), 1.0 ; "same colors")]
+    fn contrast_ratio_values(first: Rgb, second: Rgb, expected: f64) {
+        assert!((contrast_ratio(first, second) - expected).abs() < 0.01);
+    }
+
+    #[test_case(BackgroundColor::White, TextColor::Black ; "white background")]
+    #[test_case(BackgroundColor::Black, TextColor::White ; "black background")]
+    #[test_case(BackgroundColor::Dark, TextColor::White ; "dark background")]
+    #[test_case(BackgroundColor::Warning, TextColor::Black ; "warning background")]
+    #[test_case(BackgroundColor::Link, TextColor::White ; "link background")]
+    #[test_case(BackgroundColor::DangerDark, TextColor::White ; "danger dark background")]
+    #[test_case(BackgroundColor::PrimaryLight, TextColor::Black ; "primary light background")]
+    fn text_color_for_backgrounds(background: BackgroundColor, expected: TextColor) {
+        assert_eq!(text_color_for(background), expected);
+    }
+
+    #[cfg(feature = "bulma-v1")]
+    #[test_case(0, Rgb(0, 0, 0) ; "shade zero is black")]
+    #[test_case(100, Rgb(255, 255, 255) ; "shade one hundred is white")]
+    #[test_case(50, Rgb(0, 255, 217) ; "shade fifty keeps the hue")]
+    fn shade_to_rgb(lightness: u8, expected: Rgb) {
+        let shade = ColorShade::new(lightness).unwrap();
+
+        assert_eq!(
+            Rgb::from(TextColor::Shade(PaletteColor::Primary, shade)),
+            expected
+        );
+    }
+
+    #[cfg(feature = "bulma-v1")]
+    #[test_case(10, TextColor::White ; "dark shade background")]
+    #[test_case(95, TextColor::Black ; "light shade background")]
+    fn text_color_for_shades(lightness: u8, expected: TextColor) {
+        let shade = ColorShade::new(lightness).unwrap();
+
+        assert_eq!(
+            text_color_for(BackgroundColor::Shade(PaletteColor::Danger, shade)),
+            expected
+        );
+    }
+}
@@ -1,5 +1,10 @@
 use std::fmt::Display;
 
+use crate::{
+    helpers::visibility::BREAKPOINTS,
+    utils::constants::{MARGIN_PREFIX, PADDING_PREFIX},
+};
+
 /// Enum defining the possible direction values, as described in the
 /// [Bulma documentation][bd].
 ///
@@ -90,6 +95,25 @@ impl Display for Direction {
     }
 }
 
+impl Direction {
+    fn properties(&self, property: &str) -> Vec<String> {
+        let sides: &[&str] = match self {
+            Direction::All => &[""],
+            Direction::Top => &["-top"],
+            Direction::Right => &["-right"],
+            Direction::Bottom => &["-bottom"],
+            Direction::Left => &["-left"],
+            Direction::Horizontal => &["-left", "-right"],
+            Direction::Vertical => &["-top", "-bottom"],
+        };
+
+        sides
+            .iter()
+            .map(|side| format!("{property}{side}"))
+            .collect()
+    }
+}
+
 /// Enum defining the possible spacing values, as described in the
 /// [Bulma documentation][bd].
 ///
@@ -180,6 +204,95 @@ impl Display for Spacing {
     }
 }
 
+impl Spacing {
+    fn value(&self) -> &'static str {
+        match self {
+            Spacing::Zero => "0",
+            Spacing::One => "0.25rem",
+            Spacing::Two => "0.5rem",
+            Spacing::Three => "0.75rem",
+            Spacing::Four => "1rem",
+            Spacing::Five => "1.5rem",
+            Spacing::Six => "3rem",
+        }
+    }
+}
+
+const DIRECTIONS: [Direction; 7] = [
+    Direction::All,
+    Direction::Top,
+    Direction::Right,
+    Direction::Bottom,
+    Direction::Left,
+    Direction::Horizontal,
+    Direction::Vertical,
+];
+const SPACINGS: [Spacing; 7] = [
+    Spacing::Zero,
+    Spacing::One,
+    Spacing::Two,
+    Spacing::Three,
+    Spacing::Four,
+    Spacing::Five,
+    Spacing::Six,
+];
+
+/// Creates the styles needed by the viewport specific spacing classes.
+///
+/// Bulma does not provide viewport specific [spacing helpers][bd], so the
+/// classes created by
+/// [`crate::utils::class::ClassBuilder::with_viewport_margin`] and
+/// [`crate::utils::class::ClassBuilder::with_viewport_padding`] (ie
+/// `mt-4-desktop`) require these styles, using the default Bulma spacing
+/// values and breakpoints. They should be added once to the page, for example
+/// using a `<style>` element.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::helpers::spacing::viewport_spacing_styles;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html!{
+///         <>
+///             <style>{ viewport_spacing_styles() }</style>
+///             <div class="mt-4-desktop">{ "Lorem ispum..." }</div>
+///         </>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/helpers/spacing-helpers
+pub fn viewport_spacing_styles() -> String {
+    BREAKPOINTS
+        .iter()
+        .map(|(viewport, breakpoint)| {
+            let rules: String = [(MARGIN_PREFIX, "margin"), (PADDING_PREFIX, "padding")]
+                .iter()
+                .flat_map(|(prefix, property)| {
+                    DIRECTIONS.iter().flat_map(move |direction| {
+                        SPACINGS.iter().map(move |spacing| {
+                            let declarations: String = direction
+                                .properties(property)
+                                .iter()
+                                .map(|property| {
+                                    format!("{property}:{}!important;", spacing.value())
+                                })
+                                .collect();
+
+                            format!(".{prefix}{direction}-{spacing}-{viewport}{{{declarations}}}")
+                        })
+                    })
+                })
+                .collect();
+
+            format!("@media screen and {}{{{rules}}}", breakpoint.media_query())
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -210,4 +323,24 @@ mod tests {
 
         assert_eq!(converted_spacing, expected_spacing);
     }
+
+    #[test_case(Direction::All, "margin", vec!["margin"] ; "all converts to margin")]
+    #[test_case(Direction::Top, "margin", vec!["margin-top"] ; "top converts to margin-top")]
+    #[test_case(Direction::Horizontal, "padding", vec!["padding-left", "padding-right"] ; "horizontal converts to padding-left and padding-right")]
+    #[test_case(Direction::Vertical, "padding", vec!["padding-top", "padding-bottom"] ; "vertical converts to padding-top and padding-bottom")]
+    fn direction_properties(direction: Direction, property: &str, expected_properties: Vec<&str>) {
+        assert_eq!(direction.properties(property), expected_properties);
+    }
+
+    #[test]
+    fn viewport_spacing_styles_contains_rules() {
+        let styles = viewport_spacing_styles();
+
+        assert!(styles
+            .contains("@media screen and (min-width: 1024px){.m-0-desktop{margin:0!important;}"));
+        assert!(styles.contains(".mt-4-desktop{margin-top:1rem!important;}"));
+        assert!(styles.contains(
+            ".px-2-mobile{padding-left:0.5rem!important;padding-right:0.5rem!important;}"
+        ));
+    }
 }
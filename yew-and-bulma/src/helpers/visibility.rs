@@ -173,6 +173,177 @@ impl fmt::Display for Viewport {
     }
 }
 
+/// Defines the range of widths covered by a [`Viewport`].
+///
+/// Holds the minimum and maximum widths, in pixels and both inclusive, of
+/// the screens matching a viewport, as defined by the
+/// [Bulma breakpoints][bd]. A missing bound means the range is open on that
+/// side.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::helpers::visibility::{Breakpoint, Viewport};
+///
+/// assert_eq!(
+///     Viewport::TabletOnly.breakpoint(),
+///     Breakpoint { min: Some(769), max: Some(1023) }
+/// );
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/overview/responsiveness/#breakpoints
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Breakpoint {
+    /// The minimum width of the range, in pixels.
+    pub min: Option<u32>,
+    /// The maximum width of the range, in pixels.
+    pub max: Option<u32>,
+}
+
+impl Breakpoint {
+    /// Returns whether or not the given width, in pixels, is in the range.
+    pub fn contains(&self, width: u32) -> bool {
+        self.min.map(|min| width >= min).unwrap_or(true)
+            && self.max.map(|max| width <= max).unwrap_or(true)
+    }
+
+    /// Returns the CSS media query matching the range (ie
+    /// `(min-width: 769px) and (max-width: 1023px)`).
+    pub fn media_query(&self) -> String {
+        let min = self.min.map(|min| format!("(min-width: {min}px)"));
+        let max = self.max.map(|max| format!("(max-width: {max}px)"));
+
+        min.into_iter().chain(max).collect::<Vec<_>>().join(" and ")
+    }
+}
+
+/// The [Bulma breakpoints][bd] of every [`Viewport`].
+///
+/// Lists the range of widths covered by each viewport, based on the default
+/// Bulma breakpoints, so that responsive code does not need to repeat them.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::helpers::visibility::{Viewport, BREAKPOINTS};
+///
+/// let desktop = BREAKPOINTS
+///     .iter()
+///     .find(|(viewport, _)| *viewport == Viewport::Desktop)
+///     .map(|(_, breakpoint)| breakpoint.min);
+/// assert_eq!(desktop, Some(Some(1024)));
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/overview/responsiveness/#breakpoints
+pub const BREAKPOINTS: [(Viewport, Breakpoint); 9] = [
+    (
+        Viewport::Mobile,
+        Breakpoint {
+            min: None,
+            max: Some(768),
+        },
+    ),
+    (
+        Viewport::Touch,
+        Breakpoint {
+            min: None,
+            max: Some(1023),
+        },
+    ),
+    (
+        Viewport::TabletOnly,
+        Breakpoint {
+            min: Some(769),
+            max: Some(1023),
+        },
+    ),
+    (
+        Viewport::Tablet,
+        Breakpoint {
+            min: Some(769),
+            max: None,
+        },
+    ),
+    (
+        Viewport::DesktopOnly,
+        Breakpoint {
+            min: Some(1024),
+            max: Some(1215),
+        },
+    ),
+    (
+        Viewport::Desktop,
+        Breakpoint {
+            min: Some(1024),
+            max: None,
+        },
+    ),
+    (
+        Viewport::WidescreenOnly,
+        Breakpoint {
+            min: Some(1216),
+            max: Some(1407),
+        },
+    ),
+    (
+        Viewport::Widescreen,
+        Breakpoint {
+            min: Some(1216),
+            max: None,
+        },
+    ),
+    (
+        Viewport::FullHD,
+        Breakpoint {
+            min: Some(1408),
+            max: None,
+        },
+    ),
+];
+
+impl Viewport {
+    /// Returns the range of widths covered by the viewport.
+    pub fn breakpoint(&self) -> Breakpoint {
+        BREAKPOINTS
+            .iter()
+            .find(|(viewport, _)| viewport == self)
+            .map(|(_, breakpoint)| *breakpoint)
+            .unwrap_or(Breakpoint {
+                min: None,
+                max: None,
+            })
+    }
+
+    /// Returns the narrowest viewport matching the given width, in pixels,
+    /// which is one of `Mobile`, `TabletOnly`, `DesktopOnly`,
+    /// `WidescreenOnly` or `FullHD`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_and_bulma::helpers::visibility::Viewport;
+    ///
+    /// assert_eq!(Viewport::from_width(375), Viewport::Mobile);
+    /// assert_eq!(Viewport::from_width(1280), Viewport::WidescreenOnly);
+    /// ```
+    pub fn from_width(width: u32) -> Self {
+        [
+            Viewport::Mobile,
+            Viewport::TabletOnly,
+            Viewport::DesktopOnly,
+            Viewport::WidescreenOnly,
+        ]
+        .into_iter()
+        .find(|viewport| viewport.breakpoint().contains(width))
+        .unwrap_or(Viewport::FullHD)
+    }
+
+    /// Returns whether or not the viewport covers the given width, in pixels.
+    pub fn matches(&self, width: u32) -> bool {
+        self.breakpoint().contains(width)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,4 +380,35 @@ mod tests {
 
         assert_eq!(converted_viewport, expected_viewport);
     }
+
+    #[test_case(0, Viewport::Mobile ; "zero is mobile")]
+    #[test_case(768, Viewport::Mobile ; "768 is mobile")]
+    #[test_case(769, Viewport::TabletOnly ; "769 is tablet")]
+    #[test_case(1023, Viewport::TabletOnly ; "1023 is tablet")]
+    #[test_case(1024, Viewport::DesktopOnly ; "1024 is desktop")]
+    #[test_case(1216, Viewport::WidescreenOnly ; "1216 is widescreen")]
+    #[test_case(1407, Viewport::WidescreenOnly ; "1407 is widescreen")]
+    #[test_case(1408, Viewport::FullHD ; "1408 is fullhd")]
+    fn viewport_from_width(width: u32, expected_viewport: Viewport) {
+        assert_eq!(Viewport::from_width(width), expected_viewport);
+    }
+
+    #[test_case(Viewport::Touch, 800, true ; "touch matches tablets")]
+    #[test_case(Viewport::Touch, 1024, false ; "touch does not match desktops")]
+    #[test_case(Viewport::Tablet, 2000, true ; "tablet matches wider screens")]
+    #[test_case(Viewport::Desktop, 1000, false ; "desktop does not match tablets")]
+    fn viewport_matches_width(viewport: Viewport, width: u32, expected: bool) {
+        assert_eq!(viewport.matches(width), expected);
+    }
+
+    #[test_case(Viewport::Mobile, "(max-width: 768px)" ; "mobile media query")]
+    #[test_case(Viewport::Desktop, "(min-width: 1024px)" ; "desktop media query")]
+    #[test_case(
+        Viewport::TabletOnly,
+        "(min-width: 769px) and (max-width: 1023px)" ;
+        "tablet only media query"
+    )]
+    fn breakpoint_media_query(viewport: Viewport, expected_query: &str) {
+        assert_eq!(viewport.breakpoint().media_query(), expected_query);
+    }
 }
@@ -0,0 +1,116 @@
+use yew::{function_component, html, Children, Html, Properties};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::utils::class::ClassBuilder;
+
+/// Defines the properties of the affix element.
+///
+/// Defines the properties of the affix element, used to keep its content
+/// (ie a sidebar menu or a table of contents) in view while scrolling,
+/// within the bounds of its parent.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::affix::Affix;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Affix>{"This is a sidebar."}</Affix>
+///     }
+/// }
+/// ```
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct AffixProperties {
+    /// Sets the distance from the top of the viewport.
+    ///
+    /// Sets the distance, in pixels, kept between the top of the viewport and
+    /// the affix element, which will receive these properties, once it sticks.
+    /// Defaults to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::affix::Affix;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Affix offset_top={52}>{"This is a sidebar under a navbar."}</Affix>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub offset_top: u32,
+    /// The list of elements found inside the affix element.
+    ///
+    /// Defines the elements that will be found inside the affix element which
+    /// will receive these properties.
+    pub children: Children,
+}
+
+/// Yew implementation of the affix element.
+///
+/// Keeps its content in view while scrolling, by sticking it to the top of
+/// the viewport. The content only sticks while its parent is visible, being
+/// released when reaching the top or bottom edges of the parent, which makes
+/// it suited for sidebars placed inside a [Bulma column][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     columns::{Column, Columns, Size},
+///     layout::affix::Affix,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Columns>
+///             <Column size={Size::OneQuarter}>
+///                 <Affix offset_top={16}>{"This is a sidebar."}</Affix>
+///             </Column>
+///             <Column>{"This is the content."}</Column>
+///         </Columns>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/columns/
+#[function_component(Affix)]
+pub fn affix(props: &AffixProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let style = format!("position: sticky; top: {}px;", props.offset_top);
+
+    html! {
+        <div id={props.id.clone()} {class} {style}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.children.iter() }
+        </div>
+    }
+}
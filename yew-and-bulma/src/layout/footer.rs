@@ -1,7 +1,10 @@
 use yew::{function_component, html, Children, Html, Properties};
 use yew_and_bulma_macros::base_component_properties;
 
-use crate::utils::class::ClassBuilder;
+use crate::{
+    helpers::spacing::{Direction, Spacing},
+    utils::class::ClassBuilder,
+};
 
 /// Defines the points from which a [footer element][bd] is not full width.
 ///
@@ -30,6 +33,61 @@ use crate::utils::class::ClassBuilder;
 #[base_component_properties]
 #[derive(Properties, PartialEq)]
 pub struct FooterProperties {
+    /// Overrides the padding of the [footer element][bd].
+    ///
+    /// Overrides the default padding of the [Bulma footer element][bd] which
+    /// will receive these properties, using the [Bulma spacing helpers][sh].
+    /// Since the spacing helpers take precedence over the footer styles, this
+    /// avoids the need for custom classes when tightening the layout.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     helpers::spacing::{Direction, Spacing},
+    ///     layout::footer::Footer,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Footer padding={(Direction::All, Spacing::Three)}>
+    ///             {"This is some text in a compact footer."}
+    ///         </Footer>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/layout/footer/
+    /// [sh]: https://bulma.io/documentation/helpers/spacing-helpers/
+    #[prop_or_default]
+    pub padding: Option<(Direction, Spacing)>,
+    /// Whether the [footer element][bd] should be hidden when printed.
+    ///
+    /// Whether or not the [Bulma footer element][bd], which will receive these
+    /// properties, should be hidden when the page is printed. This requires
+    /// the styles found in [`crate::utils::constants::PRINT_STYLES`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::footer::Footer;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Footer print_hidden=true>
+    ///             {"This is some text in a footer."}
+    ///         </Footer>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/layout/footer/
+    #[prop_or_default]
+    pub print_hidden: bool,
     /// The list of elements found inside the [footer element][bd].
     ///
     /// Defines the elements that will be found inside the
@@ -63,8 +121,12 @@ pub struct FooterProperties {
 /// [bd]: https://bulma.io/documentation/layout/footer/
 #[function_component(Footer)]
 pub fn footer(props: &FooterProperties) -> Html {
-    let class = ClassBuilder::default()
-        .with_custom_class("footer")
+    let mut class = ClassBuilder::default().with_custom_class("footer");
+    if let Some((direction, spacing)) = &props.padding {
+        class = class.with_padding(direction.clone(), spacing.clone());
+    }
+    let class = class
+        .is_print_hidden(Some(props.print_hidden))
         .with_custom_class(
             &props
                 .class
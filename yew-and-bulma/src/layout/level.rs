@@ -1,7 +1,7 @@
 use yew::html;
 use yew::{
-    function_component, html::ChildrenRenderer, virtual_dom::VChild, Children, ChildrenWithProps,
-    Html, Properties,
+    function_component, html::ChildrenRenderer, virtual_dom::VChild, AttrValue, Children,
+    ChildrenWithProps, Html, Properties,
 };
 use yew_and_bulma_macros::base_component_properties;
 
@@ -56,12 +56,43 @@ pub struct LevelProperties {
     /// [bd]: https://bulma.io/documentation/layout/level/#mobile-level
     #[prop_or_default]
     pub mobile: bool,
+    /// The list of items to be wrapped in [level item elements][bd].
+    ///
+    /// Defines a list of items, each of which will be wrapped in a
+    /// [`crate::layout::level::LevelItem`] and rendered after the children of
+    /// the [Bulma level element][bd] which will receive these properties.
+    /// Useful when rendering dynamic data, avoiding the need to wrap each item
+    /// by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::level::Level;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let items = ["Home", "Menu", "Reservations"]
+    ///         .into_iter()
+    ///         .map(|text| html! { {text} })
+    ///         .collect::<Vec<_>>();
+    ///
+    ///     html! {
+    ///         <Level {items} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/layout/level/
+    #[prop_or_default]
+    pub items: Vec<Html>,
     /// The list of elements found inside the [level element][bd].
     ///
     /// Defines the elements that will be found inside the
     /// [Bulma level element][bd] which will receive these properties.
     ///
     /// [bd]: https://bulma.io/documentation/layout/level/
+    #[prop_or_default]
     pub children: ChildrenRenderer<LevelElement>,
 }
 
@@ -117,6 +148,7 @@ pub fn level(props: &LevelProperties) -> Html {
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
             ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
             { for props.children.iter() }
+            { for props.items.iter().map(|item| html! { <LevelItem>{item.clone()}</LevelItem> }) }
         </div>
     }
 }
@@ -210,12 +242,65 @@ impl Into<Html> for LevelElement {
 #[base_component_properties]
 #[derive(Properties, PartialEq)]
 pub struct LevelItemProperties {
+    /// Sets the heading of the [Bulma level item element][bd].
+    ///
+    /// Sets the small, uppercase heading displayed above the value of the
+    /// [Bulma level item element][bd] which will receive these properties.
+    /// Setting a heading or a value centers the text of the item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::level::{Level, LevelItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Level>
+    ///             <LevelItem heading="Tweets" value="3,456" />
+    ///             <LevelItem heading="Following" value="123" />
+    ///         </Level>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/layout/level/#centered-level
+    #[prop_or_default]
+    pub heading: Option<AttrValue>,
+    /// Sets the value of the [Bulma level item element][bd].
+    ///
+    /// Sets the value displayed as a title, below the heading, inside the
+    /// [Bulma level item element][bd] which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::level::{Level, LevelItem};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Level mobile=true>
+    ///             <LevelItem heading="Followers" value="456K" />
+    ///             <LevelItem heading="Likes" value="789" />
+    ///         </Level>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/layout/level/#centered-level
+    #[prop_or_default]
+    pub value: Option<AttrValue>,
     /// The list of elements found inside the [level item element][bd].
     ///
     /// Defines the elements that will be found inside the
-    /// [Bulma level item element][bd] which will receive these properties.
+    /// [Bulma level item element][bd] which will receive these properties,
+    /// after its heading and value, if any.
     ///
     /// [bd]: https://bulma.io/documentation/layout/level/
+    #[prop_or_default]
     pub children: Children,
 }
 
@@ -243,8 +328,10 @@ pub struct LevelItemProperties {
 /// [bd]: https://bulma.io/documentation/layout/level/
 #[function_component(LevelItem)]
 pub fn level_item(props: &LevelItemProperties) -> Html {
+    let stat = props.heading.is_some() || props.value.is_some();
     let class = ClassBuilder::default()
         .with_custom_class("level-item")
+        .with_custom_class(if stat { "has-text-centered" } else { "" })
         .with_custom_class(
             &props
                 .class
@@ -268,6 +355,16 @@ pub fn level_item(props: &LevelItemProperties) -> Html {
             onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
             onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
             ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            if stat {
+                <div>
+                    if let Some(heading) = &props.heading {
+                        <p class="heading">{heading}</p>
+                    }
+                    if let Some(value) = &props.value {
+                        <p class="title">{value}</p>
+                    }
+                </div>
+            }
             { for props.children.iter() }
         </div>
     }
@@ -1,3 +1,22 @@
+/// Provides utilities for creating affix elements in Yew.
+///
+/// Defines the necessary components to keep elements, such as sidebars, in
+/// view while scrolling, within the bounds of their parent.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::affix::Affix;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Affix>{"This is a sidebar."}</Affix>
+///     }
+/// }
+/// ```
+pub mod affix;
 /// Provides utilities for creating [container elements][bd] in Yew.
 ///
 /// Defines the necessary components to build, style and modify
@@ -113,6 +132,27 @@ pub mod level;
 ///
 /// [bd]: https://bulma.io/documentation/layout/media-object/
 pub mod media;
+/// Provides ready made presets, composed of multiple Bulma elements.
+///
+/// Defines components built by combining multiple [Bulma elements][bd], for
+/// commonly found patterns, such as dashboard stat cards.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::presets::StatCard;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <StatCard label="Orders" value="1,204" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/elements/
+pub mod presets;
 /// Provides utilities for creating [section elements][bd] in Yew.
 ///
 /// Defines the necessary components to build, style and modify
@@ -134,6 +174,27 @@ pub mod media;
 ///
 /// [bd]: https://bulma.io/documentation/layout/section/
 pub mod section;
+/// Provides utilities for creating horizontal card strips in Yew.
+///
+/// Defines the necessary components to build a horizontally scrollable,
+/// scroll snapping row of cards, with previous and next buttons.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::strip::CardStrip;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <CardStrip>
+///             <div class="card">{"Recently viewed"}</div>
+///         </CardStrip>
+///     }
+/// }
+/// ```
+pub mod strip;
 /// Provides utilities for creating [tile elements][bd] in Yew.
 ///
 /// Defines the necessary components to build, style and modify
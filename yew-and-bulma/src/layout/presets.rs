@@ -0,0 +1,962 @@
+use yew::{
+    classes, function_component, html, html_nested, AttrValue, Callback, Children, Html, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{
+    elements::{
+        content::Content,
+        delete::Delete,
+        icon::Icon,
+        image::{self, Figure, Image},
+        r#box::Box,
+        title::{Size, Subtitle, Title},
+    },
+    helpers::{
+        color::{Color, TextColor},
+        typography::TextAlignment,
+    },
+    layout::{
+        container::{Container, Width},
+        hero::{self, Hero, HeroBody},
+        level::{Level, LevelItem, LevelLeft, LevelRight},
+        media::{Media, MediaContent, MediaLeft, MediaRight},
+        section::Section,
+    },
+    utils::{class::ClassBuilder, constants::PRINT_STYLES},
+};
+
+/// Defines the properties of the stat card preset.
+///
+/// Defines the properties of the stat card preset, made up of a
+/// [Bulma box element][box] holding a [Bulma title and subtitle][title].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::presets::StatCard;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <StatCard label="Orders" value="1,204" />
+///     }
+/// }
+/// ```
+///
+/// [box]: https://bulma.io/documentation/elements/box/
+/// [title]: https://bulma.io/documentation/elements/title/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct StatCardProperties {
+    /// Sets the label of the stat card.
+    ///
+    /// Sets the label describing the value of the stat card which will receive
+    /// these properties, rendered as a [Bulma subtitle][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::StatCard;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <StatCard label="Revenue" value="$12,000" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/title/
+    pub label: AttrValue,
+    /// Sets the value of the stat card.
+    ///
+    /// Sets the value displayed by the stat card which will receive these
+    /// properties, rendered as a [Bulma title][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::StatCard;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <StatCard label="Users" value="320" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/title/
+    pub value: AttrValue,
+    /// Sets the change of the value of the stat card.
+    ///
+    /// Sets the change of the value displayed by the stat card which will
+    /// receive these properties, shown next to it with an explicit sign.
+    /// Unless `color` is set, positive changes are colored as
+    /// [success][bd] and negative ones as [danger][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::StatCard;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <StatCard label="Users" value="320" delta={-4.5} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/color-helpers/
+    #[prop_or_default]
+    pub delta: Option<f64>,
+    /// Sets the icon displayed next to the change of the value.
+    ///
+    /// Sets the icon, wrapped in a [Bulma icon element][bd], displayed next
+    /// to the change of the value of the stat card which will receive these
+    /// properties (ie an up or down arrow).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::StatCard;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <StatCard label="Users" value="320" delta={12.0}
+    ///             trend_icon={html! { <i class="fas fa-arrow-up"></i> }} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/icon/
+    #[prop_or_default]
+    pub trend_icon: Option<Html>,
+    /// Sets the color of the change of the value.
+    ///
+    /// Sets the [Bulma text color][bd] of the change of the value of the stat
+    /// card which will receive these properties, overriding the one derived
+    /// from the sign of `delta`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{helpers::color::TextColor, layout::presets::StatCard};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <StatCard label="Errors" value="3" delta={2.0} color={TextColor::Danger} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/color-helpers/
+    #[prop_or_default]
+    pub color: Option<TextColor>,
+}
+
+/// Yew implementation of the stat card preset.
+///
+/// Renders a [Bulma box element][box] holding the value of a statistic as a
+/// [Bulma title][title], its label as a [Bulma subtitle][title] and,
+/// optionally, the change of the value along with a trend icon.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::presets::StatCard;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <StatCard label="Orders" value="1,204" delta={3.2} />
+///     }
+/// }
+/// ```
+///
+/// [box]: https://bulma.io/documentation/elements/box/
+/// [title]: https://bulma.io/documentation/elements/title/
+#[function_component(StatCard)]
+pub fn stat_card(props: &StatCardProperties) -> Html {
+    let delta = props.delta.map(|delta| {
+        let color = props.color.or(if delta > 0.0 {
+            Some(TextColor::Success)
+        } else if delta < 0.0 {
+            Some(TextColor::Danger)
+        } else {
+            None
+        });
+        let class = ClassBuilder::default().with_text_color(color).build();
+
+        html! {
+            <p {class}>
+                if let Some(icon) = &props.trend_icon {
+                    <Icon icon={icon.clone()} {color} />
+                }
+                <span>{format!("{delta:+}")}</span>
+            </p>
+        }
+    });
+
+    html! {
+        <Box id={props.id.clone()} class={props.class.clone()}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <Title size={Size::Three}>{&props.value}</Title>
+            <Subtitle size={Size::Six}>{&props.label}</Subtitle>
+            { delta.unwrap_or_default() }
+        </Box>
+    }
+}
+
+/// Describes a comment of a [comment thread][crate::layout::presets::CommentThread].
+///
+/// Holds the data needed to render a comment, along with its replies, as a
+/// [Bulma media object][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::presets::Comment;
+///
+/// let comment = Comment {
+///     id: "1".into(),
+///     avatar: Some("https://bulma.io/images/placeholders/128x128.png".into()),
+///     author: "Jane Doe".into(),
+///     body: html! {"Lorem ipsum dolor sit amet."},
+///     replies: vec![],
+/// };
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/layout/media-object/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comment {
+    /// The identifier of the comment, passed to the reply and delete callbacks.
+    pub id: AttrValue,
+    /// The source of the avatar image of the author, if any.
+    pub avatar: Option<AttrValue>,
+    /// The name of the author of the comment.
+    pub author: AttrValue,
+    /// The body of the comment.
+    pub body: Html,
+    /// The replies to the comment, rendered as nested media objects.
+    pub replies: Vec<Comment>,
+}
+
+/// Defines the properties of the comment thread preset.
+///
+/// Defines the properties of the comment thread preset, made up of nested
+/// [Bulma media objects][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::presets::{Comment, CommentThread};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let comments = vec![Comment {
+///         id: "1".into(),
+///         avatar: None,
+///         author: "Jane Doe".into(),
+///         body: html! {"Lorem ipsum dolor sit amet."},
+///         replies: vec![],
+///     }];
+///
+///     html! {
+///         <CommentThread {comments} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/layout/media-object/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CommentThreadProperties {
+    /// The list of comments found in the thread.
+    ///
+    /// Defines the top level comments, along with their replies, found in the
+    /// comment thread which will receive these properties. Each comment is
+    /// rendered as a [Bulma media object][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::{Comment, CommentThread};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let reply = Comment {
+    ///         id: "2".into(),
+    ///         avatar: None,
+    ///         author: "John Doe".into(),
+    ///         body: html! {"Consectetur adipiscing elit."},
+    ///         replies: vec![],
+    ///     };
+    ///     let comments = vec![Comment {
+    ///         id: "1".into(),
+    ///         avatar: None,
+    ///         author: "Jane Doe".into(),
+    ///         body: html! {"Lorem ipsum dolor sit amet."},
+    ///         replies: vec![reply],
+    ///     }];
+    ///
+    ///     html! {
+    ///         <CommentThread {comments} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/layout/media-object/
+    pub comments: Vec<Comment>,
+    /// The callback to be used when replying to a comment.
+    ///
+    /// Defines the callback called with the identifier of the comment being
+    /// replied to. When set, a reply link is shown for each comment of the
+    /// thread which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::{Comment, CommentThread};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let comments = vec![Comment {
+    ///         id: "1".into(),
+    ///         avatar: None,
+    ///         author: "Jane Doe".into(),
+    ///         body: html! {"Lorem ipsum dolor sit amet."},
+    ///         replies: vec![],
+    ///     }];
+    ///     let onreply = Callback::from(|_id: AttrValue| ());
+    ///
+    ///     html! {
+    ///         <CommentThread {comments} {onreply} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub onreply: Option<Callback<AttrValue>>,
+    /// The callback to be used when deleting a comment.
+    ///
+    /// Defines the callback called with the identifier of the comment being
+    /// deleted. When set, a [Bulma delete element][bd] is shown for each
+    /// comment of the thread which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::{Comment, CommentThread};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let comments = vec![Comment {
+    ///         id: "1".into(),
+    ///         avatar: None,
+    ///         author: "Jane Doe".into(),
+    ///         body: html! {"Lorem ipsum dolor sit amet."},
+    ///         replies: vec![],
+    ///     }];
+    ///     let ondelete = Callback::from(|_id: AttrValue| ());
+    ///
+    ///     html! {
+    ///         <CommentThread {comments} {ondelete} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/delete/
+    #[prop_or_default]
+    pub ondelete: Option<Callback<AttrValue>>,
+}
+
+/// Yew implementation of the comment thread preset.
+///
+/// Renders each comment, along with its replies, using nested
+/// [Bulma media objects][bd], following the comment pattern found in the
+/// Bulma documentation.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::presets::{Comment, CommentThread};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let comments = vec![Comment {
+///         id: "1".into(),
+///         avatar: None,
+///         author: "Jane Doe".into(),
+///         body: html! {"Lorem ipsum dolor sit amet."},
+///         replies: vec![],
+///     }];
+///
+///     html! {
+///         <CommentThread {comments} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/layout/media-object/
+#[function_component(CommentThread)]
+pub fn comment_thread(props: &CommentThreadProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.comments.iter().map(|comment| render_comment(comment, props, image::Size::Pixels64x64)) }
+        </div>
+    }
+}
+
+fn render_comment(comment: &Comment, props: &CommentThreadProperties, size: image::Size) -> Html {
+    let avatar = comment.avatar.as_ref().map(|avatar| {
+        html_nested! {
+            <MediaLeft>
+                <Figure {size}>
+                    <Image src={avatar.clone()} />
+                </Figure>
+            </MediaLeft>
+        }
+    });
+    let reply = props.onreply.as_ref().map(|onreply| {
+        let id = comment.id.clone();
+        let onclick = onreply.reform(move |_| id.clone());
+        html! {
+            <Level mobile=true>
+                <LevelLeft>
+                    <LevelItem>
+                        <a {onclick}><small>{"Reply"}</small></a>
+                    </LevelItem>
+                </LevelLeft>
+            </Level>
+        }
+    });
+    let delete = props.ondelete.as_ref().map(|ondelete| {
+        let id = comment.id.clone();
+        let onclick = ondelete.reform(move |_| id.clone());
+        html_nested! {
+            <MediaRight>
+                <Delete {onclick} />
+            </MediaRight>
+        }
+    });
+
+    html! {
+        <Media>
+            { for avatar }
+            <MediaContent>
+                <Content>
+                    <p>
+                        <strong>{&comment.author}</strong>
+                        <br />
+                        {comment.body.clone()}
+                    </p>
+                </Content>
+                { reply.unwrap_or_default() }
+                { for comment.replies.iter().map(|reply| render_comment(reply, props, image::Size::Pixels48x48)) }
+            </MediaContent>
+            { for delete }
+        </Media>
+    }
+}
+
+/// Defines the properties of the error page preset.
+///
+/// Defines the properties of the error page preset, made up of a
+/// [Bulma hero element][bd] holding the error code, title, message and an
+/// optional action.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::presets::ErrorPage;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ErrorPage code={404} title="Page not found" />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/layout/hero/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct ErrorPageProperties {
+    /// Sets the code of the error.
+    ///
+    /// Sets the code of the error (ie an HTTP status code) displayed by the
+    /// error page which will receive these properties, as a
+    /// [Bulma title][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::ErrorPage;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ErrorPage code={500} title="Internal server error" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/title/
+    pub code: u16,
+    /// Sets the title of the error.
+    ///
+    /// Sets the title of the error displayed by the error page which will
+    /// receive these properties, as a [Bulma subtitle][bd].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::ErrorPage;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ErrorPage code={403} title="Forbidden" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/title/
+    pub title: AttrValue,
+    /// Sets the message of the error.
+    ///
+    /// Sets the message, further describing the error, displayed by the error
+    /// page which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::ErrorPage;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ErrorPage code={404} title="Page not found"
+    ///             message="The page you are looking for does not exist." />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub message: Option<AttrValue>,
+    /// Sets the action of the error page.
+    ///
+    /// Sets the element, usually a [Bulma button element][bd] leading back to
+    /// a known page, displayed below the message of the error page which will
+    /// receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::button::Button, layout::presets::ErrorPage};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ErrorPage code={404} title="Page not found"
+    ///             action={html! { <Button>{"Go home"}</Button> }} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/button/
+    #[prop_or_default]
+    pub action: Option<Html>,
+    /// Sets the color of the error page.
+    ///
+    /// Sets the color of the [Bulma hero element][bd] used by the error page
+    /// which will receive these properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{helpers::color::Color, layout::presets::ErrorPage};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <ErrorPage code={404} title="Page not found" color={Color::Danger} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/layout/hero/#colors
+    #[prop_or_default]
+    pub color: Option<Color>,
+}
+
+/// Yew implementation of the error page preset.
+///
+/// Renders a full height [Bulma hero element][bd], holding the centered
+/// error code, title, message and action, as a ready made error screen (ie
+/// for 404 routes).
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{elements::button::Button, layout::presets::ErrorPage};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <ErrorPage code={404} title="Page not found"
+///             message="The page you are looking for does not exist."
+///             action={html! { <Button>{"Go home"}</Button> }} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/layout/hero/
+#[function_component(ErrorPage)]
+pub fn error_page(props: &ErrorPageProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_text_alignment(Some(TextAlignment::Centered))
+        .build();
+
+    html! {
+        <Hero id={props.id.clone()} class={props.class.clone()} color={props.color} size={hero::Size::FullHeight}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <HeroBody>
+                <Container {class}>
+                    <Title size={Size::One}>{props.code}</Title>
+                    <Subtitle size={Size::Three}>{&props.title}</Subtitle>
+                    if let Some(message) = &props.message {
+                        <p class="mb-5">{message}</p>
+                    }
+                    { props.action.clone().unwrap_or_default() }
+                </Container>
+            </HeroBody>
+        </Hero>
+    }
+}
+
+/// Defines the properties of the document preset.
+///
+/// Defines the properties of the document preset, a printable page (ie an
+/// invoice or a report) made up of a [Bulma container][bd] holding a heading,
+/// a [Bulma table][table] section and a footer.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     elements::table::{Table, TableData, TableRow},
+///     layout::presets::Document,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <Document title="Invoice #42">
+///             <Table full_width=true>
+///                 <TableRow>
+///                     <TableData>{"Consulting"}</TableData>
+///                 </TableRow>
+///             </Table>
+///         </Document>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/layout/container/
+/// [table]: https://bulma.io/documentation/elements/table/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct DocumentProperties {
+    /// Sets the title of the document.
+    ///
+    /// Sets the title of the document which will receive these properties,
+    /// rendered as a [Bulma title][bd] at the top left of its heading.
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/title/
+    pub title: AttrValue,
+    /// Sets the subtitle of the document.
+    ///
+    /// Sets the subtitle (ie the issue date of an invoice) of the document
+    /// which will receive these properties, rendered as a
+    /// [Bulma subtitle][bd] under its title.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::Document;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Document title="Invoice #42" subtitle="Issued on 1 March 2023" />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/title/
+    #[prop_or_default]
+    pub subtitle: Option<AttrValue>,
+    /// Sets the details shown next to the title of the document.
+    ///
+    /// Sets the element (ie the address of the issuer or customer) shown at
+    /// the top right of the heading of the document which will receive these
+    /// properties.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::Document;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let meta = html! { <address>{"ACME Inc."}<br />{"1 Main Street"}</address> };
+    ///
+    ///     html! {
+    ///         <Document title="Invoice #42" {meta} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub meta: Option<Html>,
+    /// Sets the actions of the document.
+    ///
+    /// Sets the elements (ie a print or download button) shown above the
+    /// heading of the document which will receive these properties. They are
+    /// only shown on screen, being hidden when the page is printed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{elements::button::Button, layout::presets::Document};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let onclick = Callback::from(|_| {
+    ///         if let Some(window) = web_sys::window() {
+    ///             let _ = window.print();
+    ///         }
+    ///     });
+    ///     let actions = html! { <Button {onclick}>{"Print"}</Button> };
+    ///
+    ///     html! {
+    ///         <Document title="Invoice #42" {actions} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub actions: Option<Html>,
+    /// Sets the footer of the document.
+    ///
+    /// Sets the element (ie payment terms or a signature) shown at the bottom
+    /// of the document which will receive these properties, on screen and on
+    /// paper.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::presets::Document;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let footer = html! { <p>{"Payment is due within 30 days."}</p> };
+    ///
+    ///     html! {
+    ///         <Document title="Invoice #42" {footer} />
+    ///     }
+    /// }
+    /// ```
+    #[prop_or_default]
+    pub footer: Option<Html>,
+    /// Whether or not to add the styles of the print helpers.
+    ///
+    /// Whether or not the document which will receive these properties adds
+    /// the [`crate::utils::constants::PRINT_STYLES`] to the page, needed to
+    /// hide its actions when printed. Disable it if they are already added
+    /// elsewhere. Defaults to `true`.
+    #[prop_or(true)]
+    pub print_styles: bool,
+    /// The body of the document.
+    ///
+    /// Defines the elements found between the heading and the footer of the
+    /// document which will receive these properties, usually a
+    /// [Bulma table][bd].
+    ///
+    /// [bd]: https://bulma.io/documentation/elements/table/
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the document preset.
+///
+/// Renders a [Bulma section][section] holding a width constrained
+/// [Bulma container][bd] with a heading, the body of the document and a
+/// footer, meant for pages which are both read on screen and printed (ie
+/// invoices or reports). Its actions are hidden when printed, using the
+/// print helpers found in [`crate::utils::constants`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{
+///     elements::table::{Table, TableData, TableRow},
+///     layout::presets::Document,
+/// };
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let footer = html! { <p>{"Thank you for your business."}</p> };
+///
+///     html! {
+///         <Document title="Invoice #42" subtitle="Issued on 1 March 2023" {footer}>
+///             <Table full_width=true>
+///                 <TableRow>
+///                     <TableData>{"Consulting"}</TableData>
+///                     <TableData>{"$1,200.00"}</TableData>
+///                 </TableRow>
+///             </Table>
+///         </Document>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/layout/container/
+/// [section]: https://bulma.io/documentation/layout/section/
+#[function_component(Document)]
+pub fn document(props: &DocumentProperties) -> Html {
+    let meta = props.meta.as_ref().map(|meta| {
+        html_nested! {
+            <LevelRight>
+                <LevelItem>{meta.clone()}</LevelItem>
+            </LevelRight>
+        }
+    });
+    let actions_class = ClassBuilder::default()
+        .with_custom_class("mb-5")
+        .is_print_hidden(Some(true))
+        .build();
+    let class = ClassBuilder::default()
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <Section id={props.id.clone()} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            if props.print_styles {
+                <style>{ PRINT_STYLES }</style>
+            }
+            <Container width={Width::MaxDesktop}>
+                if let Some(actions) = &props.actions {
+                    <div class={actions_class}>{actions.clone()}</div>
+                }
+                <Level>
+                    <LevelLeft>
+                        <LevelItem>
+                            <div>
+                                <Title size={Size::Three}>{&props.title}</Title>
+                                if let Some(subtitle) = &props.subtitle {
+                                    <Subtitle size={Size::Six}>{subtitle}</Subtitle>
+                                }
+                            </div>
+                        </LevelItem>
+                    </LevelLeft>
+                    { for meta }
+                </Level>
+                { for props.children.iter() }
+                if let Some(footer) = &props.footer {
+                    <Content class={classes!("mt-5")}>{footer.clone()}</Content>
+                }
+            </Container>
+        </Section>
+    }
+}
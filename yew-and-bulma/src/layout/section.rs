@@ -1,7 +1,10 @@
 use yew::{function_component, html, Children, Html, Properties};
 use yew_and_bulma_macros::base_component_properties;
 
-use crate::utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size};
+use crate::{
+    helpers::spacing::{Direction, Spacing},
+    utils::{class::ClassBuilder, constants::IS_PREFIX, size::Size},
+};
 
 /// Defines the points from which a [section element][bd] is not full width.
 ///
@@ -57,6 +60,36 @@ pub struct SectionProperties {
     /// [bd]: https://bulma.io/documentation/layout/section/#sizes
     #[prop_or_default]
     pub size: Option<Size>,
+    /// Overrides the padding of the [section element][bd].
+    ///
+    /// Overrides the default padding of the [Bulma section element][bd] which
+    /// will receive these properties, using the [Bulma spacing helpers][sh].
+    /// Since the spacing helpers take precedence over the section styles, this
+    /// avoids the need for custom classes when tightening the layout.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     helpers::spacing::{Direction, Spacing},
+    ///     layout::section::Section,
+    /// };
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <Section padding={(Direction::Vertical, Spacing::Two)}>
+    ///             {"This is some text in a compact section"}
+    ///         </Section>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/layout/section/
+    /// [sh]: https://bulma.io/documentation/helpers/spacing-helpers/
+    #[prop_or_default]
+    pub padding: Option<(Direction, Spacing)>,
     /// The list of elements found inside the [section element][bd].
     ///
     /// Defines the elements that will be found inside the
@@ -99,9 +132,13 @@ pub fn section(props: &SectionProperties) -> Html {
             }
         })
         .unwrap_or("".to_owned());
-    let class = ClassBuilder::default()
+    let mut class = ClassBuilder::default()
         .with_custom_class("section")
-        .with_custom_class(&size)
+        .with_custom_class(&size);
+    if let Some((direction, spacing)) = &props.padding {
+        class = class.with_padding(direction.clone(), spacing.clone());
+    }
+    let class = class
         .with_custom_class(
             &props
                 .class
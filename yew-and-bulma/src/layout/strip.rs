@@ -0,0 +1,250 @@
+use yew::{
+    function_component, html, use_effect_with_deps, use_node_ref, use_state, AttrValue, Callback,
+    Children, Event, Html, MouseEvent, Properties,
+};
+use yew_and_bulma_macros::base_component_properties;
+
+use crate::{elements::button::Button, utils::class::ClassBuilder};
+
+const FADE: &str = "2rem";
+
+/// Defines the properties of the card strip.
+///
+/// Defines the properties of the card strip, a horizontally scrollable row
+/// of cards (ie "recently viewed" items), snapping to each card, with
+/// previous and next buttons and faded edges.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::strip::CardStrip;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <CardStrip>
+///             <div class="card">{"First"}</div>
+///             <div class="card">{"Second"}</div>
+///         </CardStrip>
+///     }
+/// }
+/// ```
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct CardStripProperties {
+    /// Sets the width of each card of the card strip.
+    ///
+    /// Sets the CSS width of each card of the card strip which will receive
+    /// these properties. Defaults to `16rem`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::strip::CardStrip;
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     html! {
+    ///         <CardStrip item_width="12rem">
+    ///             <div class="card">{"First"}</div>
+    ///         </CardStrip>
+    ///     }
+    /// }
+    /// ```
+    #[prop_or(AttrValue::from("16rem"))]
+    pub item_width: AttrValue,
+    /// Whether or not to show the previous and next buttons.
+    ///
+    /// Whether or not the card strip, which will receive these properties,
+    /// shows buttons scrolling it by its visible width. Defaults to `true`.
+    #[prop_or(true)]
+    pub buttons: bool,
+    /// Whether or not to fade the edges of the card strip.
+    ///
+    /// Whether or not the edges of the card strip, which will receive these
+    /// properties, fade out while there are more cards to scroll to in their
+    /// direction. Defaults to `true`.
+    #[prop_or(true)]
+    pub fade: bool,
+    /// Sets the text of the previous button. Defaults to `‹`.
+    #[prop_or(AttrValue::from("‹"))]
+    pub previous_label: AttrValue,
+    /// Sets the text of the next button. Defaults to `›`.
+    #[prop_or(AttrValue::from("›"))]
+    pub next_label: AttrValue,
+    /// The cards found inside the card strip.
+    ///
+    /// Defines the cards found inside the card strip which will receive these
+    /// properties, each one wrapped in a snapping item of the same width.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Yew implementation of the card strip.
+///
+/// Renders its children in a horizontally scrollable row using CSS scroll
+/// snapping, with previous and next buttons overlaid on its sides, disabled
+/// once the row is scrolled to the respective edge.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::strip::CardStrip;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let cards = (1..=10).map(|index| html! {
+///         <div class="card">
+///             <div class="card-content">{format!("Product {index}")}</div>
+///         </div>
+///     });
+///
+///     html! {
+///         <CardStrip>{ for cards }</CardStrip>
+///     }
+/// }
+/// ```
+#[function_component(CardStrip)]
+pub fn card_strip(props: &CardStripProperties) -> Html {
+    let node = use_node_ref();
+    let edges = use_state(|| (true, true));
+    let update_edges = {
+        let node = node.clone();
+        let edges = edges.clone();
+        move || {
+            if let Some(element) = node.cast::<web_sys::Element>() {
+                edges.set(scroll_edges(
+                    f64::from(element.scroll_left()),
+                    f64::from(element.scroll_width()),
+                    f64::from(element.client_width()),
+                ));
+            }
+        }
+    };
+
+    {
+        let update_edges = update_edges.clone();
+        use_effect_with_deps(move |_| update_edges(), props.children.len());
+    }
+
+    let onscroll = {
+        let onscroll = props.onscroll.clone();
+        Callback::from(move |event: Event| {
+            update_edges();
+            if let Some(onscroll) = &onscroll {
+                onscroll.emit(event);
+            }
+        })
+    };
+    let scroll = |direction: f64| {
+        let node = node.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let Some(element) = node.cast::<web_sys::Element>() {
+                element.scroll_by_with_x_and_y(direction * f64::from(element.client_width()), 0.0);
+            }
+        })
+    };
+    let onprevious = scroll(-1.0);
+    let onnext = scroll(1.0);
+    let (at_start, at_end) = *edges;
+    let mask = fade_mask(props.fade && !at_start, props.fade && !at_end)
+        .map(|mask| format!("mask-image: {mask}; -webkit-mask-image: {mask};"))
+        .unwrap_or_default();
+    let class = ClassBuilder::default()
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+    let track_style = format!(
+        "display: flex; gap: 0.75rem; overflow-x: auto; scroll-snap-type: x mandatory; \
+        scroll-behavior: smooth; {mask}"
+    );
+    let item_style = format!("flex: 0 0 {}; scroll-snap-align: start;", props.item_width);
+    let button_style = |side: &str| {
+        format!("position: absolute; top: 50%; {side}: 0; transform: translateY(-50%); z-index: 1;")
+    };
+
+    html! {
+        <div id={props.id.clone()} {class} style="position: relative;"
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            <div ref={node} style={track_style} {onscroll}>
+                { for props.children.iter().map(|child| html! {
+                    <div style={item_style.clone()}>{child}</div>
+                }) }
+            </div>
+            if props.buttons {
+                <div style={button_style("left")}>
+                    <Button onclick={onprevious} disabled={at_start}>{props.previous_label.clone()}</Button>
+                </div>
+                <div style={button_style("right")}>
+                    <Button onclick={onnext} disabled={at_end}>{props.next_label.clone()}</Button>
+                </div>
+            }
+        </div>
+    }
+}
+
+fn scroll_edges(scroll_left: f64, scroll_width: f64, client_width: f64) -> (bool, bool) {
+    (
+        scroll_left <= 1.0,
+        scroll_left + client_width >= scroll_width - 1.0,
+    )
+}
+
+fn fade_mask(start: bool, end: bool) -> Option<String> {
+    (start || end).then(|| {
+        let start = if start { FADE } else { "0" };
+        let end = if end { FADE } else { "0" };
+        format!(
+            "linear-gradient(to right, transparent, black {start}, black calc(100% - {end}), transparent)"
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(0.0, 1000.0, 400.0, (true, false) ; "unscrolled strips are at the start")]
+    #[test_case(300.0, 1000.0, 400.0, (false, false) ; "scrolled strips are between edges")]
+    #[test_case(600.0, 1000.0, 400.0, (false, true) ; "fully scrolled strips are at the end")]
+    #[test_case(0.0, 400.0, 400.0, (true, true) ; "strips without overflow are at both edges")]
+    #[test_case(599.5, 1000.0, 400.0, (false, true) ; "fractional scrolls reach the end")]
+    fn scroll_edges_values(
+        scroll_left: f64,
+        scroll_width: f64,
+        client_width: f64,
+        expected: (bool, bool),
+    ) {
+        assert_eq!(
+            scroll_edges(scroll_left, scroll_width, client_width),
+            expected
+        );
+    }
+
+    #[test_case(false, false, None ; "no fade without hidden cards")]
+    #[test_case(true, false, Some("linear-gradient(to right, transparent, black 2rem, black calc(100% - 0), transparent)") ; "start fades")]
+    #[test_case(true, true, Some("linear-gradient(to right, transparent, black 2rem, black calc(100% - 2rem), transparent)") ; "both edges fade")]
+    fn fade_mask_values(start: bool, end: bool, expected: Option<&str>) {
+        assert_eq!(fade_mask(start, end).as_deref(), expected);
+    }
+}
@@ -76,7 +76,7 @@ impl Display for Relation {
 /// ```
 ///
 /// [bd]: https://bulma.io/documentation/layout/tiles/#modifiers
-#[derive(PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Size {
     One,
     Two,
@@ -304,3 +304,252 @@ pub fn tile(props: &TileProperties) -> Html {
         </div>
     }
 }
+
+/// Describes a node of a [Bulma tile grid][bd].
+///
+/// Describes the structure of a grid of [Bulma tile elements][bd] as data,
+/// which is then used by [`crate::layout::tile::TileGrid`] to generate the
+/// ancestor, parent and child tiles. Parent nodes which only hold child nodes
+/// are rendered as `is-parent` tiles, while parent nodes holding other parents
+/// are rendered as plain (usually vertical) tiles, wrapping any child nodes
+/// they hold in `is-parent` tiles.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::tile::{Size, TileGrid, TileNode};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let nodes = vec![
+///         TileNode::parent(vec![
+///             TileNode::child(html! {"Top"}, None),
+///             TileNode::child(html! {"Bottom"}, None),
+///         ])
+///         .vertical()
+///         .with_size(Size::Four),
+///         TileNode::child(html! {"Wide"}, None),
+///     ];
+///
+///     html! {
+///         <TileGrid {nodes} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/layout/tiles/
+#[derive(Clone, Debug, PartialEq)]
+pub enum TileNode {
+    Parent {
+        children: Vec<TileNode>,
+        size: Option<Size>,
+        vertical: bool,
+    },
+    Child {
+        content: Html,
+        size: Option<Size>,
+    },
+}
+
+impl TileNode {
+    /// Creates a parent node holding the given nodes.
+    ///
+    /// Creates a node which will contain the given tile nodes, with no size
+    /// and stacking them horizontally.
+    pub fn parent(children: Vec<TileNode>) -> Self {
+        TileNode::Parent {
+            children,
+            size: None,
+            vertical: false,
+        }
+    }
+
+    /// Creates a child node holding the given content.
+    ///
+    /// Creates a node which will render the given content, having the given
+    /// size, if any.
+    pub fn child(content: Html, size: Option<Size>) -> Self {
+        TileNode::Child { content, size }
+    }
+
+    /// Sets the size of the node, overriding any previous one.
+    pub fn with_size(self, size: Size) -> Self {
+        match self {
+            TileNode::Parent {
+                children, vertical, ..
+            } => TileNode::Parent {
+                children,
+                size: Some(size),
+                vertical,
+            },
+            TileNode::Child { content, .. } => TileNode::Child {
+                content,
+                size: Some(size),
+            },
+        }
+    }
+
+    /// Makes the nodes found inside a parent node stack vertically.
+    ///
+    /// Has no effect on child nodes.
+    pub fn vertical(self) -> Self {
+        match self {
+            TileNode::Parent { children, size, .. } => TileNode::Parent {
+                children,
+                size,
+                vertical: true,
+            },
+            child => child,
+        }
+    }
+
+    fn render(&self, inside_parent: bool) -> Html {
+        match self {
+            TileNode::Child { content, size } if inside_parent => html! {
+                <Tile relation={Relation::Child} size={*size}>{content.clone()}</Tile>
+            },
+            TileNode::Child { content, size } => html! {
+                <Tile relation={Relation::Parent} size={*size}>
+                    <Tile relation={Relation::Child}>{content.clone()}</Tile>
+                </Tile>
+            },
+            TileNode::Parent {
+                children,
+                size,
+                vertical,
+            } => {
+                let is_parent = children
+                    .iter()
+                    .all(|child| matches!(child, TileNode::Child { .. }));
+                let relation = if is_parent {
+                    Some(Relation::Parent)
+                } else {
+                    None
+                };
+
+                html! {
+                    <Tile {relation} size={*size} vertical={*vertical}>
+                        { for children.iter().map(|child| child.render(is_parent)) }
+                    </Tile>
+                }
+            }
+        }
+    }
+}
+
+/// Defines the properties of the [Bulma tile grid][bd].
+///
+/// Defines the properties of the tile grid, which generates the
+/// [Bulma tile elements][bd] described by a list of
+/// [`crate::layout::tile::TileNode`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::tile::{TileGrid, TileNode};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let nodes = vec![TileNode::child(html! {"This is some text in a tile."}, None)];
+///
+///     html! {
+///         <TileGrid {nodes} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/layout/tiles/
+#[base_component_properties]
+#[derive(Properties, PartialEq)]
+pub struct TileGridProperties {
+    /// The list of nodes describing the [tile elements][bd] of the grid.
+    ///
+    /// Defines the nodes from which the [Bulma tile elements][bd] found inside
+    /// the ancestor tile of the grid, which will receive these properties,
+    /// are generated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::layout::tile::{Size, TileGrid, TileNode};
+    ///
+    /// #[function_component(App)]
+    /// fn app() -> Html {
+    ///     let nodes = vec![
+    ///         TileNode::child(html! {"First tile"}, Some(Size::Eight)),
+    ///         TileNode::child(html! {"Second tile"}, None),
+    ///     ];
+    ///
+    ///     html! {
+    ///         <TileGrid {nodes} />
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/layout/tiles/
+    pub nodes: Vec<TileNode>,
+}
+
+/// Yew implementation of a grid of [Bulma tile elements][bd].
+///
+/// Generates the ancestor, parent and child [Bulma tile elements][bd]
+/// described by the given list of [`crate::layout::tile::TileNode`], taking
+/// care of the relations between them.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::layout::tile::{TileGrid, TileNode};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let nodes = vec![
+///         TileNode::parent(vec![
+///             TileNode::child(html! {"First tile"}, None),
+///             TileNode::child(html! {"Second tile"}, None),
+///         ]),
+///     ];
+///
+///     html! {
+///         <TileGrid {nodes} />
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/layout/tiles/
+#[function_component(TileGrid)]
+pub fn tile_grid(props: &TileGridProperties) -> Html {
+    let class = ClassBuilder::default()
+        .with_custom_class("tile")
+        .with_custom_class(&format!("{IS_PREFIX}-{}", Relation::Ancestor))
+        .with_custom_class(
+            &props
+                .class
+                .as_ref()
+                .map(|c| c.to_string())
+                .unwrap_or("".to_owned()),
+        )
+        .build();
+
+    html! {
+        <div id={&props.id} {class}
+            onclick={props.onclick.clone()} onwheel={props.onwheel.clone()} onscroll={props.onscroll.clone()}
+            onmousedown={props.onmousedown.clone()} onmousemove={props.onmousemove.clone()} onmouseout={props.onmouseout.clone()} onmouseover={props.onmouseover.clone()} onmouseup={props.onmouseup.clone()}
+            ondrag={props.ondrag.clone()} ondragend={props.ondragend.clone()} ondragenter={props.ondragenter.clone()} ondragleave={props.ondragleave.clone()} ondragover={props.ondragover.clone()} ondragstart={props.ondragstart.clone()} ondrop={props.ondrop.clone()}
+            oncopy={props.oncopy.clone()} oncut={props.oncut.clone()} onpaste={props.onpaste.clone()}
+            onkeydown={props.onkeydown.clone()} onkeypress={props.onkeypress.clone()} onkeyup={props.onkeyup.clone()}
+            onblur={props.onblur.clone()} onchange={props.onchange.clone()} oncontextmenu={props.oncontextmenu.clone()} onfocus={props.onfocus.clone()} oninput={props.oninput.clone()} oninvalid={props.oninvalid.clone()} onreset={props.onreset.clone()} onselect={props.onselect.clone()} onsubmit={props.onsubmit.clone()}
+            onabort={props.onabort.clone()} oncanplay={props.oncanplay.clone()} oncanplaythrough={props.oncanplaythrough.clone()} oncuechange={props.oncuechange.clone()}
+            ondurationchange={props.ondurationchange.clone()} onemptied={props.onemptied.clone()} onended={props.onended.clone()} onerror={props.onerror.clone()}
+            onloadeddata={props.onloadeddata.clone()} onloadedmetadata={props.onloadedmetadata.clone()} onloadstart={props.onloadstart.clone()} onpause={props.onpause.clone()}
+            onplay={props.onplay.clone()} onplaying={props.onplaying.clone()} onprogress={props.onprogress.clone()} onratechange={props.onratechange.clone()}
+            onseeked={props.onseeked.clone()} onseeking={props.onseeking.clone()} onstalled={props.onstalled.clone()} onsuspend={props.onsuspend.clone()}
+            ontimeupdate={props.ontimeupdate.clone()} onvolumechange={props.onvolumechange.clone()} onwaiting={props.onwaiting.clone()}>
+            { for props.nodes.iter().map(|node| node.render(false)) }
+        </div>
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, fmt, hash::Hash};
 
 use yew::{classes, Classes};
 
@@ -13,13 +13,18 @@ use crate::{
         typography::{FontFamily, TextAlignment, TextDecoration, TextSize, TextWeight},
         visibility::{Display, Viewport},
     },
-    utils::constants::{
-        HAS_BACKGROUND_PREFIX, HAS_TEXT_PREFIX, HAS_TEXT_WEIGHT_PREFIX, IS_ALIGN_CONTENT_PREFIX,
-        IS_ALIGN_ITEMS_PREFIX, IS_ALIGN_SELF_PREFIX, IS_CLEARFIX, IS_CLICKABLE, IS_CLIPPED,
-        IS_FLEX_DIRECTION_PREFIX, IS_FLEX_GROW_PREFIX, IS_FLEX_SHRINK_PREFIX, IS_FLEX_WRAP_PREFIX,
-        IS_FONT_FAMILY_PREFIX, IS_JUSTIFY_CONTENT_PREFIX, IS_LIGHT, IS_OVERLAY, IS_PREFIX,
-        IS_PULLED_LEFT, IS_PULLED_RIGHT, IS_RADIUSLESS, IS_RELATIVE, IS_SHADOWLESS, IS_SIZE_PREFIX,
-        IS_UNSELECTABLE, MARGIN_PREFIX, PADDING_PREFIX,
+    utils::{
+        constants::{
+            HAS_BACKGROUND_PREFIX, HAS_TEXT_PREFIX, HAS_TEXT_WEIGHT_PREFIX,
+            IS_ALIGN_CONTENT_PREFIX, IS_ALIGN_ITEMS_PREFIX, IS_ALIGN_SELF_PREFIX, IS_CLEARFIX,
+            IS_CLICKABLE, IS_CLIPPED, IS_FLEX_DIRECTION_PREFIX, IS_FLEX_GROW_PREFIX,
+            IS_FLEX_SHRINK_PREFIX, IS_FLEX_WRAP_PREFIX, IS_FONT_FAMILY_PREFIX, IS_HIDDEN,
+            IS_HIDDEN_PRINT, IS_INVISIBLE, IS_JUSTIFY_CONTENT_PREFIX, IS_LIGHT, IS_OVERLAY,
+            IS_PREFIX, IS_PRINT_ONLY, IS_PULLED_LEFT, IS_PULLED_RIGHT, IS_RADIUSLESS, IS_RELATIVE,
+            IS_SHADOWLESS, IS_SIZE_PREFIX, IS_SR_ONLY, IS_UNSELECTABLE, MARGIN_PREFIX,
+            PADDING_PREFIX,
+        },
+        style::StyleBuilder,
     },
 };
 
@@ -45,24 +50,27 @@ impl From<TextModifiers> for Classes {
     fn from(value: TextModifiers) -> Self {
         let color = value.color.map(|tc| format!("{HAS_TEXT_PREFIX}-{tc}"));
         let size = value.size.map(|ts| format!("{IS_SIZE_PREFIX}-{ts}"));
-        let viewport_sizes: Vec<_> = value
+        let mut viewport_sizes: Vec<_> = value
             .viewport_sizes
             .iter()
             .map(|(viewport, size)| format!("{IS_SIZE_PREFIX}-{size}-{viewport}"))
             .collect();
+        viewport_sizes.sort_unstable();
         let alignment = value
             .alignment
             .map(|alignment| format!("{HAS_TEXT_PREFIX}-{alignment}"));
-        let viewport_alignments: Vec<_> = value
+        let mut viewport_alignments: Vec<_> = value
             .viewport_alignments
             .iter()
             .map(|(viewport, alignment)| format!("{HAS_TEXT_PREFIX}-{alignment}-{viewport}"))
             .collect();
-        let decorations: Vec<_> = value
+        viewport_alignments.sort_unstable();
+        let mut decorations: Vec<_> = value
             .decorations
             .iter()
             .map(|decoration| format!("{IS_PREFIX}-{decoration}"))
             .collect();
+        decorations.sort_unstable();
         let weight = value
             .weight
             .map(|weight| format!("{HAS_TEXT_WEIGHT_PREFIX}-{weight}"));
@@ -83,6 +91,21 @@ impl From<TextModifiers> for Classes {
     }
 }
 
+impl TextModifiers {
+    fn merge(self, other: Self) -> Self {
+        Self {
+            color: other.color.or(self.color),
+            size: other.size.or(self.size),
+            viewport_sizes: union(self.viewport_sizes, other.viewport_sizes),
+            alignment: other.alignment.or(self.alignment),
+            viewport_alignments: union(self.viewport_alignments, other.viewport_alignments),
+            decorations: union(self.decorations, other.decorations),
+            weight: other.weight.or(self.weight),
+            font_family: other.font_family.or(self.font_family),
+        }
+    }
+}
+
 /// Groups together the possible alignment modifiers
 ///
 /// Logical struct which groups together all the possible alignment modifiers
@@ -141,6 +164,21 @@ impl From<AlignmentModifiers> for Classes {
     }
 }
 
+impl AlignmentModifiers {
+    fn merge(self, other: Self) -> Self {
+        Self {
+            flex_direction: other.flex_direction.or(self.flex_direction),
+            flex_wrap: other.flex_wrap.or(self.flex_wrap),
+            justify_content: other.justify_content.or(self.justify_content),
+            align_content: other.align_content.or(self.align_content),
+            align_items: other.align_items.or(self.align_items),
+            align_self: other.align_self.or(self.align_self),
+            flex_grow: other.flex_grow.or(self.flex_grow),
+            flex_shrink: other.flex_shrink.or(self.flex_shrink),
+        }
+    }
+}
+
 /// Groups together the possible other modifiers
 ///
 /// Logical struct which groups together all the possible other modifiers that
@@ -216,6 +254,110 @@ impl From<OtherModifiers> for Classes {
     }
 }
 
+impl OtherModifiers {
+    fn merge(self, other: Self) -> Self {
+        Self {
+            is_clearfix: other.is_clearfix.or(self.is_clearfix),
+            is_pulled_left: other.is_pulled_left.or(self.is_pulled_left),
+            is_pulled_right: other.is_pulled_right.or(self.is_pulled_right),
+            is_overlay: other.is_overlay.or(self.is_overlay),
+            is_clipped: other.is_clipped.or(self.is_clipped),
+            is_radiusless: other.is_radiusless.or(self.is_radiusless),
+            is_shadowless: other.is_shadowless.or(self.is_shadowless),
+            is_unselectable: other.is_unselectable.or(self.is_unselectable),
+            is_clickable: other.is_clickable.or(self.is_clickable),
+            is_relative: other.is_relative.or(self.is_relative),
+        }
+    }
+}
+
+/// Groups of Bulma modifiers which can only hold a single value.
+///
+/// Used by [`crate::utils::class::ClassBuilder::merge`] to find classes which
+/// conflict with each other (ie `is-primary` and `is-danger`), whether they
+/// were set through the builder methods or as custom classes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum ModifierGroup {
+    Color,
+    TextColor,
+    BackgroundColor,
+    TextSize,
+    TextAlignment,
+    TextWeight,
+}
+
+const TEXT_SIZES: [TextSize; 7] = [
+    TextSize::One,
+    TextSize::Two,
+    TextSize::Three,
+    TextSize::Four,
+    TextSize::Five,
+    TextSize::Six,
+    TextSize::Seven,
+];
+const TEXT_ALIGNMENTS: [TextAlignment; 4] = [
+    TextAlignment::Centered,
+    TextAlignment::Justified,
+    TextAlignment::Left,
+    TextAlignment::Right,
+];
+
+impl ModifierGroup {
+    /// Returns the group a custom class belongs to, if it is a known Bulma
+    /// modifier.
+    fn of(class: &str) -> Option<Self> {
+        if class.starts_with(&format!("{HAS_BACKGROUND_PREFIX}-")) {
+            Some(ModifierGroup::BackgroundColor)
+        } else if class.starts_with(&format!("{HAS_TEXT_WEIGHT_PREFIX}-")) {
+            Some(ModifierGroup::TextWeight)
+        } else if let Some(text) = class.strip_prefix(&format!("{HAS_TEXT_PREFIX}-")) {
+            let alignment = TEXT_ALIGNMENTS
+                .iter()
+                .map(|alignment| alignment.to_string())
+                .find(|alignment| text.starts_with(alignment.as_str()));
+            match alignment {
+                Some(alignment) if alignment == text => Some(ModifierGroup::TextAlignment),
+                Some(_) => None,
+                None => Some(ModifierGroup::TextColor),
+            }
+        } else if let Some(size) = class.strip_prefix(&format!("{IS_SIZE_PREFIX}-")) {
+            TEXT_SIZES
+                .iter()
+                .any(|text_size| text_size.to_string() == size)
+                .then(|| ModifierGroup::TextSize)
+        } else if class == IS_LIGHT {
+            // `is-light` is usually the light variant of another color (ie
+            // `is-primary is-light`), rather than a color of its own.
+            None
+        } else if let Some(color) = class.strip_prefix(&format!("{IS_PREFIX}-")) {
+            Color::ALL
+                .iter()
+                .any(|c| c.to_string() == color)
+                .then(|| ModifierGroup::Color)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the sides affected by a spacing direction.
+fn sides(direction: &Direction) -> &'static [&'static str] {
+    match direction {
+        Direction::All => &["t", "r", "b", "l"],
+        Direction::Top => &["t"],
+        Direction::Right => &["r"],
+        Direction::Bottom => &["b"],
+        Direction::Left => &["l"],
+        Direction::Horizontal => &["r", "l"],
+        Direction::Vertical => &["t", "b"],
+    }
+}
+
+fn union<T: Eq + Hash>(mut first: HashSet<T>, second: HashSet<T>) -> HashSet<T> {
+    first.extend(second);
+    first
+}
+
 /// CSS class builder for Bulma and custom classes.
 ///
 /// Used to build various combination of CSS classes, implementing most options
@@ -254,9 +396,18 @@ pub struct ClassBuilder {
     is_light: Option<bool>,
     display: Option<Display>,
     viewport_displays: HashSet<(Viewport, Display)>,
+    is_hidden: Option<bool>,
+    viewport_hidden: HashSet<Viewport>,
+    is_invisible: Option<bool>,
+    is_sr_only: Option<bool>,
+    is_print_hidden: Option<bool>,
+    is_print_only: Option<bool>,
     alignment_modifiers: AlignmentModifiers,
     margins: HashSet<(Direction, Spacing)>,
+    viewport_margins: HashSet<(Viewport, Direction, Spacing)>,
     paddings: HashSet<(Direction, Spacing)>,
+    viewport_paddings: HashSet<(Viewport, Direction, Spacing)>,
+    raw_spacings: StyleBuilder,
     other_modifiers: OtherModifiers,
 }
 
@@ -269,7 +420,7 @@ impl ClassBuilder {
     /// checked it prior to the call.
     ///
     /// > _If you add the same class multiple times, it will only appear once
-    /// in the final list._
+    /// > in the final list._
     ///
     /// # Examples
     ///
@@ -330,6 +481,118 @@ impl ClassBuilder {
         self
     }
 
+    /// Create a builder from an existing list of classes.
+    ///
+    /// Create a new builder holding every class found in the given list as a
+    /// custom class. This is useful when combined with
+    /// [`ClassBuilder::merge`], to take the `class` property of a component
+    /// into account when building its own classes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::utils::class::ClassBuilder;
+    ///
+    /// // Create a `<div>` HTML element from the classes it received.
+    /// #[function_component(ClassesDiv)]
+    /// fn classes_div() -> Html {
+    ///     let class = ClassBuilder::from_classes(classes!("my-div", "is-primary")).build();
+    ///     html!{
+    ///         <div class={class}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    pub fn from_classes(classes: Classes) -> Self {
+        let custom_classes = classes
+            .to_string()
+            .split_whitespace()
+            .map(str::to_owned)
+            .collect();
+
+        Self {
+            custom_classes,
+            ..Self::default()
+        }
+    }
+
+    /// Merge another builder into the current one.
+    ///
+    /// Merge the classes of another builder into the current one. Classes
+    /// which can appear multiple times (ie custom classes, margins or
+    /// decorations) are combined, while the values of the other builder take
+    /// precedence for those which can only hold one. Custom classes matching
+    /// a Bulma color, text color, background color, text size, text
+    /// alignment or text weight are taken into account as well, so a
+    /// user-supplied `is-danger` replaces the `is-primary` color set by a
+    /// component, instead of both being added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     helpers::color::Color,
+    ///     utils::class::{ClassBuilder, SortedClasses},
+    /// };
+    ///
+    /// #[derive(Properties, PartialEq)]
+    /// struct ButtonProperties {
+    ///     #[prop_or_default]
+    ///     class: Classes,
+    /// }
+    ///
+    /// // Create a primary `<button>` HTML element whose classes can be
+    /// // overridden by the caller.
+    /// #[function_component(PrimaryButton)]
+    /// fn primary_button(props: &ButtonProperties) -> Html {
+    ///     let class = ClassBuilder::default()
+    ///         .with_custom_class("button")
+    ///         .with_color(Some(Color::Primary))
+    ///         .merge(ClassBuilder::from_classes(props.class.clone()))
+    ///         .build();
+    ///     html!{
+    ///         <button class={class}>{ "Lorem ispum..." }</button>
+    ///     }
+    /// }
+    ///
+    /// let class = ClassBuilder::default()
+    ///     .with_custom_class("button")
+    ///     .with_color(Some(Color::Primary))
+    ///     .merge(ClassBuilder::from_classes(classes!("is-danger")))
+    ///     .build();
+    /// assert_eq!(class.to_sorted_string(), "button is-danger");
+    /// ```
+    pub fn merge(self, other: ClassBuilder) -> Self {
+        let current = other
+            .modifier_groups()
+            .into_iter()
+            .fold(self, ClassBuilder::without_modifier_group);
+
+        Self {
+            custom_classes: union(current.custom_classes, other.custom_classes),
+            text_modifiers: current.text_modifiers.merge(other.text_modifiers),
+            background_color: other.background_color.or(current.background_color),
+            color: other.color.or(current.color),
+            is_light: other.is_light.or(current.is_light),
+            display: other.display.or(current.display),
+            viewport_displays: union(current.viewport_displays, other.viewport_displays),
+            is_hidden: other.is_hidden.or(current.is_hidden),
+            viewport_hidden: union(current.viewport_hidden, other.viewport_hidden),
+            is_invisible: other.is_invisible.or(current.is_invisible),
+            is_sr_only: other.is_sr_only.or(current.is_sr_only),
+            is_print_hidden: other.is_print_hidden.or(current.is_print_hidden),
+            is_print_only: other.is_print_only.or(current.is_print_only),
+            alignment_modifiers: current.alignment_modifiers.merge(other.alignment_modifiers),
+            margins: union(current.margins, other.margins),
+            viewport_margins: union(current.viewport_margins, other.viewport_margins),
+            paddings: union(current.paddings, other.paddings),
+            viewport_paddings: union(current.viewport_paddings, other.viewport_paddings),
+            raw_spacings: current.raw_spacings.merge(other.raw_spacings),
+            other_modifiers: current.other_modifiers.merge(other.other_modifiers),
+        }
+    }
+
     /// Set the text color using a [Bulma text color helper][bd].
     ///
     /// Set a [Bulma text color helper class][bd] to be added to the current
@@ -505,7 +768,7 @@ impl ClassBuilder {
     /// current list of classes.
     ///
     /// > _If you add the same viewport size multiple times, it will only
-    /// appear once in the final list._
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
@@ -620,7 +883,7 @@ impl ClassBuilder {
     /// the current list of classes.
     ///
     /// > _If you add the same viewport alignment multiple times, it will only
-    /// appear once in the final list._
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
@@ -708,7 +971,7 @@ impl ClassBuilder {
     /// current list of classes.
     ///
     /// > _If you add the same viewport alignment multiple times, it will only
-    /// appear once in the final list._
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
@@ -884,7 +1147,7 @@ impl ClassBuilder {
     /// the current list of classes.
     ///
     /// > _If you add the same viewport display multiple times, it will only
-    /// appear once in the final list._
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
@@ -952,6 +1215,238 @@ impl ClassBuilder {
         self
     }
 
+    /// Set whether the element should be hidden.
+    ///
+    /// Set the `is-hidden` [Bulma visibility helper class][bd] to be added to
+    /// the current list of classes, which hides the element on every viewport.
+    /// To remove it, simply pass `None` to the call. Every call to this method
+    /// overrides the previous value to the one received.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::utils::class::ClassBuilder;
+    ///
+    /// // Create a `<div>` HTML element that is hidden.
+    /// #[function_component(HiddenDiv)]
+    /// fn hidden_div() -> Html {
+    ///     let class = ClassBuilder::default()
+    ///         .is_hidden(Some(true))
+    ///         .build();
+    ///     html!{
+    ///         <div class={class}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/visibility-helpers/#hide
+    pub fn is_hidden(mut self, is_hidden: Option<bool>) -> Self {
+        self.is_hidden = is_hidden;
+        self
+    }
+
+    /// Hide the element on a given viewport.
+    ///
+    /// Add a viewport specific [Bulma hide helper class][bd] (ie
+    /// `is-hidden-touch`) to the current list of classes, which hides the
+    /// element only on the given viewport.
+    ///
+    /// > _If you add the same viewport multiple times, it will only appear
+    /// > once in the final list._
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     helpers::visibility::Viewport,
+    ///     utils::class::ClassBuilder,
+    /// };
+    ///
+    /// // Create a `<div>` HTML element that is hidden on touch devices.
+    /// #[function_component(DesktopOnlyDiv)]
+    /// fn desktop_only_div() -> Html {
+    ///     let class = ClassBuilder::default()
+    ///         .with_viewport_hidden(Viewport::Touch)
+    ///         .build();
+    ///     html!{
+    ///         <div class={class}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/visibility-helpers/#hide
+    pub fn with_viewport_hidden(mut self, viewport: Viewport) -> Self {
+        self.viewport_hidden.insert(viewport);
+        self
+    }
+
+    /// Stop hiding the element on a given viewport.
+    ///
+    /// Remove a viewport specific [Bulma hide helper class][bd] (ie
+    /// `is-hidden-touch`) from the current list of classes. If the class was
+    /// not previously added, nothing happens.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     helpers::visibility::Viewport,
+    ///     utils::class::ClassBuilder,
+    /// };
+    ///
+    /// // Create a `<div>` HTML element that is hidden on mobile only.
+    /// #[function_component(NotMobileDiv)]
+    /// fn not_mobile_div() -> Html {
+    ///     let class = ClassBuilder::default()
+    ///         .with_viewport_hidden(Viewport::Touch)
+    ///         .with_viewport_hidden(Viewport::Mobile)
+    ///         .without_viewport_hidden(Viewport::Touch)
+    ///         .build();
+    ///     html!{
+    ///         <div class={class}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/visibility-helpers/#hide
+    pub fn without_viewport_hidden(mut self, viewport: Viewport) -> Self {
+        self.viewport_hidden.remove(&viewport);
+        self
+    }
+
+    /// Set whether the element should be invisible.
+    ///
+    /// Set the `is-invisible` [Bulma visibility helper class][bd] to be added
+    /// to the current list of classes, which makes the element invisible while
+    /// still taking up space. To remove it, simply pass `None` to the call.
+    /// Every call to this method overrides the previous value to the one
+    /// received.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::utils::class::ClassBuilder;
+    ///
+    /// // Create a `<div>` HTML element that is invisible.
+    /// #[function_component(InvisibleDiv)]
+    /// fn invisible_div() -> Html {
+    ///     let class = ClassBuilder::default()
+    ///         .is_invisible(Some(true))
+    ///         .build();
+    ///     html!{
+    ///         <div class={class}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/visibility-helpers/#other-visibility-helpers
+    pub fn is_invisible(mut self, is_invisible: Option<bool>) -> Self {
+        self.is_invisible = is_invisible;
+        self
+    }
+
+    /// Set whether the element should only be available to screen readers.
+    ///
+    /// Set the `is-sr-only` [Bulma visibility helper class][bd] to be added to
+    /// the current list of classes, which hides the element visually while
+    /// keeping it available to screen readers. To remove it, simply pass
+    /// `None` to the call. Every call to this method overrides the previous
+    /// value to the one received.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::utils::class::ClassBuilder;
+    ///
+    /// // Create a `<span>` HTML element that is only read by screen readers.
+    /// #[function_component(ScreenReaderOnlySpan)]
+    /// fn screen_reader_only_span() -> Html {
+    ///     let class = ClassBuilder::default()
+    ///         .is_sr_only(Some(true))
+    ///         .build();
+    ///     html!{
+    ///         <span class={class}>{ "Opens in a new tab" }</span>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/visibility-helpers/#other-visibility-helpers
+    pub fn is_sr_only(mut self, is_sr_only: Option<bool>) -> Self {
+        self.is_sr_only = is_sr_only;
+        self
+    }
+
+    /// Set whether the element should be hidden when printed.
+    ///
+    /// Set the `is-hidden-print` class to be added to the current list of
+    /// classes, which hides the element when the page is printed. To remove
+    /// it, simply pass `None` to the call. Every call to this method overrides
+    /// the previous value to the one received.
+    ///
+    /// > _Bulma does not ship print helpers, so the styles found in
+    /// > [`crate::utils::constants::PRINT_STYLES`] need to be added to the
+    /// > page for this class to have any effect._
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::utils::class::ClassBuilder;
+    ///
+    /// // Create a `<div>` HTML element that is not printed.
+    /// #[function_component(PrintHiddenDiv)]
+    /// fn print_hidden_div() -> Html {
+    ///     let class = ClassBuilder::default()
+    ///         .is_print_hidden(Some(true))
+    ///         .build();
+    ///     html!{
+    ///         <div class={class}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    pub fn is_print_hidden(mut self, is_print_hidden: Option<bool>) -> Self {
+        self.is_print_hidden = is_print_hidden;
+        self
+    }
+
+    /// Set whether the element should only be visible when printed.
+    ///
+    /// Set the `is-print-only` class to be added to the current list of
+    /// classes, which hides the element on screen, only showing it when the
+    /// page is printed. To remove it, simply pass `None` to the call. Every
+    /// call to this method overrides the previous value to the one received.
+    ///
+    /// > _Bulma does not ship print helpers, so the styles found in
+    /// > [`crate::utils::constants::PRINT_STYLES`] need to be added to the
+    /// > page for this class to have any effect._
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::utils::class::ClassBuilder;
+    ///
+    /// // Create a `<div>` HTML element that is only printed.
+    /// #[function_component(PrintOnlyDiv)]
+    /// fn print_only_div() -> Html {
+    ///     let class = ClassBuilder::default()
+    ///         .is_print_only(Some(true))
+    ///         .build();
+    ///     html!{
+    ///         <div class={class}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    pub fn is_print_only(mut self, is_print_only: Option<bool>) -> Self {
+        self.is_print_only = is_print_only;
+        self
+    }
+
     /// Set the flex direction using a [Bulma flex direction helper][bd].
     ///
     /// Set a [Bulma flex direction helper class][bd] to be added to the current
@@ -1298,7 +1793,7 @@ impl ClassBuilder {
     /// list of classes.
     ///
     /// > _If you add the same viewport alignment multiple times, it will only
-    /// appear once in the final list._
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
@@ -1364,13 +1859,18 @@ impl ClassBuilder {
         self
     }
 
-    /// Set a the padding using a [Bulma padding helper][bd].
+    /// Set a viewport specific margin using a [Bulma spacing helper][bd].
     ///
-    /// Set a [Bulma padding helper class][bd] to be added to the current
-    /// list of classes.
+    /// Add a margin class scoped to the given viewport (ie `mt-4-desktop`)
+    /// to the current list of classes, which only applies the margin on that
+    /// viewport.
     ///
-    /// > _If you add the same viewport alignment multiple times, it will only
-    /// appear once in the final list._
+    /// > _If you add the same margin multiple times, it will only appear once
+    /// > in the final list._
+    ///
+    /// > _Bulma does not ship viewport specific spacing helpers, so the
+    /// > styles created by [`crate::helpers::spacing::viewport_spacing_styles`]
+    /// > need to be added to the page for this class to have any effect._
     ///
     /// # Examples
     ///
@@ -1378,14 +1878,15 @@ impl ClassBuilder {
     /// use yew::prelude::*;
     /// use yew_and_bulma::{
     ///     helpers::spacing::{Direction, Spacing},
+    ///     helpers::visibility::Viewport,
     ///     utils::class::ClassBuilder,
     /// };
     ///
-    /// // Create a `<div>` HTML element that has the padding set to 2.
+    /// // Create a `<div>` HTML element that has the top margin set to 4 on desktop.
     /// #[function_component(SpacedDiv)]
     /// fn spaced_div() -> Html {
     ///     let class = ClassBuilder::default()
-    ///         .with_padding(Direction::All, Spacing::Two)
+    ///         .with_viewport_margin(Direction::Top, Spacing::Four, Viewport::Desktop)
     ///         .build();
     ///     html!{
     ///         <div class={class}>{ "Lorem ispum..." }</div>
@@ -1393,19 +1894,22 @@ impl ClassBuilder {
     /// }
     /// ```
     ///
-    /// [bd]: https://bulma.io/documentation/helpers/spacing-helpers/
-    pub fn with_padding(mut self, direction: Direction, spacing: Spacing) -> Self {
-        self.paddings.insert((direction, spacing));
+    /// [bd]: https://bulma.io/documentation/helpers/spacing-helpers
+    pub fn with_viewport_margin(
+        mut self,
+        direction: Direction,
+        spacing: Spacing,
+        viewport: Viewport,
+    ) -> Self {
+        self.viewport_margins.insert((viewport, direction, spacing));
         self
     }
 
-    /// Remove a padding specifier, if it exists.
-    ///
-    /// Remove a [Bulma padding helper class][bd], from the current list of
-    /// classes, if it exists.
+    /// Remove a viewport specific margin set using a [Bulma spacing helper][bd].
     ///
-    /// Removing the same specifier multiple times has the same result as trying
-    /// to remove an inexisting one, concretely, nothing will happen.
+    /// Remove a margin class scoped to the given viewport (ie
+    /// `mt-4-desktop`) from the current list of classes. If the class
+    /// was not previously added, nothing happens.
     ///
     /// # Examples
     ///
@@ -1413,16 +1917,16 @@ impl ClassBuilder {
     /// use yew::prelude::*;
     /// use yew_and_bulma::{
     ///     helpers::spacing::{Direction, Spacing},
+    ///     helpers::visibility::Viewport,
     ///     utils::class::ClassBuilder,
     /// };
     ///
-    /// // Create a `<div>` HTML element that does not have the padding set to 2.
-    /// #[function_component(NormalDiv)]
-    /// fn normal_div() -> Html {
-    ///     // Assume that instead of the default builder, one where the class
-    ///     // to be removed is actually used.
+    /// // Create a `<div>` HTML element that has no viewport specific margin.
+    /// #[function_component(UnspacedDiv)]
+    /// fn unspaced_div() -> Html {
     ///     let class = ClassBuilder::default()
-    ///         .without_padding(Direction::All, Spacing::Two)
+    ///         .with_viewport_margin(Direction::Top, Spacing::Four, Viewport::Desktop)
+    ///         .without_viewport_margin(Direction::Top, Spacing::Four, Viewport::Desktop)
     ///         .build();
     ///     html!{
     ///         <div class={class}>{ "Lorem ispum..." }</div>
@@ -1430,26 +1934,274 @@ impl ClassBuilder {
     /// }
     /// ```
     ///
-    /// [bd]: https://bulma.io/documentation/helpers/spacing-helpers/
-    pub fn without_padding(mut self, direction: Direction, spacing: Spacing) -> Self {
-        self.paddings.remove(&(direction, spacing));
+    /// [bd]: https://bulma.io/documentation/helpers/spacing-helpers
+    pub fn without_viewport_margin(
+        mut self,
+        direction: Direction,
+        spacing: Spacing,
+        viewport: Viewport,
+    ) -> Self {
+        self.viewport_margins
+            .remove(&(viewport, direction, spacing));
         self
     }
 
-    /// Set the [Bulma clearfix helper][bd].
+    /// Set a the padding using a [Bulma padding helper][bd].
     ///
-    /// Set the [Bulma clearfix helper class][bd] to be added to the current
-    /// list of classes. To remove a [clearfix helper][bd], simply pass `None`
-    /// to the call. Every call to this method overrides the previous value to
-    /// the one received.
+    /// Set a [Bulma padding helper class][bd] to be added to the current
+    /// list of classes.
+    ///
+    /// > _If you add the same viewport alignment multiple times, it will only
+    /// > appear once in the final list._
     ///
     /// # Examples
     ///
     /// ```rust
     /// use yew::prelude::*;
-    /// use yew_and_bulma::utils::class::ClassBuilder;
-    ///
-    /// // Create a `<div>` HTML element that has the clearfix Bulma class.
+    /// use yew_and_bulma::{
+    ///     helpers::spacing::{Direction, Spacing},
+    ///     utils::class::ClassBuilder,
+    /// };
+    ///
+    /// // Create a `<div>` HTML element that has the padding set to 2.
+    /// #[function_component(SpacedDiv)]
+    /// fn spaced_div() -> Html {
+    ///     let class = ClassBuilder::default()
+    ///         .with_padding(Direction::All, Spacing::Two)
+    ///         .build();
+    ///     html!{
+    ///         <div class={class}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/spacing-helpers/
+    pub fn with_padding(mut self, direction: Direction, spacing: Spacing) -> Self {
+        self.paddings.insert((direction, spacing));
+        self
+    }
+
+    /// Remove a padding specifier, if it exists.
+    ///
+    /// Remove a [Bulma padding helper class][bd], from the current list of
+    /// classes, if it exists.
+    ///
+    /// Removing the same specifier multiple times has the same result as trying
+    /// to remove an inexisting one, concretely, nothing will happen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     helpers::spacing::{Direction, Spacing},
+    ///     utils::class::ClassBuilder,
+    /// };
+    ///
+    /// // Create a `<div>` HTML element that does not have the padding set to 2.
+    /// #[function_component(NormalDiv)]
+    /// fn normal_div() -> Html {
+    ///     // Assume that instead of the default builder, one where the class
+    ///     // to be removed is actually used.
+    ///     let class = ClassBuilder::default()
+    ///         .without_padding(Direction::All, Spacing::Two)
+    ///         .build();
+    ///     html!{
+    ///         <div class={class}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/spacing-helpers/
+    pub fn without_padding(mut self, direction: Direction, spacing: Spacing) -> Self {
+        self.paddings.remove(&(direction, spacing));
+        self
+    }
+
+    /// Set a viewport specific padding using a [Bulma spacing helper][bd].
+    ///
+    /// Add a padding class scoped to the given viewport (ie `pt-4-desktop`)
+    /// to the current list of classes, which only applies the padding on that
+    /// viewport.
+    ///
+    /// > _If you add the same padding multiple times, it will only appear once
+    /// > in the final list._
+    ///
+    /// > _Bulma does not ship viewport specific spacing helpers, so the
+    /// > styles created by [`crate::helpers::spacing::viewport_spacing_styles`]
+    /// > need to be added to the page for this class to have any effect._
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     helpers::spacing::{Direction, Spacing},
+    ///     helpers::visibility::Viewport,
+    ///     utils::class::ClassBuilder,
+    /// };
+    ///
+    /// // Create a `<div>` HTML element that has the top padding set to 4 on desktop.
+    /// #[function_component(SpacedDiv)]
+    /// fn spaced_div() -> Html {
+    ///     let class = ClassBuilder::default()
+    ///         .with_viewport_padding(Direction::Top, Spacing::Four, Viewport::Desktop)
+    ///         .build();
+    ///     html!{
+    ///         <div class={class}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/spacing-helpers
+    pub fn with_viewport_padding(
+        mut self,
+        direction: Direction,
+        spacing: Spacing,
+        viewport: Viewport,
+    ) -> Self {
+        self.viewport_paddings
+            .insert((viewport, direction, spacing));
+        self
+    }
+
+    /// Remove a viewport specific padding set using a [Bulma spacing helper][bd].
+    ///
+    /// Remove a padding class scoped to the given viewport (ie
+    /// `pt-4-desktop`) from the current list of classes. If the class
+    /// was not previously added, nothing happens.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     helpers::spacing::{Direction, Spacing},
+    ///     helpers::visibility::Viewport,
+    ///     utils::class::ClassBuilder,
+    /// };
+    ///
+    /// // Create a `<div>` HTML element that has no viewport specific padding.
+    /// #[function_component(UnspacedDiv)]
+    /// fn unspaced_div() -> Html {
+    ///     let class = ClassBuilder::default()
+    ///         .with_viewport_padding(Direction::Top, Spacing::Four, Viewport::Desktop)
+    ///         .without_viewport_padding(Direction::Top, Spacing::Four, Viewport::Desktop)
+    ///         .build();
+    ///     html!{
+    ///         <div class={class}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [bd]: https://bulma.io/documentation/helpers/spacing-helpers
+    pub fn without_viewport_padding(
+        mut self,
+        direction: Direction,
+        spacing: Spacing,
+        viewport: Viewport,
+    ) -> Self {
+        self.viewport_paddings
+            .remove(&(viewport, direction, spacing));
+        self
+    }
+
+    /// Set a spacing value which is not part of the Bulma scale.
+    ///
+    /// Escape hatch for the spacing values which cannot be expressed using
+    /// [`ClassBuilder::with_margin`] or [`ClassBuilder::with_padding`] (ie a
+    /// `13px` top margin). Since there is no Bulma class for such values, they
+    /// are not part of the classes created by [`ClassBuilder::build`], but of
+    /// the inline style returned by [`ClassBuilder::style`]. Every call to
+    /// this method for the same property overrides the previous value to the
+    /// one received.
+    ///
+    /// > _Prefer the typed spacing methods whenever the value is part of the
+    /// > Bulma scale._
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::{
+    ///     helpers::spacing::{Direction, Spacing},
+    ///     utils::class::ClassBuilder,
+    /// };
+    ///
+    /// // Create a `<div>` HTML element that has a custom top margin.
+    /// #[function_component(SpacedDiv)]
+    /// fn spaced_div() -> Html {
+    ///     let builder = ClassBuilder::default()
+    ///         .with_padding(Direction::All, Spacing::Two)
+    ///         .with_raw_spacing("margin-top", "13px");
+    ///     let style = builder.style().build();
+    ///     let class = builder.build();
+    ///     html!{
+    ///         <div {class} {style}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    pub fn with_raw_spacing(mut self, property: &str, value: &str) -> Self {
+        self.raw_spacings = self.raw_spacings.with_property(property, value);
+        self
+    }
+
+    /// Remove a spacing value set using [`ClassBuilder::with_raw_spacing`].
+    ///
+    /// Remove a spacing property from the inline style returned by
+    /// [`ClassBuilder::style`]. If the property was not previously set,
+    /// nothing happens.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_and_bulma::utils::class::ClassBuilder;
+    ///
+    /// let builder = ClassBuilder::default()
+    ///     .with_raw_spacing("margin-top", "13px")
+    ///     .without_raw_spacing("margin-top");
+    ///
+    /// assert_eq!(builder.style().build(), None);
+    /// ```
+    pub fn without_raw_spacing(mut self, property: &str) -> Self {
+        self.raw_spacings = self.raw_spacings.without_property(property);
+        self
+    }
+
+    /// Returns the inline style needed next to the built classes.
+    ///
+    /// Returns a [`StyleBuilder`] holding the values set using
+    /// [`ClassBuilder::with_raw_spacing`], which cannot be expressed using
+    /// Bulma classes. It should be used for the style attribute of the
+    /// element which receives the classes created by [`ClassBuilder::build`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_and_bulma::utils::class::ClassBuilder;
+    ///
+    /// let builder = ClassBuilder::default().with_raw_spacing("padding-left", "7px");
+    ///
+    /// assert_eq!(builder.style().build(), Some("padding-left: 7px;".to_owned()));
+    /// ```
+    pub fn style(&self) -> StyleBuilder {
+        self.raw_spacings.clone()
+    }
+
+    /// Set the [Bulma clearfix helper][bd].
+    ///
+    /// Set the [Bulma clearfix helper class][bd] to be added to the current
+    /// list of classes. To remove a [clearfix helper][bd], simply pass `None`
+    /// to the call. Every call to this method overrides the previous value to
+    /// the one received.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::utils::class::ClassBuilder;
+    ///
+    /// // Create a `<div>` HTML element that has the clearfix Bulma class.
     /// #[function_component(ClearfixDiv)]
     /// fn clearfix_div() -> Html {
     ///     let class = ClassBuilder::default()
@@ -1754,6 +2506,11 @@ impl ClassBuilder {
     /// values were set in the builder, the resulting value is equivalent to
     /// calling `yew::classes!()`.
     ///
+    /// The classes which can appear multiple times (ie custom classes,
+    /// margins or viewport displays) are sorted, so the same configuration
+    /// always creates the same `class` attribute, avoiding needless DOM
+    /// updates between renders.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -1775,7 +2532,8 @@ impl ClassBuilder {
     /// }
     /// ```
     pub fn build(self) -> Classes {
-        let custom_classes: Vec<_> = self.custom_classes.iter().collect();
+        let mut custom_classes: Vec<_> = self.custom_classes.iter().collect();
+        custom_classes.sort_unstable();
         let text_classes: Classes = self.text_modifiers.into();
         let background_color = self
             .background_color
@@ -1785,22 +2543,62 @@ impl ClassBuilder {
             .is_light
             .map(|is_light| (if is_light { IS_LIGHT } else { "" }).to_string());
         let display = self.display.map(|display| format!("{IS_PREFIX}-{display}"));
-        let viewport_displays: Vec<_> = self
+        let mut viewport_displays: Vec<_> = self
             .viewport_displays
             .iter()
             .map(|(viewport, display)| format!("{IS_PREFIX}-{display}-{viewport}"))
             .collect();
+        viewport_displays.sort_unstable();
+        let is_hidden = self
+            .is_hidden
+            .map(|is_hidden| if is_hidden { IS_HIDDEN } else { "" });
+        let mut viewport_hidden: Vec<_> = self
+            .viewport_hidden
+            .iter()
+            .map(|viewport| format!("{IS_HIDDEN}-{viewport}"))
+            .collect();
+        viewport_hidden.sort_unstable();
+        let is_invisible = self
+            .is_invisible
+            .map(|is_invisible| if is_invisible { IS_INVISIBLE } else { "" });
+        let is_sr_only = self
+            .is_sr_only
+            .map(|is_sr_only| if is_sr_only { IS_SR_ONLY } else { "" });
+        let is_print_hidden =
+            self.is_print_hidden
+                .map(|is_print_hidden| if is_print_hidden { IS_HIDDEN_PRINT } else { "" });
+        let is_print_only = self
+            .is_print_only
+            .map(|is_print_only| if is_print_only { IS_PRINT_ONLY } else { "" });
         let alignment_classes: Classes = self.alignment_modifiers.into();
-        let margin_classes: Vec<_> = self
+        let mut margin_classes: Vec<_> = self
             .margins
             .iter()
             .map(|(direction, spacing)| format!("{MARGIN_PREFIX}{direction}-{spacing}"))
             .collect();
-        let padding_classes: Vec<_> = self
+        margin_classes.sort_unstable();
+        let mut viewport_margin_classes: Vec<_> = self
+            .viewport_margins
+            .iter()
+            .map(|(viewport, direction, spacing)| {
+                format!("{MARGIN_PREFIX}{direction}-{spacing}-{viewport}")
+            })
+            .collect();
+        viewport_margin_classes.sort_unstable();
+        let mut padding_classes: Vec<_> = self
             .paddings
             .iter()
             .map(|(direction, spacing)| format!("{PADDING_PREFIX}{direction}-{spacing}"))
             .collect();
+        padding_classes.sort_unstable();
+        let mut viewport_padding_classes: Vec<_> = self
+            .viewport_paddings
+            .iter()
+            .map(|(viewport, direction, spacing)| {
+                format!("{PADDING_PREFIX}{direction}-{spacing}-{viewport}")
+            })
+            .collect();
+        viewport_padding_classes.sort_unstable();
         let other_classes: Classes = self.other_modifiers.into();
 
         classes!(
@@ -1811,17 +2609,349 @@ impl ClassBuilder {
             is_light_class,
             display,
             viewport_displays,
+            is_hidden,
+            viewport_hidden,
+            is_invisible,
+            is_sr_only,
+            is_print_hidden,
+            is_print_only,
             alignment_classes,
             margin_classes,
+            viewport_margin_classes,
             padding_classes,
+            viewport_padding_classes,
             other_classes,
         )
     }
+
+    /// Create the [`yew::html::Classes`] object from the current
+    /// configuration, checking for conflicting classes first.
+    ///
+    /// Works like [`ClassBuilder::build`], but returns a [`ClassConflict`]
+    /// instead if the configuration contains mutually exclusive Bulma classes,
+    /// such as `is-pulled-left` and `is-pulled-right`, two different text
+    /// sizes for the same viewport, two spacings setting different values on
+    /// the same side (ie `mt-1` and `my-2`) or two colors (whether set
+    /// through the builder methods or as custom classes). The `is-light`
+    /// variant is not considered a color, so `is-primary is-light` is valid. This is meant to catch styling
+    /// bugs during development, ie using `debug_assert!`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_and_bulma::{
+    ///     helpers::typography::TextSize,
+    ///     utils::class::{ClassBuilder, ClassConflict},
+    /// };
+    ///
+    /// let result = ClassBuilder::default()
+    ///     .with_text_size(Some(TextSize::One))
+    ///     .with_custom_class("is-size-2")
+    ///     .try_build();
+    ///
+    /// assert_eq!(
+    ///     result,
+    ///     Err(ClassConflict {
+    ///         first: "is-size-1".to_owned(),
+    ///         second: "is-size-2".to_owned(),
+    ///     })
+    /// );
+    /// ```
+    pub fn try_build(self) -> Result<Classes, ClassConflict> {
+        match self.find_conflict() {
+            Some(conflict) => Err(conflict),
+            None => Ok(self.build()),
+        }
+    }
+
+    fn find_conflict(&self) -> Option<ClassConflict> {
+        let text_modifiers = &self.text_modifiers;
+        let typed = [
+            (
+                ModifierGroup::Color,
+                self.color.map(|color| format!("{IS_PREFIX}-{color}")),
+            ),
+            (
+                ModifierGroup::TextColor,
+                text_modifiers
+                    .color
+                    .map(|color| format!("{HAS_TEXT_PREFIX}-{color}")),
+            ),
+            (
+                ModifierGroup::BackgroundColor,
+                self.background_color
+                    .map(|color| format!("{HAS_BACKGROUND_PREFIX}-{color}")),
+            ),
+            (
+                ModifierGroup::TextSize,
+                text_modifiers
+                    .size
+                    .as_ref()
+                    .map(|size| format!("{IS_SIZE_PREFIX}-{size}")),
+            ),
+            (
+                ModifierGroup::TextAlignment,
+                text_modifiers
+                    .alignment
+                    .as_ref()
+                    .map(|alignment| format!("{HAS_TEXT_PREFIX}-{alignment}")),
+            ),
+            (
+                ModifierGroup::TextWeight,
+                text_modifiers
+                    .weight
+                    .as_ref()
+                    .map(|weight| format!("{HAS_TEXT_WEIGHT_PREFIX}-{weight}")),
+            ),
+        ];
+        let mut slots: Vec<(String, String)> = typed
+            .into_iter()
+            .filter_map(|(group, class)| class.map(|class| (format!("{group:?}"), class)))
+            .chain(self.custom_classes.iter().filter_map(|class| {
+                ModifierGroup::of(class).map(|group| (format!("{group:?}"), class.clone()))
+            }))
+            .collect();
+        slots.extend(
+            text_modifiers
+                .viewport_sizes
+                .iter()
+                .map(|(viewport, size)| {
+                    (
+                        format!("size-{viewport}"),
+                        format!("{IS_SIZE_PREFIX}-{size}-{viewport}"),
+                    )
+                }),
+        );
+        slots.extend(
+            text_modifiers
+                .viewport_alignments
+                .iter()
+                .map(|(viewport, alignment)| {
+                    (
+                        format!("alignment-{viewport}"),
+                        format!("{HAS_TEXT_PREFIX}-{alignment}-{viewport}"),
+                    )
+                }),
+        );
+        slots.extend(self.viewport_displays.iter().map(|(viewport, display)| {
+            (
+                format!("display-{viewport}"),
+                format!("{IS_PREFIX}-{display}-{viewport}"),
+            )
+        }));
+        if self.other_modifiers.is_pulled_left == Some(true)
+            && self.other_modifiers.is_pulled_right == Some(true)
+        {
+            slots.push(("pulled".to_owned(), IS_PULLED_LEFT.to_owned()));
+            slots.push(("pulled".to_owned(), IS_PULLED_RIGHT.to_owned()));
+        }
+        if self.is_print_hidden == Some(true) && self.is_print_only == Some(true) {
+            slots.push(("print".to_owned(), IS_HIDDEN_PRINT.to_owned()));
+            slots.push(("print".to_owned(), IS_PRINT_ONLY.to_owned()));
+        }
+
+        // Spacing classes conflict when they set a different value on the
+        // same side (ie `mt-1` and `my-2`), so they are compared per side.
+        let spacings = [
+            (MARGIN_PREFIX, &self.margins, None),
+            (PADDING_PREFIX, &self.paddings, None),
+        ]
+        .into_iter()
+        .flat_map(|(prefix, spacings, viewport): (_, _, Option<&Viewport>)| {
+            spacings
+                .iter()
+                .map(move |(direction, spacing)| (prefix, viewport, direction, spacing))
+        })
+        .chain(
+            [
+                (MARGIN_PREFIX, &self.viewport_margins),
+                (PADDING_PREFIX, &self.viewport_paddings),
+            ]
+            .into_iter()
+            .flat_map(|(prefix, spacings)| {
+                spacings.iter().map(move |(viewport, direction, spacing)| {
+                    (prefix, Some(viewport), direction, spacing)
+                })
+            }),
+        );
+        let mut slots: Vec<(String, String, String)> = slots
+            .into_iter()
+            .map(|(key, class)| (key, class.clone(), class))
+            .collect();
+        for (prefix, viewport, direction, spacing) in spacings {
+            let viewport = viewport
+                .map(|viewport| format!("-{viewport}"))
+                .unwrap_or_default();
+            for side in sides(direction) {
+                slots.push((
+                    format!("{prefix}{side}{viewport}"),
+                    spacing.to_string(),
+                    format!("{prefix}{direction}-{spacing}{viewport}"),
+                ));
+            }
+        }
+
+        slots.sort();
+        slots.dedup_by(|first, second| first.0 == second.0 && first.1 == second.1);
+        slots
+            .windows(2)
+            .find(|pair| pair[0].0 == pair[1].0)
+            .map(|pair| {
+                let (first, second) = if pair[0].2 <= pair[1].2 {
+                    (&pair[0].2, &pair[1].2)
+                } else {
+                    (&pair[1].2, &pair[0].2)
+                };
+
+                ClassConflict {
+                    first: first.clone(),
+                    second: second.clone(),
+                }
+            })
+    }
+
+    fn modifier_groups(&self) -> HashSet<ModifierGroup> {
+        let typed = [
+            self.color.map(|_| ModifierGroup::Color),
+            self.text_modifiers.color.map(|_| ModifierGroup::TextColor),
+            self.background_color
+                .map(|_| ModifierGroup::BackgroundColor),
+            self.text_modifiers
+                .size
+                .as_ref()
+                .map(|_| ModifierGroup::TextSize),
+            self.text_modifiers
+                .alignment
+                .as_ref()
+                .map(|_| ModifierGroup::TextAlignment),
+            self.text_modifiers
+                .weight
+                .as_ref()
+                .map(|_| ModifierGroup::TextWeight),
+        ];
+
+        typed
+            .into_iter()
+            .flatten()
+            .chain(
+                self.custom_classes
+                    .iter()
+                    .filter_map(|class| ModifierGroup::of(class)),
+            )
+            .collect()
+    }
+
+    fn without_modifier_group(mut self, group: ModifierGroup) -> Self {
+        match group {
+            ModifierGroup::Color => self.color = None,
+            ModifierGroup::TextColor => self.text_modifiers.color = None,
+            ModifierGroup::BackgroundColor => self.background_color = None,
+            ModifierGroup::TextSize => self.text_modifiers.size = None,
+            ModifierGroup::TextAlignment => self.text_modifiers.alignment = None,
+            ModifierGroup::TextWeight => self.text_modifiers.weight = None,
+        }
+        self.custom_classes
+            .retain(|class| ModifierGroup::of(class) != Some(group));
+        self
+    }
+}
+
+/// Formats the classes which the builder would create, sorted by name.
+///
+/// Formats the classes that [`ClassBuilder::build`] would create as a space
+/// separated list, sorted alphabetically so that the output does not depend on
+/// the iteration order of the underlying sets (ie for snapshot tests).
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::{helpers::visibility::Display, utils::class::ClassBuilder};
+///
+/// let builder = ClassBuilder::default()
+///     .with_custom_class("my-div")
+///     .with_display(Some(Display::Flex));
+///
+/// assert_eq!(builder.to_string(), "is-flex my-div");
+/// ```
+impl fmt::Display for ClassBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.clone().build().to_sorted_string())
+    }
+}
+
+/// Error returned when a [`ClassBuilder`] contains conflicting classes.
+///
+/// Holds two mutually exclusive Bulma classes found by
+/// [`ClassBuilder::try_build`], such as `is-pulled-left` and
+/// `is-pulled-right`, sorted alphabetically.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew_and_bulma::utils::class::ClassBuilder;
+///
+/// let conflict = ClassBuilder::default()
+///     .is_pulled_left(Some(true))
+///     .is_pulled_right(Some(true))
+///     .try_build()
+///     .unwrap_err();
+///
+/// assert_eq!(
+///     conflict.to_string(),
+///     "conflicting classes: is-pulled-left and is-pulled-right"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClassConflict {
+    /// The first of the conflicting classes.
+    pub first: String,
+    /// The second of the conflicting classes.
+    pub second: String,
+}
+
+impl fmt::Display for ClassConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "conflicting classes: {} and {}", self.first, self.second)
+    }
+}
+
+impl std::error::Error for ClassConflict {}
+
+/// Extends [`Classes`] with a deterministic string representation.
+///
+/// Adds a way of formatting a list of [`Classes`] which does not depend on the
+/// order in which they were added, useful when comparing class lists created
+/// by the [`ClassBuilder`] in tests.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::classes;
+/// use yew_and_bulma::utils::class::SortedClasses;
+///
+/// let classes = classes!("is-primary", "button", "is-large");
+///
+/// assert_eq!(classes.to_sorted_string(), "button is-large is-primary");
+/// ```
+pub trait SortedClasses {
+    /// Returns the classes as a space separated list, sorted alphabetically.
+    fn to_sorted_string(&self) -> String;
+}
+
+impl SortedClasses for Classes {
+    fn to_sorted_string(&self) -> String {
+        let classes = self.to_string();
+        let mut classes: Vec<_> = classes.split_whitespace().collect();
+        classes.sort_unstable();
+        classes.join(" ")
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "bulma-v1")]
+    use crate::helpers::color::{ColorShade, PaletteColor};
     use test_case::test_case;
 
     #[test]
@@ -1876,12 +3006,21 @@ mod tests {
         assert!(class_builder.background_color.is_none());
         assert!(class_builder.display.is_none());
         assert!(class_builder.viewport_displays.is_empty());
+        assert!(class_builder.is_hidden.is_none());
+        assert!(class_builder.viewport_hidden.is_empty());
+        assert!(class_builder.is_invisible.is_none());
+        assert!(class_builder.is_sr_only.is_none());
+        assert!(class_builder.is_print_hidden.is_none());
+        assert!(class_builder.is_print_only.is_none());
         assert_eq!(
             class_builder.alignment_modifiers,
             AlignmentModifiers::default()
         );
         assert!(class_builder.margins.is_empty());
+        assert!(class_builder.viewport_margins.is_empty());
         assert!(class_builder.paddings.is_empty());
+        assert!(class_builder.viewport_paddings.is_empty());
+        assert_eq!(class_builder.raw_spacings, StyleBuilder::default());
         assert_eq!(class_builder.other_modifiers, OtherModifiers::default());
     }
 
@@ -2076,6 +3215,75 @@ mod tests {
         assert_eq!(classes.to_string(), expected_display);
     }
 
+    #[test_case(None, "" ; "none converts to empty string")]
+    #[test_case(Some(false), "" ; "false converts to empty string")]
+    #[test_case(Some(true), "is-hidden" ; "true converts to is-hidden")]
+    fn class_builder_is_hidden(is_hidden: Option<bool>, expected_class: &str) {
+        let classes = ClassBuilder::default().is_hidden(is_hidden).build();
+
+        assert_eq!(classes.to_string(), expected_class);
+    }
+
+    #[test_case(Viewport::Mobile, "is-hidden-mobile" ; "mobile converts to is-hidden-mobile")]
+    #[test_case(Viewport::Touch, "is-hidden-touch" ; "touch converts to is-hidden-touch")]
+    #[test_case(Viewport::DesktopOnly, "is-hidden-desktop-only" ; "desktop only converts to is-hidden-desktop-only")]
+    fn class_builder_with_viewport_hidden(viewport: Viewport, expected_class: &str) {
+        let classes = ClassBuilder::default()
+            .with_viewport_hidden(viewport)
+            .build();
+
+        assert_eq!(classes.to_string(), expected_class);
+    }
+
+    #[test]
+    fn class_builder_without_viewport_hidden() {
+        let classes = ClassBuilder::default()
+            .with_viewport_hidden(Viewport::Touch)
+            .with_viewport_hidden(Viewport::Mobile)
+            .without_viewport_hidden(Viewport::Touch)
+            .build();
+
+        assert_eq!(classes.to_string(), "is-hidden-mobile");
+    }
+
+    #[test_case(None, "" ; "none converts to empty string")]
+    #[test_case(Some(false), "" ; "false converts to empty string")]
+    #[test_case(Some(true), "is-invisible" ; "true converts to is-invisible")]
+    fn class_builder_is_invisible(is_invisible: Option<bool>, expected_class: &str) {
+        let classes = ClassBuilder::default().is_invisible(is_invisible).build();
+
+        assert_eq!(classes.to_string(), expected_class);
+    }
+
+    #[test_case(None, "" ; "none converts to empty string")]
+    #[test_case(Some(false), "" ; "false converts to empty string")]
+    #[test_case(Some(true), "is-sr-only" ; "true converts to is-sr-only")]
+    fn class_builder_is_sr_only(is_sr_only: Option<bool>, expected_class: &str) {
+        let classes = ClassBuilder::default().is_sr_only(is_sr_only).build();
+
+        assert_eq!(classes.to_string(), expected_class);
+    }
+
+    #[test_case(None, "" ; "none converts to empty string")]
+    #[test_case(Some(false), "" ; "false converts to empty string")]
+    #[test_case(Some(true), "is-hidden-print" ; "true converts to is-hidden-print")]
+    fn class_builder_is_print_hidden(is_print_hidden: Option<bool>, expected_class: &str) {
+        let classes = ClassBuilder::default()
+            .is_print_hidden(is_print_hidden)
+            .build();
+
+        assert_eq!(classes.to_string(), expected_class);
+    }
+
+    #[test_case(None, "" ; "none converts to empty string")]
+    #[test_case(Some(false), "" ; "false converts to empty string")]
+    #[test_case(Some(true), "is-print-only" ; "true converts to is-print-only")]
+    fn class_builder_is_print_only(is_print_only: Option<bool>, expected_class: &str) {
+        let classes = ClassBuilder::default().is_print_only(is_print_only).build();
+
+        assert_eq!(classes.to_string(), expected_class);
+    }
+
     #[test_case(None, "" ; "none converts to empty string")]
     #[test_case(Some(FlexDirection::Column), "is-flex-direction-column" ; "column converts to is-flex-direction-column")]
     fn class_builer_with_flex_direction(
@@ -2337,4 +3545,329 @@ mod tests {
             assert!(classes.contains(expected_class));
         }
     }
+
+    #[test]
+    fn classes_to_sorted_string_success() {
+        let classes = classes!("is-primary", "button", "is-large");
+
+        assert_eq!(classes.to_sorted_string(), "button is-large is-primary");
+    }
+
+    #[test]
+    fn class_builder_display_is_sorted() {
+        let builder = ClassBuilder::default()
+            .with_custom_class("zzz")
+            .with_custom_class("aaa")
+            .with_margin(Direction::Horizontal, Spacing::Three)
+            .with_display(Some(Display::Flex));
+
+        assert_eq!(builder.to_string(), "aaa is-flex mx-3 zzz");
+    }
+
+    #[test_case("is-primary", Some(ModifierGroup::Color) ; "is primary is a color")]
+    #[test_case("is-large", None ; "is large is not a color")]
+    #[test_case("is-light", None ; "is light is the light variant")]
+    #[test_case("has-text-danger", Some(ModifierGroup::TextColor) ; "has text danger is a text color")]
+    #[test_case("has-text-centered", Some(ModifierGroup::TextAlignment) ; "has text centered is a text alignment")]
+    #[test_case("has-text-centered-mobile", None ; "has text centered mobile is not grouped")]
+    #[test_case("has-text-weight-bold", Some(ModifierGroup::TextWeight) ; "has text weight bold is a text weight")]
+    #[test_case("has-background-light", Some(ModifierGroup::BackgroundColor) ; "has background light is a background color")]
+    #[test_case("is-size-3", Some(ModifierGroup::TextSize) ; "is size 3 is a text size")]
+    #[test_case("is-size-3-mobile", None ; "is size 3 mobile is not grouped")]
+    #[test_case("my-class", None ; "custom class is not grouped")]
+    fn modifier_group_of(class: &str, expected_group: Option<ModifierGroup>) {
+        assert_eq!(ModifierGroup::of(class), expected_group);
+    }
+
+    #[test]
+    fn class_builder_from_classes() {
+        let classes = ClassBuilder::from_classes(classes!("abc", "def")).build();
+
+        assert_eq!(classes.to_sorted_string(), "abc def");
+    }
+
+    #[test]
+    fn class_builder_merge_combines_classes() {
+        let classes = ClassBuilder::default()
+            .with_custom_class("abc")
+            .with_margin(Direction::Top, Spacing::One)
+            .merge(
+                ClassBuilder::default()
+                    .with_custom_class("def")
+                    .with_margin(Direction::Bottom, Spacing::Two),
+            )
+            .build();
+
+        assert_eq!(classes.to_sorted_string(), "abc def mb-2 mt-1");
+    }
+
+    #[test]
+    fn class_builder_merge_other_takes_precedence() {
+        let classes = ClassBuilder::default()
+            .with_color(Some(Color::Primary))
+            .with_display(Some(Display::Flex))
+            .merge(ClassBuilder::default().with_color(Some(Color::Danger)))
+            .build();
+
+        assert_eq!(classes.to_sorted_string(), "is-danger is-flex");
+    }
+
+    #[test]
+    fn class_builder_merge_dedupes_custom_classes() {
+        let classes = ClassBuilder::default()
+            .with_custom_class("button")
+            .with_color(Some(Color::Primary))
+            .with_text_color(Some(TextColor::White))
+            .merge(ClassBuilder::from_classes(classes!(
+                "is-danger",
+                "has-text-dark",
+                "is-large"
+            )))
+            .build();
+
+        assert_eq!(
+            classes.to_sorted_string(),
+            "button has-text-dark is-danger is-large"
+        );
+    }
+
+    #[test]
+    fn class_builder_merge_keeps_color_with_light_variant() {
+        let classes = ClassBuilder::default()
+            .with_custom_class("button")
+            .with_color(Some(Color::Primary))
+            .merge(ClassBuilder::from_classes(classes!("is-light")))
+            .build();
+
+        assert_eq!(classes.to_sorted_string(), "button is-light is-primary");
+    }
+
+    #[test]
+    fn class_builder_merge_typed_replaces_custom_classes() {
+        let classes = ClassBuilder::from_classes(classes!("is-primary", "has-text-centered"))
+            .merge(ClassBuilder::default().with_color(Some(Color::Link)))
+            .build();
+
+        assert_eq!(classes.to_sorted_string(), "has-text-centered is-link");
+    }
+
+    #[test]
+    fn class_builder_build_is_deterministic() {
+        let build = || {
+            ClassBuilder::default()
+                .with_custom_class("ccc")
+                .with_custom_class("aaa")
+                .with_custom_class("bbb")
+                .with_margin(Direction::Top, Spacing::Two)
+                .with_margin(Direction::Bottom, Spacing::One)
+                .with_viewport_display(Display::Flex, Viewport::Tablet)
+                .with_viewport_display(Display::Block, Viewport::Mobile)
+                .build()
+                .to_string()
+        };
+
+        assert_eq!(
+            build(),
+            "aaa bbb ccc is-block-mobile is-flex-tablet mb-1 mt-2"
+        );
+    }
+
+    #[test]
+    fn class_builder_try_build_success() {
+        let classes = ClassBuilder::default()
+            .with_color(Some(Color::Primary))
+            .with_text_size(Some(TextSize::One))
+            .with_text_viewport_size(TextSize::Two, Viewport::Mobile)
+            .with_margin(Direction::Top, Spacing::One)
+            .with_margin(Direction::Bottom, Spacing::Two)
+            .is_pulled_left(Some(true))
+            .try_build();
+
+        assert!(classes.is_ok());
+    }
+
+    #[test]
+    fn class_builder_try_build_color_with_light_variant() {
+        let classes = ClassBuilder::default()
+            .with_color(Some(Color::Primary))
+            .with_custom_class("is-light")
+            .try_build();
+
+        assert_eq!(
+            classes.map(|classes| classes.to_sorted_string()),
+            Ok("is-light is-primary".to_owned())
+        );
+    }
+
+    #[test]
+    fn class_builder_try_build_same_spacing_on_a_side() {
+        let classes = ClassBuilder::default()
+            .with_margin(Direction::All, Spacing::One)
+            .with_margin(Direction::Top, Spacing::One)
+            .with_margin(Direction::Left, Spacing::One)
+            .with_padding(Direction::Top, Spacing::Two)
+            .try_build();
+
+        assert!(classes.is_ok());
+    }
+
+    #[test_case(
+        ClassBuilder::default().is_pulled_left(Some(true)).is_pulled_right(Some(true)),
+        "is-pulled-left", "is-pulled-right" ; "pulled left and right conflict")]
+    #[test_case(
+        ClassBuilder::default().is_print_hidden(Some(true)).is_print_only(Some(true)),
+        "is-hidden-print", "is-print-only" ; "print hidden and only conflict")]
+    #[test_case(
+        ClassBuilder::default().with_text_size(Some(TextSize::One)).with_custom_class("is-size-3"),
+        "is-size-1", "is-size-3" ; "text sizes conflict")]
+    #[test_case(
+        ClassBuilder::default().with_custom_class("is-primary").with_custom_class("is-danger"),
+        "is-danger", "is-primary" ; "custom colors conflict")]
+    #[test_case(
+        ClassBuilder::default()
+            .with_text_viewport_size(TextSize::One, Viewport::Mobile)
+            .with_text_viewport_size(TextSize::Two, Viewport::Mobile),
+        "is-size-1-mobile", "is-size-2-mobile" ; "viewport text sizes conflict")]
+    #[test_case(
+        ClassBuilder::default()
+            .with_viewport_display(Display::Flex, Viewport::Tablet)
+            .with_viewport_display(Display::Block, Viewport::Tablet),
+        "is-block-tablet", "is-flex-tablet" ; "viewport displays conflict")]
+    #[test_case(
+        ClassBuilder::default()
+            .with_margin(Direction::Top, Spacing::One)
+            .with_margin(Direction::Top, Spacing::Two),
+        "mt-1", "mt-2" ; "margins conflict")]
+    #[test_case(
+        ClassBuilder::default()
+            .with_margin(Direction::Top, Spacing::One)
+            .with_margin(Direction::Vertical, Spacing::Two),
+        "mt-1", "my-2" ; "overlapping margins conflict")]
+    #[test_case(
+        ClassBuilder::default()
+            .with_padding(Direction::All, Spacing::Three)
+            .with_padding(Direction::Left, Spacing::One),
+        "p-3", "pl-1" ; "overlapping paddings conflict")]
+    #[test_case(
+        ClassBuilder::default()
+            .with_viewport_margin(Direction::Horizontal, Spacing::One, Viewport::Mobile)
+            .with_viewport_margin(Direction::Right, Spacing::Two, Viewport::Mobile),
+        "mr-2-mobile", "mx-1-mobile" ; "overlapping viewport margins conflict")]
+    fn class_builder_try_build_conflict(builder: ClassBuilder, first: &str, second: &str) {
+        let conflict = builder.try_build().unwrap_err();
+
+        assert_eq!(
+            conflict,
+            ClassConflict {
+                first: first.to_owned(),
+                second: second.to_owned(),
+            }
+        );
+    }
+
+    #[test_case(Direction::Top, Spacing::Four, Viewport::Desktop, "mt-4-desktop" ; "top four desktop converts to mt-4-desktop")]
+    #[test_case(Direction::All, Spacing::Zero, Viewport::Mobile, "m-0-mobile" ; "all zero mobile converts to m-0-mobile")]
+    #[test_case(Direction::Horizontal, Spacing::Two, Viewport::TabletOnly, "mx-2-tablet-only" ; "horizontal two tablet only converts to mx-2-tablet-only")]
+    fn class_builder_with_viewport_margin(
+        direction: Direction,
+        spacing: Spacing,
+        viewport: Viewport,
+        expected_class: &str,
+    ) {
+        let classes = ClassBuilder::default()
+            .with_viewport_margin(direction, spacing, viewport)
+            .build();
+
+        assert_eq!(classes.to_string(), expected_class);
+    }
+
+    #[test]
+    fn class_builder_without_viewport_margin() {
+        let classes = ClassBuilder::default()
+            .with_viewport_margin(Direction::Top, Spacing::Four, Viewport::Desktop)
+            .with_viewport_margin(Direction::Top, Spacing::One, Viewport::Mobile)
+            .without_viewport_margin(Direction::Top, Spacing::Four, Viewport::Desktop)
+            .build();
+
+        assert_eq!(classes.to_string(), "mt-1-mobile");
+    }
+
+    #[test_case(Direction::Bottom, Spacing::Six, Viewport::Widescreen, "pb-6-widescreen" ; "bottom six widescreen converts to pb-6-widescreen")]
+    #[test_case(Direction::Vertical, Spacing::Three, Viewport::Touch, "py-3-touch" ; "vertical three touch converts to py-3-touch")]
+    fn class_builder_with_viewport_padding(
+        direction: Direction,
+        spacing: Spacing,
+        viewport: Viewport,
+        expected_class: &str,
+    ) {
+        let classes = ClassBuilder::default()
+            .with_viewport_padding(direction, spacing, viewport)
+            .build();
+
+        assert_eq!(classes.to_string(), expected_class);
+    }
+
+    #[test]
+    fn class_builder_without_viewport_padding() {
+        let classes = ClassBuilder::default()
+            .with_viewport_padding(Direction::Left, Spacing::Two, Viewport::Tablet)
+            .with_viewport_padding(Direction::Left, Spacing::Five, Viewport::FullHD)
+            .without_viewport_padding(Direction::Left, Spacing::Two, Viewport::Tablet)
+            .build();
+
+        assert_eq!(classes.to_string(), "pl-5-fullhd");
+    }
+
+    #[test]
+    fn class_builder_with_raw_spacing() {
+        let builder = ClassBuilder::default()
+            .with_margin(Direction::All, Spacing::One)
+            .with_raw_spacing("margin-top", "13px");
+
+        assert_eq!(
+            builder.style().build(),
+            Some("margin-top: 13px;".to_owned())
+        );
+        assert_eq!(builder.build().to_string(), "m-1");
+    }
+
+    #[test]
+    fn class_builder_without_raw_spacing() {
+        let builder = ClassBuilder::default()
+            .with_raw_spacing("margin-top", "13px")
+            .with_raw_spacing("padding-left", "7px")
+            .without_raw_spacing("margin-top");
+
+        assert_eq!(
+            builder.style().build(),
+            Some("padding-left: 7px;".to_owned())
+        );
+    }
+
+    #[test]
+    fn class_builder_merge_raw_spacings() {
+        let builder = ClassBuilder::default()
+            .with_raw_spacing("margin-top", "13px")
+            .merge(ClassBuilder::default().with_raw_spacing("margin-top", "17px"));
+
+        assert_eq!(
+            builder.style().build(),
+            Some("margin-top: 17px;".to_owned())
+        );
+    }
+
+    #[cfg(feature = "bulma-v1")]
+    #[test]
+    fn class_builder_with_color_shades() {
+        let shade = ColorShade::new(35).unwrap();
+        let classes = ClassBuilder::default()
+            .with_text_color(Some(TextColor::Shade(PaletteColor::Primary, shade)))
+            .with_background_color(Some(BackgroundColor::Shade(PaletteColor::Danger, shade)))
+            .build();
+
+        assert_eq!(
+            classes.to_sorted_string(),
+            "has-background-danger-35 has-text-primary-35"
+        );
+    }
 }
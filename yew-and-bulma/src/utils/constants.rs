@@ -838,3 +838,138 @@ pub const IS_OFFSET_PREFIX: &str = "is-offset";
 ///
 /// [bd]: https://bulma.io/documentation/customize/variables/
 pub const IS_NARROW: &str = "is-narrow";
+/// Defines the `is-hidden` class.
+///
+/// Defines the `is-hidden` class, used to hide elements using one of the
+/// [Bulma visibility helpers][bd]. It is also used as a prefix for the
+/// viewport specific variants (ie `is-hidden-touch`).
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::constants::IS_HIDDEN;
+///
+/// // Create a `<div>` HTML element that is hidden.
+/// #[function_component(HiddenDiv)]
+/// fn hidden_div() -> Html {
+///     html!{
+///         <div class={IS_HIDDEN}>{ "Lorem ispum..." }</div>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/helpers/visibility-helpers/#hide
+pub const IS_HIDDEN: &str = "is-hidden";
+/// Defines the `is-invisible` class.
+///
+/// Defines the `is-invisible` class, used to make elements invisible, while still
+/// taking up space, using one of the [Bulma visibility helpers][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::constants::IS_INVISIBLE;
+///
+/// // Create a `<div>` HTML element that is invisible.
+/// #[function_component(InvisibleDiv)]
+/// fn invisible_div() -> Html {
+///     html!{
+///         <div class={IS_INVISIBLE}>{ "Lorem ispum..." }</div>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/helpers/visibility-helpers/#other-visibility-helpers
+pub const IS_INVISIBLE: &str = "is-invisible";
+/// Defines the `is-sr-only` class.
+///
+/// Defines the `is-sr-only` class, used to hide elements visually, while keeping
+/// them available to screen readers, using one of the
+/// [Bulma visibility helpers][bd].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::constants::IS_SR_ONLY;
+///
+/// // Create a `<div>` HTML element that is only read by screen readers.
+/// #[function_component(ScreenReaderOnlyDiv)]
+/// fn screen_reader_only_div() -> Html {
+///     html!{
+///         <div class={IS_SR_ONLY}>{ "Lorem ispum..." }</div>
+///     }
+/// }
+/// ```
+///
+/// [bd]: https://bulma.io/documentation/helpers/visibility-helpers/#other-visibility-helpers
+pub const IS_SR_ONLY: &str = "is-sr-only";
+/// Defines the `is-hidden-print` class.
+///
+/// Defines the `is-hidden-print` class, used to hide elements when the page is
+/// printed. Since Bulma does not provide print helpers, this class requires
+/// the styles defined in [`crate::utils::constants::PRINT_STYLES`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::constants::IS_HIDDEN_PRINT;
+///
+/// // Create a `<div>` HTML element that is not printed.
+/// #[function_component(PrintHiddenDiv)]
+/// fn print_hidden_div() -> Html {
+///     html!{
+///         <div class={IS_HIDDEN_PRINT}>{ "Lorem ispum..." }</div>
+///     }
+/// }
+/// ```
+pub const IS_HIDDEN_PRINT: &str = "is-hidden-print";
+/// Defines the `is-print-only` class.
+///
+/// Defines the `is-print-only` class, used to hide elements on screen, only
+/// showing them when the page is printed. Since Bulma does not provide print
+/// helpers, this class requires the styles defined in
+/// [`crate::utils::constants::PRINT_STYLES`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::constants::IS_PRINT_ONLY;
+///
+/// // Create a `<div>` HTML element that is only printed.
+/// #[function_component(PrintOnlyDiv)]
+/// fn print_only_div() -> Html {
+///     html!{
+///         <div class={IS_PRINT_ONLY}>{ "Lorem ispum..." }</div>
+///     }
+/// }
+/// ```
+pub const IS_PRINT_ONLY: &str = "is-print-only";
+/// Defines the styles needed by the print helper classes.
+///
+/// Defines the CSS rules backing the [`crate::utils::constants::IS_HIDDEN_PRINT`]
+/// and [`crate::utils::constants::IS_PRINT_ONLY`] classes. They should be
+/// added once to the page, for example using a `<style>` element.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::constants::PRINT_STYLES;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html!{
+///         <>
+///             <style>{ PRINT_STYLES }</style>
+///             <div>{ "Lorem ispum..." }</div>
+///         </>
+///     }
+/// }
+/// ```
+pub const PRINT_STYLES: &str = "@media print{.is-hidden-print{display:none!important}}\
+@media screen{.is-print-only{display:none!important}}";
@@ -0,0 +1,178 @@
+use js_sys::{
+    wasm_bindgen::{JsCast, JsValue},
+    Array, Function, Intl, Object, Reflect,
+};
+
+/// Defines the possible ways of formatting a number.
+///
+/// Defines the possible styles used when formatting a number according to a
+/// locale, using the [`Intl.NumberFormat`][nf] API of the browser.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::format::NumberFormat;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let price = NumberFormat::Currency("EUR".into()).format(1234.5, "de-DE");
+///     html! {
+///         <p>{ price }</p>
+///     }
+/// }
+/// ```
+///
+/// [nf]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NumberFormat {
+    Number,
+    Currency(String),
+    Percent,
+}
+
+impl NumberFormat {
+    /// Formats the given value according to the given locale (ie `en-US`).
+    /// Falls back to the unformatted value if the locale or the currency
+    /// are not valid.
+    pub fn format(&self, value: f64, locale: &str) -> String {
+        match self {
+            NumberFormat::Number => format_with(value, locale, &[]),
+            NumberFormat::Currency(currency) if is_currency_code(currency) => format_with(
+                value,
+                locale,
+                &[("style", "currency"), ("currency", currency)],
+            ),
+            NumberFormat::Currency(_) => value.to_string(),
+            NumberFormat::Percent => format_with(value, locale, &[("style", "percent")]),
+        }
+    }
+}
+
+/// Formats a number according to the given locale.
+///
+/// Formats the given value using the grouping and decimal separators of the
+/// given locale (ie `1,234.5` for `en-US` or `1.234,5` for `de-DE`).
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::format::format_number;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <p>{ format_number(1234.5, "en-US") }</p>
+///     }
+/// }
+/// ```
+pub fn format_number(value: f64, locale: &str) -> String {
+    NumberFormat::Number.format(value, locale)
+}
+
+/// Formats an amount of money according to the given locale.
+///
+/// Formats the given value as an amount of the given currency, using its
+/// [ISO 4217 code][iso] (ie `USD` or `EUR`), according to the given locale.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::format::format_currency;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <p>{ format_currency(9.99, "USD", "en-US") }</p>
+///     }
+/// }
+/// ```
+///
+/// [iso]: https://en.wikipedia.org/wiki/ISO_4217
+pub fn format_currency(value: f64, currency: &str, locale: &str) -> String {
+    NumberFormat::Currency(currency.to_owned()).format(value, locale)
+}
+
+/// Formats a ratio as a percentage according to the given locale.
+///
+/// Formats the given value, where `1.0` is `100%`, as a percentage according
+/// to the given locale.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::format::format_percent;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <p>{ format_percent(0.25, "en-US") }</p>
+///     }
+/// }
+/// ```
+pub fn format_percent(value: f64, locale: &str) -> String {
+    NumberFormat::Percent.format(value, locale)
+}
+
+fn format_with(value: f64, locale: &str, options: &[(&str, &str)]) -> String {
+    let locales = Array::of1(&JsValue::from_str(locale));
+    let format_options = Object::new();
+    for (key, option) in options {
+        let _ = Reflect::set(
+            &format_options,
+            &JsValue::from_str(key),
+            &JsValue::from_str(option),
+        );
+    }
+
+    // `Intl.NumberFormat` throws a `RangeError` for invalid locales, so it
+    // is constructed through `Reflect` to catch it instead of panicking.
+    let number_format = Reflect::get(&js_sys::global(), &JsValue::from_str("Intl"))
+        .and_then(|intl| Reflect::get(&intl, &JsValue::from_str("NumberFormat")))
+        .and_then(|constructor| constructor.dyn_into::<Function>())
+        .and_then(|constructor| {
+            Reflect::construct(&constructor, &Array::of2(&locales, &format_options))
+        })
+        .map(JsCast::unchecked_into::<Intl::NumberFormat>);
+
+    number_format
+        .and_then(|number_format| {
+            number_format
+                .format()
+                .call1(&number_format, &JsValue::from_f64(value))
+        })
+        .ok()
+        .and_then(|formatted| formatted.as_string())
+        .unwrap_or_else(|| value.to_string())
+}
+
+fn is_currency_code(currency: &str) -> bool {
+    currency.len() == 3 && currency.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use super::*;
+
+    #[test_case("USD", true ; "uppercase code")]
+    #[test_case("eur", true ; "lowercase code")]
+    #[test_case("", false ; "empty code")]
+    #[test_case("US", false ; "short code")]
+    #[test_case("EURO", false ; "long code")]
+    #[test_case("U$D", false ; "non alphabetic code")]
+    fn is_currency_code_checks_iso_4217_shape(currency: &str, expected: bool) {
+        assert_eq!(is_currency_code(currency), expected);
+    }
+
+    #[test]
+    fn number_format_invalid_currency_falls_back_to_value() {
+        let formatted = NumberFormat::Currency("".to_owned()).format(1234.5, "en-US");
+
+        assert_eq!(formatted, "1234.5");
+    }
+}
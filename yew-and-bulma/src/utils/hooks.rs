@@ -0,0 +1,272 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use gloo::{events::EventListener, timers::callback::Timeout};
+use web_sys::ScrollRestoration;
+use yew::prelude::*;
+
+use crate::helpers::visibility::Viewport;
+
+thread_local! {
+    static SAVED_SCROLL_POSITIONS: RefCell<HashMap<String, ScrollPosition>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Represents the scroll position of the page.
+///
+/// Holds the number of pixels the page has been scrolled horizontally (`x`)
+/// and vertically (`y`), as reported by the [browser window][window].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::hooks::use_scroll_position;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let position = use_scroll_position();
+///     html! {
+///         <p>{ format!("Scrolled {}px from the top.", position.y) }</p>
+///     }
+/// }
+/// ```
+///
+/// [window]: https://developer.mozilla.org/en-US/docs/Web/API/Window/scrollY
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollPosition {
+    /// The number of pixels the page has been scrolled horizontally.
+    pub x: f64,
+    /// The number of pixels the page has been scrolled vertically.
+    pub y: f64,
+}
+
+impl ScrollPosition {
+    fn current() -> Self {
+        web_sys::window()
+            .map(|window| Self {
+                x: window.scroll_x().unwrap_or_default(),
+                y: window.scroll_y().unwrap_or_default(),
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the current scroll position of the page.
+///
+/// Listens to the `scroll` event of the [browser window][window] and
+/// re-renders the component using it each time the page is scrolled. Useful
+/// for components such as sticky navbars, back to top buttons or infinite
+/// scrolling lists.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::hooks::use_scroll_position;
+///
+/// #[function_component(BackToTop)]
+/// fn back_to_top() -> Html {
+///     let position = use_scroll_position();
+///     if position.y < 200.0 {
+///         return html! {};
+///     }
+///
+///     html! {
+///         <a href="#">{"Back to top"}</a>
+///     }
+/// }
+/// ```
+///
+/// [window]: https://developer.mozilla.org/en-US/docs/Web/API/Document/scroll_event
+#[hook]
+pub fn use_scroll_position() -> ScrollPosition {
+    let position = use_state(ScrollPosition::current);
+
+    {
+        let position = position.clone();
+        use_effect_with_deps(
+            move |_| {
+                let listener = web_sys::window().map(|window| {
+                    EventListener::new(&window, "scroll", move |_| {
+                        position.set(ScrollPosition::current());
+                    })
+                });
+
+                move || drop(listener)
+            },
+            (),
+        );
+    }
+
+    *position
+}
+
+/// Returns the viewport matching the current width of the page.
+///
+/// Listens to the `resize` event of the [browser window][window] and
+/// re-renders the component using it each time the page crosses one of the
+/// [`crate::helpers::visibility::BREAKPOINTS`]. The returned viewport is the
+/// narrowest one matching the width, as returned by
+/// [`Viewport::from_width`].
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{helpers::visibility::Viewport, utils::hooks::use_breakpoint};
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let viewport = use_breakpoint();
+///     if viewport == Viewport::Mobile {
+///         return html! { <p>{"Mobile layout"}</p> };
+///     }
+///
+///     html! {
+///         <p>{ format!("Showing the {viewport} layout.") }</p>
+///     }
+/// }
+/// ```
+///
+/// [window]: https://developer.mozilla.org/en-US/docs/Web/API/Window/resize_event
+#[hook]
+pub fn use_breakpoint() -> Viewport {
+    let viewport = use_state_eq(current_viewport);
+
+    {
+        let viewport = viewport.clone();
+        use_effect_with_deps(
+            move |_| {
+                let listener = web_sys::window().map(|window| {
+                    EventListener::new(&window, "resize", move |_| {
+                        viewport.set(current_viewport());
+                    })
+                });
+
+                move || drop(listener)
+            },
+            (),
+        );
+    }
+
+    (*viewport).clone()
+}
+
+fn current_viewport() -> Viewport {
+    let width = web_sys::window()
+        .and_then(|window| window.inner_width().ok())
+        .and_then(|width| width.as_f64())
+        .unwrap_or_default();
+
+    Viewport::from_width(width as u32)
+}
+
+/// Saves and restores the scroll position of the page for the given key.
+///
+/// Each time the key changes (ie on router navigation, using the current
+/// route or path as key), the scroll position of the previous key is saved
+/// and the one previously saved for the new key is restored. Keys which were
+/// not visited yet scroll the page back to the top. While the hook is in use,
+/// the [scroll restoration][sr] of the browser is set to `manual`, so it does
+/// not interfere.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::hooks::use_scroll_restoration;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct PageProperties {
+///     path: AttrValue,
+/// }
+///
+/// #[function_component(Page)]
+/// fn page(props: &PageProperties) -> Html {
+///     use_scroll_restoration(props.path.to_string());
+///
+///     html! {
+///         <p>{ format!("Currently at {}.", props.path) }</p>
+///     }
+/// }
+/// ```
+///
+/// [sr]: https://developer.mozilla.org/en-US/docs/Web/API/History/scrollRestoration
+#[hook]
+pub fn use_scroll_restoration(key: String) {
+    use_effect_with_deps(
+        |key| {
+            let window = web_sys::window();
+            if let Some(window) = &window {
+                if let Ok(history) = window.history() {
+                    let _ = history.set_scroll_restoration(ScrollRestoration::Manual);
+                }
+
+                let position = SAVED_SCROLL_POSITIONS
+                    .with(|positions| positions.borrow().get(key).copied())
+                    .unwrap_or_default();
+                window.scroll_to_with_x_and_y(position.x, position.y);
+            }
+
+            let key = key.clone();
+            move || {
+                if window.is_some() {
+                    let position = ScrollPosition::current();
+                    SAVED_SCROLL_POSITIONS.with(|positions| {
+                        positions.borrow_mut().insert(key, position);
+                    });
+                }
+            }
+        },
+        key,
+    );
+}
+
+/// Returns the given value once it stopped changing for the given delay.
+///
+/// Returns the last value which was kept unchanged for `delay` milliseconds,
+/// starting with the initial one. Useful for waiting until the user stops
+/// typing before searching or validating.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::hooks::use_debounce;
+///
+/// #[derive(Properties, PartialEq)]
+/// struct ResultsProperties {
+///     query: AttrValue,
+/// }
+///
+/// #[function_component(Results)]
+/// fn results(props: &ResultsProperties) -> Html {
+///     let query = use_debounce(props.query.clone(), 300);
+///
+///     html! {
+///         <p>{ format!("Results for {query}.") }</p>
+///     }
+/// }
+/// ```
+#[hook]
+pub fn use_debounce<T>(value: T, delay: u32) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    let debounced = use_state(|| value.clone());
+
+    {
+        let debounced = debounced.clone();
+        use_effect_with_deps(
+            move |value| {
+                let value = value.clone();
+                let timeout = Timeout::new(delay, move || debounced.set(value));
+
+                move || drop(timeout)
+            },
+            value,
+        );
+    }
+
+    (*debounced).clone()
+}
@@ -52,6 +52,85 @@ pub mod class;
 /// }
 /// ```
 pub mod constants;
+/// Provides utilities for locale-aware number formatting.
+///
+/// Defines functions used to format numbers, amounts of money and
+/// percentages according to a locale, using the [`Intl.NumberFormat`][nf]
+/// API of the browser.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::format::format_currency;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <p>{ format_currency(9.99, "EUR", "fr-FR") }</p>
+///     }
+/// }
+/// ```
+///
+/// [nf]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat
+pub mod format;
+/// Provides [Yew hooks][hooks] shared by the components of this crate.
+///
+/// Defines hooks such as [`crate::utils::hooks::use_scroll_position`], used
+/// to track the scroll position of the page, or
+/// [`crate::utils::hooks::use_scroll_restoration`], used to restore it when
+/// navigating between routes.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::hooks::use_scroll_position;
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     let position = use_scroll_position();
+///     html! {
+///         <p>{ format!("Scrolled {}px from the top.", position.y) }</p>
+///     }
+/// }
+/// ```
+///
+/// [hooks]: https://yew.rs/docs/concepts/function-components/hooks
+pub mod hooks;
+/// Provides the [yew-router][router] integration of this crate.
+///
+/// Defines [`crate::utils::router::RouteTarget`], accepted by the
+/// link-producing components of this crate in place of a raw `href`, to
+/// navigate between routes without a full page load. Only available with the
+/// `router` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{components::dropdown::DropdownItem, utils::router::RouteTarget};
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/settings")]
+///     Settings,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <BrowserRouter>
+///             <DropdownItem to={RouteTarget::new(Route::Settings)}>{"Settings"}</DropdownItem>
+///         </BrowserRouter>
+///     }
+/// }
+/// ```
+///
+/// [router]: https://docs.rs/yew-router
+#[cfg(feature = "router")]
+pub mod router;
 /// Provides utilities for Bulma size-related styling.
 ///
 /// Defines various utilities, such as Bulma common size modifiers (ie for
@@ -76,3 +155,29 @@ pub mod constants;
 /// }
 /// ```
 pub mod size;
+/// Provides utilities for inline CSS style manipulation.
+///
+/// Defines the [`crate::utils::style::StyleBuilder`] struct, used to generate
+/// the [HTML style attribute][style] value of an element, for the styles which
+/// cannot be expressed using the Bulma helpers.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::style::StyleBuilder;
+///
+/// // Create a `<div>` HTML element that has a custom top margin.
+/// #[function_component(SpacedDiv)]
+/// fn spaced_div() -> Html {
+///     let style = StyleBuilder::default()
+///         .with_property("margin-top", "13px")
+///         .build();
+///     html!{
+///         <div {style}>{ "Lorem ispum..." }</div>
+///     }
+/// }
+/// ```
+///
+/// [style]: https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/style
+pub mod style;
@@ -0,0 +1,103 @@
+use std::{
+    fmt::{Debug, Formatter},
+    rc::Rc,
+};
+
+use yew::{AttrValue, Callback, MouseEvent};
+use yew_router::{
+    navigator::{Navigator, NavigatorKind},
+    Routable,
+};
+
+/// A client-side navigation target.
+///
+/// Holds a [`yew_router::Routable`] route, for the link-producing components
+/// of this crate (such as [`crate::components::breadcrumb::Breadcrumb`] or
+/// [`crate::components::navbar::NavbarItem`]) to navigate to without a full
+/// page load, the same way a [`yew_router::prelude::Link`] does. The route
+/// type is erased so that the components themselves do not need to be
+/// generic over it.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::{components::navbar::NavbarItem, utils::router::RouteTarget};
+/// use yew_router::prelude::*;
+///
+/// #[derive(Clone, PartialEq, Routable)]
+/// enum Route {
+///     #[at("/")]
+///     Home,
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///     html! {
+///         <BrowserRouter>
+///             <NavbarItem to={RouteTarget::new(Route::Home)}>{"Home"}</NavbarItem>
+///         </BrowserRouter>
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct RouteTarget {
+    path: AttrValue,
+    push: Rc<dyn Fn(&Navigator)>,
+}
+
+impl RouteTarget {
+    /// Creates a target navigating to the given route.
+    pub fn new<R: Routable + 'static>(route: R) -> Self {
+        Self {
+            path: AttrValue::from(route.to_path()),
+            push: Rc::new(move |navigator| navigator.push(&route)),
+        }
+    }
+
+    /// Returns the path of the route, without the basename of the router.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub(crate) fn href(&self, navigator: &Navigator) -> AttrValue {
+        let path = match navigator.basename() {
+            Some(basename) => format!("{basename}{}", self.path),
+            None => self.path.to_string(),
+        };
+
+        match navigator.kind() {
+            NavigatorKind::Hash => AttrValue::from(format!("#{path}")),
+            _ => AttrValue::from(path),
+        }
+    }
+
+    pub(crate) fn onclick(
+        &self,
+        navigator: Navigator,
+        onclick: Option<Callback<MouseEvent>>,
+    ) -> Callback<MouseEvent> {
+        let push = self.push.clone();
+        Callback::from(move |event: MouseEvent| {
+            event.prevent_default();
+            if let Some(onclick) = &onclick {
+                onclick.emit(event);
+            }
+            push(&navigator);
+        })
+    }
+}
+
+impl Debug for RouteTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RouteTarget")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+impl PartialEq for RouteTarget {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+
+/// Inline CSS style builder.
+///
+/// Used to build the value of the [HTML style attribute][style] of an element,
+/// for the values which cannot be expressed using the [Bulma helpers][bd]
+/// (ie a spacing value outside of the Bulma scale). Prefer the
+/// [`crate::utils::class::ClassBuilder`] whenever a Bulma class exists.
+///
+/// # Examples
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_and_bulma::utils::style::StyleBuilder;
+///
+/// // Create a `<div>` HTML element that has a custom top margin.
+/// #[function_component(SpacedDiv)]
+/// fn spaced_div() -> Html {
+///     let style = StyleBuilder::default()
+///         .with_property("margin-top", "13px")
+///         .build();
+///     html!{
+///         <div {style}>{ "Lorem ispum..." }</div>
+///     }
+/// }
+/// ```
+///
+/// [style]: https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/style
+/// [bd]: https://bulma.io/documentation/helpers/
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StyleBuilder {
+    properties: BTreeMap<String, String>,
+}
+
+impl StyleBuilder {
+    /// Set a CSS property to the given value.
+    ///
+    /// Set the value of a CSS property (ie `margin-top`), which will be added
+    /// to the style created by the builder. Neither the property nor the value
+    /// are validated. Every call to this method for the same property
+    /// overrides the previous value to the one received.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::utils::style::StyleBuilder;
+    ///
+    /// // Create a `<div>` HTML element that has a custom padding.
+    /// #[function_component(PaddedDiv)]
+    /// fn padded_div() -> Html {
+    ///     let style = StyleBuilder::default()
+    ///         .with_property("padding", "0.4rem 2.2rem")
+    ///         .build();
+    ///     html!{
+    ///         <div {style}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    pub fn with_property(mut self, property: &str, value: &str) -> Self {
+        self.properties
+            .insert(property.trim().to_owned(), value.trim().to_owned());
+        self
+    }
+
+    /// Remove a CSS property from the builder.
+    ///
+    /// Remove a previously set CSS property from the style created by the
+    /// builder. If the property was not previously set, nothing happens.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_and_bulma::utils::style::StyleBuilder;
+    ///
+    /// // Create a `<div>` HTML element that has no custom margin.
+    /// #[function_component(UnspacedDiv)]
+    /// fn unspaced_div() -> Html {
+    ///     let style = StyleBuilder::default()
+    ///         .with_property("margin-top", "13px")
+    ///         .without_property("margin-top")
+    ///         .build();
+    ///     html!{
+    ///         <div {style}>{ "Lorem ispum..." }</div>
+    ///     }
+    /// }
+    /// ```
+    pub fn without_property(mut self, property: &str) -> Self {
+        self.properties.remove(property.trim());
+        self
+    }
+
+    /// Merge another builder into the current one.
+    ///
+    /// Add the properties of another builder to the current one, the values
+    /// of the other builder taking precedence for properties set in both.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_and_bulma::utils::style::StyleBuilder;
+    ///
+    /// let style = StyleBuilder::default()
+    ///     .with_property("margin-top", "13px")
+    ///     .merge(StyleBuilder::default().with_property("margin-top", "17px"))
+    ///     .build();
+    ///
+    /// assert_eq!(style, Some("margin-top: 17px;".to_owned()));
+    /// ```
+    pub fn merge(mut self, other: StyleBuilder) -> Self {
+        self.properties.extend(other.properties);
+        self
+    }
+
+    /// Create the value of the style attribute from the current configuration.
+    ///
+    /// Using the set properties of the builder, create the value of the
+    /// [HTML style attribute][style], sorted by property name. This consumes
+    /// the builder. If no properties were set, `None` is returned, so that the
+    /// attribute is not rendered at all.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use yew_and_bulma::utils::style::StyleBuilder;
+    ///
+    /// let style = StyleBuilder::default()
+    ///     .with_property("padding-left", "7px")
+    ///     .with_property("margin-top", "13px")
+    ///     .build();
+    ///
+    /// assert_eq!(style, Some("margin-top: 13px; padding-left: 7px;".to_owned()));
+    /// ```
+    ///
+    /// [style]: https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes/style
+    pub fn build(self) -> Option<String> {
+        (!self.properties.is_empty()).then(|| {
+            self.properties
+                .iter()
+                .map(|(property, value)| format!("{property}: {value};"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn style_builder_default_success() {
+        let style_builder = StyleBuilder::default();
+
+        assert!(style_builder.properties.is_empty());
+        assert_eq!(style_builder.build(), None);
+    }
+
+    #[test]
+    fn style_builder_with_property() {
+        let style = StyleBuilder::default()
+            .with_property(" margin-top ", " 13px ")
+            .with_property("margin-bottom", "7px")
+            .build();
+
+        assert_eq!(
+            style,
+            Some("margin-bottom: 7px; margin-top: 13px;".to_owned())
+        );
+    }
+
+    #[test]
+    fn style_builder_with_property_overrides() {
+        let style = StyleBuilder::default()
+            .with_property("margin-top", "13px")
+            .with_property("margin-top", "17px")
+            .build();
+
+        assert_eq!(style, Some("margin-top: 17px;".to_owned()));
+    }
+
+    #[test]
+    fn style_builder_without_property() {
+        let style = StyleBuilder::default()
+            .with_property("margin-top", "13px")
+            .with_property("margin-bottom", "7px")
+            .without_property("margin-top")
+            .build();
+
+        assert_eq!(style, Some("margin-bottom: 7px;".to_owned()));
+    }
+
+    #[test]
+    fn style_builder_merge() {
+        let style = StyleBuilder::default()
+            .with_property("margin-top", "13px")
+            .with_property("padding", "1px")
+            .merge(StyleBuilder::default().with_property("margin-top", "17px"))
+            .build();
+
+        assert_eq!(style, Some("margin-top: 17px; padding: 1px;".to_owned()));
+    }
+}